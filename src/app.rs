@@ -1,8 +1,17 @@
-use crate::content::ContentError;
+use crate::config::{Config, ReaderConfig};
+use crate::content::{ContentError, SavedPage};
 use crate::feed::DiscoveredFeed;
 use crate::keybindings::KeybindingRegistry;
-use crate::storage::{Article, Database, Feed, FeedCategory, SearchScope};
+use crate::preferences::PreferenceManager;
+use crate::rewrite::RewriteSet;
+use crate::storage::{
+    Article, ArticleRevision, CachePolicy, Database, Feed, FeedCategory, FeedHealth, SearchScope,
+    TagCount, ARCHIVED_TAG, STARRED_TAG,
+};
 use crate::theme::{StyleMap, ThemeVariant};
+use crate::ui::reader::{find_matches, CodeLine, LinkSpan, MarkdownLink, SearchMatch};
+use crate::ui::Hyperlink;
+use crate::util::{normalize_tag, reading_minutes, WORDS_PER_MINUTE};
 use anyhow::Result;
 use ratatui::style::Style;
 use ratatui::text::Line;
@@ -16,6 +25,17 @@ use unicode_width::UnicodeWidthStr;
 /// Maximum scroll offset for the reader view (ratatui u16 limit).
 pub const MAX_SCROLL: usize = u16::MAX as usize;
 
+/// Limits, in minutes, the article list's reading-time filter steps through.
+pub const READING_TIME_FILTERS: [usize; 3] = [5, 10, 20];
+
+/// The article list narrowed to articles read within `max_minutes`.
+#[derive(Clone)]
+pub struct ReadingTimeFilter {
+    pub max_minutes: usize,
+    /// The list before filtering, shown again when the filter comes off.
+    all: Arc<Vec<Article>>,
+}
+
 // ============================================================================
 // Session Snapshot
 // ============================================================================
@@ -26,7 +46,8 @@ pub const MAX_SCROLL: usize = u16::MAX as usize;
 /// if new variants are added, old snapshots with unknown strings will use
 /// `#[serde(default)]` fallbacks instead of failing to deserialize.
 ///
-/// Always restores to Browse view — Reader view state is transient.
+/// Restores to Browse view; the article open in the reader is recorded so
+/// startup can reopen it, at its saved reading position.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 #[allow(dead_code)] // Used by TASK-13 (session restore)
@@ -39,6 +60,8 @@ pub struct SessionSnapshot {
     pub selected_article: usize,
     /// Scroll offset in reader/article list.
     pub scroll_offset: usize,
+    /// Article open in the reader at exit (never a linked page).
+    pub reader_article: Option<i64>,
 }
 
 impl Default for SessionSnapshot {
@@ -48,6 +71,7 @@ impl Default for SessionSnapshot {
             selected_feed: 0,
             selected_article: 0,
             scroll_offset: 0,
+            reader_article: None,
         }
     }
 }
@@ -63,7 +87,6 @@ impl Default for SessionSnapshot {
 /// once the DB confirms the insert via `AppEvent::ReadingSessionOpened`.
 pub struct ReadingSession {
     pub history_id: i64,
-    #[allow(dead_code)] // Used by TASK-8 (reading stats panel) for session context
     pub article_id: i64,
     pub started_at: std::time::Instant,
 }
@@ -142,6 +165,21 @@ fn create_redirect_policy() -> Policy {
     })
 }
 
+/// Build the client for article content and saved pages.
+///
+/// PERF-019: connection pooling and keepalive, with `create_redirect_policy`
+/// and a 30s default request timeout. Shared by the TUI and `skim save`.
+pub fn create_content_client() -> reqwest::Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .redirect(create_redirect_policy())
+        .pool_max_idle_per_host(4) // P-7: 4 idle conns per host improves throughput for domain-heavy reading
+        .pool_idle_timeout(std::time::Duration::from_secs(30)) // Close idle connections promptly
+        .tcp_keepalive(std::time::Duration::from_secs(60)) // TCP keepalive probes
+        .timeout(std::time::Duration::from_secs(30)) // Default request timeout
+        // TODO: TASK-10: .http2_adaptive_window(true) method not available in reqwest 0.13
+        .build()
+}
+
 // ============================================================================
 // View and Focus Enums
 // ============================================================================
@@ -152,6 +190,8 @@ pub enum View {
     Browse, // Side-by-side feeds/articles
     Reader, // Full-screen article reader
     Stats,  // Reading statistics panel
+    Health, // Feed health dashboard
+    Cache,  // Offline cache manager
 }
 
 /// Aggregated stats for multiple time windows.
@@ -161,6 +201,20 @@ pub struct StatsData {
     pub today: crate::storage::ReadingStats,
     pub week: crate::storage::ReadingStats,
     pub month: crate::storage::ReadingStats,
+    pub cache: crate::storage::CacheStats,
+    /// Reading speed measured from the history, if there is enough of it.
+    pub measured_speed: Option<usize>,
+}
+
+/// Offline cache usage for the cache manager.
+///
+/// Loaded asynchronously when entering `View::Cache` and after each purge.
+pub struct CacheOverview {
+    pub stats: crate::storage::CacheStats,
+    /// Entries taking the most space on disk, largest first.
+    pub largest: Vec<crate::storage::CacheEntry>,
+    /// Per-feed usage, largest first; the selectable rows of the view.
+    pub feeds: Vec<crate::storage::FeedCacheUsage>,
 }
 
 /// Which panel has focus in Browse view
@@ -202,7 +256,8 @@ pub struct CategoryTreeItem {
 /// Note: `article_id` fields in Loading/Loaded/Failed variants are stored but
 /// not read by rendering logic (ui/reader.rs uses `..` to ignore them).
 /// Retained for: Debug trait output, future validation, tracing.
-/// The `content` field in Loaded is also stored but only `rendered_lines` is read.
+/// The `content` field in Loaded is kept for re-wrapping on resize; `links`
+/// are the numbered links in `rendered_lines`.
 /// Annotation retained because: variant fields are part of enum definition.
 ///
 /// PERF-010: `fallback` uses `Arc<str>` for cheap cloning from Article.summary.
@@ -217,6 +272,11 @@ pub enum ContentState {
         article_id: i64,
         content: String,
         rendered_lines: Vec<Line<'static>>, // PERF-004: Cached render
+        links: Vec<MarkdownLink>,
+        /// Where the links appear in `rendered_lines`.
+        link_spans: Vec<LinkSpan>,
+        /// Code block lines in `rendered_lines`, which aren't wrapped.
+        code_lines: Vec<CodeLine>,
     },
     Failed {
         article_id: i64,
@@ -254,6 +314,9 @@ pub const CONTEXT_MENU_ITEMS: &[&str] = &[
     "Delete",
     "Refresh",
     "Open in Browser",
+    "Toggle Preferred Source",
+    "Toggle Unread on Update",
+    "Toggle Auto Prefetch",
 ];
 
 /// State for the feed context menu popup.
@@ -277,6 +340,167 @@ pub enum ContextMenuSubState {
     CategoryPicker { selected: usize },
 }
 
+// ============================================================================
+// Starred Mode Views
+// ============================================================================
+
+/// Cross-feed article list shown in starred mode.
+///
+/// All of them show feed prefixes and restore the feed's article list on exit.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum StarredView {
+    /// Starred articles (the built-in starred tag).
+    #[default]
+    Starred,
+    /// Articles carrying a user or rule tag.
+    Tag(String),
+    /// The read-later queue, in queue order.
+    ReadLater,
+    /// Articles left part-way in the reader, most recently opened first.
+    ContinueReading,
+}
+
+// ============================================================================
+// Reader Pages
+// ============================================================================
+
+/// A page shown in the reader: an article, or a page reached through one of
+/// its links, kept on the back/forward history stacks.
+pub struct ReaderPage {
+    pub article: Article,
+    pub content: ContentState,
+    pub scroll_offset: usize,
+}
+
+/// Whether a reader article is a linked page rather than a stored article.
+///
+/// Linked pages get negative IDs (see `App::open_link_page`); they are not
+/// in the database, so starring, tagging and caching don't apply to them.
+pub fn is_link_page(article: &Article) -> bool {
+    article.id < 0
+}
+
+/// State for the numbered-link picker over the reader.
+#[derive(Debug, Default)]
+pub struct LinkPickerState {
+    /// Index into the loaded content's links (link number - 1).
+    pub selected: usize,
+    /// Link number typed so far.
+    pub input: String,
+}
+
+impl LinkPickerState {
+    /// Add a typed digit and move to that link number when it exists.
+    pub fn push_digit(&mut self, digit: char, link_count: usize) {
+        let number = |input: &str| {
+            input
+                .parse::<usize>()
+                .ok()
+                .filter(|n| (1..=link_count).contains(n))
+        };
+        self.input.push(digit);
+        if number(&self.input).is_none() {
+            // Typing past the last link starts a new number
+            self.input = digit.to_string();
+        }
+        if let Some(n) = number(&self.input) {
+            self.selected = n - 1;
+        }
+    }
+}
+
+/// In-article search in the reader (`/`).
+#[derive(Debug, Default)]
+pub struct ReaderSearchState {
+    pub query: String,
+    /// Still typing the query: keys go to the search prompt.
+    pub editing: bool,
+    /// Matches in the rendered content, in reading order.
+    pub matches: Vec<SearchMatch>,
+    /// Index of the match jumped to last.
+    pub current: usize,
+    /// Scroll position when the search started, restored on cancel.
+    pub origin: usize,
+}
+
+// ============================================================================
+// Tag Picker State
+// ============================================================================
+
+/// State for the tag picker popup over the article list or reader.
+pub struct TagPickerState {
+    pub article_id: i64,
+    pub article_title: String,
+    /// Tags on the article, updated optimistically as the user toggles them.
+    pub tags: Vec<String>,
+    pub starred: bool,
+    /// Filter text; also the name of a new tag when nothing matches exactly.
+    pub input: String,
+    pub selected: usize,
+}
+
+/// One row of the tag picker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagOption {
+    pub tag: String,
+    pub checked: bool,
+    /// Not used anywhere yet; choosing it creates the tag.
+    pub new: bool,
+}
+
+impl TagPickerState {
+    pub fn new(article: &Article) -> Self {
+        Self {
+            article_id: article.id,
+            article_title: article.title.to_string(),
+            tags: article.tags.iter().map(|t| t.to_string()).collect(),
+            starred: article.starred,
+            input: String::new(),
+            selected: 0,
+        }
+    }
+
+    /// Rows matching the input: known tags (starred first), then the typed
+    /// name as a new tag if it isn't one of them. The archived tag is left
+    /// out; archiving has its own key.
+    pub fn options(&self, known: &[TagCount]) -> Vec<TagOption> {
+        let filter = self.input.trim().to_lowercase();
+        let mut names: Vec<&str> = known
+            .iter()
+            .map(|t| t.tag.as_str())
+            .filter(|&t| t != ARCHIVED_TAG)
+            .collect();
+        for tag in &self.tags {
+            if !names.contains(&tag.as_str()) {
+                names.push(tag);
+            }
+        }
+        let mut options: Vec<TagOption> = names
+            .iter()
+            .filter(|name| name.contains(filter.trim_start_matches('#')))
+            .map(|&name| TagOption {
+                tag: name.to_string(),
+                checked: if name == STARRED_TAG {
+                    self.starred
+                } else {
+                    self.tags.iter().any(|t| t == name)
+                },
+                new: false,
+            })
+            .collect();
+        if let Some(tag) = normalize_tag(&self.input) {
+            if !names.contains(&tag.as_str()) && tag != ARCHIVED_TAG {
+                options.push(TagOption {
+                    tag,
+                    checked: false,
+                    new: true,
+                });
+            }
+        }
+        options
+    }
+}
+
 // ============================================================================
 // Subscribe Dialog State
 // ============================================================================
@@ -353,9 +577,11 @@ pub enum AppEvent {
     /// Fields:
     /// - `feed_id`: The feed whose articles were marked read, or None for all feeds
     /// - `count`: Number of articles actually marked as read
+    /// - `copy_feeds`: Feeds of duplicate copies marked along, once per copy
     BulkMarkReadComplete {
         feed_id: Option<i64>,
         count: u64,
+        copy_feeds: Vec<i64>,
     },
     /// Bulk mark-read operation failed.
     ///
@@ -457,6 +683,101 @@ pub enum AppEvent {
     CachedIdsLoaded(HashSet<i64>),
     /// Reading stats loaded for the stats panel.
     StatsLoaded(StatsData),
+    /// Feed health rows loaded for the health view.
+    FeedHealthLoaded(Vec<FeedHealth>),
+    /// Cache usage loaded for the cache manager.
+    CacheOverviewLoaded(CacheOverview),
+    /// A cache purge finished with the number of entries removed.
+    CachePurged {
+        result: Result<u64, String>,
+    },
+    /// Feed URL rediscovery finished. `Ok(Some(url))` when the feed moved to
+    /// `url`, `Ok(None)` when discovery found the URL already in use.
+    FeedRediscovered {
+        feed_id: i64,
+        title: String,
+        result: Result<Option<String>, String>,
+    },
+    /// Feed's preferred-source flag for cross-feed duplicates saved.
+    FeedPreferSourceSet {
+        feed_id: i64,
+        title: String,
+        result: Result<bool, String>,
+    },
+    /// Feed's unread-on-update flag for edited articles saved.
+    FeedUnreadOnUpdateSet {
+        feed_id: i64,
+        title: String,
+        result: Result<bool, String>,
+    },
+    /// Feed's auto-prefetch-after-refresh flag saved.
+    FeedAutoPrefetchSet {
+        feed_id: i64,
+        title: String,
+        result: Result<bool, String>,
+    },
+    /// Earlier versions of the article in the reader, with its current cached
+    /// content (if any) to diff content revisions against.
+    RevisionsLoaded {
+        article_id: i64,
+        result: Result<(Vec<ArticleRevision>, Option<String>), String>,
+    },
+    /// Other feeds carrying a copy of the article just opened in the reader.
+    DuplicateSourcesLoaded {
+        article_id: i64,
+        sources: Vec<String>,
+    },
+    /// A tag was added to or removed from an article; `Ok` holds its tags afterwards.
+    ArticleTagsChanged {
+        article_id: i64,
+        tag: String,
+        result: Result<Vec<String>, String>,
+    },
+    /// Tag counts for the categories sidebar and tag picker.
+    TagsLoaded(Vec<TagCount>),
+    /// Article added to (`Ok(true)`) or removed from the read-later queue.
+    ReadLaterToggled {
+        article_id: i64,
+        result: Result<bool, String>,
+    },
+    /// Article archived (`Ok(true)`) or taken out of the archive; on error
+    /// the flag goes back to `was_archived`.
+    ArchiveToggled {
+        article_id: i64,
+        was_archived: bool,
+        result: Result<bool, String>,
+    },
+    /// Content of a newly queued, starred or archived article saved for
+    /// offline reading.
+    OfflineCopyCached {
+        article_id: i64,
+        result: Result<(), String>,
+    },
+    /// A queued article was read to the end and left the queue.
+    ReadLaterFinished {
+        article_id: i64,
+    },
+    /// Reordering the read-later queue failed; the view is reloaded.
+    ReadLaterMoveFailed {
+        error: String,
+    },
+    /// Queued article IDs and total word count, after the queue changed.
+    ReadLaterLoaded {
+        ids: Vec<i64>,
+        words: usize,
+    },
+    /// A page from the "Save URL" dialog was fetched and stored (or failed).
+    UrlSaved {
+        url: String,
+        result: Result<SavedPage, String>,
+    },
+    /// TL;DR of an article whose content was shown in the reader.
+    TldrLoaded {
+        article_id: i64,
+        sentences: Vec<String>,
+    },
+    /// Stored TL;DRs of the listed articles, for the list preview.
+    TldrsLoaded(HashMap<i64, Vec<String>>),
 }
 
 // ============================================================================
@@ -467,6 +788,18 @@ pub enum AppEvent {
 pub struct App {
     pub db: Database,
     pub http_client: reqwest::Client,
+    /// Client for feed refreshes: redirects are followed manually by the fetcher
+    /// so permanent moves (301/308) can update the stored feed URL.
+    pub feed_client: reqwest::Client,
+
+    /// Loaded config.toml (defaults until `main` loads the file).
+    pub config: Config,
+    /// Compiled `[[rewrites]]` from `config`, applied to article content on load.
+    pub rewrites: Arc<RewriteSet>,
+    /// `[cache]` settings from `config`: content TTLs and size budget.
+    pub cache_policy: Arc<CachePolicy>,
+    /// Config defaults merged with preferences saved in the DB.
+    pub prefs: PreferenceManager,
 
     // Theme
     /// Current theme variant (for cycling).
@@ -515,6 +848,15 @@ pub struct App {
     // Content loading
     pub content_state: ContentState,
     pub reader_article: Option<Article>, // The article currently being read
+    /// Article content set aside while the reader shows the revision diff.
+    /// `Some` means the diff is on screen; restored when the diff is toggled off.
+    pub reader_saved_content: Option<ContentState>,
+    /// Pages left by following links, most recent last.
+    pub reader_back: Vec<ReaderPage>,
+    /// Pages left by going back, most recent last. Cleared by following a link.
+    pub reader_forward: Vec<ReaderPage>,
+    /// ID for the next linked page; counts down from -1.
+    pub next_link_page_id: i64,
 
     /// Active reading session for duration tracking.
     ///
@@ -526,6 +868,17 @@ pub struct App {
     /// None when stats are loading or not yet requested.
     pub stats_data: Option<StatsData>,
 
+    /// Feed health rows for View::Health, sorted by severity group.
+    /// None while loading.
+    pub feed_health: Option<Vec<FeedHealth>>,
+    /// Selected row in the feed health view.
+    pub feed_health_selected: usize,
+
+    /// Cache usage for View::Cache. None while loading.
+    pub cache_overview: Option<CacheOverview>,
+    /// Selected feed row in the cache manager.
+    pub cache_selected: usize,
+
     // Refresh progress
     pub refresh_progress: Option<(usize, usize)>,
 
@@ -544,6 +897,26 @@ pub struct App {
 
     // Starred articles view mode
     pub starred_mode: bool,
+    /// Which cross-feed list starred mode shows.
+    pub starred_view: StarredView,
+
+    // Tag state
+    /// Tags in use with their counts, starred first (see `get_tag_counts`).
+    pub tags: Arc<Vec<TagCount>>,
+    // Read-later queue
+    /// IDs of queued articles, for list indicators and finish detection.
+    pub read_later_ids: HashSet<i64>,
+    /// Estimated minutes to read the whole queue, shown in the read-later view.
+    pub read_later_minutes: usize,
+    /// Reading speed for time estimates: measured from the reading history,
+    /// or the default until there is enough of it.
+    pub words_per_minute: usize,
+    /// Reading-time filter on the article list, cleared when another list
+    /// is shown.
+    pub reading_time_filter: Option<ReadingTimeFilter>,
+    /// Selected row in the sidebar's tag section. `Some` moves the category
+    /// cursor below the tree into the tags.
+    pub selected_tag: Option<usize>,
 
     // Category state
     /// All categories loaded from DB, ordered by sort_order then name.
@@ -614,12 +987,42 @@ pub struct App {
     /// Does not include border lines (2 lines for top/bottom borders).
     pub reader_visible_lines: usize,
 
+    /// Rows taken by the reader header (wrapped title, feed/time, blank
+    /// line) in the last render.
+    pub reader_header_lines: usize,
+
     /// Last known reader viewport width (characters).
     ///
     /// Updated during reader rendering to enable accurate wrapped line count calculation.
     /// Does not include border characters (2 chars for left/right borders).
     pub reader_viewport_width: usize,
 
+    /// Width the loaded article content was wrapped to by `render_markdown`.
+    ///
+    /// The reader re-renders the content when the viewport width differs.
+    pub reader_wrap_width: usize,
+
+    /// Columns code block lines are scrolled to the right in the reader.
+    pub reader_code_scroll: usize,
+
+    /// Text column width, justification and pagination of the reader, and
+    /// how TL;DRs are shown.
+    pub reader_layout: ReaderConfig,
+
+    /// TL;DR of the article in the reader, by article ID (empty when it is
+    /// too short for one).
+    pub reader_tldr: Option<(i64, Vec<String>)>,
+
+    /// Stored TL;DRs of listed articles, for the preview under the selection.
+    pub tldr_previews: HashMap<i64, Vec<String>>,
+
+    /// Saved reading position (article ID, fraction of its length) to
+    /// scroll to once the article's content is shown.
+    pub reader_resume: Option<(i64, f64)>,
+
+    /// Reading positions of the articles in the "Continue reading" list.
+    pub reading_positions: HashMap<i64, f64>,
+
     /// Current frame of the loading spinner animation (0-9).
     ///
     /// Incremented by the tick handler when content is loading.
@@ -660,23 +1063,53 @@ pub struct App {
     /// When set, the UI renders a context menu overlay and input is routed
     /// to the context menu handler instead of normal dispatch.
     pub context_menu: Option<ContextMenuState>,
+
+    /// Tag picker for the selected or open article.
+    ///
+    /// When set, the UI renders the picker overlay and input is routed
+    /// to the tag picker handler.
+    pub tag_picker: Option<TagPickerState>,
+
+    /// URL typed into the "Save URL" dialog.
+    ///
+    /// When set, the UI renders the dialog and input is routed to it.
+    pub save_url_input: Option<String>,
+
+    /// Link picker over the reader.
+    ///
+    /// When set, the UI renders the picker overlay and input is routed
+    /// to the link picker handler.
+    pub link_picker: Option<LinkPickerState>,
+
+    /// In-article search of the reader, with its highlighted matches.
+    pub reader_search: Option<ReaderSearchState>,
+
+    /// Whether to emit OSC 8 hyperlinks (the `hyperlinks` config key).
+    pub hyperlinks_enabled: bool,
+    /// Clickable regions of the last rendered frame, made into hyperlinks
+    /// once it is drawn.
+    pub hyperlinks: Vec<Hyperlink>,
 }
 
 impl App {
     pub fn new(db: Database) -> Result<Self> {
-        // PERF-019: Configure HTTP client with connection pooling and keepalive
-        let http_client = reqwest::Client::builder()
-            .redirect(create_redirect_policy())
-            .pool_max_idle_per_host(4) // P-7: 4 idle conns per host improves throughput for domain-heavy reading
-            .pool_idle_timeout(std::time::Duration::from_secs(30)) // Close idle connections promptly
-            .tcp_keepalive(std::time::Duration::from_secs(60)) // TCP keepalive probes
-            .timeout(std::time::Duration::from_secs(30)) // Default request timeout
-            // TODO: TASK-10: .http2_adaptive_window(true) method not available in reqwest 0.13
+        let http_client = create_content_client()?;
+        let feed_client = reqwest::Client::builder()
+            .redirect(Policy::none())
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(std::time::Duration::from_secs(30))
+            .tcp_keepalive(std::time::Duration::from_secs(60))
+            .timeout(std::time::Duration::from_secs(30))
             .build()?;
 
         Ok(Self {
             db,
             http_client,
+            feed_client,
+            config: Config::default(),
+            rewrites: Arc::default(),
+            cache_policy: Arc::default(),
+            prefs: PreferenceManager::from_config(&Config::default()),
             theme_variant: ThemeVariant::Dark,
             theme: StyleMap::from_palette(&ThemeVariant::Dark.palette()),
             keybindings: KeybindingRegistry::new(),
@@ -696,8 +1129,16 @@ impl App {
             search_scope: SearchScope::default(),
             content_state: ContentState::Idle,
             reader_article: None,
+            reader_saved_content: None,
+            reader_back: Vec::new(),
+            reader_forward: Vec::new(),
+            next_link_page_id: -1,
             reading_session: None,
             stats_data: None,
+            feed_health: None,
+            feed_health_selected: 0,
+            cache_overview: None,
+            cache_selected: 0,
             refresh_progress: None,
             status_message: None,
             whats_new: Vec::new(),
@@ -705,6 +1146,13 @@ impl App {
             show_whats_new: false,
             feed_title_cache: HashMap::new(),
             starred_mode: false,
+            starred_view: StarredView::Starred,
+            tags: Arc::new(Vec::new()),
+            selected_tag: None,
+            read_later_ids: HashSet::new(),
+            read_later_minutes: 0,
+            words_per_minute: WORDS_PER_MINUTE,
+            reading_time_filter: None,
             categories: Arc::new(Vec::new()),
             selected_category: None,
             show_categories: false,
@@ -721,7 +1169,15 @@ impl App {
             search_generation: 0,
             search_handle: None,
             reader_visible_lines: 0,
+            reader_header_lines: 3,
             reader_viewport_width: 0,
+            reader_wrap_width: 0,
+            reader_code_scroll: 0,
+            reader_layout: ReaderConfig::default(),
+            reader_tldr: None,
+            tldr_previews: HashMap::new(),
+            reader_resume: None,
+            reading_positions: HashMap::new(),
             spinner_frame: 0,
             reader_cached_line_count: None,
             cached_category_tree: None,
@@ -730,6 +1186,12 @@ impl App {
             pending_confirm: None,
             subscribe_state: None,
             context_menu: None,
+            tag_picker: None,
+            save_url_input: None,
+            link_picker: None,
+            reader_search: None,
+            hyperlinks_enabled: false,
+            hyperlinks: Vec::new(),
         })
     }
 
@@ -856,6 +1318,155 @@ impl App {
         }
     }
 
+    /// Take one unread article off the count of each listed feed, as
+    /// returned by `mark_article_read` for the copies it marked.
+    pub fn count_read_in_feeds(&mut self, feed_ids: &[i64]) {
+        if feed_ids.is_empty() {
+            return;
+        }
+        let feeds = Arc::make_mut(&mut self.feeds);
+        for feed_id in feed_ids {
+            if let Some(feed) = feeds.iter_mut().find(|f| f.id == *feed_id) {
+                feed.unread_count = (feed.unread_count - 1).max(0);
+            }
+        }
+        self.invalidate_category_tree(); // PERF-021: Unread counts changed
+        self.needs_redraw = true;
+    }
+
+    /// Estimated minutes to read an article, if its length is known.
+    pub fn reading_minutes(&self, article: &Article) -> Option<usize> {
+        article
+            .word_count
+            .filter(|&words| words > 0)
+            .map(|words| reading_minutes(words, self.words_per_minute))
+    }
+
+    /// Step the article list's reading-time filter to the next limit in
+    /// [`READING_TIME_FILTERS`], or off after the last. Returns the new limit.
+    ///
+    /// Articles of unknown length are filtered out. The selection stays on
+    /// the same article when it is still listed.
+    pub fn cycle_reading_time_filter(&mut self) -> Option<usize> {
+        let next = match &self.reading_time_filter {
+            None => READING_TIME_FILTERS.first().copied(),
+            Some(filter) => READING_TIME_FILTERS
+                .iter()
+                .copied()
+                .find(|&m| m > filter.max_minutes),
+        };
+        let mut all = match self.reading_time_filter.take() {
+            Some(filter) => filter.all,
+            None => Arc::clone(&self.articles),
+        };
+        // Carry changes made while filtered (read, starred...) back
+        let shown: HashMap<i64, &Article> = self.articles.iter().map(|a| (a.id, a)).collect();
+        if !shown.is_empty() {
+            for article in Arc::make_mut(&mut all).iter_mut() {
+                if let Some(&current) = shown.get(&article.id) {
+                    *article = current.clone();
+                }
+            }
+        }
+
+        let selected_id = self.articles.get(self.selected_article).map(|a| a.id);
+        self.articles = match next {
+            Some(max_minutes) => {
+                let filtered = all
+                    .iter()
+                    .filter(|a| self.reading_minutes(a).is_some_and(|m| m <= max_minutes))
+                    .cloned()
+                    .collect();
+                self.reading_time_filter = Some(ReadingTimeFilter { max_minutes, all });
+                Arc::new(filtered)
+            }
+            None => all,
+        };
+        self.selected_article = selected_id
+            .and_then(|id| self.articles.iter().position(|a| a.id == id))
+            .unwrap_or(0);
+        self.clamp_selections();
+        self.needs_redraw = true;
+        next
+    }
+
+    /// Articles of the list the reader was opened from (What's New or the
+    /// article list) and the position of the one being read.
+    ///
+    /// The list selection stands in while a linked page is shown.
+    pub fn reader_list(&self) -> (Vec<i64>, usize) {
+        let (ids, selected): (Vec<i64>, _) = if self.focus == Focus::WhatsNew {
+            let ids = self.whats_new.iter().map(|e| e.article_id).collect();
+            (ids, self.whats_new_selected)
+        } else {
+            let ids = self.articles.iter().map(|a| a.id).collect();
+            (ids, self.selected_article)
+        };
+        let current = self
+            .reader_article
+            .as_ref()
+            .and_then(|article| ids.iter().position(|&id| id == article.id))
+            .unwrap_or(selected);
+        (ids, current)
+    }
+
+    /// Position in [`App::reader_list`] of the article to step to from the
+    /// reader: the next or previous one, or with `unread_only` the next
+    /// unread one. `None` when there is none.
+    ///
+    /// What's New entries don't carry the read flag, so there `unread_ids`
+    /// says which are unread; the article list goes by its own flags.
+    pub fn reader_step_target(
+        &self,
+        forward: bool,
+        unread_only: bool,
+        unread_ids: &HashSet<i64>,
+    ) -> Option<usize> {
+        let (ids, current) = self.reader_list();
+        let is_unread = |index: usize| {
+            if self.focus == Focus::WhatsNew {
+                unread_ids.contains(&ids[index])
+            } else {
+                !self.articles[index].read
+            }
+        };
+        if forward {
+            (current + 1..ids.len()).find(|&i| !unread_only || is_unread(i))
+        } else {
+            (0..current.min(ids.len()))
+                .rev()
+                .find(|&i| !unread_only || is_unread(i))
+        }
+    }
+
+    /// The article list without the reading-time filter.
+    pub fn unfiltered_articles(&self) -> Arc<Vec<Article>> {
+        match &self.reading_time_filter {
+            Some(filter) => Arc::clone(&filter.all),
+            None => Arc::clone(&self.articles),
+        }
+    }
+
+    /// Show a reloaded copy of the current article list, narrowed by the
+    /// reading-time filter if one is on.
+    pub fn reload_articles(&mut self, articles: Vec<Article>) {
+        let articles = Arc::new(articles);
+        self.articles = match self.reading_time_filter.as_mut() {
+            Some(filter) => {
+                let max_minutes = filter.max_minutes;
+                filter.all = Arc::clone(&articles);
+                Arc::new(
+                    articles
+                        .iter()
+                        .filter(|a| self.reading_minutes(a).is_some_and(|m| m <= max_minutes))
+                        .cloned()
+                        .collect(),
+                )
+            }
+            None => articles,
+        };
+    }
+
     /// BUG-004: Clamp all selection indices to valid ranges.
     ///
     /// Call this after any operation that may invalidate selection indices,
@@ -879,6 +1490,14 @@ impl App {
             self.whats_new_selected
                 .min(self.whats_new.len().saturating_sub(1))
         };
+        // Clamp tag selection (the tag list can shrink as tags go out of use)
+        if let Some(idx) = self.selected_tag {
+            self.selected_tag = if self.tags.is_empty() {
+                None
+            } else {
+                Some(idx.min(self.tags.len() - 1))
+            };
+        }
         // Clamp category selection
         if let Some(idx) = self.selected_category {
             if self.categories.is_empty() {
@@ -938,6 +1557,11 @@ impl App {
         self.whats_new.get(self.whats_new_selected)
     }
 
+    /// Tag under the cursor in the sidebar's tag section, if the cursor is there.
+    pub fn selected_tag(&self) -> Option<&TagCount> {
+        self.selected_tag.and_then(|idx| self.tags.get(idx))
+    }
+
     /// Get the category ID for the currently selected category, or None for "All".
     pub fn selected_category_id(&self) -> Option<i64> {
         self.selected_category
@@ -1107,6 +1731,11 @@ impl App {
                 self.whats_new_selected = self.whats_new_selected.saturating_sub(1);
             }
             Focus::Categories => {
+                // Tag rows sit below the tree; the first one leads back into it
+                if let Some(tag_idx) = self.selected_tag {
+                    self.selected_tag = tag_idx.checked_sub(1);
+                    return;
+                }
                 // PERF-021: Single tree build for index lookup + selection
                 let tree = self.build_category_tree();
                 let current = self.category_tree_selected_index_in(&tree);
@@ -1134,12 +1763,19 @@ impl App {
                 }
             }
             Focus::Categories => {
+                if let Some(tag_idx) = self.selected_tag {
+                    let max_index = self.tags.len().saturating_sub(1);
+                    self.selected_tag = Some(tag_idx.saturating_add(1).min(max_index));
+                    return;
+                }
                 // PERF-021: Single tree build for index lookup + selection
                 let tree = self.build_category_tree();
                 let current = self.category_tree_selected_index_in(&tree);
                 let max_index = tree.len().saturating_sub(1);
                 if current < max_index {
                     self.select_category_by_tree_index_in(&tree, current + 1);
+                } else if !self.tags.is_empty() {
+                    self.selected_tag = Some(0);
                 }
             }
             Focus::Feeds => {
@@ -1194,31 +1830,28 @@ impl App {
     ///
     /// Calculates wrapped line count based on viewport width. Each logical line
     /// may wrap to multiple display lines depending on its width.
-    /// Includes the 3-line header.
+    /// Includes the header (title, feed/time, blank line).
     ///
     /// PERF-020: Uses cached line count when available to avoid recomputing
     /// wrapped line widths on every scroll clamp.
     pub fn reader_content_lines(&self) -> usize {
-        const HEADER_LINES: usize = 3; // Title, feed/time, blank line
         let width = self.reader_viewport_width.max(1); // Avoid division by zero
 
         // PERF-020: Return cached value if viewport width matches
         if let Some((cached_width, cached_count)) = self.reader_cached_line_count {
             if cached_width == width {
-                return HEADER_LINES + cached_count;
+                return self.reader_header_lines + cached_count;
             }
         }
 
         let content_lines = match &self.content_state {
             ContentState::Idle => 1,
             ContentState::Loading { .. } => 1,
-            ContentState::Loaded { rendered_lines, .. } => {
-                // Calculate wrapped line count for each line
-                rendered_lines
-                    .iter()
-                    .map(|line| Self::wrapped_line_count(line, width))
-                    .sum()
-            }
+            ContentState::Loaded {
+                rendered_lines,
+                code_lines,
+                ..
+            } => Self::loaded_line_count(rendered_lines, code_lines, width),
             ContentState::Failed { fallback, .. } => {
                 // Error line + blank + optional summary (estimate wrapping)
                 let base = 2;
@@ -1231,7 +1864,23 @@ impl App {
                 base + summary_lines
             }
         };
-        HEADER_LINES + content_lines
+        self.reader_header_lines + content_lines
+    }
+
+    /// Display lines of loaded content: code lines take one line each since
+    /// they scroll sideways instead of wrapping.
+    fn loaded_line_count(lines: &[Line<'_>], code_lines: &[CodeLine], width: usize) -> usize {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if code_lines.binary_search_by_key(&i, |c| c.line).is_ok() {
+                    1
+                } else {
+                    Self::wrapped_line_count(line, width)
+                }
+            })
+            .sum()
     }
 
     /// Calculate how many display lines a single Line will occupy after wrapping.
@@ -1255,10 +1904,11 @@ impl App {
         let content_lines = match &self.content_state {
             ContentState::Idle => 1,
             ContentState::Loading { .. } => 1,
-            ContentState::Loaded { rendered_lines, .. } => rendered_lines
-                .iter()
-                .map(|line| Self::wrapped_line_count(line, width))
-                .sum(),
+            ContentState::Loaded {
+                rendered_lines,
+                code_lines,
+                ..
+            } => Self::loaded_line_count(rendered_lines, code_lines, width),
             ContentState::Failed { fallback, .. } => {
                 let base = 2;
                 let summary_lines = fallback.as_ref().map_or(0, |s| {
@@ -1280,10 +1930,99 @@ impl App {
     ///
     /// PERF-020: Pre-populates the line count cache before reading it,
     /// so subsequent scroll clamps on the same content are O(1).
+    ///
+    /// In paginated mode the offset is also snapped to the start of a page.
     pub fn clamp_reader_scroll(&mut self) {
         self.cache_reader_line_count();
         let content_lines = self.reader_content_lines();
-        self.clamp_scroll(content_lines, self.reader_visible_lines);
+        if self.reader_layout.paginate {
+            let page = self.reader_visible_lines.max(1);
+            let last_page = content_lines.saturating_sub(1) / page;
+            let max_scroll = MAX_SCROLL / page * page;
+            self.scroll_offset =
+                ((self.scroll_offset / page).min(last_page) * page).min(max_scroll);
+        } else {
+            self.clamp_scroll(content_lines, self.reader_visible_lines);
+        }
+    }
+
+    /// Scroll to the saved reading position once the article's content is
+    /// loaded and laid out (the position is a fraction of its length).
+    pub fn apply_reader_resume(&mut self) {
+        let Some((article_id, position)) = self.reader_resume else {
+            return;
+        };
+        let loaded = matches!(
+            self.content_state,
+            ContentState::Loaded { article_id: id, .. } if id == article_id
+        );
+        if !loaded || self.reader_viewport_width == 0 {
+            return;
+        }
+        self.reader_resume = None;
+        self.cache_reader_line_count();
+        let lines = self.reader_content_lines();
+        self.scroll_offset = (position * lines as f64).round() as usize;
+        self.clamp_reader_scroll();
+        self.set_status(format!(
+            "Resumed at {}%",
+            self.reader_progress().unwrap_or(0)
+        ));
+    }
+
+    /// How far through the loaded article the bottom of the screen is, in
+    /// percent.
+    pub fn reader_progress(&self) -> Option<usize> {
+        if !matches!(self.content_state, ContentState::Loaded { .. }) {
+            return None;
+        }
+        let lines = self.reader_content_lines().max(1);
+        let seen = (self.scroll_offset + self.reader_visible_lines).min(lines);
+        Some(seen * 100 / lines)
+    }
+
+    /// The article in the reader and where it was left, to save on leaving:
+    /// a fraction of its length, or `None` at its start or end (nothing to
+    /// resume). `None` overall when there is nothing to save.
+    pub fn reader_position(&self) -> Option<(i64, Option<f64>)> {
+        let article = self.reader_article.as_ref().filter(|a| !is_link_page(a))?;
+        // Still waiting to scroll to the saved position, or showing the
+        // revision diff: leave the saved position alone
+        let loaded = matches!(self.content_state, ContentState::Loaded { .. });
+        if !loaded || self.reader_resume.is_some() || self.reader_saved_content.is_some() {
+            return None;
+        }
+        let lines = self.reader_content_lines().max(1);
+        let at_end = self.scroll_offset + self.reader_visible_lines >= lines;
+        let position =
+            (self.scroll_offset > 0 && !at_end).then(|| self.scroll_offset as f64 / lines as f64);
+        Some((article.id, position))
+    }
+
+    /// Current page and page count of the reader in paginated mode.
+    pub fn reader_page(&self) -> Option<(usize, usize)> {
+        if !self.reader_layout.paginate {
+            return None;
+        }
+        let page = self.reader_visible_lines.max(1);
+        let pages = self.reader_content_lines().div_ceil(page).max(1);
+        Some(((self.scroll_offset / page + 1).min(pages), pages))
+    }
+
+    /// Scroll the reader by whole screens (pages in paginated mode).
+    pub fn turn_reader_page(&mut self, forward: bool) {
+        // Keep a line of context when scrolling
+        let step = if self.reader_layout.paginate {
+            self.reader_visible_lines.max(1)
+        } else {
+            self.reader_visible_lines.saturating_sub(1).max(1)
+        };
+        if forward {
+            self.scroll_offset = self.scroll_offset.saturating_add(step);
+        } else {
+            self.scroll_offset = self.scroll_offset.saturating_sub(step);
+        }
+        self.clamp_reader_scroll();
     }
 
     /// Set status message (will auto-expire after 3 seconds)
@@ -1323,6 +2062,9 @@ impl App {
         self.scroll_offset = 0;
         self.content_state = ContentState::Loading { article_id };
         self.reader_article = Some(article.clone());
+        self.reader_search = None;
+        self.reader_code_scroll = 0;
+        self.reader_resume = None;
         self.reader_cached_line_count = None; // PERF-020: Invalidate cache on reader entry
         Some(article)
     }
@@ -1341,6 +2083,11 @@ impl App {
             selected_feed: self.selected_feed,
             selected_article: self.selected_article,
             scroll_offset: self.scroll_offset,
+            reader_article: self
+                .reader_article
+                .as_ref()
+                .filter(|a| self.view == View::Reader && !is_link_page(a))
+                .map(|a| a.id),
         }
     }
 
@@ -1374,8 +2121,289 @@ impl App {
         self.content_loading_for = None; // BUG-001: Clear loading flag on view exit
         self.scroll_offset = 0;
         self.reader_article = None;
+        self.reader_saved_content = None;
+        self.reader_back.clear();
+        self.reader_forward.clear();
+        self.link_picker = None;
+        self.reader_search = None;
+        self.reader_code_scroll = 0;
+        self.reader_resume = None;
+        self.reader_tldr = None;
         self.reader_cached_line_count = None; // PERF-020: Invalidate cache on reader exit
     }
+
+    /// Numbered links of the content on screen (none while loading or
+    /// showing the revision diff).
+    pub fn reader_links(&self) -> &[MarkdownLink] {
+        match &self.content_state {
+            ContentState::Loaded { links, .. } => links,
+            _ => &[],
+        }
+    }
+
+    /// Show a linked page in the reader, keeping the current page for "back".
+    /// Returns the stand-in article to load content for.
+    pub fn open_link_page(&mut self, url: &str, title: &str) -> Article {
+        let id = self.next_link_page_id;
+        self.next_link_page_id -= 1;
+        let title = if title.is_empty() { url } else { title };
+        let article = Article {
+            id,
+            feed_id: 0,
+            guid: url.to_string(),
+            title: Arc::from(title),
+            url: Some(Arc::from(url)),
+            published: None,
+            summary: None,
+            content: None,
+            read: true,
+            starred: false,
+            archived: false,
+            fetched_at: chrono::Utc::now().timestamp(),
+            updated_at: None,
+            priority: 0,
+            word_count: None,
+            tags: Vec::new(),
+        };
+
+        if let Some(page) = self.take_reader_page() {
+            self.reader_back.push(page);
+        }
+        self.reader_forward.clear();
+        self.show_reader_page(ReaderPage {
+            article: article.clone(),
+            content: ContentState::Loading { article_id: id },
+            scroll_offset: 0,
+        });
+        article
+    }
+
+    /// Return to the page before the last followed link.
+    /// Returns `false` when there is nothing to go back to.
+    pub fn reader_history_back(&mut self) -> bool {
+        let Some(page) = self.reader_back.pop() else {
+            return false;
+        };
+        if let Some(current) = self.take_reader_page() {
+            self.reader_forward.push(current);
+        }
+        self.show_reader_page(page);
+        true
+    }
+
+    /// Redo a step back. Returns `false` when there is nothing ahead.
+    pub fn reader_history_forward(&mut self) -> bool {
+        let Some(page) = self.reader_forward.pop() else {
+            return false;
+        };
+        if let Some(current) = self.take_reader_page() {
+            self.reader_back.push(current);
+        }
+        self.show_reader_page(page);
+        true
+    }
+
+    /// Take the page on screen for the history, leaving the revision diff
+    /// and stopping any content load in flight.
+    fn take_reader_page(&mut self) -> Option<ReaderPage> {
+        let article = self.reader_article.take()?;
+        if let Some(handle) = self.content_load_handle.take() {
+            handle.abort();
+        }
+        self.content_loading_for = None;
+        let shown = std::mem::replace(&mut self.content_state, ContentState::Idle);
+        Some(ReaderPage {
+            article,
+            content: self.reader_saved_content.take().unwrap_or(shown),
+            scroll_offset: self.scroll_offset,
+        })
+    }
+
+    fn show_reader_page(&mut self, page: ReaderPage) {
+        self.reader_article = Some(page.article);
+        self.content_state = page.content;
+        self.scroll_offset = page.scroll_offset;
+        self.link_picker = None;
+        self.reader_search = None;
+        self.reader_code_scroll = 0;
+        self.reader_resume = None;
+        // Re-wrap on the next render in case the terminal was resized
+        self.reader_wrap_width = 0;
+        self.reader_cached_line_count = None;
+        self.needs_redraw = true;
+    }
+
+    /// Start typing an in-article search, replacing any previous one.
+    pub fn start_reader_search(&mut self) {
+        self.reader_search = Some(ReaderSearchState {
+            editing: true,
+            origin: self.scroll_offset,
+            ..Default::default()
+        });
+    }
+
+    /// Search the reader content again after the query changed: jump to
+    /// the first match from where the search started, or back there when
+    /// nothing matches.
+    pub fn update_reader_search(&mut self) {
+        self.refresh_reader_search();
+        let Some(search) = &self.reader_search else {
+            return;
+        };
+        let origin = search.origin;
+        let header = self.reader_header_lines;
+        let first = search
+            .matches
+            .iter()
+            .position(|m| header + m.line() >= origin);
+        match first {
+            Some(index) => self.jump_to_reader_match(index),
+            None if search.matches.is_empty() => self.scroll_offset = origin,
+            None => self.jump_to_reader_match(0),
+        }
+    }
+
+    /// Find the query again in the rendered content, e.g. after re-wrapping.
+    /// The current match keeps its position in reading order.
+    pub fn refresh_reader_search(&mut self) {
+        let Some(search) = &mut self.reader_search else {
+            return;
+        };
+        search.matches = match &self.content_state {
+            ContentState::Loaded { rendered_lines, .. } => {
+                find_matches(rendered_lines, &search.query)
+            }
+            _ => Vec::new(),
+        };
+        search.current = search.current.min(search.matches.len().saturating_sub(1));
+        self.needs_redraw = true;
+    }
+
+    /// Move to the next (or previous) match, wrapping around the article.
+    /// Returns `false` when there is no match to move to.
+    pub fn step_reader_search(&mut self, forward: bool) -> bool {
+        let Some(search) = &self.reader_search else {
+            return false;
+        };
+        let count = search.matches.len();
+        if count == 0 {
+            return false;
+        }
+        let index = if forward {
+            (search.current + 1) % count
+        } else {
+            (search.current + count - 1) % count
+        };
+        self.jump_to_reader_match(index);
+        true
+    }
+
+    /// Make a match current and scroll it into view, a third down the
+    /// screen when it was off screen.
+    fn jump_to_reader_match(&mut self, index: usize) {
+        let Some(search) = &mut self.reader_search else {
+            return;
+        };
+        let Some(found) = search.matches.get(index) else {
+            return;
+        };
+        let line = found.line();
+        let start = found.parts.first().map_or(0, |(_, range)| range.start);
+        search.current = index;
+        self.reveal_code_column(line, start);
+        let row = self.reader_header_lines + line;
+        let visible = self.reader_visible_lines.max(1);
+        if row < self.scroll_offset || row >= self.scroll_offset + visible {
+            // Pages start where they start; otherwise show some context
+            self.scroll_offset = if self.reader_layout.paginate {
+                row
+            } else {
+                row.saturating_sub(visible / 3)
+            };
+            self.clamp_reader_scroll();
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Scroll code blocks sideways so byte `start` of rendered `line` is on
+    /// screen, if that line is code.
+    fn reveal_code_column(&mut self, line: usize, start: usize) {
+        let ContentState::Loaded {
+            rendered_lines,
+            code_lines,
+            ..
+        } = &self.content_state
+        else {
+            return;
+        };
+        let Ok(i) = code_lines.binary_search_by_key(&line, |c| c.line) else {
+            return;
+        };
+        let indent = code_lines[i].indent;
+        let text: String = rendered_lines[line]
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        let column = text
+            .get(..start)
+            .map_or(0, |t| t.width())
+            .saturating_sub(indent);
+        // The first and last columns may hold scroll markers
+        let visible = self.reader_viewport_width.saturating_sub(indent + 1).max(1);
+        if column < self.reader_code_scroll + 1 {
+            self.reader_code_scroll = column.saturating_sub(1);
+        } else if column >= self.reader_code_scroll + visible {
+            self.reader_code_scroll = column + 1 - visible;
+        }
+    }
+
+    /// Scroll code blocks in the reader by `delta` columns, within the
+    /// widest code line. Returns `false` when nothing moved.
+    pub fn scroll_reader_code(&mut self, delta: isize) -> bool {
+        let width = self.reader_viewport_width;
+        let max = match &self.content_state {
+            ContentState::Loaded {
+                rendered_lines,
+                code_lines,
+                ..
+            } => code_lines
+                .iter()
+                .map(|c| {
+                    let line_width: usize = rendered_lines[c.line]
+                        .spans
+                        .iter()
+                        .map(|s| s.content.width())
+                        .sum();
+                    line_width.saturating_sub(width)
+                })
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        };
+        let scroll = self
+            .reader_code_scroll
+            .saturating_add_signed(delta)
+            .min(max);
+        if scroll == self.reader_code_scroll {
+            return false;
+        }
+        self.reader_code_scroll = scroll;
+        self.needs_redraw = true;
+        true
+    }
+
+    /// "match 3/17" for the status bar, or `None` without a search.
+    pub fn reader_search_status(&self) -> Option<String> {
+        let search = self.reader_search.as_ref()?;
+        Some(if search.query.is_empty() {
+            String::new()
+        } else if search.matches.is_empty() {
+            "no matches".to_string()
+        } else {
+            format!("match {}/{}", search.current + 1, search.matches.len())
+        })
+    }
 }
 
 // ============================================================================
@@ -1498,9 +2526,29 @@ mod tests {
             unread_count: 0,
             consecutive_failures: 0,
             category_id: None,
+            gone: false,
+            url_auto_updated: false,
+            next_fetch_at: None,
+            prefer_source: false,
+            unread_on_update: false,
+            auto_prefetch: false,
         }
     }
 
+    #[tokio::test]
+    async fn test_count_read_in_feeds() {
+        let mut app = test_app().await;
+        let mut feeds = vec![test_feed(1, "One"), test_feed(2, "Two")];
+        feeds[0].unread_count = 3;
+        feeds[1].unread_count = 1;
+        app.feeds = Arc::new(feeds);
+
+        // One entry per copy; unknown feeds are ignored and counts stop at 0
+        app.count_read_in_feeds(&[1, 2, 2, 9, 1]);
+        assert_eq!(app.feeds[0].unread_count, 1);
+        assert_eq!(app.feeds[1].unread_count, 0);
+    }
+
     // BUG-004: clamp_selections tests
     #[tokio::test]
     async fn test_clamp_selections_empty_lists() {
@@ -1699,6 +2747,9 @@ mod tests {
             article_id: 1,
             content: "test".to_string(),
             rendered_lines,
+            links: Vec::new(),
+            link_spans: Vec::new(),
+            code_lines: Vec::new(),
         };
 
         app.clamp_reader_scroll();
@@ -1726,12 +2777,108 @@ mod tests {
             article_id: 1,
             content: "test".to_string(),
             rendered_lines,
+            links: Vec::new(),
+            link_spans: Vec::new(),
+            code_lines: Vec::new(),
         };
 
         // Loaded: 3 header + 50 content = 53
         assert_eq!(app.reader_content_lines(), 53);
     }
 
+    #[tokio::test]
+    async fn test_reader_pagination() {
+        use ratatui::text::Line;
+
+        let mut app = test_app().await;
+        app.reader_viewport_width = 80;
+        app.reader_visible_lines = 10;
+        app.content_state = ContentState::Loaded {
+            article_id: 1,
+            content: "test".to_string(),
+            rendered_lines: (0..50).map(|_| Line::from("test")).collect(),
+            links: Vec::new(),
+            link_spans: Vec::new(),
+            code_lines: Vec::new(),
+        };
+        assert_eq!(app.reader_page(), None);
+
+        // 53 lines make 6 pages of 10; offsets snap to page starts
+        app.reader_layout.paginate = true;
+        app.scroll_offset = 27;
+        app.clamp_reader_scroll();
+        assert_eq!(app.scroll_offset, 20);
+        assert_eq!(app.reader_page(), Some((3, 6)));
+        for _ in 0..10 {
+            app.turn_reader_page(true);
+        }
+        assert_eq!(app.scroll_offset, 50);
+        assert_eq!(app.reader_page(), Some((6, 6)));
+        app.turn_reader_page(false);
+        assert_eq!(app.reader_page(), Some((5, 6)));
+
+        // Scrolling by screens keeps a line of context
+        app.reader_layout.paginate = false;
+        app.scroll_offset = 0;
+        app.turn_reader_page(true);
+        assert_eq!(app.scroll_offset, 9);
+    }
+
+    #[tokio::test]
+    async fn test_reader_resume_and_position() {
+        use ratatui::text::Line;
+
+        let mut app = test_app().await;
+        app.reader_viewport_width = 80;
+        app.reader_visible_lines = 10;
+        app.reader_article = Some(Article {
+            id: 1,
+            feed_id: 1,
+            guid: "guid-1".to_string(),
+            title: Arc::from("Article 1"),
+            url: None,
+            published: None,
+            summary: None,
+            content: None,
+            read: true,
+            starred: false,
+            archived: false,
+            fetched_at: 0,
+            updated_at: None,
+            priority: 0,
+            word_count: None,
+            tags: Vec::new(),
+        });
+        app.reader_resume = Some((1, 0.5));
+        app.content_state = ContentState::Loading { article_id: 1 };
+
+        // Nothing to apply or save until the content is shown
+        app.apply_reader_resume();
+        assert_eq!(app.scroll_offset, 0);
+        assert_eq!(app.reader_position(), None);
+
+        app.content_state = ContentState::Loaded {
+            article_id: 1,
+            content: "test".to_string(),
+            rendered_lines: (0..50).map(|_| Line::from("test")).collect(),
+            links: Vec::new(),
+            link_spans: Vec::new(),
+            code_lines: Vec::new(),
+        };
+        app.apply_reader_resume();
+        assert_eq!(app.reader_resume, None);
+        assert_eq!(app.scroll_offset, 27);
+        assert_eq!(app.reader_progress(), Some(69));
+        assert_eq!(app.reader_position(), Some((1, Some(27.0 / 53.0))));
+
+        // Finished or not started: nothing to resume
+        app.scroll_offset = 43;
+        assert_eq!(app.reader_progress(), Some(100));
+        assert_eq!(app.reader_position(), Some((1, None)));
+        app.scroll_offset = 0;
+        assert_eq!(app.reader_position(), Some((1, None)));
+    }
+
     // SessionSnapshot tests
     #[tokio::test]
     async fn test_snapshot_captures_state() {
@@ -1759,6 +2906,8 @@ mod tests {
             selected_feed: 1,
             selected_article: 0,
             scroll_offset: 5,
+
+            reader_article: None,
         };
 
         app.restore(snap);
@@ -1777,6 +2926,8 @@ mod tests {
             selected_feed: 999,
             selected_article: 999,
             scroll_offset: 0,
+
+            reader_article: None,
         };
 
         app.restore(snap);
@@ -1792,6 +2943,8 @@ mod tests {
             selected_feed: 0,
             selected_article: 0,
             scroll_offset: 0,
+
+            reader_article: None,
         };
 
         app.restore(snap);
@@ -1805,6 +2958,7 @@ mod tests {
             selected_feed: 2,
             selected_article: 5,
             scroll_offset: 10,
+            reader_article: Some(7),
         };
 
         let json = serde_json::to_string(&snap).unwrap();
@@ -1814,6 +2968,7 @@ mod tests {
         assert_eq!(restored.selected_feed, 2);
         assert_eq!(restored.selected_article, 5);
         assert_eq!(restored.scroll_offset, 10);
+        assert_eq!(restored.reader_article, Some(7));
     }
 
     #[tokio::test]
@@ -1913,9 +3068,24 @@ mod tests {
             "reader_heading",
             "reader_code_block",
             "reader_inline_code",
+            "reader_code_keyword",
+            "reader_code_string",
+            "reader_code_comment",
+            "reader_code_number",
+            "reader_code_type",
             "reader_error",
             "reader_fallback",
             "reader_image",
+            "reader_diff_added",
+            "reader_diff_removed",
+            "reader_list_marker",
+            "reader_quote",
+            "reader_table_border",
+            "reader_strikethrough",
+            "reader_footnote",
+            "reader_link",
+            "reader_search_match",
+            "reader_search_current",
             "status_bar",
             "panel_border_focused",
             "whatsnew_border_focused",
@@ -2275,7 +3445,12 @@ mod tests {
             content: None,
             read: false,
             starred: false,
+            archived: false,
             fetched_at: 0,
+            updated_at: None,
+            priority: 0,
+            word_count: None,
+            tags: Vec::new(),
         };
         app.articles = Arc::new(vec![article]);
 
@@ -2324,7 +3499,7 @@ mod tests {
         // Without categories: Feeds -> Articles -> Feeds
         app.focus = Focus::Feeds;
         // Simulate: no categories, no whatsnew
-        assert_eq!(app.show_categories, false);
+        assert!(!app.show_categories);
         // Cycle: Feeds -> Articles
         app.focus = Focus::Articles;
         // Cycle: Articles -> Feeds
@@ -2496,4 +3671,336 @@ mod tests {
 
         assert_eq!(app.feeds[0].category_id, Some(10));
     }
+
+    // ========================================================================
+    // Tag Tests
+    // ========================================================================
+
+    fn tag_count(tag: &str) -> TagCount {
+        TagCount {
+            tag: tag.to_string(),
+            articles: 1,
+            unread: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_category_nav_moves_into_tags() {
+        let mut app = test_app().await;
+        app.show_categories = true;
+        app.focus = Focus::Categories;
+        app.tags = Arc::new(vec![tag_count(STARRED_TAG), tag_count("reference")]);
+
+        // Tree holds only "All", so the next row down is the first tag
+        app.nav_down();
+        assert_eq!(
+            app.selected_tag().map(|t| t.tag.as_str()),
+            Some(STARRED_TAG)
+        );
+        app.nav_down();
+        app.nav_down();
+        assert_eq!(app.selected_tag, Some(1));
+
+        app.nav_up();
+        app.nav_up();
+        assert_eq!(app.selected_tag, None);
+        assert_eq!(app.selected_category, None);
+
+        // Tags going out of use clamp the cursor
+        app.selected_tag = Some(1);
+        app.tags = Arc::new(vec![tag_count(STARRED_TAG)]);
+        app.clamp_selections();
+        assert_eq!(app.selected_tag, Some(0));
+    }
+
+    #[test]
+    fn test_tag_picker_options() {
+        let picker = TagPickerState {
+            article_id: 1,
+            article_title: "Post".to_string(),
+            tags: vec!["share".to_string()],
+            starred: true,
+            input: String::new(),
+            selected: 0,
+        };
+        let known = [tag_count(STARRED_TAG), tag_count("reference")];
+        let rows: Vec<(String, bool, bool)> = picker
+            .options(&known)
+            .into_iter()
+            .map(|o| (o.tag, o.checked, o.new))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                (STARRED_TAG.to_string(), true, false),
+                ("reference".to_string(), false, false),
+                ("share".to_string(), true, false),
+            ]
+        );
+
+        // Input filters, and offers a new tag when no name matches exactly
+        let picker = TagPickerState {
+            input: "#Ref".to_string(),
+            ..picker
+        };
+        let rows: Vec<(String, bool)> = picker
+            .options(&known)
+            .into_iter()
+            .map(|o| (o.tag, o.new))
+            .collect();
+        assert_eq!(
+            rows,
+            [("reference".to_string(), false), ("ref".to_string(), true)]
+        );
+    }
+
+    #[test]
+    fn test_link_picker_digits() {
+        let mut picker = LinkPickerState::default();
+        picker.push_digit('1', 12);
+        assert_eq!((picker.input.as_str(), picker.selected), ("1", 0));
+        picker.push_digit('2', 12);
+        assert_eq!((picker.input.as_str(), picker.selected), ("12", 11));
+        // "123" is past the last link: start over at 3
+        picker.push_digit('3', 12);
+        assert_eq!((picker.input.as_str(), picker.selected), ("3", 2));
+        // No link 0 or 9: selection stays
+        picker.push_digit('0', 5);
+        picker.push_digit('9', 5);
+        assert_eq!(picker.selected, 2);
+    }
+
+    #[tokio::test]
+    async fn test_reader_search() {
+        let mut app = test_app().await;
+        app.reader_visible_lines = 4;
+        let text: Vec<String> = (0..40)
+            .map(|i| {
+                if i % 10 == 5 {
+                    "a Match".into()
+                } else {
+                    format!("line {}", i)
+                }
+            })
+            .collect();
+        app.content_state = ContentState::Loaded {
+            article_id: 1,
+            content: String::new(),
+            rendered_lines: text.iter().map(|t| Line::from(t.clone())).collect(),
+            links: Vec::new(),
+            link_spans: Vec::new(),
+            code_lines: Vec::new(),
+        };
+        app.scroll_offset = 12;
+        app.start_reader_search();
+        assert_eq!(app.reader_search_status().as_deref(), Some(""));
+
+        // Typing jumps to the first match below where the search started
+        app.reader_search.as_mut().unwrap().query = "match".into();
+        app.update_reader_search();
+        assert_eq!(app.reader_search_status().as_deref(), Some("match 2/4"));
+        let row = app.reader_header_lines + 15;
+        assert!((app.scroll_offset..app.scroll_offset + 4).contains(&row));
+
+        // n/N wrap around the article
+        assert!(app.step_reader_search(false));
+        assert!(app.step_reader_search(false));
+        assert_eq!(app.reader_search_status().as_deref(), Some("match 4/4"));
+        assert!(app.step_reader_search(true));
+        assert_eq!(app.reader_search_status().as_deref(), Some("match 1/4"));
+
+        app.reader_search.as_mut().unwrap().query = "nothing".into();
+        app.update_reader_search();
+        assert_eq!(app.reader_search_status().as_deref(), Some("no matches"));
+        assert_eq!(app.scroll_offset, 12);
+        assert!(!app.step_reader_search(true));
+
+        app.exit_reader();
+        assert!(app.reader_search.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_link_pages_back_and_forward() {
+        let mut app = test_app().await;
+        let first = app.open_link_page("https://a.example/", "Page A");
+        assert!(is_link_page(&first));
+        assert!(app.reader_back.is_empty());
+        app.content_state = ContentState::Loaded {
+            article_id: first.id,
+            content: "[b](https://b.example/)".to_string(),
+            rendered_lines: vec![Line::from("b[1]")],
+            links: vec![MarkdownLink {
+                url: "https://b.example/".to_string(),
+                text: "b".to_string(),
+            }],
+            link_spans: Vec::new(),
+            code_lines: Vec::new(),
+        };
+        app.scroll_offset = 7;
+        assert_eq!(app.reader_links().len(), 1);
+
+        let second = app.open_link_page("https://b.example/", "");
+        assert_ne!(second.id, first.id);
+        assert_eq!(&*second.title, "https://b.example/");
+        assert!(matches!(app.content_state, ContentState::Loading { .. }));
+        assert!(app.reader_links().is_empty());
+        assert_eq!(app.scroll_offset, 0);
+
+        // Back restores the page with its content and scroll position
+        assert!(app.reader_history_back());
+        assert_eq!(app.reader_article.as_ref().map(|a| a.id), Some(first.id));
+        assert_eq!(app.reader_links().len(), 1);
+        assert_eq!(app.scroll_offset, 7);
+        assert!(!app.reader_history_back());
+
+        assert!(app.reader_history_forward());
+        assert_eq!(app.reader_article.as_ref().map(|a| a.id), Some(second.id));
+        assert!(!app.reader_history_forward());
+
+        // Following a link drops the forward history
+        app.reader_history_back();
+        app.open_link_page("https://c.example/", "C");
+        assert!(app.reader_forward.is_empty());
+        assert_eq!(app.reader_back.len(), 1);
+
+        app.exit_reader();
+        assert!(app.reader_back.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cycle_reading_time_filter() {
+        let mut app = test_app().await;
+        app.words_per_minute = 200;
+        let article = |id: i64, word_count: Option<usize>| Article {
+            id,
+            feed_id: 1,
+            guid: format!("guid-{id}"),
+            title: Arc::from(format!("Article {id}")),
+            url: None,
+            published: None,
+            summary: None,
+            content: None,
+            read: false,
+            starred: false,
+            archived: false,
+            fetched_at: 0,
+            updated_at: None,
+            priority: 0,
+            word_count,
+            tags: Vec::new(),
+        };
+        // 1, 8 and 15 minutes, and one of unknown length
+        app.articles = Arc::new(vec![
+            article(1, Some(200)),
+            article(2, Some(1600)),
+            article(3, Some(3000)),
+            article(4, None),
+        ]);
+        app.selected_article = 1;
+
+        assert_eq!(app.cycle_reading_time_filter(), Some(5));
+        assert_eq!(app.articles.len(), 1);
+        assert_eq!(app.selected_article, 0);
+        Arc::make_mut(&mut app.articles)[0].read = true;
+
+        assert_eq!(app.cycle_reading_time_filter(), Some(10));
+        let ids: Vec<i64> = app.articles.iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert!(app.articles[0].read);
+
+        app.reload_articles(vec![article(3, Some(3000)), article(5, Some(400))]);
+        assert_eq!(app.articles.len(), 1);
+        assert_eq!(app.unfiltered_articles().len(), 2);
+
+        assert_eq!(app.cycle_reading_time_filter(), Some(20));
+        assert_eq!(app.cycle_reading_time_filter(), None);
+        assert_eq!(app.articles.len(), 2);
+        assert!(app.reading_time_filter.is_none());
+    }
+
+    fn list_article(id: i64, read: bool) -> Article {
+        Article {
+            id,
+            feed_id: 1,
+            guid: format!("guid-{id}"),
+            title: Arc::from(format!("Article {id}")),
+            url: None,
+            published: None,
+            summary: None,
+            content: None,
+            read,
+            starred: false,
+            archived: false,
+            fetched_at: 0,
+            updated_at: None,
+            priority: 0,
+            word_count: None,
+            tags: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reader_step_target() {
+        let mut app = test_app().await;
+        let none = HashSet::new();
+        app.articles = Arc::new(vec![
+            list_article(1, false),
+            list_article(2, true),
+            list_article(3, true),
+            list_article(4, false),
+        ]);
+
+        // First item: nothing before it
+        app.reader_article = Some(list_article(1, false));
+        assert_eq!(app.reader_list(), (vec![1, 2, 3, 4], 0));
+        assert_eq!(app.reader_step_target(false, false, &none), None);
+        assert_eq!(app.reader_step_target(true, false, &none), Some(1));
+        assert_eq!(app.reader_step_target(true, true, &none), Some(3));
+
+        // Last item: nothing after it, unread or not
+        app.reader_article = Some(list_article(4, false));
+        assert_eq!(app.reader_step_target(true, false, &none), None);
+        assert_eq!(app.reader_step_target(true, true, &none), None);
+        assert_eq!(app.reader_step_target(false, false, &none), Some(2));
+
+        // No unread left after the current one
+        app.reader_article = Some(list_article(2, true));
+        Arc::make_mut(&mut app.articles)[3].read = true;
+        assert_eq!(app.reader_step_target(true, true, &none), None);
+        assert_eq!(app.reader_step_target(true, false, &none), Some(2));
+
+        // A linked page isn't in the list; the selection stands in
+        app.reader_article = Some(list_article(-1, false));
+        app.selected_article = 2;
+        assert_eq!(app.reader_list().1, 2);
+        assert_eq!(app.reader_step_target(true, false, &none), Some(3));
+        assert_eq!(app.reader_step_target(false, false, &none), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_reader_step_target_whats_new() {
+        let mut app = test_app().await;
+        app.focus = Focus::WhatsNew;
+        app.whats_new = (1..=3)
+            .map(|id| WhatsNewEntry {
+                article_id: id,
+                feed_title: Arc::from("Feed"),
+                title: Arc::from(format!("Article {id}")),
+                published: None,
+            })
+            .collect();
+        // The article list doesn't count while reading from What's New
+        app.articles = Arc::new(vec![list_article(1, false)]);
+        app.reader_article = Some(list_article(1, false));
+
+        assert_eq!(
+            app.reader_step_target(true, false, &HashSet::new()),
+            Some(1)
+        );
+        assert_eq!(app.reader_step_target(true, true, &HashSet::new()), None);
+        assert_eq!(
+            app.reader_step_target(true, true, &HashSet::from([3])),
+            Some(2)
+        );
+    }
 }
@@ -3,9 +3,15 @@
 //! The config file is optional — a missing file yields `Config::default()`.
 //! Unknown keys are silently ignored by serde (with `deny_unknown_fields` off),
 //! though we log a warning when the file contains potential typos.
+use crate::feed::{FetchPolicy, PollSchedule};
+use crate::rewrite::{RewriteConfig, RewriteSet};
+use crate::rules::{RuleConfig, RuleSet};
+use crate::storage::CachePolicy;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 // ============================================================================
@@ -57,9 +63,290 @@ pub struct Config {
     /// Custom keybinding overrides. Keys are action names, values are key strings.
     pub keybindings: HashMap<String, String>,
 
+    /// Clickable OSC 8 hyperlinks for titles and links: "auto" (detect the
+    /// terminal), "always" or "never".
+    pub hyperlinks: HyperlinkMode,
+
     /// Jina.ai API key (alternative to JINA_API_KEY env var).
     /// Env var takes precedence over config file.
     pub jina_api_key: Option<String>,
+
+    /// `[network]` section: politeness limits for feed fetching.
+    pub network: NetworkConfig,
+
+    /// `[health]` section: fetch log retention and feed health thresholds.
+    pub health: HealthConfig,
+
+    /// Adaptive polling schedule (`[polling]` table).
+    pub polling: PollingConfig,
+
+    /// `[cache]` section: article content TTLs and size budget.
+    pub cache: CacheConfig,
+
+    /// `[prefetch]` section: automatic prefetch after refresh.
+    pub prefetch: PrefetchConfig,
+
+    /// `[reader]` section: text column, pagination and TL;DR in the reader.
+    pub reader: ReaderConfig,
+
+    /// Filter and action rules applied at ingest (`[[rules]]` tables).
+    pub rules: Vec<RuleConfig>,
+
+    /// Per-feed title, summary and content rewrites (`[[rewrites]]` tables).
+    pub rewrites: Vec<RewriteConfig>,
+}
+
+/// When to emit OSC 8 hyperlinks (`hyperlinks` key).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HyperlinkMode {
+    /// Emit them when the terminal is known to support them.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl HyperlinkMode {
+    /// Whether hyperlinks should be emitted in this terminal.
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Auto => crate::util::supports_hyperlinks(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// Politeness limits applied during feed refresh (`[network]` table).
+///
+/// Many feeds often share a host (GitHub, Substack, Medium), so these limits
+/// are applied per host rather than globally.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    /// Maximum simultaneous requests to a single host (minimum 1).
+    pub max_concurrent_per_host: usize,
+
+    /// Minimum delay between the start of two requests to the same host.
+    pub min_host_interval_ms: u64,
+
+    /// Longest `Retry-After` wait honored inline during a refresh. Longer
+    /// waits are persisted and the feed is skipped until they elapse.
+    pub max_retry_wait_secs: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_per_host: 2,
+            min_host_interval_ms: 250,
+            max_retry_wait_secs: 30,
+        }
+    }
+}
+
+/// Fetch history retention and feed health view thresholds (`[health]` table).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// Days of `feed_fetch_log` history to keep.
+    pub fetch_log_retention_days: u32,
+
+    /// Maximum fetch log rows kept per feed, regardless of age.
+    pub fetch_log_max_per_feed: u32,
+
+    /// Average successful fetch latency above which a feed is listed as slow.
+    pub slow_fetch_ms: u64,
+
+    /// Months without a new article before a feed is listed as stale.
+    pub stale_after_months: u32,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            fetch_log_retention_days: 90,
+            fetch_log_max_per_feed: 500,
+            slow_fetch_ms: 5000,
+            stale_after_months: 6,
+        }
+    }
+}
+
+/// Adaptive polling settings (`[polling]` table).
+///
+/// Each feed's refetch interval is derived from its publishing cadence and
+/// feed-provided hints, then clamped to these bounds.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PollingConfig {
+    /// When false, every refresh fetches every feed.
+    pub adaptive: bool,
+
+    /// Shortest interval between fetches of one feed.
+    pub min_interval_minutes: u64,
+
+    /// Longest interval between fetches of one feed, however quiet it is.
+    pub max_interval_hours: u64,
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self {
+            adaptive: true,
+            min_interval_minutes: 15,
+            max_interval_hours: 24,
+        }
+    }
+}
+
+/// Offline content cache settings (`[cache]` table).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Hours fetched article content stays cached (minimum 1).
+    pub ttl_hours: i64,
+
+    /// Total cache size on disk in MB, enforced by evicting the least
+    /// recently read entries. 0 = unlimited.
+    pub max_size_mb: u64,
+
+    /// TTL overrides by feed title or feed URL (case-insensitive), e.g.
+    /// `"Hacker News" = 6`.
+    pub feed_ttl_hours: HashMap<String, i64>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_hours: 72,
+            max_size_mb: 0,
+            feed_ttl_hours: HashMap::new(),
+        }
+    }
+}
+
+/// Automatic prefetch of new unread articles after each refresh (`[prefetch]` table).
+///
+/// Applies to feeds with auto-prefetch switched on in their context menu and
+/// to every feed in the listed categories.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PrefetchConfig {
+    /// Category names (case-insensitive) whose feeds, including those in
+    /// subcategories, are prefetched.
+    pub categories: Vec<String>,
+
+    /// Most articles fetched per run. 0 turns automatic prefetch off.
+    pub max_articles: usize,
+
+    /// Stop starting new fetches once a run has downloaded this many MB.
+    pub max_mb: u64,
+
+    /// Articles fetched at the same time (minimum 1).
+    pub concurrency: usize,
+
+    /// Skip automatic prefetch while the machine runs on battery. Metered
+    /// networks cannot be detected portably, so power is the only signal.
+    pub only_on_ac_power: bool,
+}
+
+impl Default for PrefetchConfig {
+    fn default() -> Self {
+        Self {
+            categories: Vec::new(),
+            max_articles: 25,
+            max_mb: 20,
+            concurrency: 3,
+            only_on_ac_power: false,
+        }
+    }
+}
+
+/// Narrowest text column the reader lays out, whatever the settings.
+pub const MIN_READER_WIDTH: usize = 20;
+
+/// Reader layout (`[reader]` table). Defaults for the `reader.*`
+/// preferences, which the reader's keys change at runtime.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ReaderConfig {
+    /// Widest text column in columns. 0 = use the whole window.
+    pub max_width: usize,
+
+    /// Blank columns kept on each side of the text.
+    pub margin: usize,
+
+    /// Stretch the spaces of wrapped paragraph lines to fill the column.
+    pub justify: bool,
+
+    /// Show whole pages: Space and the scroll keys turn pages.
+    pub paginate: bool,
+
+    /// Start the TL;DR box at the top of articles collapsed.
+    pub collapse_tldr: bool,
+
+    /// Show the selected article's TL;DR under it in the article list.
+    pub tldr_preview: bool,
+}
+
+impl ReaderConfig {
+    /// Left offset and width of the text column within `available`
+    /// columns: inside the margins, at most `max_width` wide and centered.
+    pub fn column(&self, available: usize) -> (usize, usize) {
+        let mut width = available.saturating_sub(2 * self.margin);
+        if self.max_width > 0 {
+            width = width.min(self.max_width);
+        }
+        let width = width.max(MIN_READER_WIDTH).min(available);
+        ((available - width) / 2, width)
+    }
+}
+
+impl Config {
+    /// Convert the `[cache]` settings to the content cache's runtime policy.
+    pub fn cache_policy(&self) -> CachePolicy {
+        CachePolicy {
+            ttl_hours: self.cache.ttl_hours.max(1),
+            feed_ttl_hours: self
+                .cache
+                .feed_ttl_hours
+                .iter()
+                .map(|(feed, hours)| (feed.to_lowercase(), *hours))
+                .collect(),
+            max_size_bytes: (self.cache.max_size_mb > 0)
+                .then(|| (self.cache.max_size_mb as i64).saturating_mul(1024 * 1024)),
+        }
+    }
+
+    /// Convert the `[network]` and `[polling]` settings to the fetcher's runtime policy.
+    pub fn fetch_policy(&self) -> FetchPolicy {
+        let min_interval = Duration::from_secs(self.polling.min_interval_minutes * 60);
+        FetchPolicy {
+            max_concurrent_per_host: self.network.max_concurrent_per_host.max(1),
+            min_host_interval: Duration::from_millis(self.network.min_host_interval_ms),
+            max_retry_wait: Duration::from_secs(self.network.max_retry_wait_secs),
+            poll: PollSchedule {
+                adaptive: self.polling.adaptive,
+                min_interval,
+                max_interval: Duration::from_secs(self.polling.max_interval_hours * 3600)
+                    .max(min_interval),
+            },
+            rules: Arc::new(self.rule_set()),
+            rewrites: Arc::new(self.rewrite_set()),
+        }
+    }
+
+    /// Compile the `[[rules]]` tables. Invalid rules are left out; `load` warns about them.
+    pub fn rule_set(&self) -> RuleSet {
+        RuleSet::compile(&self.rules).0
+    }
+
+    /// Compile the `[[rewrites]]` tables, leaving out invalid ones.
+    pub fn rewrite_set(&self) -> RewriteSet {
+        RewriteSet::compile(&self.rewrites).0
+    }
 }
 
 impl Default for Config {
@@ -71,7 +358,16 @@ impl Default for Config {
             mark_read_on_open: true,
             confirm_mark_all_read: false,
             keybindings: HashMap::new(),
+            hyperlinks: HyperlinkMode::Auto,
             jina_api_key: None,
+            network: NetworkConfig::default(),
+            health: HealthConfig::default(),
+            polling: PollingConfig::default(),
+            cache: CacheConfig::default(),
+            prefetch: PrefetchConfig::default(),
+            reader: ReaderConfig::default(),
+            rules: Vec::new(),
+            rewrites: Vec::new(),
         }
     }
 }
@@ -86,10 +382,19 @@ impl std::fmt::Debug for Config {
             .field("mark_read_on_open", &self.mark_read_on_open)
             .field("confirm_mark_all_read", &self.confirm_mark_all_read)
             .field("keybindings", &self.keybindings)
+            .field("hyperlinks", &self.hyperlinks)
             .field(
                 "jina_api_key",
                 &self.jina_api_key.as_ref().map(|_| "[REDACTED]"),
             )
+            .field("network", &self.network)
+            .field("health", &self.health)
+            .field("polling", &self.polling)
+            .field("cache", &self.cache)
+            .field("prefetch", &self.prefetch)
+            .field("reader", &self.reader)
+            .field("rules", &self.rules)
+            .field("rewrites", &self.rewrites)
             .finish()
     }
 }
@@ -147,7 +452,16 @@ impl Config {
                 "mark_read_on_open",
                 "confirm_mark_all_read",
                 "keybindings",
+                "hyperlinks",
                 "jina_api_key",
+                "network",
+                "health",
+                "polling",
+                "cache",
+                "prefetch",
+                "reader",
+                "rules",
+                "rewrites",
             ];
             for key in raw.keys() {
                 if !known_keys.contains(&key.as_str()) {
@@ -157,6 +471,12 @@ impl Config {
         }
 
         let config: Config = toml::from_str(&content)?;
+        for error in RuleSet::compile(&config.rules).1 {
+            tracing::warn!(error = %error, "Skipping invalid rule in config file");
+        }
+        for error in RewriteSet::compile(&config.rewrites).1 {
+            tracing::warn!(error = %error, "Skipping invalid rewrite in config file");
+        }
         tracing::info!(path = %path.display(), theme = %config.theme, "Loaded configuration");
         Ok(config)
     }
@@ -180,6 +500,38 @@ mod tests {
         assert!(!config.confirm_mark_all_read);
         assert!(config.keybindings.is_empty());
         assert!(config.jina_api_key.is_none());
+        assert_eq!(config.network.max_concurrent_per_host, 2);
+        assert_eq!(config.network.min_host_interval_ms, 250);
+        assert_eq!(config.network.max_retry_wait_secs, 30);
+        assert_eq!(config.health.fetch_log_retention_days, 90);
+        assert_eq!(config.health.stale_after_months, 6);
+        assert!(config.polling.adaptive);
+        assert_eq!(config.polling.min_interval_minutes, 15);
+    }
+
+    #[test]
+    fn test_hyperlink_mode() {
+        assert_eq!(Config::default().hyperlinks, HyperlinkMode::Auto);
+        let config: Config = toml::from_str("hyperlinks = \"never\"").unwrap();
+        assert_eq!(config.hyperlinks, HyperlinkMode::Never);
+        assert!(!config.hyperlinks.enabled());
+        let config: Config = toml::from_str("hyperlinks = \"always\"").unwrap();
+        assert!(config.hyperlinks.enabled());
+        assert!(toml::from_str::<Config>("hyperlinks = \"sometimes\"").is_err());
+    }
+
+    #[test]
+    fn test_reader_column() {
+        let config: Config = toml::from_str("[reader]\nmax_width = 80\nmargin = 4").unwrap();
+        let reader = config.reader;
+        assert!(!reader.justify && !reader.paginate);
+        // Centered at the maximum width
+        assert_eq!(reader.column(240), (80, 80));
+        // Margins on narrow windows
+        assert_eq!(reader.column(60), (4, 52));
+        assert_eq!(reader.column(24), (2, 20));
+        assert_eq!(reader.column(10), (0, 10));
+        assert_eq!(ReaderConfig::default().column(240), (0, 240));
     }
 
     #[test]
@@ -256,6 +608,176 @@ refresh = "F5"
         std::fs::remove_dir_all(&dir).ok();
     }
 
+    #[test]
+    fn test_network_section() {
+        let dir = std::env::temp_dir().join("skim_config_test_network");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let content = r#"
+[network]
+max_concurrent_per_host = 0
+min_host_interval_ms = 1000
+"#;
+        std::fs::write(&path, content).unwrap();
+
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.network.min_host_interval_ms, 1000);
+        assert_eq!(config.network.max_retry_wait_secs, 30); // default
+
+        // A zero per-host limit would deadlock refresh; it is clamped to 1
+        let policy = config.fetch_policy();
+        assert_eq!(policy.max_concurrent_per_host, 1);
+        assert_eq!(policy.min_host_interval, Duration::from_secs(1));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_cache_section() {
+        let dir = std::env::temp_dir().join("skim_config_test_cache");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let content = r#"
+[cache]
+ttl_hours = 24
+max_size_mb = 50
+
+[cache.feed_ttl_hours]
+"Hacker News" = 6
+"https://example.com/feed.xml" = 720
+"#;
+        std::fs::write(&path, content).unwrap();
+
+        let policy = Config::load(&path).unwrap().cache_policy();
+        assert_eq!(policy.max_size_bytes, Some(50 * 1024 * 1024));
+        assert_eq!(policy.ttl_for("hacker news", "https://hn.example/rss"), 6);
+        assert_eq!(
+            policy.ttl_for("Example", "https://EXAMPLE.com/feed.xml"),
+            720
+        );
+        assert_eq!(policy.ttl_for("Other", "https://other.example/rss"), 24);
+
+        // Unlimited by default
+        assert_eq!(Config::default().cache_policy().max_size_bytes, None);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_prefetch_section() {
+        let dir = std::env::temp_dir().join("skim_config_test_prefetch");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let content = r#"
+[prefetch]
+categories = ["News", "Tech"]
+max_articles = 10
+only_on_ac_power = true
+"#;
+        std::fs::write(&path, content).unwrap();
+
+        let prefetch = Config::load(&path).unwrap().prefetch;
+        assert_eq!(prefetch.categories, vec!["News", "Tech"]);
+        assert_eq!(prefetch.max_articles, 10);
+        assert!(prefetch.only_on_ac_power);
+        // Unset keys keep their defaults
+        assert_eq!(prefetch.max_mb, 20);
+        assert_eq!(prefetch.concurrency, 3);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_polling_section() {
+        let dir = std::env::temp_dir().join("skim_config_test_polling");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let content = r#"
+[polling]
+min_interval_minutes = 120
+max_interval_hours = 1
+"#;
+        std::fs::write(&path, content).unwrap();
+
+        let config = Config::load(&path).unwrap();
+        assert!(config.polling.adaptive); // default
+
+        // An upper bound below the lower bound is raised to it
+        let poll = config.fetch_policy().poll;
+        assert_eq!(poll.min_interval, Duration::from_secs(2 * 3600));
+        assert_eq!(poll.max_interval, Duration::from_secs(2 * 3600));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_rules_section() {
+        let dir = std::env::temp_dir().join("skim_config_test_rules");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let content = r#"
+[[rules]]
+name = "webinars"
+title = "/sponsored|webinar/i"
+mark_read = true
+
+[[rules]]
+name = "broken"
+title = "(unclosed"
+star = true
+"#;
+        std::fs::write(&path, content).unwrap();
+
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.rules.len(), 2);
+
+        // The invalid rule is dropped when compiling
+        let rules = config.fetch_policy().rules;
+        assert_eq!(rules.rules().len(), 1);
+        assert_eq!(rules.rules()[0].name, "webinars");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_rewrites_section() {
+        let dir = std::env::temp_dir().join("skim_config_test_rewrites");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let content = r#"
+[[rewrites]]
+feed = "Hacker News"
+fields = ["title"]
+strip_prefix = ["Show HN:"]
+
+[[rewrites]]
+domain = "example.com"
+fields = ["byline"]
+strip_suffix = [" (sponsored)"]
+"#;
+        std::fs::write(&path, content).unwrap();
+
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.rewrites.len(), 2);
+        assert_eq!(config.rewrites[0].strip_prefix, ["Show HN:"]);
+
+        // The rewrite naming an unknown field is dropped
+        let errors = RewriteSet::compile(&config.rewrites).1;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].rewrite, "rewrite 2");
+        let policy = config.fetch_policy();
+        assert!(policy.rules.is_empty());
+        assert!(!policy.rewrites.is_empty());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_invalid_toml_returns_error() {
         let dir = std::env::temp_dir().join("skim_config_test_invalid");
@@ -380,8 +902,10 @@ another_unknown = 42
     // SEC-015: Debug output masks API key
     #[test]
     fn test_debug_masks_api_key() {
-        let mut config = Config::default();
-        config.jina_api_key = Some("super-secret-key-12345".to_string());
+        let config = Config {
+            jina_api_key: Some("super-secret-key-12345".to_string()),
+            ..Default::default()
+        };
 
         let debug_output = format!("{:?}", config);
         assert!(
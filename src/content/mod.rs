@@ -1,2 +1,4 @@
 mod jina;
+mod save;
 pub use jina::{fetch_content, ContentError};
+pub use save::{save_url, SavedPage};
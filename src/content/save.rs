@@ -0,0 +1,82 @@
+//! Saving arbitrary web pages as articles ("Save URL" and `skim save <url>`).
+
+use anyhow::{anyhow, Context, Result};
+
+use super::fetch_content;
+use crate::storage::{CachePolicy, Database};
+use crate::util::{strip_control_chars, validate_url};
+
+/// Longest title (in characters) kept for a saved page.
+const MAX_TITLE_CHARS: usize = 200;
+
+/// A page stored in the "Saved" pseudo-feed.
+#[derive(Debug, Clone)]
+pub struct SavedPage {
+    pub article_id: i64,
+    pub title: String,
+}
+
+/// Fetch a page through the content extraction path and store it as an
+/// article of the "Saved" pseudo-feed.
+///
+/// The extracted markdown is cached and indexed for full-text search. The
+/// title comes from the extraction metadata or the first heading, falling
+/// back to the URL.
+pub async fn save_url(
+    db: &Database,
+    client: &reqwest::Client,
+    cache_policy: &CachePolicy,
+    url: &str,
+) -> Result<SavedPage> {
+    let url = validate_url(url.trim())
+        .map_err(|e| anyhow!("Invalid URL: {}", e))?
+        .to_string();
+    let markdown = fetch_content(client, &url, None)
+        .await
+        .context("Failed to fetch page")?;
+    let title = page_title(&markdown).unwrap_or_else(|| url.clone());
+
+    let article_id = db.save_article(&url, &title).await?;
+    // index_content writes articles.content AND triggers FTS5 update
+    db.index_content(article_id, &markdown).await?;
+    db.cache_content(article_id, &markdown, cache_policy)
+        .await?;
+    Ok(SavedPage { article_id, title })
+}
+
+/// Title of an extracted page: jina.ai's `Title:` metadata line or the
+/// first level-one heading, whichever comes first.
+fn page_title(markdown: &str) -> Option<String> {
+    let title = markdown.lines().map(str::trim).find_map(|line| {
+        line.strip_prefix("Title:")
+            .or_else(|| line.strip_prefix("# "))
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+    })?;
+    Some(
+        strip_control_chars(title)
+            .chars()
+            .take(MAX_TITLE_CHARS)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::page_title;
+
+    #[test]
+    fn test_page_title() {
+        let jina = "Title: Why SQLite\n\nURL Source: https://example.com\n\n# Heading";
+        assert_eq!(page_title(jina).as_deref(), Some("Why SQLite"));
+        assert_eq!(
+            page_title("Intro text\n\n#  A heading \n\n# Second").as_deref(),
+            Some("A heading")
+        );
+        assert_eq!(page_title("Title:\n\n## Only h2"), None);
+        assert_eq!(
+            page_title(&format!("# {}", "x".repeat(500))).unwrap().len(),
+            200
+        );
+    }
+}
@@ -1,16 +1,57 @@
 use crate::app::AppEvent;
-use crate::feed::parser::{parse_feed, ParseResult};
-use crate::storage::{Database, Feed, ParsedArticle};
+use crate::feed::parser::{parse_feed, FeedHints, ParseResult};
+use crate::feed::schedule::{PollSchedule, CADENCE_SAMPLE};
+use crate::rewrite::RewriteSet;
+use crate::rules::RuleSet;
+use crate::storage::{Database, Feed, FetchLogEntry, FetchOutcome, ParsedArticle};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use futures::stream::{self, StreamExt};
+use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use thiserror::Error;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
 
 const MAX_RETRIES: u32 = 3;
 const MAX_FEED_SIZE: usize = 10 * 1024 * 1024; // 10MB
+const MAX_REDIRECTS: usize = 3;
+
+/// Upper bound on a persisted `Retry-After` deferral. A misconfigured server
+/// asking for a week-long wait should not silence a feed indefinitely.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Politeness settings for feed fetching (see `[network]` in config.toml).
+#[derive(Debug, Clone)]
+pub struct FetchPolicy {
+    /// Maximum simultaneous requests to a single host.
+    pub max_concurrent_per_host: usize,
+    /// Minimum delay between the start of two requests to the same host.
+    pub min_host_interval: Duration,
+    /// Longest `Retry-After` wait honored inline; longer waits are persisted.
+    pub max_retry_wait: Duration,
+    /// Adaptive polling bounds (see `[polling]` in config.toml).
+    pub poll: PollSchedule,
+    /// Ingest rules applied to new articles (see `[[rules]]` in config.toml).
+    pub rules: Arc<RuleSet>,
+    /// Title and summary rewrites stored with each entry (see `[[rewrites]]`).
+    pub rewrites: Arc<RewriteSet>,
+}
+
+impl Default for FetchPolicy {
+    fn default() -> Self {
+        Self {
+            max_concurrent_per_host: 2,
+            min_host_interval: Duration::from_millis(250),
+            max_retry_wait: Duration::from_secs(30),
+            poll: PollSchedule::default(),
+            rules: Arc::default(),
+            rewrites: Arc::default(),
+        }
+    }
+}
 
 /// Errors that can occur during feed fetching operations.
 ///
@@ -42,6 +83,220 @@ pub enum FetchError {
     /// Response was incomplete (received fewer bytes than Content-Length)
     #[error("Incomplete response: expected {expected} bytes, received {received}")]
     IncompleteResponse { expected: u64, received: usize },
+    /// Feed responded 410 Gone and should be unsubscribed
+    #[error("Feed is gone (HTTP 410) - consider unsubscribing")]
+    Gone,
+    /// Redirect chain was invalid (too many hops, loop, bad Location)
+    #[error("Redirect error: {0}")]
+    Redirect(String),
+    /// Server asked for a longer wait (Retry-After) than we sleep inline.
+    /// The wait is persisted and does not count toward the circuit breaker.
+    #[error("Server asked to retry in {0}s")]
+    RetryDeferred(u64),
+}
+
+impl FetchError {
+    /// Whether this error should count toward the circuit breaker.
+    ///
+    /// A 410 is a definitive answer rather than a transient failure, and a
+    /// deferral is the server asking us to wait; neither should trip the breaker.
+    fn counts_as_failure(&self) -> bool {
+        !matches!(self, FetchError::RetryDeferred(_) | FetchError::Gone)
+    }
+}
+
+// ============================================================================
+// Per-host Throttling
+// ============================================================================
+
+/// Per-host concurrency and request-spacing limits shared by one refresh run.
+///
+/// `buffer_unordered` bounds total concurrency, but without this many feeds
+/// on the same host (GitHub, Substack, Medium) would be fetched at once.
+struct HostThrottle {
+    policy: FetchPolicy,
+    hosts: Mutex<HashMap<String, Arc<HostSlot>>>,
+}
+
+struct HostSlot {
+    permits: Arc<Semaphore>,
+    /// Earliest time the next request to this host may start
+    next_start: tokio::sync::Mutex<Instant>,
+}
+
+impl HostThrottle {
+    fn new(policy: FetchPolicy) -> Self {
+        Self {
+            policy,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn slot(&self, host: &str) -> Arc<HostSlot> {
+        let mut hosts = self.hosts.lock().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(hosts.entry(host.to_string()).or_insert_with(|| {
+            Arc::new(HostSlot {
+                permits: Arc::new(Semaphore::new(self.policy.max_concurrent_per_host.max(1))),
+                next_start: tokio::sync::Mutex::new(Instant::now()),
+            })
+        }))
+    }
+
+    /// Wait for a free per-host slot and the minimum request interval.
+    ///
+    /// The returned permit must be held for the duration of the request.
+    async fn acquire(&self, url: &str) -> Option<OwnedSemaphorePermit> {
+        let slot = self.slot(&host_key(url));
+        let permit = Arc::clone(&slot.permits).acquire_owned().await.ok();
+
+        let mut next_start = slot.next_start.lock().await;
+        let now = Instant::now();
+        if *next_start > now {
+            tokio::time::sleep_until(*next_start).await;
+        }
+        *next_start = Instant::now() + self.policy.min_host_interval;
+
+        permit
+    }
+}
+
+/// Host used to group feeds for throttling (falls back to the raw URL).
+fn host_key(url: &str) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.to_ascii_lowercase()))
+        .unwrap_or_else(|| url.to_string())
+}
+
+/// Reorder feeds so consecutive entries come from different hosts.
+///
+/// Prevents head-of-line blocking: without interleaving, a run of feeds from
+/// one host would fill every `buffer_unordered` slot while waiting on that
+/// host's throttle.
+fn interleave_by_host(feeds: Vec<Feed>) -> Vec<Feed> {
+    let total = feeds.len();
+    let mut order: Vec<String> = Vec::new();
+    let mut groups: HashMap<String, VecDeque<Feed>> = HashMap::new();
+    for feed in feeds {
+        let key = host_key(&feed.url);
+        groups
+            .entry(key.clone())
+            .or_insert_with(|| {
+                order.push(key);
+                VecDeque::new()
+            })
+            .push_back(feed);
+    }
+
+    let mut result = Vec::with_capacity(total);
+    while result.len() < total {
+        for key in &order {
+            if let Some(feed) = groups.get_mut(key).and_then(VecDeque::pop_front) {
+                result.push(feed);
+            }
+        }
+    }
+    result
+}
+
+/// Parse a `Retry-After` header value: either delta-seconds or an HTTP-date.
+///
+/// Dates in the past yield a zero duration. Returns `None` for values that
+/// match neither form.
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    // HTTP-date (IMF-fixdate, e.g. "Wed, 21 Oct 2015 07:28:00 GMT") is RFC 2822 compatible
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let secs = (date.with_timezone(&Utc) - now).num_seconds().max(0);
+    Some(Duration::from_secs(secs as u64))
+}
+
+/// Read and parse the `Retry-After` header from a response, if present.
+fn retry_after_header(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| parse_retry_after(v, Utc::now()))
+}
+
+// ============================================================================
+// Redirect Handling
+// ============================================================================
+
+/// Response to a feed request after following redirects.
+struct FeedResponse {
+    response: reqwest::Response,
+    /// Final URL when every hop was a permanent redirect (301/308)
+    permanent_url: Option<String>,
+}
+
+/// Send a GET for a feed, following redirects manually.
+///
+/// The feed client is built without automatic redirects so we can tell
+/// permanent moves (301/308) from temporary ones. Mirrors the app's redirect
+/// policy: at most 3 hops with loop detection. Clients that do follow
+/// redirects themselves simply never yield a 3xx here.
+async fn send_feed_request(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<FeedResponse, FetchError> {
+    use reqwest::StatusCode;
+
+    let mut current = url.to_string();
+    let mut visited = vec![current.clone()];
+    let mut all_permanent = true;
+
+    loop {
+        // Fetch with 30 second timeout
+        let response = tokio::time::timeout(Duration::from_secs(30), client.get(&current).send())
+            .await
+            .map_err(|_| FetchError::Timeout)?
+            .map_err(FetchError::Network)?;
+
+        let status = response.status();
+        let is_redirect = matches!(
+            status,
+            StatusCode::MOVED_PERMANENTLY
+                | StatusCode::FOUND
+                | StatusCode::SEE_OTHER
+                | StatusCode::TEMPORARY_REDIRECT
+                | StatusCode::PERMANENT_REDIRECT
+        );
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok());
+        let Some(location) = location.filter(|_| is_redirect) else {
+            let permanent_url = (visited.len() > 1 && all_permanent).then_some(current);
+            return Ok(FeedResponse {
+                response,
+                permanent_url,
+            });
+        };
+
+        if visited.len() > MAX_REDIRECTS {
+            return Err(FetchError::Redirect("Too many redirects (max 3)".into()));
+        }
+        let next = url::Url::parse(&current)
+            .and_then(|base| base.join(location))
+            .map_err(|e| FetchError::Redirect(format!("Invalid Location header: {}", e)))?
+            .to_string();
+        if visited.contains(&next) {
+            return Err(FetchError::Redirect("Redirect loop detected".into()));
+        }
+
+        all_permanent &= matches!(
+            status,
+            StatusCode::MOVED_PERMANENTLY | StatusCode::PERMANENT_REDIRECT
+        );
+        tracing::debug!(from = %current, to = %next, status = %status, "Following feed redirect");
+        visited.push(next.clone());
+        current = next;
+    }
 }
 
 /// Result of a single feed fetch operation.
@@ -67,6 +322,8 @@ pub struct FetchResult {
 /// * `feeds` - List of feeds to refresh (Arc for O(1) cloning from App state)
 /// * `progress_tx` - Channel for progress updates as `(completed, total)` tuples
 /// * `event_tx` - Optional channel for UI events (rate limiting notifications)
+/// * `policy` - Per-host politeness limits, `Retry-After` cap and polling schedule
+/// * `force_all` - Fetch feeds even if their adaptive `next_fetch_at` hasn't arrived
 ///
 /// # Returns
 ///
@@ -75,10 +332,15 @@ pub struct FetchResult {
 ///
 /// # Behavior
 ///
-/// - Skips feeds with 5+ consecutive failures (circuit breaker)
-/// - Fetches up to 10 feeds simultaneously
+/// - Skips feeds with 5+ consecutive failures (circuit breaker) and feeds flagged gone (410)
+/// - Skips feeds whose server asked (via `Retry-After`) to be left alone until later
+/// - Unless `force_all` is set (or `policy.poll.adaptive` is off), skips feeds that
+///   aren't due yet according to their adaptive schedule
+/// - Fetches up to 10 feeds simultaneously, and at most `policy.max_concurrent_per_host`
+///   per host, spaced by `policy.min_host_interval`
 /// - Each request has a 30-second timeout
-/// - Rate limiting (HTTP 429) triggers exponential backoff with up to 3 retries
+/// - Rate limiting (HTTP 429) honors `Retry-After` (capped), else exponential backoff,
+///   with up to 3 retries
 /// - Response bodies are limited to 10MB to prevent memory exhaustion
 /// - PERF-002: Feed error statuses are batch-updated in a single transaction after all fetches complete
 pub async fn refresh_all(
@@ -87,26 +349,68 @@ pub async fn refresh_all(
     feeds: Arc<Vec<Feed>>,
     progress_tx: mpsc::Sender<(usize, usize)>,
     event_tx: Option<mpsc::Sender<AppEvent>>,
+    policy: FetchPolicy,
+    force_all: bool,
 ) -> Vec<FetchResult> {
     if feeds.is_empty() {
         return Vec::new();
     }
 
+    let now = Utc::now().timestamp();
+
+    // Feeds whose server asked us to back off (persisted Retry-After)
+    let deferred = db.get_deferred_feed_ids(now).await.unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Failed to load deferred feeds");
+        Default::default()
+    });
+
     // Filter out feeds that have tripped the circuit breaker
-    let active_feeds: Vec<_> = feeds
+    let breaker_ok = |f: &&Feed| f.consecutive_failures < Database::CIRCUIT_BREAKER_THRESHOLD;
+    let gone = feeds.iter().filter(|f| f.gone).count();
+    if gone > 0 {
+        tracing::info!(skipped = gone, "Skipping feeds that returned 410 Gone");
+    }
+    let reachable: Vec<_> = feeds
         .iter()
-        .filter(|f| f.consecutive_failures < Database::CIRCUIT_BREAKER_THRESHOLD)
-        .cloned()
+        .filter(|f| !f.is_saved())
+        .filter(breaker_ok)
+        .filter(|f| !f.gone)
+        .filter(|f| !deferred.contains(&f.id))
         .collect();
 
-    let skipped = feeds.len() - active_feeds.len();
-    if skipped > 0 {
+    let broken = feeds.iter().filter(|f| !breaker_ok(f) && !f.gone).count();
+    if broken > 0 {
         tracing::info!(
-            skipped = skipped,
+            skipped = broken,
             threshold = Database::CIRCUIT_BREAKER_THRESHOLD,
             "Skipping feeds due to consecutive failures (use Shift+R to force refresh)"
         );
     }
+    // The "Saved" pseudo-feed has nothing to fetch
+    let saved = feeds.iter().filter(|f| f.is_saved()).count();
+    let waiting = feeds.len() - reachable.len() - broken - gone - saved;
+    if waiting > 0 {
+        tracing::info!(
+            skipped = waiting,
+            "Skipping feeds whose server requested a later retry (Retry-After)"
+        );
+    }
+
+    // Adaptive polling: only fetch feeds whose next scheduled fetch has arrived
+    let check_schedule = policy.poll.adaptive && !force_all;
+    let is_due = |f: &&&Feed| !check_schedule || f.next_fetch_at.is_none_or(|at| at <= now);
+    let active_feeds: Vec<Feed> = reachable
+        .iter()
+        .filter(is_due)
+        .map(|f| (*f).clone())
+        .collect();
+    let not_due = reachable.len() - active_feeds.len();
+    if not_due > 0 {
+        tracing::info!(
+            skipped = not_due,
+            "Skipping feeds not yet due (adaptive polling)"
+        );
+    }
 
     if active_feeds.is_empty() {
         // All feeds are circuit-broken, send progress complete immediately
@@ -116,20 +420,24 @@ pub async fn refresh_all(
 
     let total = active_feeds.len();
     let completed = Arc::new(AtomicUsize::new(0));
+    let throttle = Arc::new(HostThrottle::new(policy));
+    let active_feeds = interleave_by_host(active_feeds);
 
     // Clone individual feeds lazily as the stream iterates (not the entire Vec upfront)
     // Feed clone is cheap: Arc<str> for title just increments refcount
-    let results: Vec<FetchResult> = stream::iter(active_feeds.into_iter())
+    let results: Vec<FetchResult> = stream::iter(active_feeds)
         .map(|feed| {
             let db = db.clone();
             let client = client.clone();
             let progress_tx = progress_tx.clone();
             let completed = completed.clone();
             let event_tx = event_tx.clone();
+            let throttle = Arc::clone(&throttle);
 
             async move {
                 let feed_id = feed.id;
-                let result = fetch_one(&db, &client, &feed, event_tx.as_ref()).await;
+                let result =
+                    fetch_and_log(&db, &client, &feed, event_tx.as_ref(), &throttle).await;
 
                 // Update progress (status updates are batched after all fetches complete)
                 let done = completed.fetch_add(1, Ordering::Relaxed).saturating_add(1);
@@ -138,7 +446,7 @@ pub async fn refresh_all(
                 }
 
                 // Track failure count for circuit breaker (success resets in complete_feed_refresh)
-                if result.is_err() {
+                if result.as_ref().is_err_and(FetchError::counts_as_failure) {
                     match db.increment_feed_failures(feed_id).await {
                         Ok(failures) => {
                             if failures >= Database::CIRCUIT_BREAKER_THRESHOLD {
@@ -202,6 +510,7 @@ pub async fn refresh_all(
 /// * `client` - HTTP client for fetching the feed
 /// * `feed` - The feed to refresh
 /// * `event_tx` - Optional channel for UI events (rate limiting notifications)
+/// * `policy` - `Retry-After` cap (per-host limits are moot for a single request)
 ///
 /// # Returns
 ///
@@ -215,6 +524,9 @@ pub async fn refresh_all(
 /// - [`FetchError::Timeout`] - Request exceeded 30 seconds
 /// - [`FetchError::HttpStatus`] - Non-2xx HTTP response
 /// - [`FetchError::RateLimited`] - 429 response after max retries
+/// - [`FetchError::Gone`] - 410 response; the feed is flagged for unsubscription
+/// - [`FetchError::Redirect`] - Too many redirects or a redirect loop
+/// - [`FetchError::RetryDeferred`] - Server asked for a wait longer than `policy.max_retry_wait`
 /// - [`FetchError::ResponseTooLarge`] - Response exceeded 10MB
 /// - [`FetchError::Parse`] - Invalid RSS/Atom XML
 /// - [`FetchError::Database`] - Failed to store articles
@@ -223,15 +535,17 @@ pub async fn refresh_all(
 ///
 /// This function bypasses the circuit breaker filter used by [`refresh_all`].
 /// Used for manual `R` (Shift+R) refresh of a single feed, it always attempts
-/// the fetch regardless of failure count. On success, the circuit breaker
-/// counter is reset via `complete_feed_refresh`.
+/// the fetch regardless of failure count, gone flag or a persisted `Retry-After`. On
+/// success, the circuit breaker counter is reset via `complete_feed_refresh`.
 pub async fn refresh_one(
     db: &Database,
     client: &reqwest::Client,
     feed: &Feed,
     event_tx: Option<&mpsc::Sender<AppEvent>>,
+    policy: FetchPolicy,
 ) -> FetchResult {
-    let result = fetch_one(db, client, feed, event_tx).await;
+    let throttle = HostThrottle::new(policy);
+    let result = fetch_and_log(db, client, feed, event_tx, &throttle).await;
 
     // Update feed error status based on result
     // Note: Success resets circuit breaker via complete_feed_refresh transaction
@@ -268,6 +582,9 @@ async fn record_fetch_result<T>(
         }
         Err(e) => {
             let _ = db.set_feed_error(feed_id, Some(&e.to_string())).await;
+            if !e.counts_as_failure() {
+                return;
+            }
             // Increment failure count for circuit breaker
             match db.increment_feed_failures(feed_id).await {
                 Ok(failures) => {
@@ -292,20 +609,121 @@ async fn record_fetch_result<T>(
     }
 }
 
+/// Persist the feed's next adaptive fetch time after a successful refresh.
+///
+/// Best effort: on failure the feed simply stays due.
+async fn schedule_next_fetch(db: &Database, feed_id: i64, poll: &PollSchedule, hints: &FeedHints) {
+    let published = match db.get_recent_published(feed_id, CADENCE_SAMPLE).await {
+        Ok(published) => published,
+        Err(e) => {
+            tracing::warn!(feed_id, error = %e, "Failed to load publish history for scheduling");
+            return;
+        }
+    };
+    let next = poll.next_fetch_at(&published, hints, Utc::now().timestamp());
+    if let Err(e) = db.set_feed_next_fetch(feed_id, next).await {
+        tracing::warn!(feed_id, error = %e, "Failed to schedule next fetch");
+    }
+}
+
+/// HTTP details of the last attempt, captured for the fetch log.
+#[derive(Default)]
+struct FetchTrace {
+    http_status: Option<u16>,
+    bytes: Option<u64>,
+    /// Request-to-body time of the last attempt (excludes throttle and backoff waits)
+    latency: Duration,
+}
+
+/// Fetch a feed and append the attempt to `feed_fetch_log`.
+async fn fetch_and_log(
+    db: &Database,
+    client: &reqwest::Client,
+    feed: &Feed,
+    event_tx: Option<&mpsc::Sender<AppEvent>>,
+    throttle: &HostThrottle,
+) -> Result<usize, FetchError> {
+    let mut trace = FetchTrace::default();
+    let result = fetch_one(db, client, feed, event_tx, throttle, &mut trace).await;
+
+    let (outcome, new_articles) = match &result {
+        Ok(count) => (FetchOutcome::Ok, *count),
+        Err(FetchError::Gone) => (FetchOutcome::Gone, 0),
+        Err(FetchError::RetryDeferred(_)) => (FetchOutcome::Deferred, 0),
+        Err(_) => (FetchOutcome::Error, 0),
+    };
+    let entry = FetchLogEntry {
+        feed_id: feed.id,
+        outcome,
+        http_status: trace.http_status,
+        bytes: trace.bytes,
+        latency_ms: trace.latency.as_millis() as u64,
+        new_articles,
+        error: result.as_ref().err().map(|e| e.to_string()),
+    };
+    if let Err(e) = db.record_fetch(&entry).await {
+        tracing::warn!(feed_id = feed.id, error = %e, "Failed to record fetch log entry");
+    }
+
+    result
+}
+
 async fn fetch_one(
     db: &Database,
     client: &reqwest::Client,
     feed: &Feed,
     event_tx: Option<&mpsc::Sender<AppEvent>>,
+    throttle: &HostThrottle,
+    trace: &mut FetchTrace,
 ) -> Result<usize, FetchError> {
     let mut retry_count = 0;
+    let mut moved_to: Option<String>;
 
     let bytes = loop {
-        // Fetch with 30 second timeout
-        let response = tokio::time::timeout(Duration::from_secs(30), client.get(&feed.url).send())
-            .await
-            .map_err(|_| FetchError::Timeout)?
-            .map_err(FetchError::Network)?;
+        // Held until the body is read; dropped before any backoff sleep
+        let permit = throttle.acquire(&feed.url).await;
+
+        let started = Instant::now();
+        let sent = send_feed_request(client, &feed.url).await;
+        trace.latency = started.elapsed();
+        let FeedResponse {
+            response,
+            permanent_url,
+        } = sent?;
+        moved_to = permanent_url;
+        trace.http_status = Some(response.status().as_u16());
+
+        if response.status() == reqwest::StatusCode::GONE {
+            tracing::info!(feed = %feed.url, "Feed returned 410 Gone");
+            if let Err(e) = db.set_feed_gone(feed.id).await {
+                tracing::warn!(feed_id = feed.id, error = %e, "Failed to flag feed as gone");
+            }
+            return Err(FetchError::Gone);
+        }
+
+        // Honor the server's Retry-After on 429/503; persist waits too long to sleep through
+        let retry_after = match response.status() {
+            reqwest::StatusCode::TOO_MANY_REQUESTS | reqwest::StatusCode::SERVICE_UNAVAILABLE => {
+                retry_after_header(&response)
+            }
+            _ => None,
+        };
+        if let Some(wait) = retry_after {
+            if wait > throttle.policy.max_retry_wait {
+                let wait = wait.min(MAX_RETRY_AFTER).as_secs();
+                tracing::warn!(
+                    feed = %feed.url,
+                    status = %response.status(),
+                    retry_after_secs = wait,
+                    "Server requested long Retry-After, deferring feed"
+                );
+                let until = Utc::now().timestamp().saturating_add(wait as i64);
+                if let Err(e) = db.set_feed_retry_after(feed.id, until).await {
+                    tracing::warn!(feed_id = feed.id, error = %e, "Failed to persist Retry-After");
+                }
+                return Err(FetchError::RetryDeferred(wait));
+            }
+        }
 
         // EDGE-004: Handle rate limiting with exponential backoff
         if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
@@ -313,7 +731,8 @@ async fn fetch_one(
                 return Err(FetchError::RateLimited(MAX_RETRIES));
             }
 
-            let delay_secs = 2u64.pow(retry_count); // 2s, 4s, 8s
+            let delay = retry_after.unwrap_or(Duration::from_secs(2u64.pow(retry_count))); // 2s, 4s, 8s
+            let delay_secs = delay.as_secs();
             tracing::warn!(
                 feed = %feed.url,
                 retry = retry_count,
@@ -332,7 +751,8 @@ async fn fetch_one(
                     .await;
             }
 
-            tokio::time::sleep(Duration::from_secs(delay_secs)).await;
+            drop(permit);
+            tokio::time::sleep(delay).await;
             retry_count += 1;
             continue;
         }
@@ -343,16 +763,17 @@ async fn fetch_one(
                 return Err(FetchError::HttpStatus(response.status().as_u16()));
             }
 
-            let delay_secs = 2u64.pow(retry_count); // 2s, 4s, 8s
+            let delay = retry_after.unwrap_or(Duration::from_secs(2u64.pow(retry_count))); // 2s, 4s, 8s
             tracing::warn!(
                 feed = %feed.url,
                 status = %response.status(),
                 retry = retry_count,
-                delay_secs = delay_secs,
+                delay_secs = delay.as_secs(),
                 "Server error, retrying after delay"
             );
 
-            tokio::time::sleep(Duration::from_secs(delay_secs)).await;
+            drop(permit);
+            tokio::time::sleep(delay).await;
             retry_count += 1;
             continue;
         }
@@ -363,8 +784,14 @@ async fn fetch_one(
         }
 
         // Read response body with size limit and completeness check
-        match read_limited_bytes(response, MAX_FEED_SIZE).await {
-            Ok(bytes) => break bytes,
+        let body = read_limited_bytes(response, MAX_FEED_SIZE).await;
+        drop(permit);
+        trace.latency = started.elapsed();
+        match body {
+            Ok(bytes) => {
+                trace.bytes = Some(bytes.len() as u64);
+                break bytes;
+            }
             Err(FetchError::IncompleteResponse { expected, received }) => {
                 // EDGE-005: Handle incomplete downloads with retry and exponential backoff
                 if retry_count >= MAX_RETRIES {
@@ -390,8 +817,11 @@ async fn fetch_one(
     };
 
     // Parse feed with best-effort recovery for malformed items
-    let ParseResult { articles, skipped } =
-        parse_feed(&bytes).map_err(|e| FetchError::Parse(e.to_string()))?;
+    let ParseResult {
+        articles,
+        skipped,
+        hints,
+    } = parse_feed(&bytes).map_err(|e| FetchError::Parse(e.to_string()))?;
 
     // Log warning if any articles were filtered due to invalid URLs
     if skipped > 0 {
@@ -411,17 +841,50 @@ async fn fetch_one(
             url: a.url,
             published: a.published,
             summary: a.summary,
+            author: a.author,
         })
         .collect();
 
     // Complete feed refresh atomically: clear error, upsert articles, update timestamp
     // All operations wrapped in single transaction for data integrity
-    let count = db
-        .complete_feed_refresh(feed.id, &parsed)
+    let counts = db
+        .complete_feed_refresh(
+            feed.id,
+            &parsed,
+            &throttle.policy.rules,
+            &throttle.policy.rewrites,
+        )
         .await
         .map_err(|e| FetchError::Database(e.to_string()))?;
+    tracing::debug!(
+        feed = %feed.url,
+        inserted = counts.inserted,
+        updated = counts.updated,
+        unchanged = counts.unchanged,
+        "Feed articles stored"
+    );
+
+    schedule_next_fetch(db, feed.id, &throttle.policy.poll, &hints).await;
+
+    // Adopt the new URL only after a successful parse, and only if it passes
+    // the same validation as user-supplied URLs (no private hosts, credentials)
+    if let Some(new_url) = moved_to.filter(|u| *u != feed.url) {
+        match crate::util::validate_url(&new_url) {
+            Ok(_) => match db.update_feed_url(feed.id, &new_url).await {
+                Ok(()) => {
+                    tracing::info!(from = %feed.url, to = %new_url, "Feed moved permanently, URL updated");
+                }
+                Err(e) => {
+                    tracing::warn!(feed_id = feed.id, to = %new_url, error = %e, "Failed to update moved feed URL");
+                }
+            },
+            Err(e) => {
+                tracing::warn!(feed = %feed.url, to = %new_url, error = %e, "Ignoring permanent redirect to invalid URL");
+            }
+        }
+    }
 
-    Ok(count)
+    Ok(counts.inserted)
 }
 
 async fn read_limited_bytes(
@@ -504,7 +967,7 @@ mod tests {
         let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
         let client = reqwest::Client::new();
 
-        let result = refresh_one(&db, &client, &feed, None).await;
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
         assert!(result.result.is_ok());
         assert_eq!(result.result.unwrap(), 1); // One article inserted
     }
@@ -520,7 +983,7 @@ mod tests {
         let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
         let client = reqwest::Client::new();
 
-        let result = refresh_one(&db, &client, &feed, None).await;
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
         assert!(result.result.is_err());
         match result.result.unwrap_err() {
             FetchError::HttpStatus(404) => {}
@@ -540,7 +1003,7 @@ mod tests {
         let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
         let client = reqwest::Client::new();
 
-        let result = refresh_one(&db, &client, &feed, None).await;
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
         assert!(result.result.is_err());
         match result.result.unwrap_err() {
             FetchError::HttpStatus(500) => {}
@@ -573,7 +1036,7 @@ mod tests {
         let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
         let client = reqwest::Client::new();
 
-        let result = refresh_one(&db, &client, &feed, None).await;
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
         assert!(result.result.is_ok());
         assert_eq!(result.result.unwrap(), 1); // One article inserted after retry
     }
@@ -589,7 +1052,7 @@ mod tests {
         let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
         let client = reqwest::Client::new();
 
-        let result = refresh_one(&db, &client, &feed, None).await;
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
         assert!(result.result.is_err());
         match result.result.unwrap_err() {
             FetchError::Parse(_) => {}
@@ -611,8 +1074,361 @@ mod tests {
         let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
         let client = reqwest::Client::new();
 
-        let result = refresh_one(&db, &client, &feed, None).await;
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
         assert!(result.result.is_ok());
         assert_eq!(result.result.unwrap(), 0); // No articles inserted
     }
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        let now = Utc::now();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+        assert_eq!(parse_retry_after("-5", now), None);
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 08:28:00 GMT", now),
+            Some(Duration::from_secs(3600))
+        );
+        // Dates in the past mean "retry now"
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 06:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+    }
+
+    fn feed_with_url(id: i64, url: &str) -> Feed {
+        Feed {
+            id,
+            title: Arc::from("Feed"),
+            url: url.to_string(),
+            html_url: None,
+            last_fetched: None,
+            error: None,
+            unread_count: 0,
+            consecutive_failures: 0,
+            category_id: None,
+            gone: false,
+            url_auto_updated: false,
+            next_fetch_at: None,
+            prefer_source: false,
+            unread_on_update: false,
+            auto_prefetch: false,
+        }
+    }
+
+    #[test]
+    fn test_interleave_by_host() {
+        let feeds = vec![
+            feed_with_url(1, "https://github.com/a.atom"),
+            feed_with_url(2, "https://GitHub.com/b.atom"),
+            feed_with_url(3, "https://github.com/c.atom"),
+            feed_with_url(4, "https://example.com/rss"),
+            feed_with_url(5, "https://blog.example.org/feed"),
+        ];
+        let ids: Vec<i64> = interleave_by_host(feeds).iter().map(|f| f.id).collect();
+        assert_eq!(ids, vec![1, 4, 5, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_short_wait_is_honored() {
+        use wiremock::matchers::any;
+
+        let mock_server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(200).set_body_string(VALID_RSS))
+            .mount(&mock_server)
+            .await;
+
+        let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
+        let client = reqwest::Client::new();
+
+        let start = std::time::Instant::now();
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
+        assert_eq!(result.result.unwrap(), 1);
+        // Retry-After: 1 replaces the default 1s first backoff step; it must not be skipped
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_long_wait_defers_feed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "3600"))
+            .expect(1) // No inline retries, and the deferred feed is skipped afterwards
+            .mount(&mock_server)
+            .await;
+
+        let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
+        let client = reqwest::Client::new();
+
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
+        match result.result {
+            Err(FetchError::RetryDeferred(3600)) => {}
+            other => panic!("Expected RetryDeferred(3600), got {:?}", other.err()),
+        }
+
+        // Deferral does not count toward the circuit breaker
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        assert_eq!(feeds[0].consecutive_failures, 0);
+
+        // Bulk refresh skips the deferred feed entirely
+        let (progress_tx, _progress_rx) = mpsc::channel(8);
+        let results = refresh_all(
+            db.clone(),
+            client,
+            Arc::new(feeds),
+            progress_tx,
+            None,
+            FetchPolicy::default(),
+            false,
+        )
+        .await;
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_all_spaces_requests_per_host() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(VALID_RSS))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let db = Database::open(":memory:").await.unwrap();
+        let opml: Vec<OpmlFeed> = (0..3)
+            .map(|i| OpmlFeed {
+                title: format!("Feed {}", i),
+                xml_url: format!("{}/feed{}", mock_server.uri(), i),
+                html_url: None,
+            })
+            .collect();
+        db.sync_feeds(&opml).await.unwrap();
+        let feeds = Arc::new(db.get_feeds_with_unread_counts().await.unwrap());
+
+        let policy = FetchPolicy {
+            max_concurrent_per_host: 1,
+            min_host_interval: Duration::from_millis(200),
+            max_retry_wait: Duration::from_secs(30),
+            ..Default::default()
+        };
+        let (progress_tx, _progress_rx) = mpsc::channel(8);
+        let start = std::time::Instant::now();
+        let results = refresh_all(
+            db,
+            reqwest::Client::new(),
+            feeds,
+            progress_tx,
+            None,
+            policy,
+            false,
+        )
+        .await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.result.is_ok()));
+        // Three requests to one host, 200ms apart: at least 400ms in total
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_all_fetches_only_due_feeds() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(VALID_RSS))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
+        let client = reqwest::Client::new();
+
+        // A successful fetch schedules the next one in the future
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
+        assert!(result.result.is_ok());
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        let next = feeds[0].next_fetch_at.expect("next fetch scheduled");
+        assert!(next > Utc::now().timestamp());
+
+        // Not due: skipped by a normal bulk refresh
+        let (progress_tx, _progress_rx) = mpsc::channel(8);
+        let feeds = Arc::new(feeds);
+        let results = refresh_all(
+            db.clone(),
+            client.clone(),
+            Arc::clone(&feeds),
+            progress_tx.clone(),
+            None,
+            FetchPolicy::default(),
+            false,
+        )
+        .await;
+        assert!(results.is_empty());
+
+        // Forced: fetched anyway
+        let results = refresh_all(
+            db,
+            client,
+            feeds,
+            progress_tx,
+            None,
+            FetchPolicy::default(),
+            true,
+        )
+        .await;
+        assert_eq!(results.len(), 1);
+    }
+
+    fn no_redirect_client() -> reqwest::Client {
+        reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_gone_flags_feed_without_tripping_breaker() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(410))
+            .expect(1) // No retries for a definitive 410
+            .mount(&mock_server)
+            .await;
+
+        let (db, feed) = setup_db_with_feed(&format!("{}/feed", mock_server.uri())).await;
+        let client = reqwest::Client::new();
+
+        let result = refresh_one(&db, &client, &feed, None, FetchPolicy::default()).await;
+        assert!(matches!(result.result, Err(FetchError::Gone)));
+
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        assert!(feeds[0].gone);
+        assert_eq!(feeds[0].consecutive_failures, 0);
+
+        // Bulk refresh skips gone feeds
+        let (progress_tx, _progress_rx) = mpsc::channel(8);
+        let results = refresh_all(
+            db,
+            client,
+            Arc::new(feeds),
+            progress_tx,
+            None,
+            FetchPolicy::default(),
+            false,
+        )
+        .await;
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_permanent_redirect_followed_and_unsafe_target_not_recorded() {
+        use wiremock::matchers::path;
+
+        let mock_server = MockServer::start().await;
+        Mock::given(path("/old"))
+            .respond_with(ResponseTemplate::new(301).insert_header("Location", "/new"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/new"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(VALID_RSS))
+            .mount(&mock_server)
+            .await;
+
+        let old_url = format!("{}/old", mock_server.uri());
+        let (db, feed) = setup_db_with_feed(&old_url).await;
+
+        let result = refresh_one(
+            &db,
+            &no_redirect_client(),
+            &feed,
+            None,
+            FetchPolicy::default(),
+        )
+        .await;
+        assert_eq!(result.result.unwrap(), 1);
+
+        // The target is a loopback address, which fails validate_url, so the
+        // stored URL must not be rewritten to it
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        assert_eq!(feeds[0].url, old_url);
+        assert!(!feeds[0].url_auto_updated);
+    }
+
+    #[tokio::test]
+    async fn test_send_feed_request_reports_permanent_target() {
+        use wiremock::matchers::path;
+
+        let mock_server = MockServer::start().await;
+        Mock::given(path("/a"))
+            .respond_with(ResponseTemplate::new(308).insert_header("Location", "/b"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/b"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", "/c"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/c"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(path("/moved"))
+            .respond_with(ResponseTemplate::new(308).insert_header("Location", "/c"))
+            .mount(&mock_server)
+            .await;
+
+        let client = no_redirect_client();
+        let base = mock_server.uri();
+
+        let moved = send_feed_request(&client, &format!("{}/moved", base))
+            .await
+            .unwrap();
+        assert!(moved.response.status().is_success());
+        assert_eq!(moved.permanent_url, Some(format!("{}/c", base)));
+
+        // A chain containing a temporary hop is not a permanent move
+        let mixed = send_feed_request(&client, &format!("{}/a", base))
+            .await
+            .unwrap();
+        assert!(mixed.response.status().is_success());
+        assert_eq!(mixed.permanent_url, None);
+
+        let direct = send_feed_request(&client, &format!("{}/c", base))
+            .await
+            .unwrap();
+        assert_eq!(direct.permanent_url, None);
+    }
+
+    #[tokio::test]
+    async fn test_redirect_loop_detected() {
+        use wiremock::matchers::path;
+
+        let mock_server = MockServer::start().await;
+        Mock::given(path("/loop"))
+            .respond_with(ResponseTemplate::new(301).insert_header("Location", "/loop"))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/loop", mock_server.uri());
+        match send_feed_request(&no_redirect_client(), &url).await {
+            Err(FetchError::Redirect(msg)) => assert!(msg.contains("loop")),
+            other => panic!("Expected redirect loop error, got {:?}", other.err()),
+        }
+    }
 }
@@ -8,31 +8,34 @@
 //!
 //! # Architecture
 //!
-//! The module is organized into three submodules:
+//! The module is organized into four submodules:
 //!
 //! - [`parser`] - Low-level feed parsing using the `feed-rs` crate
 //! - [`fetcher`] - HTTP fetching with progress reporting and database integration
+//! - [`schedule`] - Adaptive per-feed polling intervals
 //! - [`opml`] - OPML file parsing for subscription import/export
 //!
 //! # Example
 //!
 //! ```ignore
-//! use crate::feed::{parse, refresh_all, refresh_one};
+//! use crate::feed::{parse, refresh_all, refresh_one, FetchPolicy};
 //!
 //! // Import feeds from OPML file
 //! let feeds = parse("/path/to/subscriptions.opml")?;
 //!
 //! // Refresh all feeds concurrently
-//! let results = refresh_all(db, client, feeds, progress_tx).await;
+//! let results = refresh_all(db, client, feeds, progress_tx, None, FetchPolicy::default(), false).await;
 //! ```
 
 mod discovery;
 mod fetcher;
 mod opml;
 mod parser;
+mod schedule;
 
 #[allow(unused_imports)] // Re-exported for downstream consumers (TASK-10 integration tests)
 pub use discovery::DiscoveryError;
 pub use discovery::{discover_feed, DiscoveredFeed};
-pub use fetcher::{refresh_all, refresh_one};
+pub use fetcher::{refresh_all, refresh_one, FetchPolicy};
 pub use opml::{export_to_file, export_to_file_with_categories, parse, OpmlFeed};
+pub use schedule::PollSchedule;
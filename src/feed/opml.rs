@@ -167,10 +167,8 @@ fn parse_outline_attributes(
                 }
             }
             b"title" => title = Some(attr.decode_and_unescape_value(decoder)?.to_string()),
-            b"text" => {
-                if title.is_none() {
-                    title = Some(attr.decode_and_unescape_value(decoder)?.to_string())
-                }
+            b"text" if title.is_none() => {
+                title = Some(attr.decode_and_unescape_value(decoder)?.to_string())
             }
             _ => {}
         }
@@ -888,6 +886,12 @@ mod tests {
             unread_count: 0,
             consecutive_failures: 0,
             category_id,
+            gone: false,
+            url_auto_updated: false,
+            next_fetch_at: None,
+            prefer_source: false,
+            unread_on_update: false,
+            auto_prefetch: false,
         }
     }
 
@@ -1,10 +1,13 @@
 use std::fmt::Write;
+use std::time::Duration;
 
 use anyhow::Result;
 use feed_rs::parser;
+use quick_xml::events::Event;
+use quick_xml::Reader;
 use sha2::{Digest, Sha256};
 
-use crate::util::validate_url;
+use crate::util::{canonicalize_url, validate_url};
 
 /// A parsed article extracted from an RSS or Atom feed entry.
 ///
@@ -25,6 +28,8 @@ pub struct ParsedArticle {
     /// Article summary or description text, if available.
     /// Falls back to content body if no explicit summary is provided.
     pub summary: Option<String>,
+    /// Name of the first listed author (`author`/`dc:creator` or Atom `<author>`).
+    pub author: Option<String>,
 }
 
 /// Result of parsing a feed, including both successfully parsed articles
@@ -35,6 +40,23 @@ pub struct ParseResult {
     pub articles: Vec<ParsedArticle>,
     /// Number of feed entries that were skipped due to invalid URLs or other issues.
     pub skipped: usize,
+    /// Channel-level polling hints for the adaptive refresh schedule.
+    pub hints: FeedHints,
+}
+
+/// Polling hints a feed publishes about its own update schedule.
+///
+/// All fields are optional; most feeds carry none of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedHints {
+    /// RSS `<ttl>`: minutes the feed may be cached before it should be refetched.
+    pub ttl_minutes: Option<u32>,
+    /// RSS `<skipHours>`: bitmask of GMT hours (bit 0 = midnight) not worth polling.
+    pub skip_hours: u32,
+    /// RSS `<skipDays>`: bitmask of weekdays (bit 0 = Monday) not worth polling.
+    pub skip_days: u8,
+    /// Syndication module `sy:updatePeriod` divided by `sy:updateFrequency`.
+    pub update_interval: Option<Duration>,
 }
 
 /// Parses RSS or Atom feed XML into a list of articles with best-effort recovery.
@@ -66,6 +88,12 @@ pub struct ParseResult {
 pub fn parse_feed(bytes: &[u8]) -> Result<ParseResult> {
     let feed = parser::parse(bytes)?;
 
+    // feed-rs exposes <ttl> but not skipHours/skipDays or the syndication module
+    let hints = FeedHints {
+        ttl_minutes: feed.ttl.filter(|&ttl| ttl > 0),
+        ..scan_feed_hints(bytes)
+    };
+
     let total_entries = feed.entries.len();
     let mut skipped = 0usize;
 
@@ -79,7 +107,7 @@ pub fn parse_feed(bytes: &[u8]) -> Result<ParseResult> {
                     .links
                     .first()
                     .and_then(|link| match validate_url(&link.href) {
-                        Ok(validated) => Some(validated.to_string()),
+                        Ok(validated) => Some(validated),
                         Err(e) => {
                             tracing::debug!(
                                 url = %link.href,
@@ -97,12 +125,20 @@ pub fn parse_feed(bytes: &[u8]) -> Result<ParseResult> {
                 return None;
             }
 
-            let url_ref = validated_url.as_deref();
+            // GUID fallback hashes the URL as published, so it stays stable
+            // for feeds that predate canonicalization
+            let url_string = validated_url.as_ref().map(|u| u.to_string());
+            let url_ref = url_string.as_deref();
             let published = entry.published.or(entry.updated).map(|dt| dt.timestamp());
             let summary = entry
                 .summary
                 .map(|s| s.content)
                 .or_else(|| entry.content.and_then(|c| c.body));
+            let author = entry
+                .authors
+                .first()
+                .map(|p| p.name.trim().to_owned())
+                .filter(|name| !name.is_empty());
             let title = entry
                 .title
                 .map(|t| t.content)
@@ -118,9 +154,10 @@ pub fn parse_feed(bytes: &[u8]) -> Result<ParseResult> {
             Some(ParsedArticle {
                 guid,
                 title,
-                url: validated_url,
+                url: validated_url.map(|u| canonicalize_url(&u).to_string()),
                 published,
                 summary,
+                author,
             })
         })
         .collect();
@@ -137,7 +174,124 @@ pub fn parse_feed(bytes: &[u8]) -> Result<ParseResult> {
         skipped = calculated_skipped;
     }
 
-    Ok(ParseResult { articles, skipped })
+    Ok(ParseResult {
+        articles,
+        skipped,
+        hints,
+    })
+}
+
+/// Channel element whose text content is a polling hint.
+#[derive(Clone, Copy)]
+enum HintElement {
+    Hour,
+    Day,
+    UpdatePeriod,
+    UpdateFrequency,
+}
+
+/// Scan raw feed XML for `skipHours`, `skipDays` and `sy:update*` hints.
+///
+/// Best-effort: stops at the first XML error and returns what was found.
+/// Elements inside `<item>`/`<entry>` are ignored. The `sy` namespace prefix
+/// isn't checked, only local names, since feeds bind it inconsistently.
+fn scan_feed_hints(bytes: &[u8]) -> FeedHints {
+    let mut reader = Reader::from_reader(bytes);
+    reader.config_mut().trim_text(true);
+
+    let mut hints = FeedHints::default();
+    let mut buf = Vec::new();
+    let mut item_depth = 0usize;
+    let mut in_skip_hours = false;
+    let mut in_skip_days = false;
+    let mut current: Option<HintElement> = None;
+    let mut period: Option<u64> = None;
+    let mut frequency: u64 = 1;
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => item_depth += 1,
+                _ if item_depth > 0 => {}
+                b"skipHours" => in_skip_hours = true,
+                b"skipDays" => in_skip_days = true,
+                b"hour" if in_skip_hours => current = Some(HintElement::Hour),
+                b"day" if in_skip_days => current = Some(HintElement::Day),
+                b"updatePeriod" => current = Some(HintElement::UpdatePeriod),
+                b"updateFrequency" => current = Some(HintElement::UpdateFrequency),
+                _ => current = None,
+            },
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"item" | b"entry" => item_depth = item_depth.saturating_sub(1),
+                b"skipHours" => in_skip_hours = false,
+                b"skipDays" => in_skip_days = false,
+                _ => current = None,
+            },
+            Ok(Event::Text(t)) => {
+                let Some(element) = current.take() else {
+                    buf.clear();
+                    continue;
+                };
+                let Ok(text) = t.unescape() else {
+                    buf.clear();
+                    continue;
+                };
+                let text = text.trim();
+                match element {
+                    // RSS allows 0-23; some feeds write 24 for midnight
+                    HintElement::Hour => {
+                        if let Ok(hour) = text.parse::<u32>() {
+                            if hour <= 24 {
+                                hints.skip_hours |= 1 << (hour % 24);
+                            }
+                        }
+                    }
+                    HintElement::Day => {
+                        if let Some(day) = weekday_index(text) {
+                            hints.skip_days |= 1 << day;
+                        }
+                    }
+                    HintElement::UpdatePeriod => {
+                        period = match text.to_ascii_lowercase().as_str() {
+                            "hourly" => Some(3_600),
+                            "daily" => Some(86_400),
+                            "weekly" => Some(7 * 86_400),
+                            "monthly" => Some(30 * 86_400),
+                            "yearly" => Some(365 * 86_400),
+                            _ => period,
+                        };
+                    }
+                    HintElement::UpdateFrequency => {
+                        if let Ok(n) = text.parse::<u64>() {
+                            frequency = n.max(1);
+                        }
+                    }
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    hints.update_interval = period.map(|secs| Duration::from_secs(secs / frequency));
+    hints
+}
+
+/// Map an RSS `<day>` name to a weekday index (Monday = 0).
+fn weekday_index(name: &str) -> Option<u8> {
+    const DAYS: [&str; 7] = [
+        "monday",
+        "tuesday",
+        "wednesday",
+        "thursday",
+        "friday",
+        "saturday",
+        "sunday",
+    ];
+    DAYS.iter()
+        .position(|d| d.eq_ignore_ascii_case(name))
+        .map(|i| i as u8)
 }
 
 /// Generates a unique identifier for an article.
@@ -245,6 +399,19 @@ mod tests {
         assert_eq!(result.articles[0].title, "Test Article");
     }
 
+    #[test]
+    fn test_parse_author() {
+        let atom = ATOM_MINIMAL.replace(
+            "<summary>",
+            "<author><name> Jane Doe </name></author><summary>",
+        );
+        let result = parse_feed(atom.as_bytes()).unwrap();
+        assert_eq!(result.articles[0].author.as_deref(), Some("Jane Doe"));
+
+        let result = parse_feed(RSS_MINIMAL.as_bytes()).unwrap();
+        assert_eq!(result.articles[0].author, None);
+    }
+
     #[test]
     fn test_parse_empty_feed() {
         let empty_rss = r#"<?xml version="1.0"?><rss version="2.0"><channel></channel></rss>"#;
@@ -420,6 +587,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_article_url_canonicalized() {
+        let rss = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><item>
+    <title>Tracked</title>
+    <link>https://example.com/post?utm_source=rss&amp;id=7#comments</link>
+</item></channel></rss>"#;
+        let result = parse_feed(rss.as_bytes()).unwrap();
+        assert_eq!(
+            result.articles[0].url,
+            Some("https://example.com/post?id=7".to_owned())
+        );
+
+        // GUID still derives from the link as published, so it doesn't change
+        let untracked = rss.replace("utm_source=rss&amp;", "");
+        let other = parse_feed(untracked.as_bytes()).unwrap();
+        assert_ne!(result.articles[0].guid, other.articles[0].guid);
+    }
+
     #[test]
     fn test_valid_http_url_accepted() {
         let rss = r#"<?xml version="1.0"?>
@@ -490,6 +676,37 @@ mod tests {
         assert_eq!(result.articles[1].guid, "valid-2");
     }
 
+    #[test]
+    fn test_feed_hints_rss() {
+        let rss = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:sy="http://purl.org/rss/1.0/modules/syndication/"><channel>
+    <title>Hinted</title>
+    <ttl>120</ttl>
+    <skipHours><hour>0</hour><hour>23</hour><hour>24</hour></skipHours>
+    <skipDays><day>Saturday</day><day>Sunday</day></skipDays>
+    <sy:updatePeriod>daily</sy:updatePeriod>
+    <sy:updateFrequency>4</sy:updateFrequency>
+    <item>
+        <guid>1</guid>
+        <title>Item with its own period</title>
+        <sy:updatePeriod>yearly</sy:updatePeriod>
+    </item>
+</channel></rss>"#;
+        let hints = parse_feed(rss.as_bytes()).unwrap().hints;
+        assert_eq!(hints.ttl_minutes, Some(120));
+        assert_eq!(hints.skip_hours, (1 << 0) | (1 << 23));
+        assert_eq!(hints.skip_days, (1 << 5) | (1 << 6));
+        assert_eq!(hints.update_interval, Some(Duration::from_secs(6 * 3600)));
+    }
+
+    #[test]
+    fn test_feed_hints_absent() {
+        let result = parse_feed(RSS_MINIMAL.as_bytes()).unwrap();
+        assert_eq!(result.hints, FeedHints::default());
+        let result = parse_feed(ATOM_MINIMAL.as_bytes()).unwrap();
+        assert_eq!(result.hints, FeedHints::default());
+    }
+
     // Property-based tests
 
     proptest! {
@@ -0,0 +1,225 @@
+//! Adaptive per-feed polling schedule.
+//!
+//! Each successful fetch schedules the next one from the feed's publishing
+//! cadence (median gap between recent articles) and its own hints (`<ttl>`,
+//! `sy:updatePeriod`, `<skipHours>`/`<skipDays>`). Bulk refresh then skips
+//! feeds that aren't due yet.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Timelike};
+
+use super::parser::FeedHints;
+
+/// Number of recent publish timestamps used to estimate a feed's cadence.
+pub(crate) const CADENCE_SAMPLE: u32 = 20;
+
+/// Interval for feeds without enough dated articles to estimate a cadence.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Upper bound on hours skipped for `<skipHours>`/`<skipDays>` (one week).
+const MAX_SKIP_HOURS: u32 = 7 * 24;
+
+/// Bounds for the adaptive refresh schedule (see `[polling]` in config.toml).
+#[derive(Debug, Clone, Copy)]
+pub struct PollSchedule {
+    /// When false, bulk refresh fetches every feed regardless of schedule.
+    pub adaptive: bool,
+    /// Shortest interval between fetches of one feed.
+    pub min_interval: Duration,
+    /// Longest interval between fetches of one feed.
+    pub max_interval: Duration,
+}
+
+impl Default for PollSchedule {
+    fn default() -> Self {
+        Self {
+            adaptive: true,
+            min_interval: Duration::from_secs(15 * 60),
+            max_interval: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+impl PollSchedule {
+    /// Interval until the next fetch of a feed.
+    ///
+    /// `published` holds recent publish timestamps, newest first. The feed is
+    /// polled about twice per typical posting gap, backing off further while
+    /// it stays quiet. Feed hints only ever lengthen the interval, and the
+    /// result is clamped to `[min_interval, max_interval]`.
+    pub fn interval(&self, published: &[i64], hints: &FeedHints, now: i64) -> Duration {
+        let mut secs = match median_gap(published) {
+            Some(gap) => {
+                let quiet_for = published.first().map_or(0, |&latest| now - latest).max(0);
+                (gap / 2).max(quiet_for / 4) as u64
+            }
+            None => DEFAULT_INTERVAL.as_secs(),
+        };
+
+        if let Some(ttl) = hints.ttl_minutes {
+            secs = secs.max(u64::from(ttl) * 60);
+        }
+        if let Some(update) = hints.update_interval {
+            secs = secs.max(update.as_secs());
+        }
+
+        Duration::from_secs(secs).clamp(self.min_interval, self.max_interval)
+    }
+
+    /// Unix timestamp of the next fetch, moved past any hours or weekdays the
+    /// feed asked not to be polled in (`<skipHours>`/`<skipDays>`, both GMT).
+    pub fn next_fetch_at(&self, published: &[i64], hints: &FeedHints, now: i64) -> i64 {
+        let interval = self.interval(published, hints, now).as_secs() as i64;
+        let mut next = now.saturating_add(interval);
+        if hints.skip_hours == 0 && hints.skip_days == 0 {
+            return next;
+        }
+
+        for _ in 0..MAX_SKIP_HOURS {
+            let Some(at) = DateTime::from_timestamp(next, 0) else {
+                break;
+            };
+            let hour_skipped = hints.skip_hours & (1 << at.hour()) != 0;
+            let day_skipped = hints.skip_days & (1 << at.weekday().num_days_from_monday()) != 0;
+            if !hour_skipped && !day_skipped {
+                break;
+            }
+            // Jump to the start of the next hour
+            next += 3600 - i64::from(at.minute() * 60 + at.second());
+        }
+        next
+    }
+}
+
+/// Median gap in seconds between consecutive publish timestamps.
+///
+/// Returns `None` with fewer than two distinct timestamps.
+fn median_gap(published: &[i64]) -> Option<i64> {
+    let mut gaps: Vec<i64> = published
+        .windows(2)
+        .map(|w| (w[0] - w[1]).abs())
+        .filter(|&gap| gap > 0)
+        .collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    gaps.sort_unstable();
+    Some(gaps[gaps.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3600;
+    const DAY: i64 = 24 * HOUR;
+    // Monday 2024-01-15 12:00:00 UTC
+    const NOW: i64 = 1705320000;
+
+    fn series(latest: i64, gap: i64, n: i64) -> Vec<i64> {
+        (0..n).map(|i| latest - i * gap).collect()
+    }
+
+    #[test]
+    fn test_interval_follows_cadence() {
+        let schedule = PollSchedule::default();
+        let hints = FeedHints::default();
+
+        // Posts every 4 hours, latest just now: poll every 2 hours
+        let hourly = series(NOW, 4 * HOUR, 10);
+        assert_eq!(
+            schedule.interval(&hourly, &hints, NOW),
+            Duration::from_secs(2 * HOUR as u64)
+        );
+
+        // Posts every few minutes: clamped to the minimum
+        let busy = series(NOW, 60, 10);
+        assert_eq!(schedule.interval(&busy, &hints, NOW), schedule.min_interval);
+
+        // Twice a year: clamped to the maximum
+        let rare = series(NOW - 30 * DAY, 180 * DAY, 4);
+        assert_eq!(schedule.interval(&rare, &hints, NOW), schedule.max_interval);
+    }
+
+    #[test]
+    fn test_interval_backs_off_when_quiet() {
+        let schedule = PollSchedule::default();
+        // Used to post hourly, silent for two days
+        let lapsed = series(NOW - 2 * DAY, HOUR, 10);
+        assert_eq!(
+            schedule.interval(&lapsed, &FeedHints::default(), NOW),
+            Duration::from_secs(12 * HOUR as u64)
+        );
+    }
+
+    #[test]
+    fn test_interval_without_history() {
+        let schedule = PollSchedule::default();
+        let hints = FeedHints::default();
+        assert_eq!(schedule.interval(&[], &hints, NOW), DEFAULT_INTERVAL);
+        assert_eq!(schedule.interval(&[NOW], &hints, NOW), DEFAULT_INTERVAL);
+    }
+
+    #[test]
+    fn test_hints_lengthen_interval() {
+        let schedule = PollSchedule::default();
+        let busy = series(NOW, 60, 10);
+
+        let ttl = FeedHints {
+            ttl_minutes: Some(180),
+            ..Default::default()
+        };
+        assert_eq!(
+            schedule.interval(&busy, &ttl, NOW),
+            Duration::from_secs(3 * HOUR as u64)
+        );
+
+        let sy = FeedHints {
+            update_interval: Some(Duration::from_secs(6 * HOUR as u64)),
+            ..Default::default()
+        };
+        assert_eq!(
+            schedule.interval(&busy, &sy, NOW),
+            Duration::from_secs(6 * HOUR as u64)
+        );
+
+        // Even a week-long TTL is capped by max_interval
+        let weekly = FeedHints {
+            ttl_minutes: Some(7 * 24 * 60),
+            ..Default::default()
+        };
+        assert_eq!(
+            schedule.interval(&busy, &weekly, NOW),
+            schedule.max_interval
+        );
+    }
+
+    #[test]
+    fn test_next_fetch_skips_hours_and_days() {
+        let schedule = PollSchedule::default();
+        let busy = series(NOW, 60, 10);
+
+        // Due at 12:15 Monday, but 12:00-13:59 GMT are skipped
+        let hours = FeedHints {
+            skip_hours: (1 << 12) | (1 << 13),
+            ..Default::default()
+        };
+        assert_eq!(schedule.next_fetch_at(&busy, &hours, NOW), NOW + 2 * HOUR);
+
+        // Monday skipped entirely: first fetch at Tuesday midnight
+        let days = FeedHints {
+            skip_days: 1,
+            ..Default::default()
+        };
+        assert_eq!(schedule.next_fetch_at(&busy, &days, NOW), NOW + 12 * HOUR);
+
+        // Every hour skipped: give up after a week rather than loop forever
+        let all = FeedHints {
+            skip_hours: (1 << 24) - 1,
+            ..Default::default()
+        };
+        let next = schedule.next_fetch_at(&busy, &all, NOW);
+        assert!(next <= NOW + 15 * 60 + 7 * DAY);
+    }
+}
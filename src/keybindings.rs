@@ -19,6 +19,7 @@ pub enum Action {
     Back,
     Select,
     RefreshAll,
+    ForceRefreshAll,
     RefreshOne,
     ToggleStar,
     ToggleStarredMode,
@@ -45,6 +46,36 @@ pub enum Action {
     ContextMenu,
     Prefetch,
     ViewStats,
+    ViewFeedHealth,
+    ViewCache,
+    RediscoverFeed,
+    ToggleRevisions,
+    EditTags,
+    ToggleReadLater,
+    ToggleReadLaterMode,
+    ToggleArchive,
+    MoveItemUp,
+    MoveItemDown,
+    SaveUrl,
+    FollowLink,
+    HistoryBack,
+    HistoryForward,
+    SearchNext,
+    SearchPrev,
+    ScrollLeft,
+    ScrollRight,
+    NextPage,
+    TogglePaginate,
+    ToggleJustify,
+    NarrowText,
+    WidenText,
+    ToggleContinueReading,
+    NextArticle,
+    PrevArticle,
+    NextUnreadArticle,
+    CycleReadingTimeFilter,
+    ToggleTldr,
+    ToggleTldrPreview,
 }
 
 impl Action {
@@ -57,7 +88,8 @@ impl Action {
             Self::CycleFocus => "Cycle panel focus",
             Self::Back => "Go back / dismiss",
             Self::Select => "Select / open",
-            Self::RefreshAll => "Refresh all feeds",
+            Self::RefreshAll => "Refresh feeds that are due",
+            Self::ForceRefreshAll => "Refresh all feeds, ignoring the polling schedule",
             Self::RefreshOne => "Refresh current feed",
             Self::ToggleStar => "Toggle star on article",
             Self::ToggleStarredMode => "Toggle starred articles view",
@@ -84,6 +116,36 @@ impl Action {
             Self::ContextMenu => "Feed context menu",
             Self::Prefetch => "Prefetch articles for offline",
             Self::ViewStats => "View reading stats",
+            Self::ViewFeedHealth => "View feed health",
+            Self::ViewCache => "View and purge the offline cache",
+            Self::RediscoverFeed => "Rediscover feed URL from its website",
+            Self::ToggleRevisions => "Show changes from earlier versions of the article",
+            Self::EditTags => "Edit article tags",
+            Self::ToggleReadLater => "Add to or remove from read-later queue",
+            Self::ToggleReadLaterMode => "Toggle read-later queue view",
+            Self::ToggleArchive => "Archive article for permanent offline reading",
+            Self::MoveItemUp => "Move article up in read-later queue",
+            Self::MoveItemDown => "Move article down in read-later queue",
+            Self::SaveUrl => "Save a web page as an article",
+            Self::FollowLink => "Pick a numbered link to open or read",
+            Self::HistoryBack => "Go back to the previous page in the reader",
+            Self::HistoryForward => "Go forward to the next page in the reader",
+            Self::SearchNext => "Jump to the next match in the article",
+            Self::SearchPrev => "Jump to the previous match in the article",
+            Self::ScrollLeft => "Scroll code blocks left",
+            Self::ScrollRight => "Scroll code blocks right",
+            Self::NextPage => "Next page of the article",
+            Self::TogglePaginate => "Toggle paginated reading",
+            Self::ToggleJustify => "Toggle justified text",
+            Self::NarrowText => "Narrow the text column",
+            Self::WidenText => "Widen the text column",
+            Self::ToggleContinueReading => "Toggle partially read articles view",
+            Self::NextArticle => "Read the next article in the list",
+            Self::PrevArticle => "Read the previous article in the list",
+            Self::NextUnreadArticle => "Read the next unread article in the list",
+            Self::CycleReadingTimeFilter => "Filter articles by reading time",
+            Self::ToggleTldr => "Expand / collapse the article's TL;DR",
+            Self::ToggleTldrPreview => "Toggle TL;DR preview of the selected article",
         }
     }
 }
@@ -290,6 +352,7 @@ impl KeybindingRegistry {
             KeySpec::plain(KeyCode::Char('r')),
             Action::RefreshAll,
         );
+        self.bind(Context::Global, KeySpec::ctrl('r'), Action::ForceRefreshAll);
         self.bind(
             Context::Global,
             KeySpec::plain(KeyCode::Char('R')),
@@ -312,6 +375,16 @@ impl KeybindingRegistry {
             KeySpec::plain(KeyCode::Char('I')),
             Action::ViewStats,
         );
+        self.bind(
+            Context::Global,
+            KeySpec::plain(KeyCode::Char('H')),
+            Action::ViewFeedHealth,
+        );
+        self.bind(
+            Context::Global,
+            KeySpec::plain(KeyCode::Char('C')),
+            Action::ViewCache,
+        );
 
         // Search
         self.bind(
@@ -365,6 +438,13 @@ impl KeybindingRegistry {
             Action::Subscribe,
         );
 
+        // Rediscover feed URL (feed list context, also used by the feed health view)
+        self.bind(
+            Context::FeedList,
+            KeySpec::plain(KeyCode::Char('F')),
+            Action::RediscoverFeed,
+        );
+
         // Feed context menu (feed list context)
         self.bind(
             Context::FeedList,
@@ -391,6 +471,69 @@ impl KeybindingRegistry {
             Action::ShowHelp,
         );
 
+        // Tag picker (article list context)
+        self.bind(
+            Context::ArticleList,
+            KeySpec::plain(KeyCode::Char('t')),
+            Action::EditTags,
+        );
+
+        // Read-later queue: add/remove (article list), view (global),
+        // reorder (article list, read-later view only)
+        self.bind(
+            Context::ArticleList,
+            KeySpec::plain(KeyCode::Char('l')),
+            Action::ToggleReadLater,
+        );
+        self.bind(
+            Context::Global,
+            KeySpec::plain(KeyCode::Char('L')),
+            Action::ToggleReadLaterMode,
+        );
+
+        // Articles left part-way, to pick up where reading stopped
+        self.bind(
+            Context::Global,
+            KeySpec::plain(KeyCode::Char('U')),
+            Action::ToggleContinueReading,
+        );
+
+        // Offline archive
+        self.bind(
+            Context::ArticleList,
+            KeySpec::plain(KeyCode::Char('X')),
+            Action::ToggleArchive,
+        );
+        self.bind(
+            Context::ArticleList,
+            KeySpec::plain(KeyCode::Char('K')),
+            Action::MoveItemUp,
+        );
+        self.bind(
+            Context::ArticleList,
+            KeySpec::plain(KeyCode::Char('J')),
+            Action::MoveItemDown,
+        );
+
+        // Short reads: only articles read within 5, 10 or 20 minutes
+        self.bind(
+            Context::ArticleList,
+            KeySpec::plain(KeyCode::Char('f')),
+            Action::CycleReadingTimeFilter,
+        );
+        self.bind(
+            Context::ArticleList,
+            KeySpec::plain(KeyCode::Char('v')),
+            Action::ToggleTldrPreview,
+        );
+
+        // Save a web page into the "Saved" feed
+        self.bind(
+            Context::Global,
+            KeySpec::plain(KeyCode::Char('W')),
+            Action::SaveUrl,
+        );
+
         // Categories panel toggle
         self.bind(
             Context::Global,
@@ -480,6 +623,134 @@ impl KeybindingRegistry {
             Action::OpenInBrowser,
         );
 
+        // Diff against earlier versions of an edited article
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('u')),
+            Action::ToggleRevisions,
+        );
+
+        // Tag picker in reader
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('t')),
+            Action::EditTags,
+        );
+
+        // Read-later queue in reader
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('l')),
+            Action::ToggleReadLater,
+        );
+
+        // Offline archive in reader
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('X')),
+            Action::ToggleArchive,
+        );
+
+        // Numbered links and the pages they lead to
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('f')),
+            Action::FollowLink,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Backspace),
+            Action::HistoryBack,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('[')),
+            Action::HistoryBack,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char(']')),
+            Action::HistoryForward,
+        );
+
+        // Matches of the in-article search (started with the global `/`)
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('n')),
+            Action::SearchNext,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('N')),
+            Action::SearchPrev,
+        );
+
+        // Long code lines aren't wrapped but scroll sideways
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Left),
+            Action::ScrollLeft,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Right),
+            Action::ScrollRight,
+        );
+
+        // Reader layout
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char(' ')),
+            Action::NextPage,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('p')),
+            Action::TogglePaginate,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('J')),
+            Action::ToggleJustify,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('-')),
+            Action::NarrowText,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('+')),
+            Action::WidenText,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('=')),
+            Action::WidenText,
+        );
+
+        // Move through the list the reader was opened from
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('>')),
+            Action::NextArticle,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('<')),
+            Action::PrevArticle,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('.')),
+            Action::NextUnreadArticle,
+        );
+        self.bind(
+            Context::Reader,
+            KeySpec::plain(KeyCode::Char('z')),
+            Action::ToggleTldr,
+        );
+
         // === Search mode ===
         self.bind(
             Context::Search,
@@ -604,6 +875,7 @@ fn parse_action_name(name: &str) -> Option<Action> {
         "back" => Some(Action::Back),
         "select" | "enter" => Some(Action::Select),
         "refresh_all" | "refreshall" | "refresh" => Some(Action::RefreshAll),
+        "force_refresh_all" | "forcerefreshall" | "force_refresh" => Some(Action::ForceRefreshAll),
         "refresh_one" | "refreshone" => Some(Action::RefreshOne),
         "toggle_star" | "togglestar" | "star" => Some(Action::ToggleStar),
         "toggle_starred_mode" | "togglestarredmode" | "starred" => Some(Action::ToggleStarredMode),
@@ -629,6 +901,46 @@ fn parse_action_name(name: &str) -> Option<Action> {
         "expand_category" | "expandcategory" => Some(Action::ExpandCategory),
         "context_menu" | "contextmenu" | "menu" => Some(Action::ContextMenu),
         "view_stats" | "viewstats" | "stats" => Some(Action::ViewStats),
+        "view_feed_health" | "viewfeedhealth" | "health" => Some(Action::ViewFeedHealth),
+        "view_cache" | "viewcache" | "cache" => Some(Action::ViewCache),
+        "rediscover_feed" | "rediscoverfeed" | "rediscover" => Some(Action::RediscoverFeed),
+        "toggle_revisions" | "togglerevisions" | "revisions" => Some(Action::ToggleRevisions),
+        "edit_tags" | "edittags" | "tags" => Some(Action::EditTags),
+        "toggle_read_later" | "togglereadlater" | "read_later" => Some(Action::ToggleReadLater),
+        "toggle_read_later_mode" | "togglereadlatermode" | "queue" => {
+            Some(Action::ToggleReadLaterMode)
+        }
+        "toggle_archive" | "togglearchive" | "archive" => Some(Action::ToggleArchive),
+        "move_item_up" | "moveitemup" | "move_up" => Some(Action::MoveItemUp),
+        "move_item_down" | "moveitemdown" | "move_down" => Some(Action::MoveItemDown),
+        "save_url" | "saveurl" | "save" => Some(Action::SaveUrl),
+        "follow_link" | "followlink" | "links" => Some(Action::FollowLink),
+        "history_back" | "historyback" | "page_back" => Some(Action::HistoryBack),
+        "history_forward" | "historyforward" | "page_forward" => Some(Action::HistoryForward),
+        "search_next" | "searchnext" | "next_match" => Some(Action::SearchNext),
+        "search_prev" | "searchprev" | "prev_match" => Some(Action::SearchPrev),
+        "scroll_left" | "scrollleft" => Some(Action::ScrollLeft),
+        "scroll_right" | "scrollright" => Some(Action::ScrollRight),
+        "next_page" | "nextpage" => Some(Action::NextPage),
+        "toggle_paginate" | "togglepaginate" | "paginate" => Some(Action::TogglePaginate),
+        "toggle_justify" | "togglejustify" | "justify" => Some(Action::ToggleJustify),
+        "narrow_text" | "narrowtext" => Some(Action::NarrowText),
+        "widen_text" | "widentext" => Some(Action::WidenText),
+        "next_article" | "nextarticle" => Some(Action::NextArticle),
+        "prev_article" | "prevarticle" | "previous_article" => Some(Action::PrevArticle),
+        "next_unread_article" | "nextunreadarticle" | "next_unread" => {
+            Some(Action::NextUnreadArticle)
+        }
+        "cycle_reading_time_filter" | "cyclereadingtimefilter" | "reading_time_filter" => {
+            Some(Action::CycleReadingTimeFilter)
+        }
+        "toggle_tldr" | "toggletldr" | "tldr" => Some(Action::ToggleTldr),
+        "toggle_tldr_preview" | "toggletldrpreview" | "tldr_preview" => {
+            Some(Action::ToggleTldrPreview)
+        }
+        "toggle_continue_reading" | "togglecontinuereading" | "continue_reading" => {
+            Some(Action::ToggleContinueReading)
+        }
         _ => None,
     }
 }
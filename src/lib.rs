@@ -3,5 +3,7 @@
 //! This library crate re-exports modules for integration testing.
 //! The binary entry point is in `main.rs`.
 
+pub mod rewrite;
+pub mod rules;
 pub mod storage;
 pub mod util;
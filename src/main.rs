@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use chrono::Utc;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
@@ -11,13 +11,17 @@ mod content;
 mod feed;
 mod keybindings;
 mod preferences;
+mod rewrite;
+mod rules;
 mod storage;
 mod theme;
 mod ui;
 mod util;
 
 use app::{App, AppEvent};
-use storage::{Database, DatabaseError, OpmlFeed};
+use rewrite::{Field, RewriteSet, RewriteTarget};
+use storage::{CachePolicy, Database, DatabaseError, OpmlFeed};
+use util::{line_diff, DiffLine};
 
 /// Get the config directory path (~/.config/skim/)
 fn get_config_dir() -> Result<PathBuf> {
@@ -164,6 +168,104 @@ fn rotate_opml_backups(config_dir: &Path, keep: usize) {
     }
 }
 
+/// Entries shown by `--preview-rewrites`.
+const REWRITE_PREVIEW_ENTRIES: i64 = 10;
+
+/// Print the original and rewritten title, summary and cached content of a
+/// feed's latest entries. Nothing is written to the database.
+async fn preview_rewrites(db: &Database, rewrites: &RewriteSet, feed: &str) -> Result<()> {
+    let feeds = db.get_feeds_with_unread_counts().await?;
+    let pattern = feed.to_lowercase();
+    let Some(feed) = feeds
+        .iter()
+        .find(|f| rules::feed_matches(&pattern, &f.title, &f.url))
+    else {
+        anyhow::bail!("No feed with title or URL '{}'", feed);
+    };
+
+    let entries = db
+        .get_rewrite_preview(feed.id, REWRITE_PREVIEW_ENTRIES)
+        .await?;
+    println!("{} ({} latest entries)", feed.title, entries.len());
+    for entry in &entries {
+        let target = RewriteTarget {
+            feed_title: &feed.title,
+            feed_url: &feed.url,
+            url: entry.url.as_deref(),
+        };
+        println!();
+        println!("{}", entry.title);
+        let names = rewrites.names_for(&target);
+        if names.is_empty() {
+            println!("  no rewrites apply");
+            continue;
+        }
+        println!("  rewrites: {}", names.join(", "));
+
+        let texts = [
+            (Field::Title, "title", Some(entry.title.as_str())),
+            (Field::Summary, "summary", entry.summary.as_deref()),
+            (Field::Content, "content", entry.markdown.as_deref()),
+        ];
+        for (field, label, original) in texts {
+            let Some(original) = original else {
+                continue;
+            };
+            let Some(rewritten) = rewrites.apply(field, &target, original) else {
+                println!("  {label}: unchanged");
+                continue;
+            };
+            println!("  {label}:");
+            for line in line_diff(original, &rewritten) {
+                match line {
+                    DiffLine::Same(_) => {}
+                    DiffLine::Removed(l) => println!("    - {l}"),
+                    DiffLine::Added(l) => println!("    + {l}"),
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write every tagged article, with its feed and tags, to a JSON file
+/// (`--export-articles`).
+async fn export_articles(db: &Database, path: &Path) -> Result<()> {
+    let parent = path.parent().unwrap_or(Path::new("."));
+    if !parent.as_os_str().is_empty() && !parent.exists() {
+        anyhow::bail!("Parent directory does not exist: {}", parent.display());
+    }
+    let articles = db.get_tagged_articles_for_export().await?;
+    if articles.is_empty() {
+        println!("No tagged articles to export.");
+        return Ok(());
+    }
+    let entries: Vec<serde_json::Value> = articles
+        .iter()
+        .map(|a| {
+            serde_json::json!({
+                "title": a.title,
+                "url": a.url,
+                "published": a.published
+                    .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+                    .map(|t| t.to_rfc3339()),
+                "read": a.read,
+                "feed": { "title": a.feed_title, "url": a.feed_url },
+                "tags": a.tags,
+            })
+        })
+        .collect();
+    let json = serde_json::to_string_pretty(&entries)?;
+    std::fs::write(path, json + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    println!(
+        "Exported {} articles to: {}",
+        articles.len(),
+        path.display()
+    );
+    Ok(())
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "skim", about = "Terminal RSS reader with jina.ai integration")]
 struct Args {
@@ -179,9 +281,58 @@ struct Args {
     #[arg(long, value_name = "FILE")]
     export: Option<PathBuf>,
 
+    /// Export tagged, starred and archived articles with their tags to a JSON file
+    #[arg(long, value_name = "FILE")]
+    export_articles: Option<PathBuf>,
+
     /// Rebuild the search index (FTS5)
     #[arg(long)]
     rebuild_search: bool,
+
+    /// Apply the [[rules]] in config.toml to all stored articles, then print rule hit counts
+    #[arg(long)]
+    apply_rules: bool,
+
+    /// Show the [[rewrites]] in config.toml applied to the latest entries of a feed (title or URL)
+    #[arg(long, value_name = "FEED")]
+    preview_rewrites: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Save a web page as an article in the "Saved" feed
+    Save {
+        /// Address of the page
+        url: String,
+    },
+}
+
+/// Open the database, exiting with a message if another instance holds it.
+async fn open_database(db_path: &Path) -> Result<Database> {
+    let db_path_str = db_path
+        .to_str()
+        .ok_or_else(|| anyhow::anyhow!("Invalid UTF-8 in database path"))?;
+    match Database::open(db_path_str).await {
+        Ok(db) => Ok(db),
+        Err(DatabaseError::InstanceLocked) => {
+            eprintln!(
+                "Error: Another instance of skim appears to be running. Please close it and try again."
+            );
+            std::process::exit(1);
+        }
+        Err(e) => Err(anyhow::anyhow!("Failed to open database: {}", e)),
+    }
+}
+
+/// Fetch a page and store it in the "Saved" feed (`skim save <url>`).
+async fn save_page(db: &Database, cache_policy: &CachePolicy, url: &str) -> Result<()> {
+    let client = app::create_content_client()?;
+    let page = content::save_url(db, &client, cache_policy, url).await?;
+    println!("Saved \"{}\"", page.title);
+    Ok(())
 }
 
 #[tokio::main]
@@ -234,6 +385,15 @@ async fn main() -> Result<()> {
     let opml_path = config_dir.join("feeds.opml");
     let db_path = config_dir.join("rss.db");
 
+    // Handle `skim save <url>` (needs no feeds file)
+    if let Some(Command::Save { url }) = &args.command {
+        let db = open_database(&db_path).await?;
+        let cache_policy = config::Config::load(&config_dir.join("config.toml"))
+            .map(|c| c.cache_policy())
+            .unwrap_or_default();
+        return save_page(&db, &cache_policy, url).await;
+    }
+
     // Handle --import flag
     if let Some(import_file) = &args.import {
         // S-2: Open file first — binds FD to inode, closing TOCTOU window.
@@ -352,21 +512,7 @@ async fn main() -> Result<()> {
         .collect();
 
     // Open database
-    let db_path_str = db_path
-        .to_str()
-        .ok_or_else(|| anyhow::anyhow!("Invalid UTF-8 in database path"))?;
-    let db = match Database::open(db_path_str).await {
-        Ok(db) => db,
-        Err(DatabaseError::InstanceLocked) => {
-            eprintln!(
-                "Error: Another instance of skim appears to be running. Please close it and try again."
-            );
-            std::process::exit(1);
-        }
-        Err(e) => {
-            return Err(anyhow::anyhow!("Failed to open database: {}", e));
-        }
-    };
+    let db = open_database(&db_path).await?;
 
     // B-3: Spawn FTS consistency check BEFORE sync_feeds to avoid false positives
     // from concurrent article inserts during the first refresh.
@@ -438,6 +584,11 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Handle --export-articles flag
+    if let Some(export_path) = &args.export_articles {
+        return export_articles(&db, export_path).await;
+    }
+
     // Evict expired content cache entries at startup (once per session)
     match db.evict_expired().await {
         Ok(evicted) => {
@@ -450,6 +601,75 @@ async fn main() -> Result<()> {
         }
     }
 
+    // Load config (also consulted by startup maintenance below)
+    let config_path = config_dir.join("config.toml");
+    let config = config::Config::load(&config_path).unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Failed to load config, using defaults");
+        config::Config::default()
+    });
+
+    // Handle --apply-rules flag (backfill for articles fetched before the rules existed)
+    if args.apply_rules {
+        let rules = config.rule_set();
+        if rules.is_empty() {
+            println!("No valid rules in {}", config_path.display());
+            return Ok(());
+        }
+        let matched = db
+            .apply_rules(&rules)
+            .await
+            .context("Failed to apply rules")?;
+        println!("Rules matched {} articles", matched);
+        println!("Rule hits on new articles (all time):");
+        for rule in db.get_rule_hits().await? {
+            let last = chrono::DateTime::from_timestamp(rule.last_hit_at, 0)
+                .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default();
+            println!("  {:>8}  {:<16}  {}", rule.hits, last, rule.rule);
+        }
+        return Ok(());
+    }
+
+    // Handle --preview-rewrites flag (dry run of [[rewrites]] against stored entries)
+    if let Some(feed) = args.preview_rewrites.as_deref() {
+        return preview_rewrites(&db, &config.rewrite_set(), feed).await;
+    }
+
+    // Compress bodies cached by older versions, then apply the size budget
+    match db.compress_legacy_cache().await {
+        Ok(converted) if converted > 0 => {
+            tracing::info!(converted, "Compressed legacy content cache entries")
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!(error = %e, "Failed to compress legacy cache entries"),
+    }
+    let cache_policy = config.cache_policy();
+    if let Some(max_bytes) = cache_policy.max_size_bytes {
+        match db.enforce_cache_budget(max_bytes).await {
+            Ok(evicted) if evicted > 0 => {
+                tracing::info!(
+                    evicted,
+                    "Evicted content cache entries over the size budget"
+                )
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!(error = %e, "Failed to enforce cache size budget"),
+        }
+    }
+
+    // Apply fetch log retention at startup (once per session)
+    match db
+        .prune_fetch_log(
+            config.health.fetch_log_retention_days,
+            config.health.fetch_log_max_per_feed,
+        )
+        .await
+    {
+        Ok(pruned) if pruned > 0 => tracing::info!(pruned, "Pruned feed fetch log"),
+        Ok(_) => {}
+        Err(e) => tracing::warn!(error = %e, "Failed to prune feed fetch log"),
+    }
+
     // Create app state
     let mut app = App::new(db.clone()).context("Failed to create application")?;
 
@@ -463,12 +683,26 @@ async fn main() -> Result<()> {
     // PERF-005: Build feed title cache
     app.rebuild_feed_cache();
 
-    // Load config and preferences
-    let config_path = config_dir.join("config.toml");
-    let config = config::Config::load(&config_path).unwrap_or_else(|e| {
-        tracing::warn!(error = %e, "Failed to load config, using defaults");
-        config::Config::default()
+    app.tags = std::sync::Arc::new(db.get_tag_counts().await.unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Failed to load tag counts");
+        Vec::new()
+    }));
+    app.read_later_ids = db
+        .read_later_ids()
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to load read-later queue");
+            Vec::new()
+        })
+        .into_iter()
+        .collect();
+
+    app.words_per_minute = db.reading_speed().await.unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Failed to measure reading speed");
+        util::WORDS_PER_MINUTE
     });
+
+    // Load preferences
     let prefs = preferences::PreferenceManager::load(&config, &db)
         .await
         .unwrap_or_else(|e| {
@@ -477,14 +711,37 @@ async fn main() -> Result<()> {
             preferences::PreferenceManager::from_config(&config)
         });
 
+    app.rewrites = std::sync::Arc::new(config.rewrite_set());
+    app.cache_policy = std::sync::Arc::new(cache_policy);
+    app.hyperlinks_enabled = config.hyperlinks.enabled();
+    app.reader_layout = prefs.reader_layout();
+    app.config = config;
+
+    // Create event channel for background tasks
+    // Sized for burst scenarios: 10 concurrent feed refreshes x progress/complete events
+    // + concurrent content loads + search results + star toggles
+    let (event_tx, event_rx) = mpsc::channel::<AppEvent>(256);
+
     // Restore session if enabled
     if prefs.restore_session() {
         if let Some(snapshot_json) = db.get_preference("session.snapshot").await.unwrap_or(None) {
             match serde_json::from_str::<app::SessionSnapshot>(&snapshot_json) {
-                Ok(snapshot) => {
+                Ok(mut snapshot) => {
+                    let reader_article = snapshot.reader_article.take();
                     app.restore(snapshot);
                     app.set_status("Session restored");
                     tracing::info!("Session restored from preferences");
+
+                    // Reopen the article that was being read
+                    if let Some(article_id) = reader_article {
+                        match ui::open_article(&mut app, article_id, &event_tx).await {
+                            Ok(true) => {}
+                            Ok(false) => tracing::debug!(article_id, "Session article is gone"),
+                            Err(e) => {
+                                tracing::warn!(error = %e, "Failed to reopen session article")
+                            }
+                        }
+                    }
                 }
                 Err(e) => {
                     tracing::warn!(error = %e, "Corrupt session snapshot, ignoring");
@@ -492,11 +749,7 @@ async fn main() -> Result<()> {
             }
         }
     }
-
-    // Create event channel for background tasks
-    // Sized for burst scenarios: 10 concurrent feed refreshes x progress/complete events
-    // + concurrent content loads + search results + star toggles
-    let (event_tx, event_rx) = mpsc::channel::<AppEvent>(256);
+    app.prefs = prefs;
 
     // Run the TUI
     ui::run(&mut app, event_tx, event_rx).await?;
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 
-use crate::config::Config;
+use crate::config::{Config, ReaderConfig};
 use crate::storage::Database;
 
 // ============================================================================
@@ -53,7 +53,6 @@ impl PreferenceManager {
     }
 
     /// Set a preference: writes to DB and updates in-memory map.
-    #[cfg_attr(not(test), allow(dead_code))] // Wired when session save + theme persistence lands
     pub async fn set(&mut self, db: &Database, key: &str, value: &str) -> Result<()> {
         db.set_preference(key, value).await?;
         self.prefs.insert(key.to_string(), value.to_string());
@@ -85,6 +84,34 @@ impl PreferenceManager {
             .unwrap_or(false)
     }
 
+    /// Reader layout: `reader.*` keys, falling back to the defaults for
+    /// missing or unparsable values.
+    pub fn reader_layout(&self) -> ReaderConfig {
+        let default = ReaderConfig::default();
+        ReaderConfig {
+            max_width: self.parse("reader.max_width").unwrap_or(default.max_width),
+            margin: self.parse("reader.margin").unwrap_or(default.margin),
+            justify: self.parse("reader.justify").unwrap_or(default.justify),
+            paginate: self.parse("reader.paginate").unwrap_or(default.paginate),
+            collapse_tldr: self
+                .parse("reader.collapse_tldr")
+                .unwrap_or(default.collapse_tldr),
+            tldr_preview: self
+                .parse("reader.tldr_preview")
+                .unwrap_or(default.tldr_preview),
+        }
+    }
+
+    /// Persist the reader layout, writing only the keys that changed.
+    pub async fn set_reader_layout(&mut self, db: &Database, layout: ReaderConfig) -> Result<()> {
+        for (key, value) in Self::reader_entries(&layout) {
+            if self.get(key) != Some(value.as_str()) {
+                self.set(db, key, &value).await?;
+            }
+        }
+        Ok(())
+    }
+
     // ========================================================================
     // Internal Helpers
     // ========================================================================
@@ -111,6 +138,10 @@ impl PreferenceManager {
             config.confirm_mark_all_read.to_string(),
         );
 
+        for (key, value) in Self::reader_entries(&config.reader) {
+            map.insert(key.to_string(), value);
+        }
+
         // Flatten keybindings into keybind.{action} keys
         for (action, key_str) in &config.keybindings {
             map.insert(format!("keybind.{}", action), key_str.clone());
@@ -123,6 +154,23 @@ impl PreferenceManager {
 
         map
     }
+
+    /// A preference parsed as `T`, if set and valid.
+    fn parse<T: std::str::FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key).and_then(|v| v.parse().ok())
+    }
+
+    /// `reader.*` keys and values of a reader layout.
+    fn reader_entries(layout: &ReaderConfig) -> [(&'static str, String); 6] {
+        [
+            ("reader.max_width", layout.max_width.to_string()),
+            ("reader.margin", layout.margin.to_string()),
+            ("reader.justify", layout.justify.to_string()),
+            ("reader.paginate", layout.paginate.to_string()),
+            ("reader.collapse_tldr", layout.collapse_tldr.to_string()),
+            ("reader.tldr_preview", layout.tldr_preview.to_string()),
+        ]
+    }
 }
 
 // ============================================================================
@@ -207,8 +255,10 @@ mod tests {
     #[tokio::test]
     async fn test_refresh_interval_parse() {
         let db = test_db().await;
-        let mut config = Config::default();
-        config.refresh_interval_minutes = 30;
+        let config = Config {
+            refresh_interval_minutes: 30,
+            ..Default::default()
+        };
 
         let pm = PreferenceManager::load(&config, &db).await.unwrap();
         assert_eq!(pm.refresh_interval(), 30);
@@ -245,9 +295,11 @@ mod tests {
         let db = test_db().await;
 
         // Start with custom config
-        let mut config = Config::default();
-        config.theme = "solarized".to_string();
-        config.refresh_interval_minutes = 15;
+        let config = Config {
+            theme: "solarized".to_string(),
+            refresh_interval_minutes: 15,
+            ..Default::default()
+        };
 
         // Load preferences (config defaults)
         let mut pm = PreferenceManager::load(&config, &db).await.unwrap();
@@ -277,6 +329,7 @@ mod tests {
             selected_feed: 3,
             selected_article: 7,
             scroll_offset: 42,
+            reader_article: None,
         };
 
         // Serialize and store
@@ -339,9 +392,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_from_config_fallback() {
-        let mut config = Config::default();
-        config.theme = "monokai".to_string();
-        config.refresh_interval_minutes = 45;
+        let config = Config {
+            theme: "monokai".to_string(),
+            refresh_interval_minutes: 45,
+            ..Default::default()
+        };
 
         let pm = PreferenceManager::from_config(&config);
         assert_eq!(pm.theme_variant(), "monokai");
@@ -380,4 +435,28 @@ mark_read_on_open = false
 
         std::fs::remove_dir_all(&dir).ok();
     }
+
+    #[tokio::test]
+    async fn test_reader_layout_round_trip() {
+        let db = test_db().await;
+        let config: Config = toml::from_str("[reader]\nmax_width = 80\nmargin = 2").unwrap();
+
+        let mut pm = PreferenceManager::load(&config, &db).await.unwrap();
+        let mut layout = pm.reader_layout();
+        assert_eq!(layout, config.reader);
+
+        layout.paginate = true;
+        layout.max_width = 100;
+        pm.set_reader_layout(&db, layout).await.unwrap();
+        // Only changed keys are written
+        assert_eq!(db.get_preference("reader.margin").await.unwrap(), None);
+
+        let pm2 = PreferenceManager::load(&config, &db).await.unwrap();
+        assert_eq!(pm2.reader_layout(), layout);
+
+        // Garbage in the DB falls back to the default
+        db.set_preference("reader.max_width", "wide").await.unwrap();
+        let pm3 = PreferenceManager::load(&config, &db).await.unwrap();
+        assert_eq!(pm3.reader_layout().max_width, 0);
+    }
 }
@@ -0,0 +1,411 @@
+//! Per-feed title, summary and content rewrites (`[[rewrites]]` in config.toml).
+//!
+//! Rewrites clean up text a publisher adds to every entry: title prefixes
+//! like "[Sponsored]", tracking footers in summaries, newsletter boilerplate
+//! in the fetched article body. The original text is never replaced; the
+//! rewritten version is stored next to it and shown instead.
+//!
+//! ```toml
+//! [[rewrites]]
+//! feed = "Hacker News"             # feed title or URL; or `domain = "..."`
+//! fields = ["title"]               # default: title, summary and content
+//! strip_prefix = ["Show HN:", "Ask HN:"]
+//!
+//! [[rewrites]]
+//! domain = "example.com"
+//! replace = "/\\s*\\(sponsored\\)$/i"
+//! with = ""
+//! drop_paragraphs = "/unsubscribe|view in browser/i"
+//! ```
+//!
+//! Within one table the steps run in order: strip prefix, strip suffix,
+//! replace, drop paragraphs. Tables apply in file order. `drop_paragraphs`
+//! never touches titles.
+
+use regex::Regex;
+use serde::Deserialize;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+use crate::rules::{compile_pattern, domain_matches, feed_matches};
+
+/// `<p>` elements of an HTML summary, for `drop_paragraphs`.
+static HTML_PARAGRAPH: OnceLock<Regex> = OnceLock::new();
+
+/// One `[[rewrites]]` table as written in config.toml.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RewriteConfig {
+    /// Name used in warnings (defaults to "rewrite N").
+    pub name: Option<String>,
+    /// Feed title (case-insensitive) or feed URL; all feeds when unset.
+    pub feed: Option<String>,
+    /// Article URL host, including subdomains.
+    pub domain: Option<String>,
+    /// Fields to rewrite: "title", "summary", "content" (default: all three).
+    pub fields: Vec<String>,
+    /// Literal prefixes removed from the start (first match only).
+    pub strip_prefix: Vec<String>,
+    /// Literal suffixes removed from the end (first match only).
+    pub strip_suffix: Vec<String>,
+    /// Pattern whose matches are replaced with `with`.
+    pub replace: Option<String>,
+    /// Replacement text; `$1` etc. refer to capture groups.
+    pub with: String,
+    /// Paragraphs matching this pattern are removed.
+    pub drop_paragraphs: Option<String>,
+}
+
+/// A rewrite that could not be compiled; it is skipped.
+#[derive(Debug, Error)]
+#[error("rewrite '{rewrite}': {message}")]
+pub struct RewriteError {
+    pub rewrite: String,
+    pub message: String,
+}
+
+/// Which text of an article a rewrite applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Title,
+    Summary,
+    /// Markdown fetched via jina.ai
+    Content,
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "title" => Some(Self::Title),
+            "summary" => Some(Self::Summary),
+            "content" => Some(Self::Content),
+            _ => None,
+        }
+    }
+}
+
+/// The feed and article a text belongs to, for scoping rewrites.
+#[derive(Debug, Clone, Copy)]
+pub struct RewriteTarget<'a> {
+    pub feed_title: &'a str,
+    pub feed_url: &'a str,
+    /// Article URL
+    pub url: Option<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct Rewrite {
+    pub name: String,
+    /// Lowercased feed title or URL
+    feed: Option<String>,
+    /// Lowercased host
+    domain: Option<String>,
+    fields: Vec<Field>,
+    strip_prefix: Vec<String>,
+    strip_suffix: Vec<String>,
+    replace: Option<(Regex, String)>,
+    drop_paragraphs: Option<Regex>,
+}
+
+/// Compiled `[[rewrites]]`, in config file order.
+#[derive(Debug, Default)]
+pub struct RewriteSet {
+    rewrites: Vec<Rewrite>,
+}
+
+impl RewriteSet {
+    /// Compile rewrite tables, returning the valid rewrites and an error per skipped one.
+    pub fn compile(configs: &[RewriteConfig]) -> (Self, Vec<RewriteError>) {
+        let mut rewrites = Vec::with_capacity(configs.len());
+        let mut errors = Vec::new();
+        for (i, config) in configs.iter().enumerate() {
+            let name = config
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("rewrite {}", i + 1));
+            match Rewrite::compile(name.clone(), config) {
+                Ok(rewrite) => rewrites.push(rewrite),
+                Err(message) => errors.push(RewriteError {
+                    rewrite: name,
+                    message,
+                }),
+            }
+        }
+        (Self { rewrites }, errors)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rewrites.is_empty()
+    }
+
+    /// Apply every rewrite scoped to `target` and `field` to `text`.
+    ///
+    /// Returns `None` when no rewrite changed the text, so callers store
+    /// nothing and fall back to the original.
+    pub fn apply(&self, field: Field, target: &RewriteTarget<'_>, text: &str) -> Option<String> {
+        let mut current = text.to_string();
+        for rewrite in self.rewrites.iter().filter(|r| r.applies(field, target)) {
+            current = rewrite.run(field, &current);
+        }
+        (current != text).then_some(current)
+    }
+
+    /// Names of the rewrites scoped to `target` for any field.
+    pub fn names_for(&self, target: &RewriteTarget<'_>) -> Vec<&str> {
+        self.rewrites
+            .iter()
+            .filter(|r| r.fields.iter().any(|&field| r.applies(field, target)))
+            .map(|r| r.name.as_str())
+            .collect()
+    }
+}
+
+impl Rewrite {
+    fn compile(name: String, config: &RewriteConfig) -> Result<Self, String> {
+        let fields = if config.fields.is_empty() {
+            vec![Field::Title, Field::Summary, Field::Content]
+        } else {
+            config
+                .fields
+                .iter()
+                .map(|f| Field::parse(f).ok_or_else(|| format!("unknown field '{}'", f)))
+                .collect::<Result<_, _>>()?
+        };
+        let replace = config
+            .replace
+            .as_deref()
+            .map(|p| compile_pattern("replace", p).map(|re| (re, config.with.clone())))
+            .transpose()?;
+        let drop_paragraphs = config
+            .drop_paragraphs
+            .as_deref()
+            .map(|p| compile_pattern("drop_paragraphs", p))
+            .transpose()?;
+        let strip_prefix: Vec<String> = config
+            .strip_prefix
+            .iter()
+            .filter(|p| !p.is_empty())
+            .cloned()
+            .collect();
+        let strip_suffix: Vec<String> = config
+            .strip_suffix
+            .iter()
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .collect();
+        if strip_prefix.is_empty()
+            && strip_suffix.is_empty()
+            && replace.is_none()
+            && drop_paragraphs.is_none()
+        {
+            return Err(
+                "no steps (strip_prefix, strip_suffix, replace, drop_paragraphs)".to_string(),
+            );
+        }
+
+        Ok(Self {
+            name,
+            feed: config.feed.as_deref().map(str::to_lowercase),
+            domain: config
+                .domain
+                .as_deref()
+                .map(|d| d.trim_start_matches('.').to_lowercase()),
+            fields,
+            strip_prefix,
+            strip_suffix,
+            replace,
+            drop_paragraphs,
+        })
+    }
+
+    fn applies(&self, field: Field, target: &RewriteTarget<'_>) -> bool {
+        self.fields.contains(&field)
+            && self
+                .feed
+                .as_deref()
+                .is_none_or(|feed| feed_matches(feed, target.feed_title, target.feed_url))
+            && self
+                .domain
+                .as_deref()
+                .is_none_or(|domain| target.url.is_some_and(|url| domain_matches(domain, url)))
+    }
+
+    /// Run this table's steps on `text`. Whitespace is only trimmed around
+    /// a step that changed something, so untouched text compares equal.
+    fn run(&self, field: Field, text: &str) -> String {
+        let mut text = text.to_string();
+        let stripped = self
+            .strip_prefix
+            .iter()
+            .find_map(|p| text.trim_start().strip_prefix(p.as_str()))
+            .map(|rest| rest.trim_start().to_string());
+        if let Some(rest) = stripped {
+            text = rest;
+        }
+        let stripped = self
+            .strip_suffix
+            .iter()
+            .find_map(|s| text.trim_end().strip_suffix(s.as_str()))
+            .map(|rest| rest.trim_end().to_string());
+        if let Some(rest) = stripped {
+            text = rest;
+        }
+        if let Some((re, with)) = &self.replace {
+            if re.is_match(&text) {
+                text = re.replace_all(&text, with.as_str()).trim().to_string();
+            }
+        }
+        if let (Some(re), false) = (&self.drop_paragraphs, field == Field::Title) {
+            if let Some(kept) = drop_paragraphs(&text, re) {
+                text = kept;
+            }
+        }
+        text
+    }
+}
+
+/// Remove paragraphs matching `re`: `<p>` elements in HTML, blank-line
+/// separated blocks otherwise. Returns `None` when nothing matched.
+fn drop_paragraphs(text: &str, re: &Regex) -> Option<String> {
+    if text.contains("<p") {
+        // Matches are dropped element by element; markup between paragraphs is kept
+        let paragraphs = HTML_PARAGRAPH
+            .get_or_init(|| Regex::new(r"(?is)<p\b[^>]*>.*?</p>").expect("valid paragraph regex"));
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+        for m in paragraphs
+            .find_iter(text)
+            .filter(|m| re.is_match(m.as_str()))
+        {
+            out.push_str(&text[last..m.start()]);
+            last = m.end();
+        }
+        if last == 0 {
+            return None;
+        }
+        out.push_str(&text[last..]);
+        return Some(out.trim().to_string());
+    }
+    let blocks: Vec<&str> = text.split("\n\n").collect();
+    let kept: Vec<&str> = blocks
+        .iter()
+        .copied()
+        .filter(|block| !re.is_match(block))
+        .collect();
+    (kept.len() < blocks.len()).then(|| kept.join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HN: RewriteTarget<'static> = RewriteTarget {
+        feed_title: "Hacker News",
+        feed_url: "https://news.ycombinator.com/rss",
+        url: Some("https://www.example.com/post"),
+    };
+
+    fn rewrites(toml: &str) -> (RewriteSet, Vec<RewriteError>) {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            rewrites: Vec<RewriteConfig>,
+        }
+        let wrapper: Wrapper = toml::from_str(toml).unwrap();
+        RewriteSet::compile(&wrapper.rewrites)
+    }
+
+    #[test]
+    fn test_strip_prefix_scoped_to_feed_and_field() {
+        let (set, errors) = rewrites(
+            r#"
+            [[rewrites]]
+            feed = "hacker news"
+            fields = ["title"]
+            strip_prefix = ["Show HN:", "Ask HN:"]
+            "#,
+        );
+        assert!(errors.is_empty());
+        assert_eq!(
+            set.apply(Field::Title, &HN, "Show HN: A thing").as_deref(),
+            Some("A thing")
+        );
+        assert_eq!(set.apply(Field::Title, &HN, "Plain title"), None);
+        assert_eq!(set.apply(Field::Summary, &HN, "Show HN: summary"), None);
+
+        let other = RewriteTarget {
+            feed_title: "Other",
+            ..HN
+        };
+        assert_eq!(set.apply(Field::Title, &other, "Show HN: A thing"), None);
+    }
+
+    #[test]
+    fn test_replace_and_suffix_by_domain() {
+        let (set, _) = rewrites(
+            r#"
+            [[rewrites]]
+            domain = "example.com"
+            strip_suffix = ["- Example Blog"]
+            replace = "/^\\[sponsored\\]\\s*/i"
+            "#,
+        );
+        assert_eq!(
+            set.apply(Field::Title, &HN, "[SPONSORED] Deal - Example Blog")
+                .as_deref(),
+            Some("Deal")
+        );
+        let elsewhere = RewriteTarget {
+            url: Some("https://other.org/post"),
+            ..HN
+        };
+        assert_eq!(
+            set.apply(Field::Title, &elsewhere, "[Sponsored] Deal"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_drop_paragraphs_html_and_markdown() {
+        let (set, _) = rewrites(
+            r#"
+            [[rewrites]]
+            drop_paragraphs = "/unsubscribe|tracking/i"
+            "#,
+        );
+        let html = "<p>Real news.</p>\n<p>Click to <a href=\"x\">unsubscribe</a>.</p>";
+        assert_eq!(
+            set.apply(Field::Summary, &HN, html).as_deref(),
+            Some("<p>Real news.</p>")
+        );
+        let markdown = "# Title\n\nBody text.\n\nThis email has tracking pixels.\n\nEnd.";
+        assert_eq!(
+            set.apply(Field::Content, &HN, markdown).as_deref(),
+            Some("# Title\n\nBody text.\n\nEnd.")
+        );
+        // Titles are never dropped wholesale
+        assert_eq!(set.apply(Field::Title, &HN, "Unsubscribe now"), None);
+    }
+
+    #[test]
+    fn test_invalid_rewrites_are_skipped() {
+        let (set, errors) = rewrites(
+            r#"
+            [[rewrites]]
+            fields = ["body"]
+            strip_prefix = ["x"]
+
+            [[rewrites]]
+            name = "empty"
+            feed = "Hacker News"
+
+            [[rewrites]]
+            replace = "(unclosed"
+            "#,
+        );
+        assert!(set.is_empty());
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        assert_eq!(messages[0], "rewrite 'rewrite 1': unknown field 'body'");
+        assert!(messages[1].starts_with("rewrite 'empty': no steps"));
+        assert!(messages[2].starts_with("rewrite 'rewrite 3': invalid replace pattern"));
+    }
+}
@@ -0,0 +1,493 @@
+//! Filter and action rules applied to articles at ingest.
+//!
+//! Rules are declared as `[[rules]]` tables in config.toml. Every condition
+//! present in a rule must match (conditions are ANDed); a rule with no
+//! conditions matches every article. All matching rules apply their actions,
+//! in file order, so a later rule's `priority` wins.
+//!
+//! ```toml
+//! [[rules]]
+//! name = "drop webinars"
+//! feed = "Example Blog"
+//! title = "/sponsored|webinar/i"
+//! mark_read = true
+//!
+//! [[rules]]
+//! name = "cves"
+//! text = "CVE-2024"
+//! star = true
+//! tags = ["security"]
+//!
+//! [[rules]]
+//! name = "short news"
+//! category = "News"
+//! shorter_than = 200
+//! hide = true
+//! ```
+//!
+//! Patterns are regular expressions; `/pattern/i` is accepted as shorthand
+//! for a case-insensitive match.
+
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use thiserror::Error;
+use url::Url;
+
+use crate::util::normalize_tag;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// One `[[rules]]` table as written in config.toml.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RuleConfig {
+    /// Name used for hit counts and warnings (defaults to "rule N").
+    pub name: Option<String>,
+
+    /// Feed title (case-insensitive) or feed URL.
+    pub feed: Option<String>,
+    /// Name of the feed's category (case-insensitive).
+    pub category: Option<String>,
+    /// Pattern matched against the title.
+    pub title: Option<String>,
+    /// Pattern matched against the summary.
+    pub summary: Option<String>,
+    /// Pattern matched against the author name.
+    pub author: Option<String>,
+    /// Pattern matched against the title or the summary.
+    pub text: Option<String>,
+    /// Article URL host, including subdomains.
+    pub domain: Option<String>,
+    /// Published less than this many days ago.
+    pub newer_than_days: Option<u32>,
+    /// Published more than this many days ago.
+    pub older_than_days: Option<u32>,
+    /// Summary has fewer visible characters than this (markup excluded).
+    pub shorter_than: Option<usize>,
+    /// Summary has more visible characters than this (markup excluded).
+    pub longer_than: Option<usize>,
+
+    pub mark_read: bool,
+    pub star: bool,
+    /// Keep the article out of every list and unread count.
+    pub hide: bool,
+    pub tags: Vec<String>,
+    /// Higher priorities sort first within a feed and in What's New.
+    pub priority: Option<i64>,
+}
+
+/// A rule that could not be compiled; it is skipped.
+#[derive(Debug, Error)]
+#[error("rule '{rule}': {message}")]
+pub struct RuleError {
+    pub rule: String,
+    pub message: String,
+}
+
+// ============================================================================
+// Compiled Rules
+// ============================================================================
+
+/// A typed test on one article field.
+#[derive(Debug)]
+pub enum Condition {
+    /// Lowercased feed title or feed URL
+    Feed(String),
+    /// Lowercased category name
+    Category(String),
+    Title(Regex),
+    Summary(Regex),
+    Author(Regex),
+    /// Title or summary
+    Text(Regex),
+    /// Lowercased host without a leading dot
+    Domain(String),
+    /// Maximum age in seconds
+    NewerThan(i64),
+    /// Minimum age in seconds
+    OlderThan(i64),
+    ShorterThan(usize),
+    LongerThan(usize),
+}
+
+/// What a matching rule does to the article.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    MarkRead,
+    Star,
+    Hide,
+    Tag(String),
+    SetPriority(i64),
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    pub name: String,
+    pub conditions: Vec<Condition>,
+    pub actions: Vec<Action>,
+}
+
+/// The article fields rules are evaluated against.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleInput<'a> {
+    pub feed_title: &'a str,
+    pub feed_url: &'a str,
+    pub category: Option<&'a str>,
+    pub title: &'a str,
+    pub summary: Option<&'a str>,
+    pub author: Option<&'a str>,
+    pub url: Option<&'a str>,
+    /// Publish time, or first-seen time for undated entries (Unix seconds)
+    pub published: i64,
+    pub now: i64,
+}
+
+/// Compiled `[[rules]]`, in config file order.
+#[derive(Debug, Default)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+const SECS_PER_DAY: i64 = 86_400;
+
+impl RuleSet {
+    /// Compile rule tables, returning the valid rules and an error per skipped rule.
+    ///
+    /// A rule is skipped when a pattern does not compile, when it has no
+    /// actions, or when its name repeats an earlier rule's (hit counts are
+    /// keyed by name).
+    pub fn compile(configs: &[RuleConfig]) -> (Self, Vec<RuleError>) {
+        let mut rules: Vec<Rule> = Vec::with_capacity(configs.len());
+        let mut errors = Vec::new();
+        for (i, config) in configs.iter().enumerate() {
+            let name = config
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("rule {}", i + 1));
+            if rules.iter().any(|r| r.name == name) {
+                errors.push(RuleError {
+                    rule: name,
+                    message: "duplicate rule name".to_string(),
+                });
+                continue;
+            }
+            match Rule::compile(name.clone(), config) {
+                Ok(rule) => rules.push(rule),
+                Err(message) => errors.push(RuleError {
+                    rule: name,
+                    message,
+                }),
+            }
+        }
+        (Self { rules }, errors)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+}
+
+impl Rule {
+    fn compile(name: String, config: &RuleConfig) -> Result<Self, String> {
+        let mut conditions = Vec::new();
+        if let Some(feed) = &config.feed {
+            conditions.push(Condition::Feed(feed.to_lowercase()));
+        }
+        if let Some(category) = &config.category {
+            conditions.push(Condition::Category(category.to_lowercase()));
+        }
+        if let Some(p) = &config.title {
+            conditions.push(Condition::Title(compile_pattern("title", p)?));
+        }
+        if let Some(p) = &config.summary {
+            conditions.push(Condition::Summary(compile_pattern("summary", p)?));
+        }
+        if let Some(p) = &config.author {
+            conditions.push(Condition::Author(compile_pattern("author", p)?));
+        }
+        if let Some(p) = &config.text {
+            conditions.push(Condition::Text(compile_pattern("text", p)?));
+        }
+        if let Some(domain) = &config.domain {
+            let domain = domain.trim_start_matches('.').to_lowercase();
+            if domain.is_empty() {
+                return Err("domain is empty".to_string());
+            }
+            conditions.push(Condition::Domain(domain));
+        }
+        if let Some(days) = config.newer_than_days {
+            conditions.push(Condition::NewerThan(i64::from(days) * SECS_PER_DAY));
+        }
+        if let Some(days) = config.older_than_days {
+            conditions.push(Condition::OlderThan(i64::from(days) * SECS_PER_DAY));
+        }
+        if let Some(len) = config.shorter_than {
+            conditions.push(Condition::ShorterThan(len));
+        }
+        if let Some(len) = config.longer_than {
+            conditions.push(Condition::LongerThan(len));
+        }
+
+        let mut actions = Vec::new();
+        if config.mark_read {
+            actions.push(Action::MarkRead);
+        }
+        if config.star {
+            actions.push(Action::Star);
+        }
+        if config.hide {
+            actions.push(Action::Hide);
+        }
+        for tag in config.tags.iter().filter_map(|t| normalize_tag(t)) {
+            actions.push(Action::Tag(tag));
+        }
+        if let Some(priority) = config.priority {
+            actions.push(Action::SetPriority(priority));
+        }
+        if actions.is_empty() {
+            return Err("no actions (mark_read, star, hide, tags, priority)".to_string());
+        }
+
+        Ok(Self {
+            name,
+            conditions,
+            actions,
+        })
+    }
+
+    /// Whether every condition holds for `input`.
+    pub fn matches(&self, input: &RuleInput<'_>) -> bool {
+        self.conditions.iter().all(|c| c.matches(input))
+    }
+}
+
+impl Condition {
+    fn matches(&self, input: &RuleInput<'_>) -> bool {
+        let summary = input.summary.unwrap_or("");
+        match self {
+            Self::Feed(feed) => feed_matches(feed, input.feed_title, input.feed_url),
+            Self::Category(name) => input.category.is_some_and(|c| c.to_lowercase() == *name),
+            Self::Title(re) => re.is_match(input.title),
+            Self::Summary(re) => re.is_match(summary),
+            Self::Author(re) => input.author.is_some_and(|a| re.is_match(a)),
+            Self::Text(re) => re.is_match(input.title) || re.is_match(summary),
+            Self::Domain(domain) => input.url.is_some_and(|url| domain_matches(domain, url)),
+            Self::NewerThan(secs) => input.now - input.published < *secs,
+            Self::OlderThan(secs) => input.now - input.published > *secs,
+            Self::ShorterThan(len) => visible_len(summary) < *len,
+            Self::LongerThan(len) => visible_len(summary) > *len,
+        }
+    }
+}
+
+/// Whether a lowercased `feed` selector names the feed by title or URL.
+pub(crate) fn feed_matches(feed: &str, feed_title: &str, feed_url: &str) -> bool {
+    feed_title.to_lowercase() == feed || feed_url.eq_ignore_ascii_case(feed)
+}
+
+/// Whether `url`'s host is the lowercased `domain` or one of its subdomains.
+pub(crate) fn domain_matches(domain: &str, url: &str) -> bool {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_lowercase))
+        .is_some_and(|host| {
+            host == domain
+                || host
+                    .strip_suffix(domain)
+                    .is_some_and(|rest| rest.ends_with('.'))
+        })
+}
+
+/// Compile `pattern`, accepting `/regex/` and `/regex/i` delimiters.
+pub(crate) fn compile_pattern(field: &str, pattern: &str) -> Result<Regex, String> {
+    let (body, case_insensitive) = match pattern.strip_prefix('/') {
+        Some(rest) if rest.ends_with("/i") => (&rest[..rest.len() - 2], true),
+        Some(rest) if rest.ends_with('/') => (&rest[..rest.len() - 1], false),
+        _ => (pattern, false),
+    };
+    RegexBuilder::new(body)
+        .case_insensitive(case_insensitive)
+        .build()
+        .map_err(|e| format!("invalid {} pattern: {}", field, e))
+}
+
+/// Character count of `text` outside HTML tags, with surrounding whitespace trimmed.
+fn visible_len(text: &str) -> usize {
+    let mut in_tag = false;
+    text.trim()
+        .chars()
+        .filter(|&c| match c {
+            '<' => {
+                in_tag = true;
+                false
+            }
+            '>' if in_tag => {
+                in_tag = false;
+                false
+            }
+            _ => !in_tag,
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input<'a>(title: &'a str, summary: Option<&'a str>) -> RuleInput<'a> {
+        RuleInput {
+            feed_title: "Example Blog",
+            feed_url: "https://example.com/feed.xml",
+            category: Some("News"),
+            title,
+            summary,
+            author: Some("Jane Doe"),
+            url: Some("https://blog.example.com/post"),
+            published: 1_700_000_000,
+            now: 1_700_000_000 + 3 * SECS_PER_DAY,
+        }
+    }
+
+    fn rules(toml: &str) -> (RuleSet, Vec<RuleError>) {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            rules: Vec<RuleConfig>,
+        }
+        let wrapper: Wrapper = toml::from_str(toml).unwrap();
+        RuleSet::compile(&wrapper.rules)
+    }
+
+    fn names<'a>(set: &'a RuleSet, input: &'a RuleInput<'_>) -> Vec<&'a str> {
+        set.rules()
+            .iter()
+            .filter(|r| r.matches(input))
+            .map(|r| r.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_title_pattern_with_case_insensitive_delimiters() {
+        let (set, errors) = rules(
+            r#"
+            [[rules]]
+            name = "webinars"
+            feed = "example blog"
+            title = "/sponsored|webinar/i"
+            mark_read = true
+            "#,
+        );
+        assert!(errors.is_empty());
+        assert_eq!(names(&set, &input("Join our WEBINAR", None)), ["webinars"]);
+        assert!(names(&set, &input("Release notes", None)).is_empty());
+        assert_eq!(set.rules()[0].actions, [Action::MarkRead]);
+    }
+
+    #[test]
+    fn test_conditions_are_anded() {
+        let (set, _) = rules(
+            r#"
+            [[rules]]
+            name = "short news"
+            category = "news"
+            shorter_than = 20
+            hide = true
+            "#,
+        );
+        assert_eq!(
+            names(&set, &input("t", Some("<p>Brief.</p>"))),
+            ["short news"]
+        );
+        let long = "<p>This summary is well over twenty characters.</p>";
+        assert!(names(&set, &input("t", Some(long))).is_empty());
+
+        let mut other_category = input("t", Some("Brief."));
+        other_category.category = Some("Tech");
+        assert!(names(&set, &other_category).is_empty());
+    }
+
+    #[test]
+    fn test_text_author_domain_and_age() {
+        let (set, errors) = rules(
+            r#"
+            [[rules]]
+            name = "cve"
+            text = "CVE-2024"
+            star = true
+            tags = ["security", " "]
+
+            [[rules]]
+            name = "jane"
+            author = "^Jane"
+            priority = 2
+
+            [[rules]]
+            name = "domain"
+            domain = "example.com"
+            priority = 5
+
+            [[rules]]
+            name = "fresh"
+            newer_than_days = 1
+            mark_read = true
+
+            [[rules]]
+            name = "stale"
+            older_than_days = 2
+            mark_read = true
+            "#,
+        );
+        assert!(errors.is_empty());
+        let article = input("Patch notes", Some("Fixes CVE-2024-1234"));
+        assert_eq!(names(&set, &article), ["cve", "jane", "domain", "stale"]);
+        assert_eq!(
+            set.rules()[0].actions,
+            [Action::Star, Action::Tag("security".to_string())]
+        );
+
+        let mut lookalike = article;
+        lookalike.url = Some("https://notexample.com/post");
+        assert!(!names(&set, &lookalike).contains(&"domain"));
+    }
+
+    #[test]
+    fn test_invalid_rules_are_skipped() {
+        let (set, errors) = rules(
+            r#"
+            [[rules]]
+            name = "bad regex"
+            title = "(unclosed"
+            mark_read = true
+
+            [[rules]]
+            name = "no actions"
+            title = "x"
+
+            [[rules]]
+            star = true
+
+            [[rules]]
+            name = "rule 3"
+            hide = true
+            "#,
+        );
+        assert_eq!(set.rules().len(), 1);
+        assert_eq!(set.rules()[0].name, "rule 3");
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].starts_with("rule 'bad regex': invalid title pattern"));
+        assert!(messages[1].starts_with("rule 'no actions': no actions"));
+        assert_eq!(messages[2], "rule 'rule 3': duplicate rule name");
+    }
+
+    #[test]
+    fn test_rule_without_conditions_matches_everything() {
+        let (set, _) = rules("[[rules]]\nname = \"all\"\npriority = -1\n");
+        assert_eq!(names(&set, &input("anything", None)), ["all"]);
+    }
+}
@@ -1,8 +1,12 @@
 use anyhow::Result;
-use sqlx::QueryBuilder;
+use sqlx::{QueryBuilder, SqliteConnection};
 
+use super::duplicates::{propagate_to_duplicates, HIDE_DUPLICATES};
+use super::revisions::{metadata_hash, record_metadata_revisions};
 use super::schema::Database;
-use super::types::{Article, ArticleDbRow, ArticleRow, ParsedArticle};
+use super::tags::ARTICLE_TAGS;
+use super::types::{Article, ArticleDbRow, ArticleRow, ParsedArticle, UpsertCounts};
+use crate::util::{title_hash, url_dedup_key, word_count};
 
 // ============================================================================
 // Query Limit Constants
@@ -14,117 +18,213 @@ const MAX_ARTICLES: i64 = 2000;
 /// Maximum limit for batch article queries like get_recent_articles_for_feeds
 const MAX_BATCH_LIMIT: usize = 10000;
 
+/// Articles per upsert batch: 12 bound columns * 50 = 600 parameters on insert.
+pub(super) const UPSERT_BATCH_SIZE: usize = 50;
+
+/// Insert new articles of one batch and rewrite existing ones whose metadata changed.
+///
+/// Each article's metadata is fingerprinted with [`metadata_hash`] and compared
+/// with the stored `content_hash`. Existing articles with a matching hash are
+/// left untouched, so a refresh of an unchanged feed performs no UPDATE and
+/// `articles_fts_update` never fires for it.
+///
+/// # PERF-012
+///
+/// Uses two-phase insert (INSERT OR IGNORE + UPDATE) with `changes()` instead of
+/// before/after COUNT queries. This eliminates 2 table scans per upsert operation.
+pub(super) async fn upsert_chunk(
+    conn: &mut SqliteConnection,
+    feed_id: i64,
+    chunk: &[ParsedArticle],
+    now: i64,
+) -> sqlx::Result<UpsertCounts> {
+    let keys: Vec<(Option<String>, Option<String>)> = chunk
+        .iter()
+        .map(|a| {
+            (
+                a.url.as_deref().and_then(url_dedup_key),
+                title_hash(&a.title),
+            )
+        })
+        .collect();
+    let hashes: Vec<String> = chunk.iter().map(metadata_hash).collect();
+    // Until the content is fetched, reading time goes by the summary
+    let words: Vec<Option<i64>> = chunk
+        .iter()
+        .map(|a| a.summary.as_deref().map(|s| word_count(s) as i64))
+        .collect();
+
+    // Phase 1: Insert new articles only (INSERT OR IGNORE)
+    let mut insert_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "INSERT OR IGNORE INTO articles \
+         (feed_id, guid, title, url, published, summary, author, fetched_at, canonical_key, \
+         title_hash, content_hash, word_count) ",
+    );
+
+    let rows = chunk.iter().zip(&keys).zip(&hashes).zip(&words);
+    insert_builder.push_values(rows, |mut b, (((article, key), hash), words)| {
+        b.push_bind(feed_id)
+            .push_bind(&article.guid)
+            .push_bind(&article.title)
+            .push_bind(&article.url)
+            .push_bind(article.published)
+            .push_bind(&article.summary)
+            .push_bind(&article.author)
+            .push_bind(now)
+            .push_bind(&key.0)
+            .push_bind(&key.1)
+            .push_bind(hash)
+            .push_bind(words);
+    });
+
+    insert_builder.build().execute(&mut *conn).await?;
+
+    // PERF-012: Use changes() to count inserted rows (no table scan)
+    let (inserted,): (i64,) = sqlx::query_as("SELECT changes()")
+        .fetch_one(&mut *conn)
+        .await?;
+    let inserted = inserted as usize;
+
+    // Save the previous version of entries the publisher edited; only those
+    // (and rows stored before hashing existed) need their metadata rewritten
+    let stale = record_metadata_revisions(conn, feed_id, chunk, &hashes, now).await?;
+    let counts = UpsertCounts {
+        inserted,
+        updated: stale.len(),
+        unchanged: chunk.len().saturating_sub(inserted + stale.len()),
+    };
+    if stale.is_empty() {
+        return Ok(counts);
+    }
+
+    // Phase 2: Update metadata for changed articles (preserves user state)
+    // - fetched_at is NOT updated to preserve "first seen" timestamp for What's New ordering
+    let mut update_builder: QueryBuilder<sqlx::Sqlite> =
+        QueryBuilder::new("UPDATE articles SET title = CASE guid ");
+
+    // Build CASE expressions for each field
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(&chunk[i].title);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE title END, url = CASE guid ");
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(&chunk[i].url);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE url END, published = CASE guid ");
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(chunk[i].published);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE published END, summary = CASE guid ");
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(&chunk[i].summary);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE summary END, author = CASE guid ");
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(&chunk[i].author);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE author END, canonical_key = CASE guid ");
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(&keys[i].0);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE canonical_key END, title_hash = CASE guid ");
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(&keys[i].1);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE title_hash END, content_hash = CASE guid ");
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(&hashes[i]);
+        update_builder.push(" ");
+    }
+    // Fetched content, once there is some, counts instead of the summary
+    update_builder.push(
+        "ELSE content_hash END, word_count = CASE WHEN content IS NULL AND NOT EXISTS \
+         (SELECT 1 FROM content_cache WHERE article_id = articles.id) THEN CASE guid ",
+    );
+    for &i in &stale {
+        update_builder.push("WHEN ");
+        update_builder.push_bind(&chunk[i].guid);
+        update_builder.push(" THEN ");
+        update_builder.push_bind(words[i]);
+        update_builder.push(" ");
+    }
+    update_builder.push("ELSE word_count END ELSE word_count END WHERE feed_id = ");
+    update_builder.push_bind(feed_id);
+    update_builder.push(" AND guid IN (");
+
+    let mut separated = update_builder.separated(", ");
+    for &i in &stale {
+        separated.push_bind(&chunk[i].guid);
+    }
+    separated.push_unseparated(")");
+
+    update_builder.build().execute(&mut *conn).await?;
+    Ok(counts)
+}
+
 impl Database {
     // ========================================================================
     // Article Operations
     // ========================================================================
 
-    /// Upsert articles for a feed, returns the number of new articles inserted
+    /// Upsert articles for a feed, returns how many were inserted, updated and unchanged
     ///
-    /// PERF-003: Uses INSERT ... ON CONFLICT DO UPDATE (UPSERT) for efficient handling
-    /// of both new and existing articles in a single pass.
-    /// Batch size of 50 keeps us well under SQLite's 999 parameter limit (7 columns * 50 = 350).
+    /// Batch size of 50 keeps us well under SQLite's 999 parameter limit.
     ///
     /// Preserves user state (read, starred, content, fetched_at) for existing articles
     /// while updating metadata (title, url, published, summary) from the feed.
-    ///
-    /// # PERF-012
-    ///
-    /// Uses two-phase insert (INSERT OR IGNORE + UPDATE) with `changes()` instead of
-    /// before/after COUNT queries. This eliminates 2 table scans per upsert operation.
+    /// Entries whose metadata changed keep their previous version in `article_revisions`;
+    /// unchanged entries are not written at all (see [`upsert_chunk`]).
     #[allow(dead_code)] // Kept for potential use outside of complete_feed_refresh
-    pub async fn upsert_articles(&self, feed_id: i64, articles: &[ParsedArticle]) -> Result<usize> {
+    pub async fn upsert_articles(
+        &self,
+        feed_id: i64,
+        articles: &[ParsedArticle],
+    ) -> Result<UpsertCounts> {
         if articles.is_empty() {
-            return Ok(0);
+            return Ok(UpsertCounts::default());
         }
 
         let now = chrono::Utc::now().timestamp();
         let mut tx = self.pool.begin().await?;
 
-        // PERF-012: Two-phase insert to accurately count new articles using changes()
-        // Phase 1: INSERT OR IGNORE to insert only new articles, track count via changes()
-        // Phase 2: UPDATE to refresh metadata for all articles (new and existing)
-        const BATCH_SIZE: usize = 50;
-        let mut total_inserted: usize = 0;
-
-        for chunk in articles.chunks(BATCH_SIZE) {
-            // Phase 1: Insert new articles only (INSERT OR IGNORE)
-            let mut insert_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-                "INSERT OR IGNORE INTO articles (feed_id, guid, title, url, published, summary, fetched_at) ",
-            );
-
-            insert_builder.push_values(chunk, |mut b, article| {
-                b.push_bind(feed_id)
-                    .push_bind(&article.guid)
-                    .push_bind(&article.title)
-                    .push_bind(&article.url)
-                    .push_bind(article.published)
-                    .push_bind(&article.summary)
-                    .push_bind(now);
-            });
-
-            insert_builder.build().execute(&mut *tx).await?;
-
-            // PERF-012: Use changes() to count inserted rows (no table scan)
-            let changes: (i64,) = sqlx::query_as("SELECT changes()")
-                .fetch_one(&mut *tx)
-                .await?;
-            total_inserted += changes.0 as usize;
-
-            // Phase 2: Update metadata for existing articles (preserves user state)
-            // - fetched_at is NOT updated to preserve "first seen" timestamp for What's New ordering
-            let mut update_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-                "UPDATE articles SET \
-                 title = CASE guid ",
-            );
-
-            // Build CASE expressions for each field
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(&article.title);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE title END, url = CASE guid ");
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(&article.url);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE url END, published = CASE guid ");
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(article.published);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE published END, summary = CASE guid ");
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(&article.summary);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE summary END WHERE feed_id = ");
-            update_builder.push_bind(feed_id);
-            update_builder.push(" AND guid IN (");
-
-            let mut separated = update_builder.separated(", ");
-            for article in chunk {
-                separated.push_bind(&article.guid);
-            }
-            separated.push_unseparated(")");
-
-            update_builder.build().execute(&mut *tx).await?;
+        let mut counts = UpsertCounts::default();
+        for chunk in articles.chunks(UPSERT_BATCH_SIZE) {
+            counts += upsert_chunk(&mut tx, feed_id, chunk, now).await?;
         }
 
         tx.commit().await?;
-        Ok(total_inserted)
+        Ok(counts)
     }
 
     // ========================================================================
@@ -146,16 +246,17 @@ impl Database {
             "get_articles_for_feed with limit cap"
         );
 
-        let rows = sqlx::query_as::<_, ArticleDbRow>(
+        let rows = sqlx::query_as::<_, ArticleDbRow>(&format!(
             r#"
-            SELECT id, feed_id, guid, title, url, published, summary, content,
-                   read, starred, fetched_at
+            SELECT id, feed_id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                   COALESCE(summary_rewritten, summary) AS summary, content,
+                   read, starred, archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
             FROM articles
-            WHERE feed_id = ?
-            ORDER BY published DESC, fetched_at DESC
+            WHERE feed_id = ? AND hidden = 0
+            ORDER BY priority DESC, published DESC, fetched_at DESC
             LIMIT ?
-        "#,
-        )
+        "#
+        ))
         .bind(feed_id)
         .bind(limit)
         .fetch_all(&self.pool)
@@ -169,14 +270,15 @@ impl Database {
     /// Used by What's New panel navigation when the user selects an entry
     /// and needs the full Article for the reader view.
     pub async fn get_article_by_id(&self, article_id: i64) -> Result<Option<Article>> {
-        let row = sqlx::query_as::<_, ArticleDbRow>(
+        let row = sqlx::query_as::<_, ArticleDbRow>(&format!(
             r#"
-            SELECT id, feed_id, guid, title, url, published, summary, content,
-                   read, starred, fetched_at
+            SELECT id, feed_id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                   COALESCE(summary_rewritten, summary) AS summary, content,
+                   read, starred, archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
             FROM articles
             WHERE id = ?
-        "#,
-        )
+        "#
+        ))
         .bind(article_id)
         .fetch_optional(&self.pool)
         .await?;
@@ -193,23 +295,26 @@ impl Database {
     /// # Returns
     ///
     /// A vector of all articles where `starred = true`, ordered by publication
-    /// date (most recent first). Limited to MAX_ARTICLES (2000) to prevent OOM.
+    /// date (most recent first), one copy per cross-feed duplicate group.
+    /// Limited to MAX_ARTICLES (2000) to prevent OOM.
     ///
     /// # PERF-003
     ///
     /// Hard cap at MAX_ARTICLES to prevent unbounded memory allocation.
     pub async fn get_starred_articles(&self) -> Result<Vec<Article>> {
         tracing::debug!(limit = MAX_ARTICLES, "get_starred_articles with limit cap");
-        let rows = sqlx::query_as::<_, ArticleDbRow>(
+        // Stars propagate across duplicates; list each story once
+        let rows = sqlx::query_as::<_, ArticleDbRow>(&format!(
             r#"
-            SELECT id, feed_id, guid, title, url, published, summary, content,
-                   read, starred, fetched_at
+            SELECT id, feed_id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                   COALESCE(summary_rewritten, summary) AS summary, content,
+                   read, starred, archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
             FROM articles
-            WHERE starred = 1
+            WHERE starred = 1 AND hidden = 0 AND {HIDE_DUPLICATES}
             ORDER BY published DESC, fetched_at DESC
             LIMIT ?
-        "#,
-        )
+        "#
+        ))
         .bind(MAX_ARTICLES)
         .fetch_all(&self.pool)
         .await?;
@@ -226,41 +331,77 @@ impl Database {
     /// Uses `WHERE read = 0` to make the operation idempotent and efficient.
     /// The article is only updated if it was previously unread, avoiding
     /// unnecessary writes and race conditions.
-    pub async fn mark_article_read(&self, article_id: i64) -> Result<bool> {
-        let result = sqlx::query("UPDATE articles SET read = 1 WHERE id = ? AND read = 0")
-            .bind(article_id)
-            .execute(&self.pool)
-            .await?;
+    ///
+    /// Copies of the article in other feeds (cross-feed duplicates) are marked too.
+    /// Returns the feed of every visible copy that went from unread to read,
+    /// once per copy (empty if the article was already read), so callers can
+    /// adjust unread counts.
+    pub async fn mark_article_read(&self, article_id: i64) -> Result<Vec<i64>> {
+        let mut tx = self.pool.begin().await?;
+        let own: Option<Option<i64>> = sqlx::query_scalar(
+            "UPDATE articles SET read = 1 WHERE id = ? AND read = 0 \
+             RETURNING CASE WHEN hidden = 0 THEN feed_id END",
+        )
+        .bind(article_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let mut feeds: Vec<i64> = own.flatten().into_iter().collect();
+        feeds.extend(propagate_to_duplicates(&mut tx, "read", true, "id = ?", article_id).await?);
+        tx.commit().await?;
 
-        Ok(result.rows_affected() > 0)
+        Ok(feeds)
     }
 
     /// Atomically toggle starred status, returning the new value
     ///
     /// Uses SQLite's RETURNING clause to perform the toggle and get the
     /// new value in a single atomic operation, preventing TOCTOU races.
+    ///
+    /// Copies of the article in other feeds (cross-feed duplicates) follow the new value.
     pub async fn toggle_article_starred(&self, article_id: i64) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
         let result: (bool,) = sqlx::query_as(
             r#"UPDATE articles SET starred = NOT starred WHERE id = ? RETURNING starred"#,
         )
         .bind(article_id)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await?;
+        propagate_to_duplicates(&mut tx, "starred", result.0, "id = ?", article_id).await?;
+        tx.commit().await?;
 
         Ok(result.0)
     }
 
+    /// Atomically toggle the archive flag, returning the new value.
+    ///
+    /// Archived articles keep their cached content permanently, like starred ones.
+    pub async fn toggle_article_archived(&self, article_id: i64) -> Result<bool> {
+        let (archived,): (bool,) = sqlx::query_as(
+            "UPDATE articles SET archived = NOT archived WHERE id = ? RETURNING archived",
+        )
+        .bind(article_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(archived)
+    }
+
     /// Mark all articles as read for a specific feed, returns count of articles marked
     ///
     /// Uses `WHERE read = 0` to make the operation idempotent. Only unread articles
     /// are updated, avoiding unnecessary writes when called repeatedly.
+    ///
+    /// Copies of these articles in other feeds are marked too, but not counted;
+    /// their feeds are returned alongside the count, once per visible copy.
     #[allow(dead_code)] // Kept for future UI integration
-    pub async fn mark_all_read_for_feed(&self, feed_id: i64) -> Result<u64> {
+    pub async fn mark_all_read_for_feed(&self, feed_id: i64) -> Result<(u64, Vec<i64>)> {
+        let mut tx = self.pool.begin().await?;
         let result = sqlx::query("UPDATE articles SET read = 1 WHERE feed_id = ? AND read = 0")
             .bind(feed_id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
-        Ok(result.rows_affected())
+        let copies = propagate_to_duplicates(&mut tx, "read", true, "feed_id = ?", feed_id).await?;
+        tx.commit().await?;
+        Ok((result.rows_affected(), copies))
     }
 
     /// Mark all articles as read across all feeds, returns count of articles marked
@@ -326,6 +467,8 @@ impl Database {
 
     /// PERF-001 + BUG-005: Get recent unread articles from multiple feeds in one query
     /// BUG-005: Safe limit cap at MAX_BATCH_LIMIT (10000) to prevent overflow
+    ///
+    /// Cross-feed duplicates are collapsed to their preferred copy.
     pub async fn get_recent_articles_for_feeds(
         &self,
         feed_ids: &[i64],
@@ -344,16 +487,20 @@ impl Database {
             "get_recent_articles_for_feeds with limit cap"
         );
 
-        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-            r#"SELECT feed_id, id, guid, title, url, published, summary, content, read, starred, fetched_at
-               FROM articles WHERE read = 0 AND feed_id IN ("#,
-        );
+        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(format!(
+            r#"SELECT feed_id, id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                      COALESCE(summary_rewritten, summary) AS summary, content, read, starred,
+                      archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
+               FROM articles WHERE read = 0 AND hidden = 0 AND feed_id IN ("#
+        ));
 
         let mut separated = builder.separated(", ");
         for id in feed_ids {
             separated.push_bind(*id);
         }
-        separated.push_unseparated(") ORDER BY published DESC LIMIT ");
+        separated.push_unseparated(") AND ");
+        builder.push(HIDE_DUPLICATES);
+        builder.push(" ORDER BY priority DESC, published DESC LIMIT ");
         builder.push_bind(safe_limit);
 
         let rows: Vec<ArticleRow> = builder.build_query_as().fetch_all(&self.pool).await?;
@@ -363,7 +510,7 @@ impl Database {
 
 #[cfg(test)]
 mod tests {
-    use crate::storage::{Database, OpmlFeed, ParsedArticle};
+    use crate::storage::{Database, OpmlFeed, ParsedArticle, UpsertCounts};
 
     async fn test_db() -> Database {
         Database::open(":memory:").await.unwrap()
@@ -384,6 +531,7 @@ mod tests {
             url: Some(format!("https://example.com/{}", guid)),
             published: Some(1704067200),
             summary: Some("Test summary".to_string()),
+            author: None,
         }
     }
 
@@ -404,7 +552,7 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(count, 2);
+        assert_eq!(count.inserted, 2);
     }
 
     #[tokio::test]
@@ -421,7 +569,14 @@ mod tests {
             .upsert_articles(feeds[0].id, &[test_article("guid-1", "Updated")])
             .await
             .unwrap();
-        assert_eq!(count, 0);
+        assert_eq!(
+            count,
+            UpsertCounts {
+                inserted: 0,
+                updated: 1,
+                unchanged: 0
+            }
+        );
     }
 
     #[tokio::test]
@@ -444,6 +599,7 @@ mod tests {
             url: Some("https://example.com/updated".to_string()),
             published: Some(1704153600),
             summary: Some("Updated summary".to_string()),
+            author: None,
         };
         db.upsert_articles(feeds[0].id, &[updated]).await.unwrap();
 
@@ -481,7 +637,46 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(count, 2);
+        assert_eq!(
+            count,
+            UpsertCounts {
+                inserted: 2,
+                updated: 1,
+                unchanged: 0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upsert_articles_skips_unchanged() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1)]).await.unwrap();
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        let batch = [
+            test_article("guid-1", "Article 1"),
+            test_article("guid-2", "Article 2"),
+        ];
+        db.upsert_articles(feeds[0].id, &batch).await.unwrap();
+
+        let count = db.upsert_articles(feeds[0].id, &batch).await.unwrap();
+        assert_eq!(
+            count,
+            UpsertCounts {
+                inserted: 0,
+                updated: 0,
+                unchanged: 2
+            }
+        );
+
+        // Rows stored before hashing are rewritten once to adopt a hash
+        sqlx::query("UPDATE articles SET content_hash = NULL")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        let count = db.upsert_articles(feeds[0].id, &batch).await.unwrap();
+        assert_eq!(count.updated, 2);
+        let count = db.upsert_articles(feeds[0].id, &batch).await.unwrap();
+        assert_eq!(count.unchanged, 2);
     }
 
     #[tokio::test]
@@ -491,7 +686,7 @@ mod tests {
         let feeds = db.get_feeds_with_unread_counts().await.unwrap();
 
         let count = db.upsert_articles(feeds[0].id, &[]).await.unwrap();
-        assert_eq!(count, 0);
+        assert_eq!(count, UpsertCounts::default());
     }
 
     #[tokio::test]
@@ -507,13 +702,13 @@ mod tests {
         assert!(!articles[0].read);
 
         let changed = db.mark_article_read(articles[0].id).await.unwrap();
-        assert!(changed);
+        assert_eq!(changed, [feeds[0].id]);
 
         let articles = db.get_articles_for_feed(feeds[0].id, None).await.unwrap();
         assert!(articles[0].read);
 
         let changed = db.mark_article_read(articles[0].id).await.unwrap();
-        assert!(!changed);
+        assert!(changed.is_empty());
     }
 
     #[tokio::test]
@@ -599,7 +794,7 @@ mod tests {
         let articles = db.get_articles_for_feed(feeds[0].id, None).await.unwrap();
         db.mark_article_read(articles[0].id).await.unwrap();
 
-        let count = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
+        let (count, _) = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
         assert_eq!(count, 2);
 
         let articles = db.get_articles_for_feed(feeds[0].id, None).await.unwrap();
@@ -625,10 +820,10 @@ mod tests {
         .await
         .unwrap();
 
-        let count = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
+        let (count, _) = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
         assert_eq!(count, 2);
 
-        let count = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
+        let (count, _) = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
         assert_eq!(count, 0);
     }
 
@@ -692,7 +887,7 @@ mod tests {
         let feeds = db.get_feeds_with_unread_counts().await.unwrap();
 
         // Feed has zero articles — should be a no-op
-        let count = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
+        let (count, _) = db.mark_all_read_for_feed(feeds[0].id).await.unwrap();
         assert_eq!(count, 0);
     }
 
@@ -1,115 +1,408 @@
-use anyhow::Result;
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
 use sqlx::QueryBuilder;
 
+use super::revisions::record_content_revision;
 use super::schema::Database;
-use super::types::{CacheStats, CachedContent};
+use super::types::{CacheEntry, CacheStats, CachedContent, FeedCacheUsage};
+use crate::util::{compress, decompress, summarize, word_count};
 
 /// Default TTL for cached content (72 hours)
 const DEFAULT_TTL_HOURS: i64 = 72;
 
+/// Articles whose cached content is pinned (never expires): starred,
+/// archived, or in the read-later queue. A macro so it can be `concat!`ed.
+macro_rules! pinned_articles {
+    () => {
+        "SELECT id FROM articles WHERE starred = 1 OR archived = 1 \
+         UNION SELECT article_id FROM read_later"
+    };
+}
+
+/// Condition for a `content_cache` row whose article is pinned.
+const CACHE_PINNED: &str = concat!("article_id IN (", pinned_articles!(), ")");
+
+/// Condition for a `content_cache` row that may still be served: unexpired or pinned.
+const CACHE_LIVE: &str = concat!(
+    "(expires_at > datetime('now') OR article_id IN (",
+    pinned_articles!(),
+    "))"
+);
+
+/// Bytes an entry takes on disk. Rows cached before compression have no
+/// `stored_bytes` and hold their markdown as plain text.
+const STORED_BYTES: &str = "COALESCE(stored_bytes, size_bytes)";
+
+/// When an entry was last read, for least-recently-read eviction.
+const LAST_ACCESSED: &str = "COALESCE(last_accessed_at, fetched_at)";
+
+/// How long cached content lives and how much of it is kept (`[cache]` config).
+#[derive(Debug, Clone)]
+pub struct CachePolicy {
+    /// TTL for feeds without an override.
+    pub ttl_hours: i64,
+    /// Per-feed TTL overrides keyed by lowercased feed title or URL.
+    pub feed_ttl_hours: HashMap<String, i64>,
+    /// Total on-disk budget; the least recently read unpinned entries are
+    /// evicted beyond it. `None` = unlimited.
+    pub max_size_bytes: Option<i64>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            ttl_hours: DEFAULT_TTL_HOURS,
+            feed_ttl_hours: HashMap::new(),
+            max_size_bytes: None,
+        }
+    }
+}
+
+impl CachePolicy {
+    /// TTL in hours for an article of the given feed (at least 1).
+    pub fn ttl_for(&self, feed_title: &str, feed_url: &str) -> i64 {
+        self.feed_ttl_hours
+            .get(&feed_url.to_lowercase())
+            .or_else(|| self.feed_ttl_hours.get(&feed_title.to_lowercase()))
+            .copied()
+            .unwrap_or(self.ttl_hours)
+            .max(1)
+    }
+}
+
+/// Markdown of a cache row: the compressed `body`, or the plain `markdown`
+/// column for rows cached before compression.
+pub(super) fn decode_body(markdown: String, body: Option<Vec<u8>>) -> Result<String> {
+    match body {
+        None => Ok(markdown),
+        Some(body) => decompress(&body)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(|| anyhow!("Corrupt compressed cache entry")),
+    }
+}
+
 impl Database {
     // ========================================================================
     // Content Cache Operations
     // ========================================================================
 
-    /// Cache article content with a TTL.
+    /// Cache article content, compressed, with the TTL `policy` gives its feed.
     ///
     /// Inserts or replaces the cached markdown for the given article.
-    /// `size_bytes` is computed from the markdown byte length.
-    /// `expires_at` is computed as `now + ttl_hours`.
+    /// `size_bytes` is the markdown byte length, `stored_bytes` the
+    /// compressed length. When `policy` sets a size budget it is enforced
+    /// afterwards (see [`Database::enforce_cache_budget`]).
+    ///
+    /// When a re-fetch replaces different markdown (expired or not), the old
+    /// body is kept as a content revision and the article is marked updated.
+    /// The article's word count is taken from the new markdown.
     ///
     /// # Arguments
     ///
     /// * `article_id` - The database ID of the article
     /// * `markdown` - The full article content (markdown from jina.ai)
-    /// * `ttl_hours` - Hours until this cache entry expires (use `None` for default 72h)
+    /// * `policy` - TTL and size budget from the `[cache]` config
     pub async fn cache_content(
         &self,
         article_id: i64,
         markdown: &str,
-        ttl_hours: Option<i64>,
+        policy: &CachePolicy,
     ) -> Result<()> {
-        let ttl = ttl_hours.unwrap_or(DEFAULT_TTL_HOURS).max(1);
-        let size_bytes = markdown.len() as i64;
-        let ttl_modifier = format!("+{ttl} hours");
+        let body = compress(markdown.as_bytes());
+        let mut tx = self.pool.begin().await?;
+
+        #[allow(clippy::type_complexity)]
+        let row: Option<(String, String, Option<String>, Option<Vec<u8>>)> = sqlx::query_as(
+            r#"
+            SELECT f.title, f.url, cc.markdown, cc.body
+            FROM articles a
+            JOIN feeds f ON f.id = a.feed_id
+            LEFT JOIN content_cache cc ON cc.article_id = a.id
+            WHERE a.id = ?
+        "#,
+        )
+        .bind(article_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let ttl = match &row {
+            Some((feed_title, feed_url, _, _)) => policy.ttl_for(feed_title, feed_url),
+            None => policy.ttl_hours.max(1),
+        };
+        if let Some((_, _, Some(previous), previous_body)) = row {
+            // An unreadable old body is simply replaced
+            if let Ok(previous) = decode_body(previous, previous_body) {
+                if previous != markdown {
+                    let now = chrono::Utc::now().timestamp();
+                    record_content_revision(&mut tx, article_id, &previous, now).await?;
+                }
+            }
+        }
 
         sqlx::query(
             r#"
             INSERT OR REPLACE INTO content_cache
-                (article_id, markdown, fetched_at, expires_at, size_bytes)
-            VALUES (?, ?, datetime('now'), datetime('now', ?), ?)
+                (article_id, markdown, body, fetched_at, expires_at, last_accessed_at,
+                 size_bytes, stored_bytes)
+            VALUES (?, '', ?, datetime('now'), datetime('now', ?), datetime('now'), ?, ?)
         "#,
         )
         .bind(article_id)
-        .bind(markdown)
-        .bind(&ttl_modifier)
-        .bind(size_bytes)
-        .execute(&self.pool)
+        .bind(&body)
+        .bind(format!("+{ttl} hours"))
+        .bind(markdown.len() as i64)
+        .bind(body.len() as i64)
+        .execute(&mut *tx)
         .await?;
+        sqlx::query("UPDATE articles SET word_count = ? WHERE id = ?")
+            .bind(word_count(markdown) as i64)
+            .bind(article_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
 
+        if let Some(max_bytes) = policy.max_size_bytes {
+            self.enforce_cache_budget(max_bytes).await?;
+        }
         Ok(())
     }
 
-    /// Retrieve cached content if it has not expired.
+    /// Retrieve cached content if it has not expired, recording the access
+    /// for least-recently-read eviction.
     ///
     /// Returns `None` if no cache entry exists or if the entry has expired.
+    /// Entries of starred, archived and queued articles never expire.
     pub async fn get_cached_content(&self, article_id: i64) -> Result<Option<CachedContent>> {
-        let row: Option<(i64, String, String, String, i64)> = sqlx::query_as(
+        #[allow(clippy::type_complexity)]
+        let row: Option<(
+            i64,
+            String,
+            Option<Vec<u8>>,
+            Option<String>,
+            String,
+            String,
+            i64,
+        )> = sqlx::query_as(&format!(
             r#"
-            SELECT article_id, markdown, fetched_at, expires_at, size_bytes
-            FROM content_cache
-            WHERE article_id = ? AND expires_at > datetime('now')
-        "#,
-        )
+            UPDATE content_cache SET last_accessed_at = datetime('now')
+            WHERE article_id = ? AND {CACHE_LIVE}
+            RETURNING article_id, markdown, body, markdown_rewritten, fetched_at, expires_at,
+                      size_bytes
+        "#
+        ))
         .bind(article_id)
         .fetch_optional(&self.pool)
         .await?;
 
-        Ok(row.map(
-            |(article_id, markdown, fetched_at, expires_at, size_bytes)| CachedContent {
-                article_id,
-                markdown,
-                fetched_at,
-                expires_at,
-                size_bytes,
-            },
-        ))
+        let Some((
+            article_id,
+            markdown,
+            body,
+            markdown_rewritten,
+            fetched_at,
+            expires_at,
+            size_bytes,
+        )) = row
+        else {
+            return Ok(None);
+        };
+        Ok(Some(CachedContent {
+            article_id,
+            markdown: decode_body(markdown, body)?,
+            markdown_rewritten,
+            fetched_at,
+            expires_at,
+            size_bytes,
+        }))
     }
 
-    /// Delete all expired cache entries.
+    /// Delete all expired cache entries, keeping pinned ones (starred, archived, queued).
     ///
     /// Returns the number of entries evicted.
     pub async fn evict_expired(&self) -> Result<u64> {
-        let result = sqlx::query("DELETE FROM content_cache WHERE expires_at < datetime('now')")
+        let result = sqlx::query(&format!("DELETE FROM content_cache WHERE NOT {CACHE_LIVE}"))
             .execute(&self.pool)
             .await?;
 
         Ok(result.rows_affected())
     }
 
+    /// Evict the least recently read unpinned entries until the cache takes
+    /// at most `max_bytes` on disk.
+    ///
+    /// Pinned entries count towards the total but are never evicted, so a
+    /// budget smaller than the pinned content leaves only pinned entries.
+    /// Returns the number of entries evicted.
+    pub async fn enforce_cache_budget(&self, max_bytes: i64) -> Result<u64> {
+        let total: Option<i64> =
+            sqlx::query_scalar(&format!("SELECT SUM({STORED_BYTES}) FROM content_cache"))
+                .fetch_one(&self.pool)
+                .await?;
+        let mut excess = total.unwrap_or(0) - max_bytes;
+        if excess <= 0 {
+            return Ok(0);
+        }
+
+        let candidates: Vec<(i64, i64)> = sqlx::query_as(&format!(
+            "SELECT article_id, {STORED_BYTES} FROM content_cache WHERE NOT {CACHE_PINNED} \
+             ORDER BY {LAST_ACCESSED}, article_id"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        let mut victims = Vec::new();
+        for (article_id, stored) in candidates {
+            if excess <= 0 {
+                break;
+            }
+            victims.push(article_id);
+            excess -= stored;
+        }
+        self.delete_cache_entries(&victims).await
+    }
+
+    /// Delete the cache entries of the given articles. Returns how many existed.
+    async fn delete_cache_entries(&self, ids: &[i64]) -> Result<u64> {
+        const CHUNK_SIZE: usize = 500;
+        let mut deleted = 0;
+        for chunk in ids.chunks(CHUNK_SIZE) {
+            let mut builder: QueryBuilder<sqlx::Sqlite> =
+                QueryBuilder::new("DELETE FROM content_cache WHERE article_id IN (");
+            let mut separated = builder.separated(", ");
+            for id in chunk {
+                separated.push_bind(*id);
+            }
+            separated.push_unseparated(")");
+            deleted += builder.build().execute(&self.pool).await?.rows_affected();
+        }
+        Ok(deleted)
+    }
+
+    /// Delete unpinned cache entries, of one feed or of every feed.
+    ///
+    /// Starred, archived and queued articles keep their content. Returns the
+    /// number of entries deleted.
+    pub async fn purge_cache(&self, feed_id: Option<i64>) -> Result<u64> {
+        let result = sqlx::query(&format!(
+            "DELETE FROM content_cache WHERE NOT {CACHE_PINNED} \
+             AND (?1 IS NULL OR article_id IN (SELECT id FROM articles WHERE feed_id = ?1))"
+        ))
+        .bind(feed_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Compress entries cached before bodies were stored compressed.
+    ///
+    /// Returns the number of entries rewritten.
+    pub async fn compress_legacy_cache(&self) -> Result<u64> {
+        const BATCH: i64 = 100;
+        let mut converted = 0;
+        loop {
+            let rows: Vec<(i64, String)> = sqlx::query_as(
+                "SELECT article_id, markdown FROM content_cache WHERE body IS NULL LIMIT ?",
+            )
+            .bind(BATCH)
+            .fetch_all(&self.pool)
+            .await?;
+            if rows.is_empty() {
+                return Ok(converted);
+            }
+            let mut tx = self.pool.begin().await?;
+            for (article_id, markdown) in rows {
+                let body = compress(markdown.as_bytes());
+                sqlx::query(
+                    "UPDATE content_cache SET markdown = '', body = ?, stored_bytes = ? \
+                     WHERE article_id = ?",
+                )
+                .bind(&body)
+                .bind(body.len() as i64)
+                .bind(article_id)
+                .execute(&mut *tx)
+                .await?;
+                converted += 1;
+            }
+            tx.commit().await?;
+        }
+    }
+
     /// Compute aggregate cache statistics.
     ///
-    /// Returns total entry count, total size in bytes, and oldest/newest
-    /// `fetched_at` timestamps.
-    #[allow(dead_code)] // Consumed by TASK-8 (stats panel) and TASK-10 (cache tests)
+    /// Returns total entry count, total (uncompressed) and on-disk size in
+    /// bytes, oldest/newest `fetched_at` timestamps, and how much of the
+    /// cache is pinned.
     pub async fn cache_stats(&self) -> Result<CacheStats> {
-        let row: (i64, Option<i64>, Option<String>, Option<String>) = sqlx::query_as(
+        #[allow(clippy::type_complexity)]
+        let row: (
+            i64,
+            Option<i64>,
+            Option<i64>,
+            Option<String>,
+            Option<String>,
+            Option<i64>,
+            Option<i64>,
+        ) = sqlx::query_as(&format!(
             r#"
-            SELECT COUNT(*), SUM(size_bytes), MIN(fetched_at), MAX(fetched_at)
+            SELECT COUNT(*), SUM(size_bytes), SUM({STORED_BYTES}), MIN(fetched_at), MAX(fetched_at),
+                   SUM({CACHE_PINNED}), SUM(CASE WHEN {CACHE_PINNED} THEN size_bytes END)
             FROM content_cache
-        "#,
-        )
+        "#
+        ))
         .fetch_one(&self.pool)
         .await?;
 
         Ok(CacheStats {
             total_entries: row.0,
             total_size_bytes: row.1.unwrap_or(0),
-            oldest_entry: row.2,
-            newest_entry: row.3,
+            stored_size_bytes: row.2.unwrap_or(0),
+            oldest_entry: row.3,
+            newest_entry: row.4,
+            pinned_entries: row.5.unwrap_or(0),
+            pinned_size_bytes: row.6.unwrap_or(0),
         })
     }
 
+    /// The cache entries taking the most space on disk, largest first.
+    pub async fn largest_cache_entries(&self, limit: i64) -> Result<Vec<CacheEntry>> {
+        let rows = sqlx::query_as::<_, CacheEntry>(&format!(
+            r#"
+            SELECT article_id, COALESCE(a.title_rewritten, a.title) AS title,
+                   f.title AS feed_title, size_bytes, {STORED_BYTES} AS stored_bytes,
+                   {CACHE_PINNED} AS pinned,
+                   COALESCE(cc.last_accessed_at, cc.fetched_at) AS last_accessed_at
+            FROM content_cache cc
+            JOIN articles a ON a.id = article_id
+            JOIN feeds f ON f.id = a.feed_id
+            ORDER BY stored_bytes DESC, article_id
+            LIMIT ?
+        "#
+        ))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Cache usage per feed, largest first. Feeds with nothing cached are left out.
+    pub async fn cache_usage_by_feed(&self) -> Result<Vec<FeedCacheUsage>> {
+        let rows = sqlx::query_as::<_, FeedCacheUsage>(&format!(
+            r#"
+            SELECT f.id AS feed_id, f.title, COUNT(*) AS entries,
+                   SUM(size_bytes) AS size_bytes, SUM({STORED_BYTES}) AS stored_bytes,
+                   SUM({CACHE_PINNED}) AS pinned_entries
+            FROM content_cache cc
+            JOIN articles a ON a.id = article_id
+            JOIN feeds f ON f.id = a.feed_id
+            GROUP BY f.id
+            ORDER BY stored_bytes DESC, f.title
+        "#
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
     /// Find unread articles that have no cache entries, ordered by published DESC.
     ///
     /// Used by the prefetch scheduler to prioritise which articles to fetch next.
@@ -120,7 +413,7 @@ impl Database {
             SELECT a.id
             FROM articles a
             LEFT JOIN content_cache cc ON a.id = cc.article_id
-            WHERE a.read = 0 AND cc.article_id IS NULL
+            WHERE a.read = 0 AND a.hidden = 0 AND cc.article_id IS NULL
             ORDER BY a.published DESC
             LIMIT ?
         "#,
@@ -142,7 +435,7 @@ impl Database {
             SELECT a.id
             FROM articles a
             LEFT JOIN content_cache cc ON a.id = cc.article_id
-            WHERE a.feed_id = ? AND a.read = 0 AND cc.article_id IS NULL
+            WHERE a.feed_id = ? AND a.read = 0 AND a.hidden = 0 AND cc.article_id IS NULL
             ORDER BY a.published DESC
             LIMIT ?
         "#,
@@ -168,9 +461,9 @@ impl Database {
         let mut result = Vec::new();
 
         for chunk in ids.chunks(CHUNK_SIZE) {
-            let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-                "SELECT article_id FROM content_cache WHERE expires_at > datetime('now') AND article_id IN (",
-            );
+            let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(format!(
+                "SELECT article_id FROM content_cache WHERE {CACHE_LIVE} AND article_id IN ("
+            ));
 
             let mut separated = builder.separated(", ");
             for id in chunk {
@@ -184,11 +477,75 @@ impl Database {
 
         Ok(result)
     }
+
+    /// TL;DR of an article showing `markdown` (see [`summarize`]).
+    ///
+    /// Stored next to the cached content the first time it is asked for, and
+    /// dropped when the content is re-cached or rewritten differently. Articles
+    /// without a cache entry get a summary that isn't stored.
+    pub async fn article_tldr(&self, article_id: i64, markdown: &str) -> Result<Vec<String>> {
+        let stored: Option<Option<String>> =
+            sqlx::query_scalar("SELECT tldr FROM content_cache WHERE article_id = ?")
+                .bind(article_id)
+                .fetch_optional(&self.pool)
+                .await?;
+        if let Some(Some(tldr)) = stored {
+            return Ok(tldr_sentences(&tldr));
+        }
+
+        let sentences = summarize(markdown);
+        if stored.is_some() {
+            sqlx::query("UPDATE content_cache SET tldr = ? WHERE article_id = ?")
+                .bind(sentences.join("\n"))
+                .bind(article_id)
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(sentences)
+    }
+
+    /// Stored TL;DRs of the given articles, for previews in the article list.
+    ///
+    /// Articles whose summary hasn't been computed yet, or that are too short
+    /// for one, are left out.
+    pub async fn cached_tldrs(&self, ids: &[i64]) -> Result<HashMap<i64, Vec<String>>> {
+        const CHUNK_SIZE: usize = 500;
+        let mut result = HashMap::new();
+
+        for chunk in ids.chunks(CHUNK_SIZE) {
+            let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(format!(
+                "SELECT article_id, tldr FROM content_cache \
+                 WHERE {CACHE_LIVE} AND tldr <> '' AND article_id IN ("
+            ));
+
+            let mut separated = builder.separated(", ");
+            for id in chunk {
+                separated.push_bind(*id);
+            }
+            separated.push_unseparated(")");
+
+            let rows: Vec<(i64, String)> = builder.build_query_as().fetch_all(&self.pool).await?;
+            result.extend(
+                rows.into_iter()
+                    .map(|(id, tldr)| (id, tldr_sentences(&tldr))),
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+/// Sentences of a stored TL;DR, one per line.
+fn tldr_sentences(tldr: &str) -> Vec<String> {
+    tldr.lines()
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::storage::{Database, OpmlFeed, ParsedArticle};
+    use crate::storage::{CachePolicy, Database, OpmlFeed, ParsedArticle};
 
     async fn test_db() -> Database {
         Database::open(":memory:").await.unwrap()
@@ -209,6 +566,7 @@ mod tests {
             url: Some(format!("https://example.com/{guid}")),
             published: Some(1704067200),
             summary: Some("Test summary".to_string()),
+            author: None,
         }
     }
 
@@ -233,7 +591,7 @@ mod tests {
         let db = test_db().await;
         let (_feed_id, ids) = setup_articles(&db, 1).await;
 
-        db.cache_content(ids[0], "# Hello World", None)
+        db.cache_content(ids[0], "# Hello World", &CachePolicy::default())
             .await
             .unwrap();
 
@@ -250,8 +608,12 @@ mod tests {
         let db = test_db().await;
         let (_feed_id, ids) = setup_articles(&db, 1).await;
 
-        db.cache_content(ids[0], "old content", None).await.unwrap();
-        db.cache_content(ids[0], "new content", None).await.unwrap();
+        db.cache_content(ids[0], "old content", &CachePolicy::default())
+            .await
+            .unwrap();
+        db.cache_content(ids[0], "new content", &CachePolicy::default())
+            .await
+            .unwrap();
 
         let cached = db.get_cached_content(ids[0]).await.unwrap().unwrap();
         assert_eq!(cached.markdown, "new content");
@@ -286,7 +648,7 @@ mod tests {
         let (_feed_id, ids) = setup_articles(&db, 2).await;
 
         // Cache one article with default TTL (valid)
-        db.cache_content(ids[0], "valid content", None)
+        db.cache_content(ids[0], "valid content", &CachePolicy::default())
             .await
             .unwrap();
 
@@ -330,11 +692,15 @@ mod tests {
         assert!(stats.newest_entry.is_none());
 
         // Add some entries
-        db.cache_content(ids[0], "short", None).await.unwrap();
-        db.cache_content(ids[1], "medium content here", None)
+        db.cache_content(ids[0], "short", &CachePolicy::default())
+            .await
+            .unwrap();
+        db.cache_content(ids[1], "medium content here", &CachePolicy::default())
+            .await
+            .unwrap();
+        db.cache_content(ids[2], "a", &CachePolicy::default())
             .await
             .unwrap();
-        db.cache_content(ids[2], "a", None).await.unwrap();
 
         let stats = db.cache_stats().await.unwrap();
         assert_eq!(stats.total_entries, 3);
@@ -346,6 +712,185 @@ mod tests {
         assert!(stats.newest_entry.is_some());
     }
 
+    #[tokio::test]
+    async fn test_starred_and_archived_content_pinned() {
+        let db = test_db().await;
+        let (_feed_id, ids) = setup_articles(&db, 3).await;
+        for (id, body) in ids.iter().zip(["starred", "archived", "plain"]) {
+            db.cache_content(*id, body, &CachePolicy::default())
+                .await
+                .unwrap();
+        }
+        sqlx::query("UPDATE content_cache SET expires_at = datetime('now', '-1 hour')")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        db.toggle_article_starred(ids[0]).await.unwrap();
+        assert!(db.toggle_article_archived(ids[1]).await.unwrap());
+
+        let stats = db.cache_stats().await.unwrap();
+        assert_eq!((stats.total_entries, stats.pinned_entries), (3, 2));
+        assert_eq!(
+            stats.pinned_size_bytes,
+            ("starred".len() + "archived".len()) as i64
+        );
+
+        assert_eq!(db.evict_expired().await.unwrap(), 1);
+        assert!(db.get_cached_content(ids[0]).await.unwrap().is_some());
+        assert!(db.get_cached_content(ids[1]).await.unwrap().is_some());
+
+        // Unarchiving lets the normal TTL apply again
+        assert!(!db.toggle_article_archived(ids[1]).await.unwrap());
+        assert!(db.get_cached_content(ids[1]).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_bodies_stored_compressed() {
+        let db = test_db().await;
+        let (_feed_id, ids) = setup_articles(&db, 2).await;
+        let markdown = "Paragraph with [a link](https://example.com/page).\n\n".repeat(100);
+        db.cache_content(ids[0], &markdown, &CachePolicy::default())
+            .await
+            .unwrap();
+
+        let (plain, stored): (String, i64) =
+            sqlx::query_as("SELECT markdown, stored_bytes FROM content_cache")
+                .fetch_one(&db.pool)
+                .await
+                .unwrap();
+        assert!(plain.is_empty());
+        assert!(stored * 5 < markdown.len() as i64);
+        let cached = db.get_cached_content(ids[0]).await.unwrap().unwrap();
+        assert_eq!(cached.markdown, markdown);
+
+        let stats = db.cache_stats().await.unwrap();
+        assert_eq!(stats.total_size_bytes, markdown.len() as i64);
+        assert_eq!(stats.stored_size_bytes, stored);
+
+        // Rows from before compression are read as-is, then compressed in place
+        sqlx::query(
+            "INSERT INTO content_cache (article_id, markdown, fetched_at, expires_at, size_bytes) \
+             VALUES (?, 'legacy body', datetime('now'), datetime('now', '+1 hour'), 11)",
+        )
+        .bind(ids[1])
+        .execute(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            db.get_cached_content(ids[1])
+                .await
+                .unwrap()
+                .unwrap()
+                .markdown,
+            "legacy body"
+        );
+        assert_eq!(db.compress_legacy_cache().await.unwrap(), 1);
+        assert_eq!(db.compress_legacy_cache().await.unwrap(), 0);
+        assert_eq!(
+            db.get_cached_content(ids[1])
+                .await
+                .unwrap()
+                .unwrap()
+                .markdown,
+            "legacy body"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_feed_ttl_override() {
+        let db = test_db().await;
+        let (_feed_id, ids) = setup_articles(&db, 1).await;
+        let mut policy = CachePolicy::default();
+        policy
+            .feed_ttl_hours
+            .insert("https://cache-test.example.com/rss".to_string(), 2);
+        db.cache_content(ids[0], "short-lived", &policy)
+            .await
+            .unwrap();
+
+        let hours: f64 = sqlx::query_scalar(
+            "SELECT (julianday(expires_at) - julianday(fetched_at)) * 24 FROM content_cache",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+        assert!((hours - 2.0).abs() < 0.01, "{hours}");
+    }
+
+    #[tokio::test]
+    async fn test_size_budget_evicts_least_recently_read() {
+        let db = test_db().await;
+        let (_feed_id, ids) = setup_articles(&db, 4).await;
+        let policy = CachePolicy::default();
+        for id in &ids {
+            db.cache_content(*id, &format!("body of article {id}"), &policy)
+                .await
+                .unwrap();
+        }
+        // Oldest access first: ids[1], ids[0], ids[2], ids[3]
+        for (id, minutes) in [(ids[1], 40), (ids[0], 30), (ids[2], 20), (ids[3], 10)] {
+            sqlx::query(
+                "UPDATE content_cache SET last_accessed_at = datetime('now', ?) \
+                 WHERE article_id = ?",
+            )
+            .bind(format!("-{minutes} minutes"))
+            .bind(id)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        }
+        // Pinned entries are never evicted, however old
+        db.toggle_article_starred(ids[1]).await.unwrap();
+
+        let stored = db.cache_stats().await.unwrap().stored_size_bytes;
+        let entry = stored / 4;
+        assert_eq!(db.enforce_cache_budget(stored).await.unwrap(), 0);
+        assert_eq!(db.enforce_cache_budget(stored - entry).await.unwrap(), 1);
+        assert_eq!(
+            db.cached_article_ids(&ids).await.unwrap().len(),
+            3,
+            "one entry evicted"
+        );
+        assert!(db.get_cached_content(ids[0]).await.unwrap().is_none());
+        assert!(db.get_cached_content(ids[1]).await.unwrap().is_some());
+
+        // Reading ids[2] makes ids[3] the least recently read
+        db.get_cached_content(ids[2]).await.unwrap();
+        db.get_cached_content(ids[1]).await.unwrap();
+        assert_eq!(db.enforce_cache_budget(1).await.unwrap(), 2);
+        let mut left = db.cached_article_ids(&ids).await.unwrap();
+        left.sort();
+        assert_eq!(left, [ids[1]]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_and_usage_reports() {
+        let db = test_db().await;
+        let (feed_id, ids) = setup_articles(&db, 3).await;
+        for (id, body) in ids.iter().zip(["one", "two two", "three three three"]) {
+            db.cache_content(*id, body, &CachePolicy::default())
+                .await
+                .unwrap();
+        }
+        assert!(db.toggle_article_archived(ids[0]).await.unwrap());
+
+        let largest = db.largest_cache_entries(2).await.unwrap();
+        assert_eq!(largest.len(), 2);
+        assert_eq!(largest[0].article_id, ids[2]);
+        assert_eq!(largest[0].feed_title, "Cache Test Feed");
+        assert!(!largest[0].pinned);
+
+        let usage = db.cache_usage_by_feed().await.unwrap();
+        assert_eq!(usage.len(), 1);
+        assert_eq!((usage[0].feed_id, usage[0].entries), (feed_id, 3));
+        assert_eq!(usage[0].pinned_entries, 1);
+
+        assert_eq!(db.purge_cache(Some(feed_id + 1)).await.unwrap(), 0);
+        assert_eq!(db.purge_cache(Some(feed_id)).await.unwrap(), 2);
+        assert_eq!(db.purge_cache(None).await.unwrap(), 0);
+        assert_eq!(db.cached_article_ids(&ids).await.unwrap(), [ids[0]]);
+    }
+
     #[tokio::test]
     async fn test_prefetch_candidates() {
         let db = test_db().await;
@@ -356,7 +901,9 @@ mod tests {
         assert_eq!(candidates.len(), 3);
 
         // Cache one article — it should no longer be a candidate
-        db.cache_content(ids[0], "cached", None).await.unwrap();
+        db.cache_content(ids[0], "cached", &CachePolicy::default())
+            .await
+            .unwrap();
         let candidates = db.prefetch_candidates(10).await.unwrap();
         assert_eq!(candidates.len(), 2);
         assert!(!candidates.contains(&ids[0]));
@@ -382,8 +929,12 @@ mod tests {
         assert!(cached.is_empty());
 
         // Cache two articles
-        db.cache_content(ids[0], "content 0", None).await.unwrap();
-        db.cache_content(ids[2], "content 2", None).await.unwrap();
+        db.cache_content(ids[0], "content 0", &CachePolicy::default())
+            .await
+            .unwrap();
+        db.cache_content(ids[2], "content 2", &CachePolicy::default())
+            .await
+            .unwrap();
 
         let cached = db.cached_article_ids(&ids).await.unwrap();
         assert_eq!(cached.len(), 2);
@@ -401,7 +952,9 @@ mod tests {
         let db = test_db().await;
         let (_feed_id, ids) = setup_articles(&db, 1).await;
 
-        db.cache_content(ids[0], "cached", None).await.unwrap();
+        db.cache_content(ids[0], "cached", &CachePolicy::default())
+            .await
+            .unwrap();
         assert!(db.get_cached_content(ids[0]).await.unwrap().is_some());
 
         // Delete the feed (cascades to articles, which cascades to content_cache)
@@ -413,4 +966,58 @@ mod tests {
         let stats = db.cache_stats().await.unwrap();
         assert_eq!(stats.total_entries, 0);
     }
+
+    #[tokio::test]
+    async fn test_word_count_from_summary_then_content() {
+        let db = test_db().await;
+        let (feed_id, ids) = setup_articles(&db, 1).await;
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        assert_eq!(articles[0].word_count, Some(2));
+
+        db.cache_content(ids[0], "# Hello\n\none two three", &CachePolicy::default())
+            .await
+            .unwrap();
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        assert_eq!(articles[0].word_count, Some(4));
+
+        // A feed refresh doesn't replace the count of the fetched content
+        let mut updated = test_article("guid-0", "Article 0");
+        updated.summary = Some("A longer test summary".to_string());
+        db.upsert_articles(feed_id, &[updated]).await.unwrap();
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        assert_eq!(articles[0].word_count, Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_article_tldr_stored_with_cache() {
+        let db = test_db().await;
+        let (_feed_id, ids) = setup_articles(&db, 2).await;
+        let policy = CachePolicy::default();
+        let markdown: String = (1..=8)
+            .map(|i| format!("Sentence number {i} talks about the cache and its summary.\n\n"))
+            .collect();
+        db.cache_content(ids[0], &markdown, &policy).await.unwrap();
+
+        // Nothing stored until first asked for
+        assert!(db.cached_tldrs(&ids).await.unwrap().is_empty());
+        let tldr = db.article_tldr(ids[0], &markdown).await.unwrap();
+        assert_eq!(tldr.len(), 3);
+        // Read back rather than recomputed from the text passed in
+        assert_eq!(db.article_tldr(ids[0], "").await.unwrap(), tldr);
+        let stored = db.cached_tldrs(&ids).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[&ids[0]], tldr);
+
+        // Re-caching drops it; uncached articles aren't stored
+        db.cache_content(ids[0], "Short now.", &policy)
+            .await
+            .unwrap();
+        assert!(db
+            .article_tldr(ids[0], "Short now.")
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(db.article_tldr(ids[1], &markdown).await.unwrap().len(), 3);
+        assert!(db.cached_tldrs(&ids).await.unwrap().is_empty());
+    }
 }
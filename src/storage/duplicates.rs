@@ -0,0 +1,359 @@
+use anyhow::Result;
+use sqlx::SqliteConnection;
+
+use super::schema::Database;
+
+/// Title-hash matches only count within this window, so a recurring title
+/// ("This Week in Rust 512" vs. a year-old repost) isn't merged by accident.
+const TITLE_MATCH_WINDOW_SECS: i64 = 3 * 86_400;
+
+/// SQL condition that hides an `articles` row when another copy of the same
+/// story ranks higher: from a preferred-source feed, else first seen (lowest id).
+///
+/// Appended to merged-view queries (starred, What's New, search). The outer
+/// query must expose the row as `articles.id` / `articles.feed_id`, or via an
+/// alias substituted for `articles` with [`hide_duplicates_for`].
+pub(super) const HIDE_DUPLICATES: &str = r#"
+    NOT EXISTS (
+        SELECT 1
+        FROM article_duplicates d
+        JOIN article_duplicates d2 ON d2.group_id = d.group_id AND d2.article_id != d.article_id
+        JOIN articles a2 ON a2.id = d2.article_id
+        JOIN feeds f2 ON f2.id = a2.feed_id
+        JOIN feeds f1 ON f1.id = articles.feed_id
+        WHERE d.article_id = articles.id
+          AND (f2.prefer_source > f1.prefer_source
+               OR (f2.prefer_source = f1.prefer_source AND a2.id < articles.id))
+    )
+"#;
+
+/// [`HIDE_DUPLICATES`] for a query that aliases `articles` as `alias`.
+pub(super) fn hide_duplicates_for(alias: &str) -> String {
+    HIDE_DUPLICATES
+        .replace("articles.id", &format!("{}.id", alias))
+        .replace("articles.feed_id", &format!("{}.feed_id", alias))
+}
+
+/// Link articles inserted after `after_id` in `feed_id` to copies in other feeds.
+///
+/// Matches on canonical URL key, or on normalized title hash when the two
+/// publish dates are close. A new copy joins the existing group of its match,
+/// or starts one rooted at the match. Newly linked copies inherit the group's
+/// read and starred state. Returns the number of articles linked.
+pub(super) async fn link_new_duplicates(
+    conn: &mut SqliteConnection,
+    feed_id: i64,
+    after_id: i64,
+) -> sqlx::Result<u64> {
+    let linked = sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO article_duplicates (article_id, group_id)
+        SELECT n.id, MIN(COALESCE(d.group_id, o.id))
+        FROM articles n
+        JOIN articles o ON o.feed_id != n.feed_id AND o.id != n.id AND (
+            (n.canonical_key IS NOT NULL AND o.canonical_key = n.canonical_key)
+            OR (n.title_hash IS NOT NULL AND o.title_hash = n.title_hash
+                AND (n.published IS NULL OR o.published IS NULL
+                     OR ABS(n.published - o.published) <= ?))
+        )
+        LEFT JOIN article_duplicates d ON d.article_id = o.id
+        WHERE n.feed_id = ? AND n.id > ?
+        GROUP BY n.id
+    "#,
+    )
+    .bind(TITLE_MATCH_WINDOW_SECS)
+    .bind(feed_id)
+    .bind(after_id)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+
+    if linked == 0 {
+        return Ok(0);
+    }
+
+    // Matched copies that weren't in a group yet become its root
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO article_duplicates (article_id, group_id)
+        SELECT DISTINCT group_id, group_id FROM article_duplicates
+        WHERE group_id IN (SELECT id FROM articles)
+    "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    // Already read or starred elsewhere: the new copy is too
+    for column in ["read", "starred"] {
+        sqlx::query(&format!(
+            r#"
+            UPDATE articles SET {column} = 1
+            WHERE feed_id = ? AND id > ? AND {column} = 0
+              AND EXISTS (
+                SELECT 1 FROM article_duplicates d
+                JOIN article_duplicates d2 ON d2.group_id = d.group_id
+                JOIN articles a2 ON a2.id = d2.article_id
+                WHERE d.article_id = articles.id AND a2.{column} = 1
+              )
+        "#
+        ))
+        .bind(feed_id)
+        .bind(after_id)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(linked)
+}
+
+/// Set `column` (`read` or `starred`) on every other copy of the given articles.
+///
+/// `article_filter` selects the source articles and is bound to `param`.
+/// Returns the feed of each changed copy that isn't hidden, once per copy, so
+/// callers can adjust unread counts.
+pub(super) async fn propagate_to_duplicates(
+    conn: &mut SqliteConnection,
+    column: &str,
+    value: bool,
+    article_filter: &str,
+    param: i64,
+) -> sqlx::Result<Vec<i64>> {
+    let feeds: Vec<Option<i64>> = sqlx::query_scalar(&format!(
+        r#"
+        UPDATE articles SET {column} = ?
+        WHERE {column} != ? AND id IN (
+            SELECT d2.article_id FROM article_duplicates d
+            JOIN article_duplicates d2 ON d2.group_id = d.group_id
+            WHERE d.article_id IN (SELECT id FROM articles WHERE {article_filter})
+        )
+        RETURNING CASE WHEN hidden = 0 THEN feed_id END
+    "#
+    ))
+    .bind(value)
+    .bind(value)
+    .bind(param)
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(feeds.into_iter().flatten().collect())
+}
+
+impl Database {
+    // ========================================================================
+    // Duplicate Operations
+    // ========================================================================
+
+    /// Mark a feed as the preferred source for stories duplicated across feeds.
+    pub async fn set_feed_prefer_source(&self, feed_id: i64, prefer: bool) -> Result<()> {
+        sqlx::query("UPDATE feeds SET prefer_source = ? WHERE id = ?")
+            .bind(prefer)
+            .bind(feed_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Titles of the other feeds carrying a copy of this article.
+    pub async fn get_duplicate_sources(&self, article_id: i64) -> Result<Vec<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT f.title
+            FROM article_duplicates d
+            JOIN article_duplicates d2 ON d2.group_id = d.group_id AND d2.article_id != d.article_id
+            JOIN articles a ON a.id = d2.article_id
+            JOIN feeds f ON f.id = a.feed_id
+            WHERE d.article_id = ?
+            ORDER BY f.title COLLATE NOCASE
+        "#,
+        )
+        .bind(article_id)
+        .fetch_all(&self.pool)
+        .await?;
+        // SEC-001: Sanitize feed titles at the DB boundary
+        Ok(rows
+            .into_iter()
+            .map(|(t,)| crate::util::strip_control_chars(&t).into_owned())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rewrite::RewriteSet;
+    use crate::rules::RuleSet;
+    use crate::storage::{Database, OpmlFeed, ParsedArticle};
+
+    async fn db_with_feeds(n: i64) -> (Database, Vec<i64>) {
+        let db = Database::open(":memory:").await.unwrap();
+        let opml: Vec<OpmlFeed> = (1..=n)
+            .map(|i| OpmlFeed {
+                title: format!("Feed {}", i),
+                xml_url: format!("https://feed{}.example.com/rss", i),
+                html_url: None,
+            })
+            .collect();
+        db.sync_feeds(&opml).await.unwrap();
+        let ids = db
+            .get_feeds_with_unread_counts()
+            .await
+            .unwrap()
+            .iter()
+            .map(|f| f.id)
+            .collect();
+        (db, ids)
+    }
+
+    fn article(guid: &str, title: &str, url: &str) -> ParsedArticle {
+        ParsedArticle {
+            guid: guid.to_string(),
+            title: title.to_string(),
+            url: Some(url.to_string()),
+            published: Some(1704067200),
+            summary: None,
+            author: None,
+        }
+    }
+
+    async fn only_article(db: &Database, feed_id: i64) -> crate::storage::Article {
+        db.get_articles_for_feed(feed_id, None).await.unwrap()[0].clone()
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_linked_by_canonical_url() {
+        let (db, feeds) = db_with_feeds(2).await;
+        db.complete_feed_refresh(
+            feeds[0],
+            &[article("a", "Short", "https://blog.example.com/post")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        db.complete_feed_refresh(
+            feeds[1],
+            &[article(
+                "b",
+                "Different title",
+                "http://www.blog.example.com/post/?utm_source=planet",
+            )],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        let copy = only_article(&db, feeds[1]).await;
+        assert_eq!(
+            db.get_duplicate_sources(copy.id).await.unwrap(),
+            vec!["Feed 1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_and_star_propagate() {
+        let (db, feeds) = db_with_feeds(3).await;
+        let title = "An Interesting Story About Databases";
+        db.complete_feed_refresh(
+            feeds[0],
+            &[article("a", title, "https://a.example.com/1")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let original = only_article(&db, feeds[0]).await;
+        db.mark_article_read(original.id).await.unwrap();
+
+        // A copy arriving later inherits the read state (title match, different URL)
+        db.complete_feed_refresh(
+            feeds[1],
+            &[article("b", title, "https://b.example.com/2")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let copy = only_article(&db, feeds[1]).await;
+        assert!(copy.read);
+
+        // Starring one copy stars the other
+        assert!(db.toggle_article_starred(copy.id).await.unwrap());
+        assert!(only_article(&db, feeds[0]).await.starred);
+        assert!(!db.toggle_article_starred(original.id).await.unwrap());
+        assert!(!only_article(&db, feeds[1]).await.starred);
+
+        // Unrelated article in a third feed is untouched
+        db.complete_feed_refresh(
+            feeds[2],
+            &[article(
+                "c",
+                "Something else entirely, really",
+                "https://c.example.com/3",
+            )],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        assert!(!only_article(&db, feeds[2]).await.read);
+    }
+
+    #[tokio::test]
+    async fn test_mark_read_reports_feeds_of_copies() {
+        let (db, feeds) = db_with_feeds(2).await;
+        let title = "An Interesting Story About Databases";
+        for (i, &feed_id) in feeds.iter().enumerate() {
+            db.complete_feed_refresh(
+                feed_id,
+                &[article(&i.to_string(), title, "https://a.example.com/1")],
+                &RuleSet::default(),
+                &RewriteSet::default(),
+            )
+            .await
+            .unwrap();
+        }
+        let original = only_article(&db, feeds[0]).await;
+
+        // Both feeds lose an unread article, so both counts need updating
+        assert_eq!(db.mark_article_read(original.id).await.unwrap(), feeds);
+        assert!(db.mark_article_read(original.id).await.unwrap().is_empty());
+        let counts = db.get_feeds_with_unread_counts().await.unwrap();
+        assert!(counts.iter().all(|f| f.unread_count == 0));
+    }
+
+    #[tokio::test]
+    async fn test_merged_views_show_preferred_copy() {
+        let (db, feeds) = db_with_feeds(2).await;
+        for (i, &feed_id) in feeds.iter().enumerate() {
+            db.complete_feed_refresh(
+                feed_id,
+                &[article(
+                    &format!("g{}", i),
+                    "Same",
+                    "https://example.com/story",
+                )],
+                &RuleSet::default(),
+                &RewriteSet::default(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let recent = db.get_recent_articles_for_feeds(&feeds, 10).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].0, feeds[0]); // First seen wins by default
+
+        db.set_feed_prefer_source(feeds[1], true).await.unwrap();
+        let recent = db.get_recent_articles_for_feeds(&feeds, 10).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].0, feeds[1]);
+
+        // Per-feed lists still show every copy
+        assert_eq!(
+            db.get_articles_for_feed(feeds[0], None)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
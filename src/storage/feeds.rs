@@ -1,9 +1,17 @@
 use anyhow::Result;
 use sqlx::QueryBuilder;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+use super::articles::{upsert_chunk, UPSERT_BATCH_SIZE};
+use super::duplicates::link_new_duplicates;
+use super::rewrites::apply_entry_rewrites;
+use super::rules::apply_rules;
+use super::saved::SAVED_FEED_URL;
 use super::schema::Database;
-use super::types::{DatabaseError, Feed, FeedRow, OpmlFeed, ParsedArticle};
+use super::types::{DatabaseError, Feed, FeedRow, OpmlFeed, ParsedArticle, UpsertCounts};
+use crate::rewrite::RewriteSet;
+use crate::rules::RuleSet;
 use crate::util::strip_control_chars;
 
 impl Database {
@@ -21,6 +29,25 @@ impl Database {
         const BATCH_SIZE: usize = 100;
         let mut tx = self.pool.begin().await?;
 
+        // Old URLs of feeds moved by permanent redirects map onto the existing feed
+        let aliases: HashMap<String, i64> =
+            sqlx::query_as::<_, (String, i64)>("SELECT url, feed_id FROM feed_url_aliases")
+                .fetch_all(&mut *tx)
+                .await?
+                .into_iter()
+                .collect();
+        let (aliased, feeds): (Vec<&OpmlFeed>, Vec<&OpmlFeed>) =
+            feeds.iter().partition(|f| aliases.contains_key(&f.xml_url));
+
+        for feed in aliased {
+            sqlx::query("UPDATE feeds SET title = ?, html_url = ? WHERE id = ?")
+                .bind(&feed.title)
+                .bind(&feed.html_url)
+                .bind(aliases[&feed.xml_url])
+                .execute(&mut *tx)
+                .await?;
+        }
+
         for chunk in feeds.chunks(BATCH_SIZE) {
             let mut builder: QueryBuilder<sqlx::Sqlite> =
                 QueryBuilder::new("INSERT INTO feeds (title, url, html_url) ");
@@ -49,9 +76,15 @@ impl Database {
             r#"
                 SELECT
                     f.id, f.title, f.url, f.html_url, f.last_fetched, f.error,
-                    COUNT(CASE WHEN a.read = 0 THEN 1 END) as unread_count,
+                    COUNT(CASE WHEN a.read = 0 AND a.hidden = 0 THEN 1 END) as unread_count,
                     f.consecutive_failures,
-                    f.category_id
+                    f.category_id,
+                    f.gone,
+                    f.url_auto_updated,
+                    f.next_fetch_at,
+                    f.prefer_source,
+                    f.unread_on_update,
+                    f.auto_prefetch
                 FROM feeds f
                 LEFT JOIN articles a ON f.id = a.feed_id
                 GROUP BY f.id
@@ -75,6 +108,12 @@ impl Database {
                     unread_count,
                     consecutive_failures,
                     category_id,
+                    gone,
+                    url_auto_updated,
+                    next_fetch_at,
+                    prefer_source,
+                    unread_on_update,
+                    auto_prefetch,
                 )| Feed {
                     id,
                     title: Arc::from(strip_control_chars(&title)),
@@ -85,6 +124,12 @@ impl Database {
                     unread_count,
                     consecutive_failures,
                     category_id,
+                    gone,
+                    url_auto_updated,
+                    next_fetch_at,
+                    prefer_source,
+                    unread_on_update,
+                    auto_prefetch,
                 },
             )
             .collect();
@@ -97,6 +142,22 @@ impl Database {
     /// Returns the feed's database ID (either newly inserted or existing).
     /// Used by the subscribe dialog for single-feed subscription.
     pub async fn insert_feed(&self, url: &str, title: &str, html_url: Option<&str>) -> Result<i64> {
+        // Subscribing to a feed's pre-redirect URL resolves to the existing feed
+        let alias: Option<(i64,)> =
+            sqlx::query_as("SELECT feed_id FROM feed_url_aliases WHERE url = ?")
+                .bind(url)
+                .fetch_optional(&self.pool)
+                .await?;
+        if let Some((feed_id,)) = alias {
+            sqlx::query("UPDATE feeds SET title = ?, html_url = ? WHERE id = ?")
+                .bind(title)
+                .bind(html_url)
+                .bind(feed_id)
+                .execute(&self.pool)
+                .await?;
+            return Ok(feed_id);
+        }
+
         let row: (i64,) = sqlx::query_as(
             r#"
             INSERT INTO feeds (title, url, html_url)
@@ -201,7 +262,14 @@ impl Database {
         let mut tx = self.pool.begin().await?;
 
         // Step 1: Delete articles (the articles_fts_delete trigger handles FTS cleanup
-        // automatically for each deleted row via the external content delete protocol)
+        // automatically for each deleted row via the external content delete protocol),
+        // unlinking them from cross-feed duplicate groups first
+        sqlx::query(
+            "DELETE FROM article_duplicates WHERE article_id IN (SELECT id FROM articles WHERE feed_id = ?)",
+        )
+        .bind(feed_id)
+        .execute(&mut *tx)
+        .await?;
         sqlx::query("DELETE FROM articles WHERE feed_id = ?")
             .bind(feed_id)
             .execute(&mut *tx)
@@ -210,7 +278,17 @@ impl Database {
             .fetch_one(&mut *tx)
             .await?;
 
-        // Step 2: Delete the feed itself
+        // Step 2: Delete URL aliases left by permanent redirects, and fetch history
+        sqlx::query("DELETE FROM feed_url_aliases WHERE feed_id = ?")
+            .bind(feed_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM feed_fetch_log WHERE feed_id = ?")
+            .bind(feed_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // Step 3: Delete the feed itself
         sqlx::query("DELETE FROM feeds WHERE id = ?")
             .bind(feed_id)
             .execute(&mut *tx)
@@ -220,12 +298,63 @@ impl Database {
         Ok(articles_deleted as usize)
     }
 
+    /// Move a feed to a new URL after a permanent redirect (301/308).
+    ///
+    /// The previous URL is kept in `feed_url_aliases` so that re-syncing an OPML
+    /// file that still lists it updates this feed instead of adding a duplicate.
+    /// Fails if another feed already uses `new_url` (UNIQUE constraint).
+    pub async fn update_feed_url(&self, feed_id: i64, new_url: &str) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let (old_url,): (String,) = sqlx::query_as("SELECT url FROM feeds WHERE id = ?")
+            .bind(feed_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if old_url == new_url {
+            return Ok(());
+        }
+
+        sqlx::query("UPDATE feeds SET url = ?, url_auto_updated = 1 WHERE id = ?")
+            .bind(new_url)
+            .bind(feed_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("INSERT OR REPLACE INTO feed_url_aliases (url, feed_id) VALUES (?, ?)")
+            .bind(&old_url)
+            .bind(feed_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // The new URL is canonical now; drop it if it was an alias (A -> B -> A)
+        sqlx::query("DELETE FROM feed_url_aliases WHERE url = ?")
+            .bind(new_url)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Flag a feed as gone (HTTP 410). Cleared by the next successful refresh.
+    pub async fn set_feed_gone(&self, feed_id: i64) -> Result<()> {
+        sqlx::query("UPDATE feeds SET gone = 1 WHERE id = ?")
+            .bind(feed_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     /// Get all feeds formatted for OPML export, ordered alphabetically by title.
+    ///
+    /// The "Saved" pseudo-feed is left out: it has no feed URL to export.
     pub async fn get_feeds_for_export(&self) -> Result<Vec<OpmlFeed>> {
-        let rows: Vec<(String, String, Option<String>)> =
-            sqlx::query_as("SELECT title, url, html_url FROM feeds ORDER BY title COLLATE NOCASE")
-                .fetch_all(&self.pool)
-                .await?;
+        let rows: Vec<(String, String, Option<String>)> = sqlx::query_as(
+            "SELECT title, url, html_url FROM feeds WHERE url != ? ORDER BY title COLLATE NOCASE",
+        )
+        .bind(SAVED_FEED_URL)
+        .fetch_all(&self.pool)
+        .await?;
 
         Ok(rows
             .into_iter()
@@ -274,6 +403,53 @@ impl Database {
         Ok(())
     }
 
+    /// Defer bulk refreshes of a feed until the given Unix timestamp.
+    ///
+    /// Set when a server's `Retry-After` asks for a longer wait than the
+    /// fetcher is willing to sleep inline. Cleared by `complete_feed_refresh`.
+    pub async fn set_feed_retry_after(&self, feed_id: i64, until: i64) -> Result<()> {
+        sqlx::query("UPDATE feeds SET retry_after = ? WHERE id = ?")
+            .bind(until)
+            .bind(feed_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Get IDs of feeds whose server-requested retry time is still in the future.
+    pub async fn get_deferred_feed_ids(&self, now: i64) -> Result<HashSet<i64>> {
+        let rows: Vec<(i64,)> = sqlx::query_as("SELECT id FROM feeds WHERE retry_after > ?")
+            .bind(now)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+
+    /// Schedule the next bulk-refresh fetch of a feed (adaptive polling).
+    pub async fn set_feed_next_fetch(&self, feed_id: i64, next_fetch_at: i64) -> Result<()> {
+        sqlx::query("UPDATE feeds SET next_fetch_at = ? WHERE id = ?")
+            .bind(next_fetch_at)
+            .bind(feed_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Most recent article publish timestamps for a feed, newest first.
+    ///
+    /// Input to the adaptive polling cadence estimate.
+    pub async fn get_recent_published(&self, feed_id: i64, limit: u32) -> Result<Vec<i64>> {
+        let rows: Vec<(i64,)> = sqlx::query_as(
+            "SELECT published FROM articles WHERE feed_id = ? AND published IS NOT NULL \
+             ORDER BY published DESC LIMIT ?",
+        )
+        .bind(feed_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|(ts,)| ts).collect())
+    }
+
     /// Get feeds that haven't exceeded the failure threshold.
     ///
     /// Returns feeds with `consecutive_failures < CIRCUIT_BREAKER_THRESHOLD`.
@@ -284,9 +460,15 @@ impl Database {
             r#"
                 SELECT
                     f.id, f.title, f.url, f.html_url, f.last_fetched, f.error,
-                    COUNT(CASE WHEN a.read = 0 THEN 1 END) as unread_count,
+                    COUNT(CASE WHEN a.read = 0 AND a.hidden = 0 THEN 1 END) as unread_count,
                     f.consecutive_failures,
-                    f.category_id
+                    f.category_id,
+                    f.gone,
+                    f.url_auto_updated,
+                    f.next_fetch_at,
+                    f.prefer_source,
+                    f.unread_on_update,
+                    f.auto_prefetch
                 FROM feeds f
                 LEFT JOIN articles a ON f.id = a.feed_id
                 WHERE f.consecutive_failures < ?
@@ -312,6 +494,12 @@ impl Database {
                     unread_count,
                     consecutive_failures,
                     category_id,
+                    gone,
+                    url_auto_updated,
+                    next_fetch_at,
+                    prefer_source,
+                    unread_on_update,
+                    auto_prefetch,
                 )| Feed {
                     id,
                     title: Arc::from(strip_control_chars(&title)),
@@ -322,6 +510,12 @@ impl Database {
                     unread_count,
                     consecutive_failures,
                     category_id,
+                    gone,
+                    url_auto_updated,
+                    next_fetch_at,
+                    prefer_source,
+                    unread_on_update,
+                    auto_prefetch,
                 },
             )
             .collect();
@@ -339,109 +533,55 @@ impl Database {
     ///
     /// * `feed_id` - The database ID of the feed being refreshed
     /// * `articles` - Parsed articles to upsert
+    /// * `rules` - Ingest rules evaluated against newly inserted articles
+    /// * `rewrites` - Title and summary rewrites stored alongside the entries
     ///
     /// # Returns
     ///
-    /// The number of newly inserted articles (not updated).
+    /// How many articles were inserted, updated (publisher edits) and left unchanged.
     ///
     /// # PERF-012
     ///
-    /// Uses two-phase insert (INSERT OR IGNORE + UPDATE) with `changes()` instead of
-    /// before/after COUNT queries. This eliminates 2 table scans per feed refresh.
+    /// Articles are written by [`upsert_chunk`]: a two-phase insert (INSERT OR IGNORE
+    /// + UPDATE) that counts with `changes()` and skips entries whose stored hash matches.
     pub async fn complete_feed_refresh(
         &self,
         feed_id: i64,
         articles: &[ParsedArticle],
-    ) -> Result<usize, DatabaseError> {
+        rules: &RuleSet,
+        rewrites: &RewriteSet,
+    ) -> Result<UpsertCounts, DatabaseError> {
         let now = chrono::Utc::now().timestamp();
         let mut tx = self.pool.begin().await?;
 
-        // Clear any previous error and reset circuit breaker
-        sqlx::query("UPDATE feeds SET error = NULL, consecutive_failures = 0 WHERE id = ?")
+        // Clear any previous error, reset circuit breaker, drop any Retry-After deferral
+        // and the gone flag
+        sqlx::query(
+            "UPDATE feeds SET error = NULL, consecutive_failures = 0, retry_after = NULL, gone = 0 \
+             WHERE id = ?",
+        )
             .bind(feed_id)
             .execute(&mut *tx)
             .await?;
 
-        // PERF-012: Two-phase insert to accurately count new articles using changes()
-        // Phase 1: INSERT OR IGNORE to insert only new articles, track count via changes()
-        // Phase 2: UPDATE to refresh metadata for all articles (new and existing)
-        const BATCH_SIZE: usize = 50;
-        let mut total_inserted: usize = 0;
-
-        for chunk in articles.chunks(BATCH_SIZE) {
-            // Phase 1: Insert new articles only (INSERT OR IGNORE)
-            let mut insert_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-                "INSERT OR IGNORE INTO articles (feed_id, guid, title, url, published, summary, fetched_at) ",
-            );
-
-            insert_builder.push_values(chunk, |mut b, article| {
-                b.push_bind(feed_id)
-                    .push_bind(&article.guid)
-                    .push_bind(&article.title)
-                    .push_bind(&article.url)
-                    .push_bind(article.published)
-                    .push_bind(&article.summary)
-                    .push_bind(now);
-            });
-
-            insert_builder.build().execute(&mut *tx).await?;
-
-            // PERF-012: Use changes() to count inserted rows (no table scan)
-            let changes: (i64,) = sqlx::query_as("SELECT changes()")
-                .fetch_one(&mut *tx)
-                .await?;
-            total_inserted += changes.0 as usize;
-
-            // Phase 2: Update metadata for existing articles (preserves user state)
-            let mut update_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-                "UPDATE articles SET \
-                 title = CASE guid ",
-            );
-
-            // Build CASE expressions for each field
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(&article.title);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE title END, url = CASE guid ");
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(&article.url);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE url END, published = CASE guid ");
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(article.published);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE published END, summary = CASE guid ");
-            for article in chunk {
-                update_builder.push("WHEN ");
-                update_builder.push_bind(&article.guid);
-                update_builder.push(" THEN ");
-                update_builder.push_bind(&article.summary);
-                update_builder.push(" ");
-            }
-            update_builder.push("ELSE summary END WHERE feed_id = ");
-            update_builder.push_bind(feed_id);
-            update_builder.push(" AND guid IN (");
+        // Articles with a higher id than this are new; used to link cross-feed duplicates
+        let (last_id_before,): (i64,) = sqlx::query_as("SELECT COALESCE(MAX(id), 0) FROM articles")
+            .fetch_one(&mut *tx)
+            .await?;
 
-            let mut separated = update_builder.separated(", ");
-            for article in chunk {
-                separated.push_bind(&article.guid);
-            }
-            separated.push_unseparated(")");
+        // Only new and changed entries are written; unchanged ones skip the FTS update
+        let mut counts = UpsertCounts::default();
+        for chunk in articles.chunks(UPSERT_BATCH_SIZE) {
+            counts += upsert_chunk(&mut tx, feed_id, chunk, now).await?;
+        }
 
-            update_builder.build().execute(&mut *tx).await?;
+        // Link new articles to copies of the same story in other feeds
+        if counts.inserted > 0 {
+            link_new_duplicates(&mut tx, feed_id, last_id_before).await?;
+            // Evaluate ingest rules against the new articles only
+            apply_rules(&mut tx, rules, Some(feed_id), last_id_before, now, true).await?;
         }
+        apply_entry_rewrites(&mut tx, rewrites, feed_id, articles).await?;
 
         // Update fetched timestamp
         sqlx::query("UPDATE feeds SET last_fetched = ? WHERE id = ?")
@@ -455,13 +595,15 @@ impl Database {
         // SQLite guarantees atomicity: if commit succeeds, both articles and FTS are consistent.
         tx.commit().await?;
 
-        Ok(total_inserted)
+        Ok(counts)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::storage::{Database, OpmlFeed, ParsedArticle};
+    use crate::rewrite::RewriteSet;
+    use crate::rules::RuleSet;
+    use crate::storage::{Database, OpmlFeed, ParsedArticle, UpsertCounts};
 
     async fn test_db() -> Database {
         Database::open(":memory:").await.unwrap()
@@ -482,6 +624,7 @@ mod tests {
             url: Some(format!("https://example.com/{}", guid)),
             published: Some(1704067200),
             summary: Some("Test summary".to_string()),
+            author: None,
         }
     }
 
@@ -640,8 +783,16 @@ mod tests {
             test_article("guid-1", "Article 1"),
             test_article("guid-2", "Article 2"),
         ];
-        let count = db.complete_feed_refresh(feed_id, &articles).await.unwrap();
-        assert_eq!(count, 2);
+        let count = db
+            .complete_feed_refresh(
+                feed_id,
+                &articles,
+                &RuleSet::default(),
+                &RewriteSet::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(count.inserted, 2);
 
         let feeds = db.get_feeds_with_unread_counts().await.unwrap();
         assert!(feeds[0].error.is_none(), "Error should be cleared");
@@ -662,8 +813,11 @@ mod tests {
         let feeds = db.get_feeds_with_unread_counts().await.unwrap();
         let feed_id = feeds[0].id;
 
-        let count = db.complete_feed_refresh(feed_id, &[]).await.unwrap();
-        assert_eq!(count, 0);
+        let count = db
+            .complete_feed_refresh(feed_id, &[], &RuleSet::default(), &RewriteSet::default())
+            .await
+            .unwrap();
+        assert_eq!(count, UpsertCounts::default());
 
         let feeds = db.get_feeds_with_unread_counts().await.unwrap();
         assert!(
@@ -680,10 +834,15 @@ mod tests {
         let feed_id = feeds[0].id;
 
         let count = db
-            .complete_feed_refresh(feed_id, &[test_article("existing", "Original")])
+            .complete_feed_refresh(
+                feed_id,
+                &[test_article("existing", "Original")],
+                &RuleSet::default(),
+                &RewriteSet::default(),
+            )
             .await
             .unwrap();
-        assert_eq!(count, 1);
+        assert_eq!(count.inserted, 1);
 
         let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
         db.mark_article_read(articles[0].id).await.unwrap();
@@ -692,8 +851,23 @@ mod tests {
             test_article("existing", "Updated Title"),
             test_article("new-1", "New Article"),
         ];
-        let count = db.complete_feed_refresh(feed_id, &articles).await.unwrap();
-        assert_eq!(count, 1);
+        let count = db
+            .complete_feed_refresh(
+                feed_id,
+                &articles,
+                &RuleSet::default(),
+                &RewriteSet::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            count,
+            UpsertCounts {
+                inserted: 1,
+                updated: 1,
+                unchanged: 0
+            }
+        );
 
         let stored = db.get_articles_for_feed(feed_id, None).await.unwrap();
         let existing = stored.iter().find(|a| a.guid == "existing").unwrap();
@@ -819,9 +993,14 @@ mod tests {
             db.increment_feed_failures(feed_id).await.unwrap();
         }
 
-        db.complete_feed_refresh(feed_id, &[test_article("1", "Test")])
-            .await
-            .unwrap();
+        db.complete_feed_refresh(
+            feed_id,
+            &[test_article("1", "Test")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
 
         let feeds = db.get_feeds_with_unread_counts().await.unwrap();
         assert_eq!(
@@ -859,12 +1038,19 @@ mod tests {
                 test_article("a2", "Article 2"),
                 test_article("a3", "Article 3"),
             ],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        db.complete_feed_refresh(
+            feed2_id,
+            &[test_article("b1", "Article B1")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
         )
         .await
         .unwrap();
-        db.complete_feed_refresh(feed2_id, &[test_article("b1", "Article B1")])
-            .await
-            .unwrap();
 
         // Delete feed 1
         let deleted = db.delete_feed(feed1_id).await.unwrap();
@@ -901,6 +1087,8 @@ mod tests {
         db.complete_feed_refresh(
             feed_id,
             &[test_article("fts-1", "Unique Searchable Content")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
         )
         .await
         .unwrap();
@@ -957,4 +1145,110 @@ mod tests {
         // Renaming a nonexistent feed succeeds (no rows affected, no error)
         db.rename_feed(99999, "New Title").await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_next_fetch_and_recent_published() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1)]).await.unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+
+        let mut older = test_article("old", "Old");
+        older.published = Some(1_000);
+        let mut undated = test_article("undated", "Undated");
+        undated.published = None;
+        db.complete_feed_refresh(
+            feed_id,
+            &[test_article("new", "New"), older, undated],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            db.get_recent_published(feed_id, 10).await.unwrap(),
+            vec![1704067200, 1_000]
+        );
+
+        assert_eq!(
+            db.get_feeds_with_unread_counts().await.unwrap()[0].next_fetch_at,
+            None
+        );
+        db.set_feed_next_fetch(feed_id, 5_000).await.unwrap();
+        assert_eq!(
+            db.get_feeds_with_unread_counts().await.unwrap()[0].next_fetch_at,
+            Some(5_000)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_deferral_cleared_on_success() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1), test_feed(2)]).await.unwrap();
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        let (deferred, other) = (feeds[0].id, feeds[1].id);
+
+        db.set_feed_retry_after(deferred, 2_000).await.unwrap();
+        let ids = db.get_deferred_feed_ids(1_000).await.unwrap();
+        assert!(ids.contains(&deferred));
+        assert!(!ids.contains(&other));
+
+        // Deferral has elapsed
+        assert!(db.get_deferred_feed_ids(2_000).await.unwrap().is_empty());
+
+        // A successful refresh clears the deferral
+        db.complete_feed_refresh(
+            deferred,
+            &[test_article("a", "A")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        assert!(db.get_deferred_feed_ids(1_000).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_feed_url_keeps_alias_for_opml_resync() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1)]).await.unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+
+        db.update_feed_url(feed_id, "https://moved.example.com/rss")
+            .await
+            .unwrap();
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        assert_eq!(feeds[0].url, "https://moved.example.com/rss");
+        assert!(feeds[0].url_auto_updated);
+
+        // Re-syncing an OPML that still lists the old URL must not duplicate the feed
+        let mut renamed = test_feed(1);
+        renamed.title = "Renamed".to_string();
+        db.sync_feeds(&[renamed]).await.unwrap();
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        assert_eq!(feeds.len(), 1);
+        assert_eq!(&*feeds[0].title, "Renamed");
+
+        // Subscribing to the old URL resolves to the same feed
+        let id = db
+            .insert_feed(&test_feed(1).xml_url, "Again", None)
+            .await
+            .unwrap();
+        assert_eq!(id, feed_id);
+        assert_eq!(db.get_feeds_with_unread_counts().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gone_flag_cleared_on_success() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1)]).await.unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+
+        db.set_feed_gone(feed_id).await.unwrap();
+        assert!(db.get_feeds_with_unread_counts().await.unwrap()[0].gone);
+
+        db.complete_feed_refresh(feed_id, &[], &RuleSet::default(), &RewriteSet::default())
+            .await
+            .unwrap();
+        assert!(!db.get_feeds_with_unread_counts().await.unwrap()[0].gone);
+    }
 }
@@ -0,0 +1,259 @@
+use anyhow::Result;
+
+use super::saved::SAVED_FEED_URL;
+use super::schema::Database;
+use super::types::{FeedHealth, FetchLogEntry};
+use crate::util::strip_control_chars;
+
+/// Window for the posting-frequency estimate in the feed health view.
+const POSTING_WINDOW_DAYS: i64 = 90;
+
+impl Database {
+    // ========================================================================
+    // Fetch Log Operations
+    // ========================================================================
+
+    /// Append one fetch attempt to `feed_fetch_log`.
+    pub async fn record_fetch(&self, entry: &FetchLogEntry) -> Result<()> {
+        let now = chrono::Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            INSERT INTO feed_fetch_log
+                (feed_id, fetched_at, status, http_status, bytes, latency_ms, new_articles, error)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        )
+        .bind(entry.feed_id)
+        .bind(now)
+        .bind(entry.outcome.as_str())
+        .bind(entry.http_status.map(i64::from))
+        .bind(entry.bytes.map(|b| b as i64))
+        .bind(entry.latency_ms as i64)
+        .bind(entry.new_articles as i64)
+        .bind(entry.error.as_deref())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Apply the fetch log retention policy.
+    ///
+    /// Deletes rows older than `retention_days`, then trims each feed to its
+    /// `max_per_feed` most recent rows so hourly polling can't bloat the table.
+    /// Returns the number of rows deleted.
+    pub async fn prune_fetch_log(&self, retention_days: u32, max_per_feed: u32) -> Result<u64> {
+        let cutoff = chrono::Utc::now().timestamp() - i64::from(retention_days) * 86_400;
+        let mut tx = self.pool.begin().await?;
+
+        let by_age = sqlx::query("DELETE FROM feed_fetch_log WHERE fetched_at < ?")
+            .bind(cutoff)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        let by_count = sqlx::query(
+            r#"
+            DELETE FROM feed_fetch_log WHERE id IN (
+                SELECT id FROM (
+                    SELECT id, ROW_NUMBER() OVER (
+                        PARTITION BY feed_id ORDER BY fetched_at DESC, id DESC
+                    ) AS rn
+                    FROM feed_fetch_log
+                ) WHERE rn > ?
+            )
+        "#,
+        )
+        .bind(max_per_feed)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        tx.commit().await?;
+        Ok(by_age + by_count)
+    }
+
+    /// Summarize fetch reliability and posting activity for every feed.
+    ///
+    /// Ordered by title; the health view groups rows into failing/slow/stale.
+    pub async fn get_feed_health(&self) -> Result<Vec<FeedHealth>> {
+        let window_start = chrono::Utc::now().timestamp() - POSTING_WINDOW_DAYS * 86_400;
+
+        #[allow(clippy::type_complexity)]
+        let rows: Vec<(
+            i64,
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            i64,
+            bool,
+            i64,
+            i64,
+            Option<i64>,
+            Option<i64>,
+            i64,
+        )> = sqlx::query_as(
+            r#"
+            SELECT
+                f.id, f.title, f.url, f.html_url, f.error, f.consecutive_failures, f.gone,
+                COALESCE(l.attempts, 0),
+                COALESCE(l.failures, 0),
+                l.avg_latency,
+                (SELECT MAX(a.published) FROM articles a WHERE a.feed_id = f.id),
+                (SELECT COUNT(*) FROM articles a WHERE a.feed_id = f.id AND a.published > ?)
+            FROM feeds f
+            LEFT JOIN (
+                SELECT feed_id,
+                       COUNT(*) AS attempts,
+                       SUM(CASE WHEN status IN ('error', 'gone') THEN 1 ELSE 0 END) AS failures,
+                       CAST(AVG(CASE WHEN status = 'ok' THEN latency_ms END) AS INTEGER) AS avg_latency
+                FROM feed_fetch_log
+                GROUP BY feed_id
+            ) l ON l.feed_id = f.id
+            WHERE f.url != ?
+            ORDER BY f.title COLLATE NOCASE
+        "#,
+        )
+        .bind(window_start)
+        .bind(SAVED_FEED_URL)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // SEC-001: Sanitize feed strings — these bypass the normal Feed loading path
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    feed_id,
+                    title,
+                    url,
+                    html_url,
+                    error,
+                    consecutive_failures,
+                    gone,
+                    fetch_attempts,
+                    fetch_failures,
+                    avg_latency_ms,
+                    last_published,
+                    recent_posts,
+                )| FeedHealth {
+                    feed_id,
+                    title: strip_control_chars(&title).into_owned(),
+                    url: strip_control_chars(&url).into_owned(),
+                    html_url: html_url.map(|h| strip_control_chars(&h).into_owned()),
+                    error: error.map(|e| strip_control_chars(&e).into_owned()),
+                    consecutive_failures,
+                    gone,
+                    fetch_attempts,
+                    fetch_failures,
+                    avg_latency_ms,
+                    last_published,
+                    posts_per_week: recent_posts as f64 * 7.0 / POSTING_WINDOW_DAYS as f64,
+                },
+            )
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rewrite::RewriteSet;
+    use crate::rules::RuleSet;
+    use crate::storage::{Database, FetchLogEntry, FetchOutcome, OpmlFeed, ParsedArticle};
+
+    async fn test_db_with_feed() -> (Database, i64) {
+        let db = Database::open(":memory:").await.unwrap();
+        db.sync_feeds(&[OpmlFeed {
+            title: "Feed".into(),
+            xml_url: "https://example.com/rss".into(),
+            html_url: None,
+        }])
+        .await
+        .unwrap();
+        let id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+        (db, id)
+    }
+
+    fn entry(feed_id: i64, outcome: FetchOutcome, latency_ms: u64) -> FetchLogEntry {
+        FetchLogEntry {
+            feed_id,
+            outcome,
+            http_status: Some(200),
+            bytes: Some(1024),
+            latency_ms,
+            new_articles: 0,
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_feed_health_aggregates_log() {
+        let (db, feed_id) = test_db_with_feed().await;
+        db.record_fetch(&entry(feed_id, FetchOutcome::Ok, 100))
+            .await
+            .unwrap();
+        db.record_fetch(&entry(feed_id, FetchOutcome::Ok, 300))
+            .await
+            .unwrap();
+        db.record_fetch(&entry(feed_id, FetchOutcome::Error, 5000))
+            .await
+            .unwrap();
+
+        let now = chrono::Utc::now().timestamp();
+        db.complete_feed_refresh(
+            feed_id,
+            &[ParsedArticle {
+                guid: "1".into(),
+                title: "Recent".into(),
+                url: None,
+                published: Some(now - 86_400),
+                summary: None,
+                author: None,
+            }],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        let health = db.get_feed_health().await.unwrap();
+        assert_eq!(health.len(), 1);
+        let h = &health[0];
+        assert_eq!(h.fetch_attempts, 3);
+        assert_eq!(h.fetch_failures, 1);
+        // Failed attempts don't skew latency
+        assert_eq!(h.avg_latency_ms, Some(200));
+        assert_eq!(h.last_published, Some(now - 86_400));
+        assert!(h.posts_per_week > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_feed_health_without_history() {
+        let (db, _) = test_db_with_feed().await;
+        let health = db.get_feed_health().await.unwrap();
+        assert_eq!(health[0].fetch_attempts, 0);
+        assert_eq!(health[0].avg_latency_ms, None);
+        assert_eq!(health[0].last_published, None);
+    }
+
+    #[tokio::test]
+    async fn test_prune_fetch_log_caps_rows_per_feed() {
+        let (db, feed_id) = test_db_with_feed().await;
+        for _ in 0..5 {
+            db.record_fetch(&entry(feed_id, FetchOutcome::Ok, 10))
+                .await
+                .unwrap();
+        }
+
+        let deleted = db.prune_fetch_log(30, 3).await.unwrap();
+        assert_eq!(deleted, 2);
+        assert_eq!(db.get_feed_health().await.unwrap()[0].fetch_attempts, 3);
+
+        // Zero-day retention removes everything older than now
+        sqlx::query("UPDATE feed_fetch_log SET fetched_at = fetched_at - 10")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(db.prune_fetch_log(0, 3).await.unwrap(), 3);
+    }
+}
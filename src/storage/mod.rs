@@ -1,19 +1,35 @@
 mod articles;
 mod categories;
 mod content_cache;
+mod duplicates;
 mod feeds;
+mod fetch_log;
 mod preferences;
+mod prefetch;
+mod read_later;
 mod reading_history;
+mod revisions;
+mod rewrites;
+mod rules;
+mod saved;
 mod schema;
 mod search;
+mod tags;
 mod types;
 
+pub use content_cache::CachePolicy;
+pub use saved::SAVED_FEED_URL;
 pub use schema::Database;
+pub use tags::{ARCHIVED_TAG, STARRED_TAG};
+#[allow(unused_imports)] // Returned by Database::get_rule_hits
+pub use types::RuleHits;
 #[allow(unused_imports)]
 // FeedCategory, ReadingHistoryEntry, ReadingStats consumed by downstream tasks (TASK-5, TASK-8)
 pub use types::{
     Article, DatabaseError, Feed, FeedCategory, OpmlFeed, ParsedArticle, ReadingHistoryEntry,
-    ReadingStats, SearchScope,
+    ReadingStats, SearchScope, TagCount, TaggedArticle, UpsertCounts,
 };
+pub use types::{ArticleRevision, RevisionKind};
 #[allow(unused_imports)] // CachedContent/CacheStats consumed by TASK-4, TASK-7
-pub use types::{CacheStats, CachedContent};
+pub use types::{CacheEntry, CacheStats, CachedContent, FeedCacheUsage};
+pub use types::{FeedHealth, FetchLogEntry, FetchOutcome};
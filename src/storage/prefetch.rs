@@ -0,0 +1,287 @@
+use anyhow::Result;
+use sqlx::QueryBuilder;
+
+use super::schema::Database;
+
+/// Automatic prefetch attempts per article before it is given up on.
+const MAX_PREFETCH_ATTEMPTS: i64 = 5;
+
+/// Wait before the first retry; doubles with every further failure.
+const RETRY_BASE_SECS: i64 = 3600;
+
+impl Database {
+    // ========================================================================
+    // Automatic Prefetch
+    // ========================================================================
+
+    /// Enable or disable prefetching a feed's new articles after each refresh.
+    pub async fn set_feed_auto_prefetch(&self, feed_id: i64, enabled: bool) -> Result<()> {
+        sqlx::query("UPDATE feeds SET auto_prefetch = ? WHERE id = ?")
+            .bind(enabled)
+            .bind(feed_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Unread, uncached articles to prefetch after a refresh, newest first,
+    /// as `(article_id, url)`.
+    ///
+    /// Covers feeds with `auto_prefetch` set and feeds in any of `categories`
+    /// (names, case-insensitive) or their subcategories. Articles whose last
+    /// attempt failed are left out until their backoff has elapsed, and for
+    /// good after `MAX_PREFETCH_ATTEMPTS`.
+    pub async fn auto_prefetch_candidates(
+        &self,
+        categories: &[String],
+        limit: i64,
+    ) -> Result<Vec<(i64, String)>> {
+        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+            "WITH RECURSIVE chosen(id) AS (SELECT id FROM feed_categories WHERE ",
+        );
+        if categories.is_empty() {
+            builder.push("0");
+        } else {
+            builder.push("LOWER(name) IN (");
+            let mut names = builder.separated(", ");
+            for name in categories {
+                names.push_bind(name.to_lowercase());
+            }
+            builder.push(")");
+        }
+        builder.push(
+            r#"
+                UNION
+                SELECT fc.id FROM feed_categories fc JOIN chosen ON fc.parent_id = chosen.id
+            )
+            SELECT a.id, a.url
+            FROM articles a
+            JOIN feeds f ON f.id = a.feed_id
+            LEFT JOIN content_cache cc ON cc.article_id = a.id
+            LEFT JOIN prefetch_failures pf ON pf.article_id = a.id
+            WHERE a.read = 0 AND a.hidden = 0 AND a.url IS NOT NULL AND cc.article_id IS NULL
+              AND (f.auto_prefetch = 1 OR f.category_id IN (SELECT id FROM chosen))
+              AND (pf.article_id IS NULL OR (pf.attempts < "#,
+        );
+        builder.push_bind(MAX_PREFETCH_ATTEMPTS);
+        builder.push(" AND pf.next_attempt_at <= ");
+        builder.push_bind(chrono::Utc::now().timestamp());
+        builder.push(")) ORDER BY a.published DESC LIMIT ");
+        builder.push_bind(limit);
+
+        let rows: Vec<(i64, String)> = builder.build_query_as().fetch_all(&self.pool).await?;
+        Ok(rows)
+    }
+
+    /// Whether an article's earlier prefetch failures allow another attempt
+    /// now: true when none are recorded or the backoff has elapsed, false
+    /// after `MAX_PREFETCH_ATTEMPTS`.
+    pub async fn prefetch_allowed(&self, article_id: i64) -> Result<bool> {
+        let blocked: Option<i64> = sqlx::query_scalar(
+            "SELECT 1 FROM prefetch_failures WHERE article_id = ? AND (attempts >= ? OR next_attempt_at > ?)",
+        )
+        .bind(article_id)
+        .bind(MAX_PREFETCH_ATTEMPTS)
+        .bind(chrono::Utc::now().timestamp())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(blocked.is_none())
+    }
+
+    /// Record a failed automatic prefetch and schedule the next attempt:
+    /// one hour after the first failure, doubling after each further one.
+    pub async fn record_prefetch_failure(&self, article_id: i64, error: &str) -> Result<()> {
+        let now = chrono::Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            INSERT INTO prefetch_failures (article_id, attempts, next_attempt_at, last_error)
+            VALUES (?1, 1, ?2 + ?3, ?4)
+            ON CONFLICT(article_id) DO UPDATE SET
+                attempts = attempts + 1,
+                next_attempt_at = ?2 + (?3 << MIN(attempts, 10)),
+                last_error = excluded.last_error
+        "#,
+        )
+        .bind(article_id)
+        .bind(now)
+        .bind(RETRY_BASE_SECS)
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Forget earlier prefetch failures of an article once it has been cached.
+    pub async fn clear_prefetch_failure(&self, article_id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM prefetch_failures WHERE article_id = ?")
+            .bind(article_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_PREFETCH_ATTEMPTS;
+    use crate::rewrite::RewriteSet;
+    use crate::rules::RuleSet;
+    use crate::storage::{CachePolicy, Database, OpmlFeed, ParsedArticle};
+
+    /// Two feeds with two articles each; returns (db, feed ids, article ids per feed).
+    async fn setup() -> (Database, Vec<i64>, Vec<Vec<i64>>) {
+        let db = Database::open(":memory:").await.unwrap();
+        let feeds: Vec<OpmlFeed> = (0..2)
+            .map(|i| OpmlFeed {
+                title: format!("Feed {}", i),
+                xml_url: format!("https://example.com/{}.xml", i),
+                html_url: None,
+            })
+            .collect();
+        db.sync_feeds(&feeds).await.unwrap();
+        let feed_ids: Vec<i64> = db
+            .get_feeds_with_unread_counts()
+            .await
+            .unwrap()
+            .iter()
+            .map(|f| f.id)
+            .collect();
+        let mut article_ids = Vec::new();
+        for feed_id in &feed_ids {
+            let articles: Vec<ParsedArticle> = (0..2)
+                .map(|i| ParsedArticle {
+                    guid: format!("{}-{}", feed_id, i),
+                    title: format!("Article {}", i),
+                    url: Some(format!("https://example.com/{}/{}", feed_id, i)),
+                    published: Some(1704067200 + i),
+                    summary: None,
+                    author: None,
+                })
+                .collect();
+            db.complete_feed_refresh(
+                *feed_id,
+                &articles,
+                &RuleSet::default(),
+                &RewriteSet::default(),
+            )
+            .await
+            .unwrap();
+            let mut ids: Vec<i64> = db
+                .get_articles_for_feed(*feed_id, None)
+                .await
+                .unwrap()
+                .iter()
+                .map(|a| a.id)
+                .collect();
+            ids.sort();
+            article_ids.push(ids);
+        }
+        (db, feed_ids, article_ids)
+    }
+
+    fn ids(candidates: &[(i64, String)]) -> Vec<i64> {
+        let mut ids: Vec<i64> = candidates.iter().map(|(id, _)| *id).collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_candidates_by_feed_flag_and_category() {
+        let (db, feeds, articles) = setup().await;
+        assert!(db
+            .auto_prefetch_candidates(&[], 10)
+            .await
+            .unwrap()
+            .is_empty());
+
+        db.set_feed_auto_prefetch(feeds[0], true).await.unwrap();
+        assert!(db.get_feeds_with_unread_counts().await.unwrap()[0].auto_prefetch);
+        let candidates = db.auto_prefetch_candidates(&[], 10).await.unwrap();
+        assert_eq!(ids(&candidates), articles[0]);
+        // Newest first, with URLs
+        assert_eq!(candidates[0].0, articles[0][1]);
+        assert!(candidates[0].1.starts_with("https://example.com/"));
+        assert_eq!(db.auto_prefetch_candidates(&[], 1).await.unwrap().len(), 1);
+
+        // Feeds in a subcategory of a listed category count too
+        let parent = db.create_category("Tech", None).await.unwrap();
+        let child = db.create_category("Rust", Some(parent)).await.unwrap();
+        db.move_feed_to_category(feeds[1], Some(child))
+            .await
+            .unwrap();
+        let all = db
+            .auto_prefetch_candidates(&["tech".to_string()], 10)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 4);
+
+        // Cached and read articles are skipped
+        db.cache_content(articles[1][0], "# Body", &CachePolicy::default())
+            .await
+            .unwrap();
+        db.mark_article_read(articles[1][1]).await.unwrap();
+        let rest = db
+            .auto_prefetch_candidates(&["Tech".to_string()], 10)
+            .await
+            .unwrap();
+        assert_eq!(ids(&rest), articles[0]);
+    }
+
+    #[tokio::test]
+    async fn test_failures_back_off_then_give_up() {
+        let (db, feeds, articles) = setup().await;
+        db.set_feed_auto_prefetch(feeds[0], true).await.unwrap();
+        let failing = articles[0][0];
+
+        db.record_prefetch_failure(failing, "timed out")
+            .await
+            .unwrap();
+        let candidates = db.auto_prefetch_candidates(&[], 10).await.unwrap();
+        assert_eq!(ids(&candidates), vec![articles[0][1]]);
+        assert!(!db.prefetch_allowed(failing).await.unwrap());
+        assert!(db.prefetch_allowed(articles[0][1]).await.unwrap());
+
+        let (attempts, wait): (i64, i64) = sqlx::query_as(
+            "SELECT attempts, next_attempt_at - strftime('%s', 'now') FROM prefetch_failures",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(attempts, 1);
+        assert!((3590..=3600).contains(&wait), "{}", wait);
+
+        // Backoff elapsed: retried
+        sqlx::query("UPDATE prefetch_failures SET next_attempt_at = 0")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(db.auto_prefetch_candidates(&[], 10).await.unwrap().len(), 2);
+        assert!(db.prefetch_allowed(failing).await.unwrap());
+
+        // The wait doubles with each failure
+        db.record_prefetch_failure(failing, "timed out")
+            .await
+            .unwrap();
+        let (attempts, wait): (i64, i64) = sqlx::query_as(
+            "SELECT attempts, next_attempt_at - strftime('%s', 'now') FROM prefetch_failures",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!(attempts, 2);
+        assert!((7190..=7200).contains(&wait), "{}", wait);
+
+        // After the last attempt it is never retried
+        sqlx::query("UPDATE prefetch_failures SET attempts = ?, next_attempt_at = 0")
+            .bind(MAX_PREFETCH_ATTEMPTS)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(db.auto_prefetch_candidates(&[], 10).await.unwrap().len(), 1);
+        assert!(!db.prefetch_allowed(failing).await.unwrap());
+
+        // Success clears the record
+        db.clear_prefetch_failure(failing).await.unwrap();
+        assert_eq!(db.auto_prefetch_candidates(&[], 10).await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,289 @@
+use anyhow::Result;
+
+use super::schema::Database;
+use super::tags::ARTICLE_TAGS;
+use super::types::{Article, ArticleDbRow};
+
+/// Share of the estimated reading time a reader must spend on a queued
+/// article, across sessions, before it counts as finished.
+const FINISHED_READING_SHARE: f64 = 0.75;
+
+/// Reading time that always counts as finished, for articles whose length
+/// is unknown or badly underestimated (summary only, no cached content).
+const FINISHED_MIN_SECONDS: i64 = 30;
+
+impl Database {
+    // ========================================================================
+    // Read-Later Queue Operations
+    // ========================================================================
+
+    /// Append an article to the end of the read-later queue.
+    ///
+    /// Returns `false` if it was already queued (its position is kept).
+    pub async fn add_to_read_later(&self, article_id: i64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO read_later (article_id, position, added_at)
+            VALUES (?, (SELECT COALESCE(MAX(position), 0) + 1 FROM read_later), ?)
+        "#,
+        )
+        .bind(article_id)
+        .bind(chrono::Utc::now().timestamp())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Remove an article from the read-later queue. Returns `false` if it wasn't queued.
+    pub async fn remove_from_read_later(&self, article_id: i64) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM read_later WHERE article_id = ?")
+            .bind(article_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Swap a queued article with its neighbour above (`up`) or below.
+    ///
+    /// Returns `false` when there is nothing to swap with.
+    pub async fn move_in_read_later(&self, article_id: i64, up: bool) -> Result<bool> {
+        let neighbour_query = if up {
+            "SELECT r.article_id, r.position, q.position FROM read_later r, read_later q \
+             WHERE q.article_id = ? AND r.position < q.position ORDER BY r.position DESC LIMIT 1"
+        } else {
+            "SELECT r.article_id, r.position, q.position FROM read_later r, read_later q \
+             WHERE q.article_id = ? AND r.position > q.position ORDER BY r.position ASC LIMIT 1"
+        };
+        let mut tx = self.pool.begin().await?;
+        let neighbour: Option<(i64, i64, i64)> = sqlx::query_as(neighbour_query)
+            .bind(article_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some((other_id, other_position, position)) = neighbour else {
+            return Ok(false);
+        };
+        for (id, new_position) in [(article_id, other_position), (other_id, position)] {
+            sqlx::query("UPDATE read_later SET position = ? WHERE article_id = ?")
+                .bind(new_position)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// The read-later queue in order.
+    pub async fn get_read_later_articles(&self) -> Result<Vec<Article>> {
+        let rows = sqlx::query_as::<_, ArticleDbRow>(&format!(
+            r#"
+            SELECT id, feed_id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                   COALESCE(summary_rewritten, summary) AS summary, content,
+                   read, starred, archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
+            FROM read_later r
+            JOIN articles ON articles.id = r.article_id
+            ORDER BY r.position
+        "#
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into_article()).collect())
+    }
+
+    /// IDs of every queued article, for list indicators.
+    pub async fn read_later_ids(&self) -> Result<Vec<i64>> {
+        let ids = sqlx::query_scalar("SELECT article_id FROM read_later ORDER BY position")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(ids)
+    }
+
+    /// Total words in the queue, from each article's stored `word_count`.
+    pub async fn read_later_words(&self) -> Result<usize> {
+        let words: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(a.word_count), 0)
+            FROM read_later r
+            JOIN articles a ON a.id = r.article_id
+        "#,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(usize::try_from(words).unwrap_or(0))
+    }
+
+    /// Take a queued article out of the queue if it has been read to the end.
+    ///
+    /// `reached_end` is whether the reader scrolled to the bottom in the
+    /// session that just closed. Otherwise the article counts as finished once
+    /// its recorded `reading_history` time covers most of the reading time
+    /// estimated at `words_per_minute` (see `reading_speed`). Returns `true`
+    /// if the article left the queue.
+    pub async fn finish_read_later(
+        &self,
+        article_id: i64,
+        reached_end: bool,
+        words_per_minute: usize,
+    ) -> Result<bool> {
+        if !reached_end {
+            let row: Option<(i64, Option<i64>)> = sqlx::query_as(
+                r#"
+                SELECT (SELECT COALESCE(SUM(duration_seconds), 0)
+                        FROM reading_history WHERE article_id = r.article_id),
+                       a.word_count
+                FROM read_later r
+                JOIN articles a ON a.id = r.article_id
+                WHERE r.article_id = ?
+            "#,
+            )
+            .bind(article_id)
+            .fetch_optional(&self.pool)
+            .await?;
+            let Some((seconds_read, words)) = row else {
+                return Ok(false);
+            };
+            let words = words.unwrap_or(0);
+            let estimated_seconds = words as f64 * 60.0 / words_per_minute.max(1) as f64;
+            let needed =
+                ((estimated_seconds * FINISHED_READING_SHARE) as i64).max(FINISHED_MIN_SECONDS);
+            if seconds_read < needed {
+                return Ok(false);
+            }
+        }
+        self.remove_from_read_later(article_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rewrite::RewriteSet;
+    use crate::rules::RuleSet;
+    use crate::storage::{CachePolicy, Database, OpmlFeed, ParsedArticle};
+
+    async fn setup() -> (Database, Vec<i64>) {
+        let db = Database::open(":memory:").await.unwrap();
+        db.sync_feeds(&[OpmlFeed {
+            title: "Feed".to_string(),
+            xml_url: "https://example.com/feed.xml".to_string(),
+            html_url: None,
+        }])
+        .await
+        .unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+        let articles: Vec<ParsedArticle> = (0..3)
+            .map(|i| ParsedArticle {
+                guid: i.to_string(),
+                title: format!("Article {}", i),
+                url: Some(format!("https://example.com/{}", i)),
+                published: Some(1704067200 + i),
+                summary: Some("A short summary".to_string()),
+                author: None,
+            })
+            .collect();
+        db.complete_feed_refresh(
+            feed_id,
+            &articles,
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let mut ids: Vec<i64> = db
+            .get_articles_for_feed(feed_id, None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|a| a.id)
+            .collect();
+        ids.sort();
+        (db, ids)
+    }
+
+    #[tokio::test]
+    async fn test_queue_order_and_reorder() {
+        let (db, ids) = setup().await;
+        for id in [ids[2], ids[0], ids[1]] {
+            assert!(db.add_to_read_later(id).await.unwrap());
+        }
+        assert!(!db.add_to_read_later(ids[2]).await.unwrap());
+        assert_eq!(db.read_later_ids().await.unwrap(), [ids[2], ids[0], ids[1]]);
+
+        assert!(db.move_in_read_later(ids[1], true).await.unwrap());
+        assert!(!db.move_in_read_later(ids[2], true).await.unwrap());
+        let titles: Vec<String> = db
+            .get_read_later_articles()
+            .await
+            .unwrap()
+            .iter()
+            .map(|a| a.title.to_string())
+            .collect();
+        assert_eq!(titles, ["Article 2", "Article 1", "Article 0"]);
+
+        assert!(db.remove_from_read_later(ids[1]).await.unwrap());
+        assert!(!db.remove_from_read_later(ids[1]).await.unwrap());
+        assert_eq!(db.read_later_ids().await.unwrap(), [ids[2], ids[0]]);
+        assert_eq!(db.read_later_words().await.unwrap(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_queued_content_never_expires() {
+        let (db, ids) = setup().await;
+        db.cache_content(
+            ids[0],
+            "# Long read",
+            &CachePolicy {
+                ttl_hours: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        sqlx::query("UPDATE content_cache SET expires_at = datetime('now', '-1 hour')")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        db.add_to_read_later(ids[0]).await.unwrap();
+        assert!(db.get_cached_content(ids[0]).await.unwrap().is_some());
+        assert_eq!(db.cached_article_ids(&ids).await.unwrap(), [ids[0]]);
+        assert_eq!(db.evict_expired().await.unwrap(), 0);
+
+        // Once out of the queue the normal TTL applies again
+        db.remove_from_read_later(ids[0]).await.unwrap();
+        assert!(db.get_cached_content(ids[0]).await.unwrap().is_none());
+        assert_eq!(db.evict_expired().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_finish_read_later() {
+        let (db, ids) = setup().await;
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+        db.add_to_read_later(ids[0]).await.unwrap();
+        db.add_to_read_later(ids[1]).await.unwrap();
+
+        // Scrolling to the end finishes regardless of time spent
+        assert!(db.finish_read_later(ids[0], true, 200).await.unwrap());
+
+        // A short glance does not; enough time across sessions does
+        let history = db.record_open(ids[1], feed_id).await.unwrap();
+        db.record_close(history, 5).await.unwrap();
+        assert!(!db.finish_read_later(ids[1], false, 200).await.unwrap());
+        let history = db.record_open(ids[1], feed_id).await.unwrap();
+        db.record_close(history, 40).await.unwrap();
+        assert!(db.finish_read_later(ids[1], false, 200).await.unwrap());
+
+        // The estimate uses the stored word count at the given speed
+        db.cache_content(ids[2], &"word ".repeat(400), &CachePolicy::default())
+            .await
+            .unwrap();
+        db.add_to_read_later(ids[2]).await.unwrap();
+        let history = db.record_open(ids[2], feed_id).await.unwrap();
+        db.record_close(history, 40).await.unwrap();
+        assert!(!db.finish_read_later(ids[2], false, 200).await.unwrap());
+        assert!(db.finish_read_later(ids[2], false, 800).await.unwrap());
+
+        assert!(db.read_later_ids().await.unwrap().is_empty());
+        assert!(!db.finish_read_later(ids[2], true, 200).await.unwrap());
+    }
+}
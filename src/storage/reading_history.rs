@@ -1,10 +1,28 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 use anyhow::Result;
 
 use super::schema::Database;
-use super::types::{ReadingHistoryEntry, ReadingStats};
-use crate::util::strip_control_chars;
+use super::tags::ARTICLE_TAGS;
+use super::types::{Article, ArticleDbRow, ReadingHistoryEntry, ReadingStats};
+use crate::util::{strip_control_chars, WORDS_PER_MINUTE};
+
+/// Most articles listed under "Continue reading".
+const CONTINUE_READING_LIMIT: i64 = 50;
+
+/// Most recent articles the reading speed is measured on.
+const SPEED_SAMPLE_LIMIT: i64 = 200;
+
+/// Articles measured before the reading speed replaces [`WORDS_PER_MINUTE`].
+const MIN_SPEED_SAMPLES: usize = 5;
+
+/// Shortest article and reading time that say anything about the speed.
+const MIN_SAMPLE_WORDS: i64 = 150;
+const MIN_SAMPLE_SECONDS: i64 = 30;
+
+/// Speeds outside this range mean the article was skimmed or left open.
+const PLAUSIBLE_WPM: std::ops::RangeInclusive<f64> = 80.0..=1000.0;
 
 impl Database {
     // ========================================================================
@@ -56,6 +74,8 @@ impl Database {
     /// Compute aggregated reading statistics over the last N days.
     ///
     /// Returns articles per day, total reading time, and top feeds by read count.
+    /// Words read count each article's reading time at the measured speed, up
+    /// to its length.
     /// Used by TASK-8 (reading stats panel).
     #[allow(dead_code)] // Consumed by downstream TASK-8
     pub async fn get_reading_stats(&self, days: u32) -> Result<ReadingStats> {
@@ -84,6 +104,26 @@ impl Database {
             total_articles as f64 / days as f64
         };
 
+        let words_per_minute = self.reading_speed().await? as i64;
+        let (words_read,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COALESCE(SUM(MIN(words, seconds * ? / 60)), 0)
+            FROM (
+                SELECT a.word_count AS words, SUM(rh.duration_seconds) AS seconds
+                FROM reading_history rh
+                JOIN articles a ON a.id = rh.article_id
+                WHERE rh.opened_at > datetime('now', '-' || ? || ' days')
+                  AND a.word_count IS NOT NULL AND rh.duration_seconds IS NOT NULL
+                GROUP BY rh.article_id
+            )
+        "#,
+        )
+        .bind(words_per_minute)
+        .bind(days)
+        .fetch_one(&self.pool)
+        .await?;
+        let words_per_day = words_read.max(0) as u64 / u64::from(days.max(1));
+
         // SEC-001: Sanitize feed titles — these bypass the normal ArticleDbRow::into_article() path
         let top_feeds = rows
             .into_iter()
@@ -100,9 +140,55 @@ impl Database {
             articles_per_day,
             total_minutes: total_seconds / 60,
             top_feeds,
+            words_per_day,
         })
     }
 
+    /// The user's reading speed in words per minute, measured from the time
+    /// spent on recent articles of known length.
+    ///
+    /// The median over articles is used, so ones skimmed or left open don't
+    /// skew it. `None` until enough articles have been read.
+    pub async fn measured_reading_speed(&self) -> Result<Option<usize>> {
+        let samples: Vec<(i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT a.word_count, SUM(rh.duration_seconds) AS seconds
+            FROM reading_history rh
+            JOIN articles a ON a.id = rh.article_id
+            WHERE rh.duration_seconds IS NOT NULL AND a.word_count >= ?
+            GROUP BY rh.article_id
+            HAVING seconds >= ?
+            ORDER BY MAX(rh.opened_at) DESC
+            LIMIT ?
+        "#,
+        )
+        .bind(MIN_SAMPLE_WORDS)
+        .bind(MIN_SAMPLE_SECONDS)
+        .bind(SPEED_SAMPLE_LIMIT)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut speeds: Vec<f64> = samples
+            .iter()
+            .map(|&(words, seconds)| words as f64 * 60.0 / seconds as f64)
+            .filter(|wpm| PLAUSIBLE_WPM.contains(wpm))
+            .collect();
+        if speeds.len() < MIN_SPEED_SAMPLES {
+            return Ok(None);
+        }
+        speeds.sort_by(f64::total_cmp);
+        Ok(Some(speeds[speeds.len() / 2].round() as usize))
+    }
+
+    /// Words per minute for reading-time estimates: the measured speed, or
+    /// [`WORDS_PER_MINUTE`] until there is one.
+    pub async fn reading_speed(&self) -> Result<usize> {
+        Ok(self
+            .measured_reading_speed()
+            .await?
+            .unwrap_or(WORDS_PER_MINUTE))
+    }
+
     /// Get recent reading history entries with article and feed titles.
     ///
     /// Returns the most recent `limit` entries ordered by opened_at descending.
@@ -141,6 +227,85 @@ impl Database {
             )
             .collect())
     }
+
+    /// Remember where an article was left, as a fraction (0.0-1.0) of its
+    /// length. `None` forgets it: the article was finished or not started.
+    pub async fn save_reading_position(
+        &self,
+        article_id: i64,
+        position: Option<f64>,
+    ) -> Result<()> {
+        match position {
+            Some(position) => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO reading_positions (article_id, position, saved_at)
+                    VALUES (?, ?, ?)
+                    ON CONFLICT(article_id) DO UPDATE
+                    SET position = excluded.position, saved_at = excluded.saved_at
+                "#,
+                )
+                .bind(article_id)
+                .bind(position.clamp(0.0, 1.0))
+                .bind(chrono::Utc::now().timestamp())
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM reading_positions WHERE article_id = ?")
+                    .bind(article_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Where an article was left, if it was left part-way.
+    pub async fn get_reading_position(&self, article_id: i64) -> Result<Option<f64>> {
+        let position =
+            sqlx::query_scalar("SELECT position FROM reading_positions WHERE article_id = ?")
+                .bind(article_id)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(position)
+    }
+
+    /// Every remembered reading position by article ID.
+    pub async fn get_reading_positions(&self) -> Result<HashMap<i64, f64>> {
+        let rows: Vec<(i64, f64)> =
+            sqlx::query_as("SELECT article_id, position FROM reading_positions")
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Partially read articles, most recently opened first: those read in
+    /// the reader (per `reading_history`) and left part-way.
+    pub async fn get_continue_reading_articles(&self) -> Result<Vec<Article>> {
+        let rows = sqlx::query_as::<_, ArticleDbRow>(&format!(
+            r#"
+            SELECT id, feed_id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                   COALESCE(summary_rewritten, summary) AS summary, content,
+                   read, starred, archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
+            FROM articles
+            JOIN reading_positions rp ON rp.article_id = articles.id
+            JOIN (
+                SELECT article_id, MAX(opened_at) AS last_opened
+                FROM reading_history
+                GROUP BY article_id
+            ) rh ON rh.article_id = articles.id
+            WHERE articles.hidden = 0
+            ORDER BY rh.last_opened DESC, rp.saved_at DESC
+            LIMIT ?
+        "#
+        ))
+        .bind(CONTINUE_READING_LIMIT)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into_article()).collect())
+    }
 }
 
 #[cfg(test)]
@@ -166,6 +331,7 @@ mod tests {
             url: Some(format!("https://example.com/{}", guid)),
             published: Some(1704067200),
             summary: Some("Test summary".to_string()),
+            author: None,
         }
     }
 
@@ -388,4 +554,96 @@ mod tests {
         let history = db.get_reading_history(10).await.unwrap();
         assert!(history.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_continue_reading() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1)]).await.unwrap();
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        let feed_id = feeds[0].id;
+        db.upsert_articles(
+            feed_id,
+            &[
+                test_article("1", "Article 1"),
+                test_article("2", "Article 2"),
+                test_article("3", "Article 3"),
+            ],
+        )
+        .await
+        .unwrap();
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        let ids: Vec<i64> = articles.iter().map(|a| a.id).collect();
+
+        // Opened and left part-way, oldest first
+        for (id, position) in [(ids[0], 0.25), (ids[1], 0.5)] {
+            db.record_open(id, feed_id).await.unwrap();
+            db.save_reading_position(id, Some(position)).await.unwrap();
+        }
+        sqlx::query("UPDATE reading_history SET opened_at = datetime('now', '-1 hour') WHERE article_id = ?")
+            .bind(ids[0])
+            .execute(&db.pool)
+            .await
+            .unwrap();
+        // A position without a reading session isn't listed
+        db.save_reading_position(ids[2], Some(0.1)).await.unwrap();
+
+        let listed: Vec<i64> = db
+            .get_continue_reading_articles()
+            .await
+            .unwrap()
+            .iter()
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(listed, [ids[1], ids[0]]);
+        assert_eq!(db.get_reading_position(ids[1]).await.unwrap(), Some(0.5));
+
+        // Finishing forgets the position; positions are clamped
+        db.save_reading_position(ids[1], None).await.unwrap();
+        db.save_reading_position(ids[0], Some(1.5)).await.unwrap();
+        assert_eq!(db.get_reading_position(ids[1]).await.unwrap(), None);
+        let positions = db.get_reading_positions().await.unwrap();
+        assert_eq!(positions.get(&ids[0]), Some(&1.0));
+        assert_eq!(db.get_continue_reading_articles().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_measured_reading_speed() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1)]).await.unwrap();
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        let articles: Vec<_> = (0..6)
+            .map(|i| test_article(&i.to_string(), &format!("Article {}", i)))
+            .collect();
+        db.upsert_articles(feeds[0].id, &articles).await.unwrap();
+        let ids: Vec<i64> = db
+            .get_articles_for_feed(feeds[0].id, None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|a| a.id)
+            .collect();
+
+        let body = "word ".repeat(600);
+        let policy = crate::storage::CachePolicy::default();
+        // 600 words in 2 minutes, the last one skimmed in 5 seconds
+        for (i, &id) in ids.iter().enumerate() {
+            db.cache_content(id, &body, &policy).await.unwrap();
+            let h = db.record_open(id, feeds[0].id).await.unwrap();
+            db.record_close(h, if i == 5 { 5 } else { 120 })
+                .await
+                .unwrap();
+            if i == 3 {
+                assert_eq!(db.measured_reading_speed().await.unwrap(), None);
+                assert_eq!(
+                    db.reading_speed().await.unwrap(),
+                    crate::util::WORDS_PER_MINUTE
+                );
+            }
+        }
+        assert_eq!(db.measured_reading_speed().await.unwrap(), Some(300));
+
+        // 5 x 600 words plus the 25 skimmed, over 7 days
+        let stats = db.get_reading_stats(7).await.unwrap();
+        assert_eq!(stats.words_per_day, 3025 / 7);
+    }
 }
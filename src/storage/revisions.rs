@@ -0,0 +1,461 @@
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use sqlx::{QueryBuilder, SqliteConnection};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use super::schema::Database;
+use super::types::{ArticleRevision, ParsedArticle, RevisionKind};
+
+/// Revisions kept per article; older ones are pruned when a new one is recorded.
+const MAX_REVISIONS_PER_ARTICLE: i64 = 10;
+
+/// Fingerprint of the feed entry fields that make up an article's metadata.
+pub(super) fn metadata_hash(article: &ParsedArticle) -> String {
+    let mut hasher = Sha256::new();
+    // NUL separators so moving text between fields changes the hash
+    hasher.update(article.title.as_bytes());
+    hasher.update([0]);
+    hasher.update(article.url.as_deref().unwrap_or("").as_bytes());
+    hasher.update([0]);
+    if let Some(published) = article.published {
+        hasher.update(published.to_le_bytes());
+    }
+    hasher.update([0]);
+    hasher.update(article.summary.as_deref().unwrap_or("").as_bytes());
+    hasher.update([0]);
+    hasher.update(article.author.as_deref().unwrap_or("").as_bytes());
+
+    let mut hex = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+/// Previous entry fields of an existing article, keyed by GUID.
+type ExistingRow = (
+    i64,
+    String,
+    Option<String>,
+    String,
+    Option<String>,
+    Option<i64>,
+    Option<String>,
+);
+
+/// Record metadata revisions for articles of `feed_id` whose incoming hash differs.
+///
+/// `hashes` holds [`metadata_hash`] for each article in `chunk`. For every
+/// existing article with a different stored hash, the old fields are saved to
+/// `article_revisions` and the article is marked updated (and unread, if the
+/// feed asks for it). Rows from before hashing existed have no stored hash and
+/// are adopted silently.
+///
+/// Returns the indices into `chunk` of existing articles whose stored metadata
+/// is stale: the edited ones plus the unhashed ones. Everything else in the
+/// chunk is either new or unchanged and needs no UPDATE.
+pub(super) async fn record_metadata_revisions(
+    conn: &mut SqliteConnection,
+    feed_id: i64,
+    chunk: &[ParsedArticle],
+    hashes: &[String],
+    now: i64,
+) -> sqlx::Result<Vec<usize>> {
+    let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+        "SELECT id, guid, content_hash, title, url, published, summary \
+         FROM articles WHERE feed_id = ",
+    );
+    builder.push_bind(feed_id);
+    builder.push(" AND guid IN (");
+    let mut separated = builder.separated(", ");
+    for article in chunk {
+        separated.push_bind(&article.guid);
+    }
+    separated.push_unseparated(")");
+    let existing: HashMap<String, ExistingRow> = builder
+        .build_query_as::<ExistingRow>()
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|row| (row.1.clone(), row))
+        .collect();
+
+    let mut stale = Vec::new();
+    let mut seen = HashSet::new();
+    for (i, (article, hash)) in chunk.iter().zip(hashes).enumerate() {
+        let Some((id, _, old_hash, title, url, published, summary)) = existing.get(&article.guid)
+        else {
+            continue;
+        };
+        // A GUID repeated within one fetch is only handled once
+        if !seen.insert(article.guid.as_str()) {
+            continue;
+        }
+        let Some(old_hash) = old_hash else {
+            stale.push(i);
+            continue;
+        };
+        if old_hash == hash {
+            continue;
+        }
+        sqlx::query(
+            "INSERT INTO article_revisions \
+             (article_id, kind, title, url, published, summary, replaced_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id)
+        .bind(RevisionKind::Metadata.as_str())
+        .bind(title)
+        .bind(url)
+        .bind(published)
+        .bind(summary)
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+        mark_updated(conn, *id, now).await?;
+        stale.push(i);
+    }
+    Ok(stale)
+}
+
+/// Save replaced `content_cache` markdown as a content revision and mark the article updated.
+pub(super) async fn record_content_revision(
+    conn: &mut SqliteConnection,
+    article_id: i64,
+    previous: &str,
+    now: i64,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO article_revisions (article_id, kind, content, replaced_at) \
+         VALUES (?, ?, ?, ?)",
+    )
+    .bind(article_id)
+    .bind(RevisionKind::Content.as_str())
+    .bind(previous)
+    .bind(now)
+    .execute(&mut *conn)
+    .await?;
+    mark_updated(conn, article_id, now).await
+}
+
+/// Set `updated_at`, flip to unread for `unread_on_update` feeds, and prune old revisions.
+async fn mark_updated(conn: &mut SqliteConnection, article_id: i64, now: i64) -> sqlx::Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE articles SET
+            updated_at = ?,
+            read = CASE WHEN (SELECT unread_on_update FROM feeds WHERE feeds.id = articles.feed_id)
+                        THEN 0 ELSE read END
+        WHERE id = ?
+    "#,
+    )
+    .bind(now)
+    .bind(article_id)
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM article_revisions
+        WHERE article_id = ?1 AND id NOT IN (
+            SELECT id FROM article_revisions WHERE article_id = ?1 ORDER BY id DESC LIMIT ?2
+        )
+    "#,
+    )
+    .bind(article_id)
+    .bind(MAX_REVISIONS_PER_ARTICLE)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+impl Database {
+    // ========================================================================
+    // Revision Operations
+    // ========================================================================
+
+    /// Previous versions of an article, newest first.
+    pub async fn get_article_revisions(&self, article_id: i64) -> Result<Vec<ArticleRevision>> {
+        type RevisionRow = (
+            i64,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<i64>,
+            Option<String>,
+            Option<String>,
+            i64,
+        );
+        let rows: Vec<RevisionRow> = sqlx::query_as(
+            r#"
+            SELECT id, kind, title, url, published, summary, content, replaced_at
+            FROM article_revisions
+            WHERE article_id = ?
+            ORDER BY id DESC
+        "#,
+        )
+        .bind(article_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // SEC-001: Sanitize external text at the DB boundary
+        let clean =
+            |s: Option<String>| s.map(|s| crate::util::strip_control_chars(&s).into_owned());
+        Ok(rows
+            .into_iter()
+            .map(
+                |(id, kind, title, url, published, summary, content, replaced_at)| {
+                    ArticleRevision {
+                        id,
+                        article_id,
+                        kind: RevisionKind::from_db(&kind),
+                        title: clean(title),
+                        url: clean(url),
+                        published,
+                        summary: clean(summary),
+                        content: content.map(|c| {
+                            // Markdown keeps newlines and tabs; strip only the rest
+                            crate::util::strip_control_chars(&c).into_owned()
+                        }),
+                        replaced_at,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// Choose whether a feed's edited articles are flipped back to unread.
+    pub async fn set_feed_unread_on_update(&self, feed_id: i64, enabled: bool) -> Result<()> {
+        sqlx::query("UPDATE feeds SET unread_on_update = ? WHERE id = ?")
+            .bind(enabled)
+            .bind(feed_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rewrite::RewriteSet;
+    use crate::rules::RuleSet;
+    use crate::storage::{CachePolicy, Database, OpmlFeed, ParsedArticle, RevisionKind};
+
+    async fn db_with_feed() -> (Database, i64) {
+        let db = Database::open(":memory:").await.unwrap();
+        db.sync_feeds(&[OpmlFeed {
+            title: "Feed".to_string(),
+            xml_url: "https://feed.example.com/rss".to_string(),
+            html_url: None,
+        }])
+        .await
+        .unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+        (db, feed_id)
+    }
+
+    fn advisory(title: &str, summary: &str) -> ParsedArticle {
+        ParsedArticle {
+            guid: "advisory-1".to_string(),
+            title: title.to_string(),
+            url: Some("https://example.com/advisory".to_string()),
+            published: Some(1704067200),
+            summary: Some(summary.to_string()),
+            author: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_refresh_records_nothing() {
+        let (db, feed_id) = db_with_feed().await;
+        let entry = advisory("CVE-2024-0001", "Initial report");
+        db.complete_feed_refresh(
+            feed_id,
+            std::slice::from_ref(&entry),
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        db.complete_feed_refresh(
+            feed_id,
+            &[entry],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        let article = &db.get_articles_for_feed(feed_id, None).await.unwrap()[0];
+        assert!(article.updated_at.is_none());
+        assert!(db
+            .get_article_revisions(article.id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_edited_entry_keeps_previous_version() {
+        let (db, feed_id) = db_with_feed().await;
+        db.complete_feed_refresh(
+            feed_id,
+            &[advisory("CVE-2024-0001", "Initial report")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let id = db.get_articles_for_feed(feed_id, None).await.unwrap()[0].id;
+        db.mark_article_read(id).await.unwrap();
+
+        db.complete_feed_refresh(
+            feed_id,
+            &[advisory("CVE-2024-0001 (updated)", "Patch available")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        let article = db.get_article_by_id(id).await.unwrap().unwrap();
+        assert_eq!(&*article.title, "CVE-2024-0001 (updated)");
+        assert!(article.updated_at.is_some());
+        assert!(article.read, "read state kept unless the feed opts in");
+
+        let revisions = db.get_article_revisions(id).await.unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].kind, RevisionKind::Metadata);
+        assert_eq!(revisions[0].title.as_deref(), Some("CVE-2024-0001"));
+        assert_eq!(revisions[0].summary.as_deref(), Some("Initial report"));
+    }
+
+    #[tokio::test]
+    async fn test_author_only_edit_recorded() {
+        let (db, feed_id) = db_with_feed().await;
+        let mut entry = advisory("Incident", "Investigating");
+        entry.author = Some("Alice".to_string());
+        db.complete_feed_refresh(
+            feed_id,
+            std::slice::from_ref(&entry),
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let id = db.get_articles_for_feed(feed_id, None).await.unwrap()[0].id;
+
+        entry.author = Some("Bob".to_string());
+        db.complete_feed_refresh(
+            feed_id,
+            &[entry],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        let author: Option<String> = sqlx::query_scalar("SELECT author FROM articles WHERE id = ?")
+            .bind(id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(author.as_deref(), Some("Bob"));
+        assert_eq!(db.get_article_revisions(id).await.unwrap().len(), 1);
+        let article = db.get_article_by_id(id).await.unwrap().unwrap();
+        assert!(article.updated_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unread_on_update_flips_read_state() {
+        let (db, feed_id) = db_with_feed().await;
+        db.set_feed_unread_on_update(feed_id, true).await.unwrap();
+        db.complete_feed_refresh(
+            feed_id,
+            &[advisory("Incident", "Investigating")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let id = db.get_articles_for_feed(feed_id, None).await.unwrap()[0].id;
+        db.mark_article_read(id).await.unwrap();
+
+        db.complete_feed_refresh(
+            feed_id,
+            &[advisory("Incident", "Resolved")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        let article = db.get_article_by_id(id).await.unwrap().unwrap();
+        assert!(!article.read);
+        assert!(db.get_feeds_with_unread_counts().await.unwrap()[0].unread_on_update);
+    }
+
+    #[tokio::test]
+    async fn test_changed_cached_content_recorded() {
+        let (db, feed_id) = db_with_feed().await;
+        db.complete_feed_refresh(
+            feed_id,
+            &[advisory("Incident", "Investigating")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let id = db.get_articles_for_feed(feed_id, None).await.unwrap()[0].id;
+
+        db.cache_content(id, "# Incident\n\nInvestigating", &CachePolicy::default())
+            .await
+            .unwrap();
+        db.cache_content(id, "# Incident\n\nInvestigating", &CachePolicy::default())
+            .await
+            .unwrap();
+        assert!(db.get_article_revisions(id).await.unwrap().is_empty());
+
+        db.cache_content(id, "# Incident\n\nResolved", &CachePolicy::default())
+            .await
+            .unwrap();
+        let revisions = db.get_article_revisions(id).await.unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].kind, RevisionKind::Content);
+        assert_eq!(
+            revisions[0].content.as_deref(),
+            Some("# Incident\n\nInvestigating")
+        );
+        let article = db.get_article_by_id(id).await.unwrap().unwrap();
+        assert!(article.updated_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_old_revisions_pruned() {
+        let (db, feed_id) = db_with_feed().await;
+        db.complete_feed_refresh(
+            feed_id,
+            &[advisory("Live blog", "v0")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        for i in 1..=12 {
+            db.complete_feed_refresh(
+                feed_id,
+                &[advisory("Live blog", &format!("v{}", i))],
+                &RuleSet::default(),
+                &RewriteSet::default(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let id = db.get_articles_for_feed(feed_id, None).await.unwrap()[0].id;
+        let revisions = db.get_article_revisions(id).await.unwrap();
+        assert_eq!(revisions.len(), super::MAX_REVISIONS_PER_ARTICLE as usize);
+        assert_eq!(revisions[0].summary.as_deref(), Some("v11"));
+    }
+}
@@ -0,0 +1,384 @@
+use anyhow::Result;
+use sqlx::{QueryBuilder, SqliteConnection};
+use std::collections::HashMap;
+
+use super::articles::UPSERT_BATCH_SIZE;
+use super::content_cache::decode_body;
+use super::schema::Database;
+use super::types::{ParsedArticle, RewritePreview};
+use crate::rewrite::{Field, RewriteSet, RewriteTarget};
+
+/// Rewritten title and summary of an entry; `None` where no rewrite applies.
+type Rewritten = (Option<String>, Option<String>);
+
+/// Store rewritten titles and summaries for the entries of one feed refresh.
+///
+/// Rewrites are computed from the parsed entry, so the stored originals are
+/// never read back. Per batch, the stored results are read once and only rows
+/// whose rewritten columns differ are written, in one UPDATE, so an unchanged
+/// feed writes nothing. With no rewrites configured, stale values are cleared.
+pub(super) async fn apply_entry_rewrites(
+    conn: &mut SqliteConnection,
+    rewrites: &RewriteSet,
+    feed_id: i64,
+    articles: &[ParsedArticle],
+) -> sqlx::Result<()> {
+    if rewrites.is_empty() {
+        sqlx::query(
+            "UPDATE articles SET title_rewritten = NULL, summary_rewritten = NULL \
+             WHERE feed_id = ? AND (title_rewritten IS NOT NULL OR summary_rewritten IS NOT NULL)",
+        )
+        .bind(feed_id)
+        .execute(&mut *conn)
+        .await?;
+        return Ok(());
+    }
+
+    let (feed_title, feed_url): (String, String) =
+        sqlx::query_as("SELECT title, url FROM feeds WHERE id = ?")
+            .bind(feed_id)
+            .fetch_one(&mut *conn)
+            .await?;
+    for chunk in articles.chunks(UPSERT_BATCH_SIZE) {
+        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+            "SELECT guid, title_rewritten, summary_rewritten FROM articles WHERE feed_id = ",
+        );
+        builder.push_bind(feed_id);
+        builder.push(" AND guid IN (");
+        let mut separated = builder.separated(", ");
+        for article in chunk {
+            separated.push_bind(&article.guid);
+        }
+        separated.push_unseparated(")");
+        let stored: HashMap<String, Rewritten> = builder
+            .build_query_as::<(String, Option<String>, Option<String>)>()
+            .fetch_all(&mut *conn)
+            .await?
+            .into_iter()
+            .map(|(guid, title, summary)| (guid, (title, summary)))
+            .collect();
+
+        let mut changed: Vec<(&str, Rewritten)> = Vec::new();
+        for article in chunk {
+            let Some(previous) = stored.get(&article.guid) else {
+                continue;
+            };
+            let target = RewriteTarget {
+                feed_title: &feed_title,
+                feed_url: &feed_url,
+                url: article.url.as_deref(),
+            };
+            let title = rewrites.apply(Field::Title, &target, &article.title);
+            let summary = article
+                .summary
+                .as_deref()
+                .and_then(|s| rewrites.apply(Field::Summary, &target, s));
+            // A GUID repeated within one fetch is only handled once
+            let current = (title, summary);
+            if current != *previous && changed.iter().all(|(guid, _)| *guid != article.guid) {
+                changed.push((&article.guid, current));
+            }
+        }
+        if changed.is_empty() {
+            continue;
+        }
+
+        let mut update: QueryBuilder<sqlx::Sqlite> =
+            QueryBuilder::new("UPDATE articles SET title_rewritten = CASE guid ");
+        for (guid, (title, _)) in &changed {
+            update.push("WHEN ");
+            update.push_bind(*guid);
+            update.push(" THEN ");
+            update.push_bind(title);
+            update.push(" ");
+        }
+        update.push("ELSE title_rewritten END, summary_rewritten = CASE guid ");
+        for (guid, (_, summary)) in &changed {
+            update.push("WHEN ");
+            update.push_bind(*guid);
+            update.push(" THEN ");
+            update.push_bind(summary);
+            update.push(" ");
+        }
+        update.push("ELSE summary_rewritten END WHERE feed_id = ");
+        update.push_bind(feed_id);
+        update.push(" AND guid IN (");
+        let mut separated = update.separated(", ");
+        for (guid, _) in &changed {
+            separated.push_bind(*guid);
+        }
+        separated.push_unseparated(")");
+        update.build().execute(&mut *conn).await?;
+    }
+    Ok(())
+}
+
+impl Database {
+    /// Apply content rewrites to an article's markdown and return the text to show.
+    ///
+    /// The result is stored in `content_cache.markdown_rewritten` next to the
+    /// original (NULL when no rewrite applies), so the cached body is never lost.
+    /// A changed result drops the stored TL;DR, which summarized the old text.
+    pub async fn rewrite_content(
+        &self,
+        article_id: i64,
+        rewrites: &RewriteSet,
+        markdown: &str,
+    ) -> Result<String> {
+        let rewritten = if rewrites.is_empty() {
+            None
+        } else {
+            let row: Option<(String, String, Option<String>)> = sqlx::query_as(
+                "SELECT f.title, f.url, a.url FROM articles a JOIN feeds f ON f.id = a.feed_id \
+                 WHERE a.id = ?",
+            )
+            .bind(article_id)
+            .fetch_optional(&self.pool)
+            .await?;
+            row.and_then(|(feed_title, feed_url, url)| {
+                let target = RewriteTarget {
+                    feed_title: &feed_title,
+                    feed_url: &feed_url,
+                    url: url.as_deref(),
+                };
+                rewrites.apply(Field::Content, &target, markdown)
+            })
+        };
+
+        sqlx::query(
+            "UPDATE content_cache SET markdown_rewritten = ?, tldr = NULL \
+             WHERE article_id = ? AND markdown_rewritten IS NOT ?",
+        )
+        .bind(&rewritten)
+        .bind(article_id)
+        .bind(&rewritten)
+        .execute(&self.pool)
+        .await?;
+        Ok(rewritten.unwrap_or_else(|| markdown.to_string()))
+    }
+
+    /// Original title, summary and cached content of a feed's latest entries,
+    /// newest first (the `--preview-rewrites` input).
+    pub async fn get_rewrite_preview(
+        &self,
+        feed_id: i64,
+        limit: i64,
+    ) -> Result<Vec<RewritePreview>> {
+        #[allow(clippy::type_complexity)]
+        let rows: Vec<(
+            String,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<Vec<u8>>,
+        )> = sqlx::query_as(
+            r#"
+                SELECT a.title, a.url, a.summary, cc.markdown, cc.body
+                FROM articles a
+                LEFT JOIN content_cache cc ON cc.article_id = a.id
+                WHERE a.feed_id = ?
+                ORDER BY COALESCE(a.published, a.fetched_at) DESC, a.id DESC
+                LIMIT ?
+            "#,
+        )
+        .bind(feed_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(title, url, summary, markdown, body)| RewritePreview {
+                title,
+                url,
+                summary,
+                // A corrupt cache entry previews like an uncached one
+                markdown: markdown.and_then(|m| decode_body(m, body).ok()),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rewrite::{RewriteConfig, RewriteSet};
+    use crate::rules::RuleSet;
+    use crate::storage::{CachePolicy, Database, OpmlFeed, ParsedArticle, SearchScope};
+
+    async fn setup() -> (Database, i64) {
+        let db = Database::open(":memory:").await.unwrap();
+        db.sync_feeds(&[OpmlFeed {
+            title: "Hacker News".to_string(),
+            xml_url: "https://news.ycombinator.com/rss".to_string(),
+            html_url: None,
+        }])
+        .await
+        .unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+        (db, feed_id)
+    }
+
+    fn article(guid: &str, title: &str, summary: &str) -> ParsedArticle {
+        ParsedArticle {
+            guid: guid.to_string(),
+            title: title.to_string(),
+            url: Some(format!("https://example.com/{}", guid)),
+            published: Some(1704067200),
+            summary: Some(summary.to_string()),
+            author: None,
+        }
+    }
+
+    fn rewrite_set() -> RewriteSet {
+        let (rewrites, errors) = RewriteSet::compile(&[
+            RewriteConfig {
+                feed: Some("hacker news".to_string()),
+                fields: vec!["title".to_string()],
+                strip_prefix: vec!["Show HN:".to_string()],
+                ..Default::default()
+            },
+            RewriteConfig {
+                domain: Some("example.com".to_string()),
+                fields: vec!["summary".to_string(), "content".to_string()],
+                drop_paragraphs: Some("/unsubscribe/i".to_string()),
+                ..Default::default()
+            },
+        ]);
+        assert!(errors.is_empty());
+        rewrites
+    }
+
+    async fn stored(db: &Database, guid: &str) -> (String, Option<String>, Option<String>) {
+        sqlx::query_as(
+            "SELECT title, title_rewritten, summary_rewritten FROM articles WHERE guid = ?",
+        )
+        .bind(guid)
+        .fetch_one(&db.pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_entry_rewrites_keep_originals() {
+        let (db, feed_id) = setup().await;
+        db.complete_feed_refresh(
+            feed_id,
+            &[
+                article("1", "Show HN: A tiny editor", "Built in a weekend"),
+                article("2", "Plain title", "Body\n\nUnsubscribe here"),
+            ],
+            &RuleSet::default(),
+            &rewrite_set(),
+        )
+        .await
+        .unwrap();
+
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        let titles: Vec<&str> = articles.iter().map(|a| &*a.title).collect();
+        assert!(titles.contains(&"A tiny editor"));
+        let plain = articles
+            .iter()
+            .find(|a| &*a.title == "Plain title")
+            .unwrap();
+        assert_eq!(plain.summary.as_deref(), Some("Body"));
+
+        let (title, title_rewritten, summary_rewritten) = stored(&db, "1").await;
+        assert_eq!(title, "Show HN: A tiny editor");
+        assert_eq!(title_rewritten.as_deref(), Some("A tiny editor"));
+        assert_eq!(summary_rewritten, None, "unchanged summary is not stored");
+
+        // Removing the rewrites restores the originals on the next refresh
+        db.complete_feed_refresh(
+            feed_id,
+            &[article("1", "Show HN: A tiny editor", "Built in a weekend")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(stored(&db, "1").await.1, None);
+        assert_eq!(stored(&db, "2").await.2, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_finds_rewritten_text() {
+        let (db, feed_id) = setup().await;
+        let entries = [
+            article("1", "Show HN: A tiny editor", "Built in a weekend"),
+            article("2", "Plain title", "Body\n\nUnsubscribe here"),
+        ];
+        let search = |query: &'static str| {
+            let db = db.clone();
+            async move {
+                db.search_articles(query, SearchScope::TitleAndSummary)
+                    .await
+                    .unwrap()
+                    .len()
+            }
+        };
+        db.complete_feed_refresh(feed_id, &entries, &RuleSet::default(), &rewrite_set())
+            .await
+            .unwrap();
+        assert_eq!(search("editor").await, 1);
+        assert_eq!(search("HN").await, 0);
+        assert_eq!(search("unsubscribe").await, 0);
+
+        // An unchanged refresh leaves the index as it is
+        db.complete_feed_refresh(feed_id, &entries, &RuleSet::default(), &rewrite_set())
+            .await
+            .unwrap();
+        assert_eq!(search("HN").await, 0);
+
+        // Without rewrites the original text is searchable again
+        db.complete_feed_refresh(
+            feed_id,
+            &entries,
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(search("HN").await, 1);
+        assert_eq!(search("unsubscribe").await, 1);
+        assert_eq!(db.rebuild_fts_index().await.unwrap(), 2);
+        assert_eq!(search("HN").await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_content_stored_separately() {
+        let (db, feed_id) = setup().await;
+        db.complete_feed_refresh(
+            feed_id,
+            &[article("1", "Post", "Summary")],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let id = db.get_articles_for_feed(feed_id, None).await.unwrap()[0].id;
+        let markdown = "# Post\n\nText\n\nUnsubscribe from this list";
+        db.cache_content(id, markdown, &CachePolicy::default())
+            .await
+            .unwrap();
+
+        let shown = db
+            .rewrite_content(id, &rewrite_set(), markdown)
+            .await
+            .unwrap();
+        assert_eq!(shown, "# Post\n\nText");
+        let cached = db.get_cached_content(id).await.unwrap().unwrap();
+        assert_eq!(cached.markdown, markdown);
+        assert_eq!(cached.markdown_rewritten.as_deref(), Some("# Post\n\nText"));
+
+        let shown = db
+            .rewrite_content(id, &RewriteSet::default(), markdown)
+            .await
+            .unwrap();
+        assert_eq!(shown, markdown);
+        let cached = db.get_cached_content(id).await.unwrap().unwrap();
+        assert_eq!(cached.markdown_rewritten, None);
+
+        let preview = db.get_rewrite_preview(feed_id, 10).await.unwrap();
+        assert_eq!(preview.len(), 1);
+        assert_eq!(preview[0].markdown.as_deref(), Some(markdown));
+    }
+}
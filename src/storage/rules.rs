@@ -0,0 +1,406 @@
+use anyhow::Result;
+use sqlx::SqliteConnection;
+use std::collections::HashMap;
+
+use super::schema::Database;
+use super::types::RuleHits;
+use crate::rules::{Action, RuleInput, RuleSet};
+
+/// Articles loaded per query while evaluating rules.
+const RULE_BATCH_SIZE: i64 = 500;
+
+/// Article fields plus feed title, feed URL and category name.
+type CandidateRow = (
+    i64,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<i64>,
+    i64,
+    String,
+    String,
+    Option<String>,
+);
+
+/// Combined effect of every rule that matched one article.
+#[derive(Default)]
+struct Outcome {
+    read: bool,
+    starred: bool,
+    hidden: bool,
+    priority: Option<i64>,
+    tags: Vec<String>,
+}
+
+/// Evaluate `rules` against articles with an id above `after_id` and apply their actions.
+///
+/// Restricted to `feed_id` when given. Actions only ever set flags (read,
+/// starred, hidden); they never clear what the user or an earlier rule set.
+/// With `count_hits`, each match increments the rule's row in `rule_hits`;
+/// only ingest counts, since a backfill sees articles counted before.
+/// Returns the number of articles at least one rule matched.
+pub(super) async fn apply_rules(
+    conn: &mut SqliteConnection,
+    rules: &RuleSet,
+    feed_id: Option<i64>,
+    after_id: i64,
+    now: i64,
+    count_hits: bool,
+) -> sqlx::Result<u64> {
+    if rules.is_empty() {
+        return Ok(0);
+    }
+
+    let mut hits: HashMap<&str, i64> = HashMap::new();
+    let mut matched = 0;
+    let mut cursor = after_id;
+    loop {
+        let rows: Vec<CandidateRow> = sqlx::query_as(
+            r#"
+            SELECT a.id, a.title, a.url, a.summary, a.author, a.published, a.fetched_at,
+                   f.title, f.url, c.name
+            FROM articles a
+            JOIN feeds f ON f.id = a.feed_id
+            LEFT JOIN feed_categories c ON c.id = f.category_id
+            WHERE a.id > ? AND (? IS NULL OR a.feed_id = ?)
+            ORDER BY a.id
+            LIMIT ?
+        "#,
+        )
+        .bind(cursor)
+        .bind(feed_id)
+        .bind(feed_id)
+        .bind(RULE_BATCH_SIZE)
+        .fetch_all(&mut *conn)
+        .await?;
+        let Some(last) = rows.last() else {
+            break;
+        };
+        cursor = last.0;
+
+        for (
+            id,
+            title,
+            url,
+            summary,
+            author,
+            published,
+            fetched_at,
+            feed_title,
+            feed_url,
+            category,
+        ) in &rows
+        {
+            let input = RuleInput {
+                feed_title,
+                feed_url,
+                category: category.as_deref(),
+                title,
+                summary: summary.as_deref(),
+                author: author.as_deref(),
+                url: url.as_deref(),
+                published: published.unwrap_or(*fetched_at),
+                now,
+            };
+            let mut outcome = Outcome::default();
+            let mut any = false;
+            for rule in rules.rules().iter().filter(|r| r.matches(&input)) {
+                any = true;
+                *hits.entry(rule.name.as_str()).or_default() += 1;
+                for action in &rule.actions {
+                    match action {
+                        Action::MarkRead => outcome.read = true,
+                        Action::Star => outcome.starred = true,
+                        Action::Hide => outcome.hidden = true,
+                        Action::Tag(tag) => outcome.tags.push(tag.clone()),
+                        Action::SetPriority(p) => outcome.priority = Some(*p),
+                    }
+                }
+            }
+            if any {
+                matched += 1;
+                apply_outcome(conn, *id, &outcome).await?;
+            }
+        }
+    }
+
+    if !count_hits {
+        return Ok(matched);
+    }
+    for (rule, count) in hits {
+        sqlx::query(
+            "INSERT INTO rule_hits (rule, hits, last_hit_at) VALUES (?, ?, ?) \
+             ON CONFLICT(rule) DO UPDATE SET hits = hits + excluded.hits, \
+             last_hit_at = excluded.last_hit_at",
+        )
+        .bind(rule)
+        .bind(count)
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(matched)
+}
+
+async fn apply_outcome(
+    conn: &mut SqliteConnection,
+    article_id: i64,
+    outcome: &Outcome,
+) -> sqlx::Result<()> {
+    if outcome.read || outcome.starred || outcome.hidden || outcome.priority.is_some() {
+        sqlx::query(
+            "UPDATE articles SET read = MAX(read, ?), starred = MAX(starred, ?), \
+             hidden = MAX(hidden, ?), priority = COALESCE(?, priority) WHERE id = ?",
+        )
+        .bind(outcome.read)
+        .bind(outcome.starred)
+        .bind(outcome.hidden)
+        .bind(outcome.priority)
+        .bind(article_id)
+        .execute(&mut *conn)
+        .await?;
+    }
+    for tag in &outcome.tags {
+        sqlx::query("INSERT OR IGNORE INTO article_tags (article_id, tag) VALUES (?, ?)")
+            .bind(article_id)
+            .bind(tag)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+impl Database {
+    /// Run `rules` over every stored article (the `--apply-rules` backfill).
+    ///
+    /// Leaves `rule_hits` alone, so running it again doesn't count the same
+    /// articles twice. Returns the number of articles at least one rule matched.
+    pub async fn apply_rules(&self, rules: &RuleSet) -> Result<u64> {
+        let now = chrono::Utc::now().timestamp();
+        let mut tx = self.pool.begin().await?;
+        let matched = apply_rules(&mut tx, rules, None, 0, now, false).await?;
+        tx.commit().await?;
+        Ok(matched)
+    }
+
+    /// Hit counts of every rule that has fired, most hits first.
+    pub async fn get_rule_hits(&self) -> Result<Vec<RuleHits>> {
+        let rows: Vec<(String, i64, i64)> = sqlx::query_as(
+            "SELECT rule, hits, last_hit_at FROM rule_hits ORDER BY hits DESC, rule",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(rule, hits, last_hit_at)| RuleHits {
+                rule,
+                hits,
+                last_hit_at,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rewrite::RewriteSet;
+    use crate::rules::{RuleConfig, RuleSet};
+    use crate::storage::{Database, OpmlFeed, ParsedArticle};
+
+    async fn tags(db: &Database, article_id: i64) -> Vec<String> {
+        sqlx::query_scalar("SELECT tag FROM article_tags WHERE article_id = ? ORDER BY tag")
+            .bind(article_id)
+            .fetch_all(&db.pool)
+            .await
+            .unwrap()
+    }
+
+    async fn test_db() -> Database {
+        Database::open(":memory:").await.unwrap()
+    }
+
+    fn article(guid: &str, title: &str, summary: &str) -> ParsedArticle {
+        ParsedArticle {
+            guid: guid.to_string(),
+            title: title.to_string(),
+            url: Some(format!("https://example.com/{}", guid)),
+            published: Some(chrono::Utc::now().timestamp()),
+            summary: Some(summary.to_string()),
+            author: Some("Jane Doe".to_string()),
+        }
+    }
+
+    async fn setup() -> (Database, i64) {
+        let db = test_db().await;
+        db.sync_feeds(&[OpmlFeed {
+            title: "Security Blog".to_string(),
+            xml_url: "https://example.com/feed.xml".to_string(),
+            html_url: None,
+        }])
+        .await
+        .unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+        (db, feed_id)
+    }
+
+    fn rule_set() -> RuleSet {
+        let (rules, errors) = RuleSet::compile(&[
+            RuleConfig {
+                name: Some("webinars".to_string()),
+                feed: Some("security blog".to_string()),
+                title: Some("/sponsored|webinar/i".to_string()),
+                mark_read: true,
+                ..Default::default()
+            },
+            RuleConfig {
+                name: Some("cves".to_string()),
+                text: Some("CVE-2024".to_string()),
+                star: true,
+                tags: vec!["security".to_string()],
+                priority: Some(3),
+                ..Default::default()
+            },
+            RuleConfig {
+                name: Some("short".to_string()),
+                shorter_than: Some(10),
+                hide: true,
+                ..Default::default()
+            },
+        ]);
+        assert!(errors.is_empty());
+        rules
+    }
+
+    #[tokio::test]
+    async fn test_rules_applied_to_new_articles_on_refresh() {
+        let (db, feed_id) = setup().await;
+        let rules = rule_set();
+
+        db.complete_feed_refresh(
+            feed_id,
+            &[
+                article("1", "Sponsored: our Webinar", "Join us for an hour"),
+                article("2", "Patch Tuesday", "Fixes CVE-2024-1234 and more"),
+                article("3", "Tiny", "Short"),
+                article("4", "Plain post", "Nothing to see here"),
+            ],
+            &rules,
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        assert_eq!(articles.len(), 3, "hidden article is not listed");
+        // Priority sorts the CVE post first
+        assert_eq!(&*articles[0].title, "Patch Tuesday");
+        assert!(articles[0].starred);
+        assert_eq!(articles[0].priority, 3);
+        assert_eq!(tags(&db, articles[0].id).await, ["security"]);
+        let webinar = articles
+            .iter()
+            .find(|a| a.title.contains("Webinar"))
+            .unwrap();
+        assert!(webinar.read);
+
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        assert_eq!(
+            feeds[0].unread_count, 2,
+            "read and hidden articles not counted"
+        );
+
+        let hits: Vec<(String, i64)> = db
+            .get_rule_hits()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|h| (h.rule, h.hits))
+            .collect();
+        assert_eq!(
+            hits,
+            [
+                ("cves".to_string(), 1),
+                ("short".to_string(), 1),
+                ("webinars".to_string(), 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rules_skip_existing_articles_on_refresh() {
+        let (db, feed_id) = setup().await;
+        let entry = article("1", "Webinar recap", "An hour of slides");
+        db.complete_feed_refresh(
+            feed_id,
+            std::slice::from_ref(&entry),
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+
+        db.complete_feed_refresh(feed_id, &[entry], &rule_set(), &RewriteSet::default())
+            .await
+            .unwrap();
+
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        assert!(!articles[0].read, "only new articles are evaluated");
+        assert!(db.get_rule_hits().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_rules_backfill() {
+        let (db, feed_id) = setup().await;
+        db.complete_feed_refresh(
+            feed_id,
+            &[
+                article("1", "Webinar recap", "An hour of slides"),
+                article("2", "CVE-2024-0001", "Advisory details here"),
+            ],
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let matched = db.apply_rules(&rule_set()).await.unwrap();
+        assert_eq!(matched, 2);
+
+        let articles = db.get_articles_for_feed(feed_id, None).await.unwrap();
+        let webinar = articles
+            .iter()
+            .find(|a| a.title.contains("Webinar"))
+            .unwrap();
+        let advisory = articles.iter().find(|a| a.title.contains("CVE")).unwrap();
+        assert!(webinar.read && !webinar.starred);
+        assert!(advisory.starred && !advisory.read);
+        assert_eq!(tags(&db, advisory.id).await, ["security"]);
+    }
+
+    #[tokio::test]
+    async fn test_apply_rules_backfill_keeps_hit_counts() {
+        let (db, feed_id) = setup().await;
+        db.complete_feed_refresh(
+            feed_id,
+            &[
+                article("1", "Webinar recap", "An hour of slides"),
+                article("2", "CVE-2024-0001", "Advisory details here"),
+            ],
+            &rule_set(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let counts = |hits: Vec<crate::storage::RuleHits>| -> Vec<(String, i64)> {
+            hits.into_iter().map(|h| (h.rule, h.hits)).collect()
+        };
+        let before = counts(db.get_rule_hits().await.unwrap());
+        assert!(!before.is_empty() && before.iter().all(|(_, hits)| *hits == 1));
+
+        // Backfills match the same articles again without re-counting them
+        for _ in 0..2 {
+            assert_eq!(db.apply_rules(&rule_set()).await.unwrap(), 2);
+            assert_eq!(counts(db.get_rule_hits().await.unwrap()), before);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use anyhow::Result;
+
+use super::articles::upsert_chunk;
+use super::schema::Database;
+use super::types::ParsedArticle;
+
+/// URL of the built-in pseudo-feed holding pages saved with "Save URL".
+///
+/// Not a fetchable address: feed refresh, health and OPML export skip it.
+pub const SAVED_FEED_URL: &str = "skim:saved";
+
+/// Title the "Saved" pseudo-feed is created with (users may rename it).
+const SAVED_FEED_TITLE: &str = "Saved";
+
+impl Database {
+    // ========================================================================
+    // Saved URL Operations
+    // ========================================================================
+
+    /// Store a saved page as an article of the "Saved" pseudo-feed, creating
+    /// the feed on first use. Returns the article ID.
+    ///
+    /// The URL is the article's GUID, so saving a page again updates its
+    /// title (keeping the previous one as a revision) and leaves read and
+    /// starred state alone.
+    pub async fn save_article(&self, url: &str, title: &str) -> Result<i64> {
+        let now = chrono::Utc::now().timestamp();
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("INSERT INTO feeds (title, url) VALUES (?, ?) ON CONFLICT(url) DO NOTHING")
+            .bind(SAVED_FEED_TITLE)
+            .bind(SAVED_FEED_URL)
+            .execute(&mut *tx)
+            .await?;
+        let (feed_id,): (i64,) = sqlx::query_as("SELECT id FROM feeds WHERE url = ?")
+            .bind(SAVED_FEED_URL)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let article = ParsedArticle {
+            guid: url.to_string(),
+            title: title.to_string(),
+            url: Some(url.to_string()),
+            published: Some(now),
+            summary: None,
+            author: None,
+        };
+        upsert_chunk(&mut tx, feed_id, std::slice::from_ref(&article), now).await?;
+        let (article_id,): (i64,) =
+            sqlx::query_as("SELECT id FROM articles WHERE feed_id = ? AND guid = ?")
+                .bind(feed_id)
+                .bind(url)
+                .fetch_one(&mut *tx)
+                .await?;
+        tx.commit().await?;
+        Ok(article_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SAVED_FEED_URL;
+    use crate::storage::{Database, SearchScope};
+
+    #[tokio::test]
+    async fn test_save_article_creates_saved_feed() {
+        let db = Database::open(":memory:").await.unwrap();
+        let id = db
+            .save_article("https://example.com/post", "A saved post")
+            .await
+            .unwrap();
+        db.index_content(id, "# A saved post\n\nAbout sourdough starters")
+            .await
+            .unwrap();
+
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        assert_eq!(feeds.len(), 1);
+        assert!(feeds[0].is_saved());
+        assert_eq!(feeds[0].unread_count, 1);
+        assert!(db.get_feeds_for_export().await.unwrap().is_empty());
+        assert!(db.get_feed_health().await.unwrap().is_empty());
+
+        // Saved articles are searchable and starrable like any other
+        let found = db
+            .search_articles("sourdough", SearchScope::All)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert!(db.toggle_article_starred(id).await.unwrap());
+
+        // Saving again reuses the article and keeps its state
+        let again = db
+            .save_article("https://example.com/post", "A saved post, updated")
+            .await
+            .unwrap();
+        assert_eq!(again, id);
+        let article = db.get_article_by_id(id).await.unwrap().unwrap();
+        assert_eq!(&*article.title, "A saved post, updated");
+        assert!(article.starred);
+        let feed_url: String = sqlx::query_scalar("SELECT url FROM feeds")
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        assert_eq!(feed_url, SAVED_FEED_URL);
+    }
+}
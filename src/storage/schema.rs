@@ -7,6 +7,7 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use super::types::DatabaseError;
+use crate::util::word_count;
 
 // ============================================================================
 // Database
@@ -212,10 +213,35 @@ impl Database {
             .execute(&mut *tx)
             .await?;
 
+        // Output of [[rewrites]] (see crate::rewrite). The originals stay in
+        // title / summary; NULL means no rewrite applied.
+        for column in [
+            "ALTER TABLE articles ADD COLUMN title_rewritten TEXT",
+            "ALTER TABLE articles ADD COLUMN summary_rewritten TEXT",
+        ] {
+            sqlx::query(column).execute(&mut *tx).await.ok(); // Ignore error if column already exists
+        }
+
+        // The index covers the text lists show: rewritten titles and summaries
+        // where a rewrite applied, so searching finds what is on screen
+        sqlx::query("DROP VIEW IF EXISTS articles_fts_source")
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            CREATE VIEW articles_fts_source AS
+            SELECT id, COALESCE(title_rewritten, title) AS title,
+                   COALESCE(summary_rewritten, summary) AS summary, content
+            FROM articles
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
         sqlx::query(
             r#"
             CREATE VIRTUAL TABLE IF NOT EXISTS articles_fts
-            USING fts5(title, summary, content, content=articles, content_rowid=id)
+            USING fts5(title, summary, content, content=articles_fts_source, content_rowid=id)
         "#,
         )
         .execute(&mut *tx)
@@ -231,7 +257,7 @@ impl Database {
             sqlx::query(
                 r#"
                 INSERT INTO articles_fts(rowid, title, summary, content)
-                SELECT id, title, summary, COALESCE(content, '') FROM articles
+                SELECT id, title, summary, COALESCE(content, '') FROM articles_fts_source
             "#,
             )
             .execute(&mut *tx)
@@ -243,7 +269,8 @@ impl Database {
             r#"
             CREATE TRIGGER IF NOT EXISTS articles_fts_insert AFTER INSERT ON articles BEGIN
                 INSERT INTO articles_fts(rowid, title, summary, content)
-                VALUES (new.id, new.title, new.summary, COALESCE(new.content, ''));
+                VALUES (new.id, COALESCE(new.title_rewritten, new.title),
+                        COALESCE(new.summary_rewritten, new.summary), COALESCE(new.content, ''));
             END
         "#,
         )
@@ -254,20 +281,27 @@ impl Database {
             r#"
             CREATE TRIGGER IF NOT EXISTS articles_fts_delete AFTER DELETE ON articles BEGIN
                 INSERT INTO articles_fts(articles_fts, rowid, title, summary, content)
-                VALUES ('delete', old.id, old.title, old.summary, COALESCE(old.content, ''));
+                VALUES ('delete', old.id, COALESCE(old.title_rewritten, old.title),
+                        COALESCE(old.summary_rewritten, old.summary), COALESCE(old.content, ''));
             END
         "#,
         )
         .execute(&mut *tx)
         .await?;
 
+        // Only fires when an indexed column changes: read/starred flips and
+        // bookkeeping columns (hashes, duplicate links) leave the FTS row alone
         sqlx::query(
             r#"
-            CREATE TRIGGER IF NOT EXISTS articles_fts_update AFTER UPDATE ON articles BEGIN
+            CREATE TRIGGER IF NOT EXISTS articles_fts_update
+            AFTER UPDATE OF title, summary, content, title_rewritten, summary_rewritten
+            ON articles BEGIN
                 INSERT INTO articles_fts(articles_fts, rowid, title, summary, content)
-                VALUES ('delete', old.id, old.title, old.summary, COALESCE(old.content, ''));
+                VALUES ('delete', old.id, COALESCE(old.title_rewritten, old.title),
+                        COALESCE(old.summary_rewritten, old.summary), COALESCE(old.content, ''));
                 INSERT INTO articles_fts(rowid, title, summary, content)
-                VALUES (new.id, new.title, new.summary, COALESCE(new.content, ''));
+                VALUES (new.id, COALESCE(new.title_rewritten, new.title),
+                        COALESCE(new.summary_rewritten, new.summary), COALESCE(new.content, ''));
             END
         "#,
         )
@@ -280,6 +314,191 @@ impl Database {
             .await
             .ok(); // Ignore error if column already exists
 
+        // Unix timestamp before which bulk refresh skips the feed (server Retry-After)
+        sqlx::query("ALTER TABLE feeds ADD COLUMN retry_after INTEGER")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Feed responded 410 Gone; skipped by bulk refresh and suggested for unsubscription
+        sqlx::query("ALTER TABLE feeds ADD COLUMN gone INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Set when feeds.url was rewritten after a permanent redirect (301/308)
+        sqlx::query("ALTER TABLE feeds ADD COLUMN url_auto_updated INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Adaptive polling: earliest time bulk refresh should fetch the feed again
+        sqlx::query("ALTER TABLE feeds ADD COLUMN next_fetch_at INTEGER")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Cross-feed duplicate detection keys (see util::url_dedup_key / util::title_hash)
+        sqlx::query("ALTER TABLE articles ADD COLUMN canonical_key TEXT")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+        sqlx::query("ALTER TABLE articles ADD COLUMN title_hash TEXT")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_articles_canonical_key ON articles(canonical_key) \
+             WHERE canonical_key IS NOT NULL",
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_articles_title_hash ON articles(title_hash) \
+             WHERE title_hash IS NOT NULL",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Copies of one story across feeds share a group_id (the first copy's article id).
+        // Read/star state propagates within a group; merged views show one copy.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS article_duplicates (
+                article_id INTEGER PRIMARY KEY REFERENCES articles(id) ON DELETE CASCADE,
+                group_id INTEGER NOT NULL
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_article_duplicates_group ON article_duplicates(group_id)",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Preferred source: merged views show this feed's copy of a duplicated story
+        sqlx::query("ALTER TABLE feeds ADD COLUMN prefer_source INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Article revisions: content_hash fingerprints title/url/published/summary
+        // (and author) so refreshes can tell when a publisher edited an entry;
+        // updated_at marks the edit
+        sqlx::query("ALTER TABLE articles ADD COLUMN content_hash TEXT")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+        sqlx::query("ALTER TABLE articles ADD COLUMN updated_at INTEGER")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Per-feed: flip edited articles back to unread
+        sqlx::query("ALTER TABLE feeds ADD COLUMN unread_on_update INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Previous versions of edited articles. Metadata revisions keep the feed
+        // entry fields, content revisions the replaced content_cache markdown.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS article_revisions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                article_id INTEGER NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+                kind TEXT NOT NULL,
+                title TEXT,
+                url TEXT,
+                published INTEGER,
+                summary TEXT,
+                content TEXT,
+                replaced_at INTEGER NOT NULL
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_article_revisions_article ON article_revisions(article_id, id DESC)",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Ingest rules (see crate::rules): entry author for author patterns, and
+        // the hide / priority actions. Hidden articles stay out of lists and counts.
+        let added = sqlx::query("ALTER TABLE articles ADD COLUMN author TEXT")
+            .execute(&mut *tx)
+            .await
+            .is_ok();
+        if added {
+            // content_hash now covers the author too: let the next refresh adopt
+            // existing rows (filling in their author) instead of seeing edits
+            sqlx::query("UPDATE articles SET content_hash = NULL")
+                .execute(&mut *tx)
+                .await?;
+        }
+        sqlx::query("ALTER TABLE articles ADD COLUMN hidden INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+        sqlx::query("ALTER TABLE articles ADD COLUMN priority INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Archived articles keep their cached content forever (see content_cache::CACHE_LIVE)
+        sqlx::query("ALTER TABLE articles ADD COLUMN archived INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Tags attached by rule actions
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS article_tags (
+                article_id INTEGER NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+                tag TEXT NOT NULL,
+                PRIMARY KEY (article_id, tag)
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_article_tags_tag ON article_tags(tag)")
+            .execute(&mut *tx)
+            .await?;
+
+        // How often each rule (keyed by name) has fired
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS rule_hits (
+                rule TEXT PRIMARY KEY,
+                hits INTEGER NOT NULL DEFAULT 0,
+                last_hit_at INTEGER NOT NULL
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Previous URLs of feeds moved by permanent redirects, so OPML re-sync
+        // maps the old URL onto the existing feed instead of creating a duplicate
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS feed_url_aliases (
+                url TEXT PRIMARY KEY,
+                feed_id INTEGER NOT NULL REFERENCES feeds(id) ON DELETE CASCADE
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
         // Create feed_categories table for grouping feeds into a tree hierarchy
         sqlx::query(
             r#"
@@ -336,6 +555,38 @@ impl Database {
         .await?;
         // Note: No explicit index on article_id — the UNIQUE constraint creates an implicit one
 
+        // Content output of [[rewrites]], next to the original markdown (the
+        // title and summary columns are added with the search index above)
+        sqlx::query("ALTER TABLE content_cache ADD COLUMN markdown_rewritten TEXT")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Compressed cache bodies (crate::util::compress) and last read time
+        // for least-recently-read eviction. Rows cached before keep their
+        // text in `markdown` with a NULL `body`.
+        for column in [
+            "ALTER TABLE content_cache ADD COLUMN body BLOB",
+            "ALTER TABLE content_cache ADD COLUMN stored_bytes INTEGER",
+            "ALTER TABLE content_cache ADD COLUMN last_accessed_at TEXT",
+        ] {
+            sqlx::query(column).execute(&mut *tx).await.ok(); // Ignore error if column already exists
+        }
+
+        // Read-later queue, ordered by position. Queued articles' cached
+        // content never expires (see content_cache::CACHE_LIVE).
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS read_later (
+                article_id INTEGER PRIMARY KEY REFERENCES articles(id) ON DELETE CASCADE,
+                position INTEGER NOT NULL,
+                added_at INTEGER NOT NULL
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
         // Create reading_history table for tracking article reading sessions
         sqlx::query(
             r#"
@@ -364,6 +615,94 @@ impl Database {
         .execute(&mut *tx)
         .await?;
 
+        // Per-attempt fetch history for the feed health view (pruned by prune_fetch_log)
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS feed_fetch_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                feed_id INTEGER NOT NULL REFERENCES feeds(id) ON DELETE CASCADE,
+                fetched_at INTEGER NOT NULL,
+                status TEXT NOT NULL,
+                http_status INTEGER,
+                bytes INTEGER,
+                latency_ms INTEGER NOT NULL,
+                new_articles INTEGER NOT NULL DEFAULT 0,
+                error TEXT
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_feed_fetch_log_feed ON feed_fetch_log(feed_id, fetched_at DESC)",
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Per-feed: fetch new unread articles after each refresh
+        sqlx::query("ALTER TABLE feeds ADD COLUMN auto_prefetch INTEGER NOT NULL DEFAULT 0")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
+        // Automatic prefetch failures, retried with backoff (see storage::prefetch)
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS prefetch_failures (
+                article_id INTEGER PRIMARY KEY REFERENCES articles(id) ON DELETE CASCADE,
+                attempts INTEGER NOT NULL,
+                next_attempt_at INTEGER NOT NULL,
+                last_error TEXT
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Where each article was left in the reader, as a fraction of its
+        // rendered length, so it survives re-wrapping (see reading_history)
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS reading_positions (
+                article_id INTEGER PRIMARY KEY REFERENCES articles(id) ON DELETE CASCADE,
+                position REAL NOT NULL,
+                saved_at INTEGER NOT NULL
+            )
+        "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // Words per article for reading-time estimates, kept up to date by
+        // upserts and cache_content; counted once for articles stored before
+        let added = sqlx::query("ALTER TABLE articles ADD COLUMN word_count INTEGER")
+            .execute(&mut *tx)
+            .await
+            .is_ok();
+        if added {
+            let texts: Vec<(i64, String)> = sqlx::query_as(
+                "SELECT id, COALESCE(content, summary) FROM articles \
+                 WHERE COALESCE(content, summary) IS NOT NULL",
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+            for (id, text) in texts {
+                sqlx::query("UPDATE articles SET word_count = ? WHERE id = ?")
+                    .bind(word_count(&text) as i64)
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+        }
+
+        // Extractive summary of the shown markdown, one sentence per line
+        // ('' when too short to summarize); NULL until first computed
+        sqlx::query("ALTER TABLE content_cache ADD COLUMN tldr TEXT")
+            .execute(&mut *tx)
+            .await
+            .ok(); // Ignore error if column already exists
+
         // Commit all migrations atomically
         tx.commit().await?;
 
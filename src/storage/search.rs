@@ -2,14 +2,16 @@ use std::time::Duration;
 
 use anyhow::Result;
 
+use super::duplicates::{hide_duplicates_for, HIDE_DUPLICATES};
 use super::schema::Database;
+use super::tags::{article_tags_for, bound_tags, tag_conditions_for, ARTICLE_TAGS};
 use super::types::{Article, ArticleDbRow, DatabaseError, FtsConsistencyReport, SearchScope};
 
 // ============================================================================
 // FTS5 Query Validation
 // ============================================================================
 
-use crate::util::MAX_SEARCH_QUERY_LENGTH;
+use crate::util::{normalize_tag, MAX_SEARCH_QUERY_LENGTH};
 const MAX_WILDCARDS: usize = 3;
 const MAX_OR_OPERATORS: usize = 5;
 const MAX_PARENTHESES: usize = 5;
@@ -153,6 +155,86 @@ fn validate_fts_query(query: &str) -> Result<()> {
     Ok(())
 }
 
+/// Split `tag:name` terms out of a search query.
+///
+/// Returns the rest of the query and the normalized tag names, deduplicated.
+/// An empty `tag:` term is left in the query as plain text.
+fn split_tag_filters(query: &str) -> (String, Vec<String>) {
+    let mut rest = Vec::new();
+    let mut tags: Vec<String> = Vec::new();
+    for term in query.split_whitespace() {
+        let tag = term
+            .get(..4)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("tag:"))
+            .and_then(|_| normalize_tag(&term[4..]));
+        match tag {
+            Some(tag) if !tags.contains(&tag) => tags.push(tag),
+            Some(_) => {}
+            None => rest.push(term),
+        }
+    }
+    if tags.is_empty() {
+        return (query.to_string(), tags);
+    }
+    (rest.join(" "), tags)
+}
+
+/// A `time:<N` or `time:>N` search term: articles that take at most, or
+/// more than, N minutes to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReadingTime {
+    AtMost(usize),
+    Over(usize),
+}
+
+impl ReadingTime {
+    /// SQL condition on `alias.word_count` at `words_per_minute`. The bound is
+    /// a computed number, so it is inlined rather than bound.
+    fn condition_for(self, alias: &str, words_per_minute: usize) -> String {
+        match self {
+            Self::AtMost(minutes) => format!(
+                " AND {alias}.word_count > 0 AND {alias}.word_count <= {}",
+                minutes.saturating_mul(words_per_minute)
+            ),
+            Self::Over(minutes) => format!(
+                " AND {alias}.word_count > {}",
+                minutes.saturating_mul(words_per_minute)
+            ),
+        }
+    }
+}
+
+/// Split a `time:<N` / `time:>N` term out of a search query (the last one
+/// wins). Terms that don't parse are left in the query as plain text.
+fn split_time_filter(query: &str) -> (String, Option<ReadingTime>) {
+    let mut rest = Vec::new();
+    let mut filter = None;
+    for term in query.split_whitespace() {
+        let parsed = term
+            .get(..5)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("time:"))
+            .and_then(|_| {
+                let value = &term[5..];
+                let minutes = |n: &str| n.trim_end_matches("min").parse::<usize>().ok();
+                if let Some(n) = value.strip_prefix('<') {
+                    minutes(n).map(ReadingTime::AtMost)
+                } else if let Some(n) = value.strip_prefix('>') {
+                    minutes(n).map(ReadingTime::Over)
+                } else {
+                    None
+                }
+            });
+        match parsed {
+            Some(parsed) => filter = Some(parsed),
+            None => rest.push(term),
+        }
+    }
+    if filter.is_none() {
+        return (query.to_string(), None);
+    }
+    (rest.join(" "), filter)
+}
+
 impl Database {
     // ========================================================================
     // Search Operations
@@ -162,21 +244,41 @@ impl Database {
     ///
     /// `SearchScope::TitleAndSummary` restricts to title+summary columns (original behavior).
     /// `SearchScope::All` searches title, summary, AND content for full-text search.
+    /// `tag:name` terms restrict results to articles carrying that tag (`tag:starred`
+    /// to starred ones), and `time:<N` / `time:>N` to articles read in at most or
+    /// more than N minutes at the user's reading speed; a query of only such
+    /// terms lists the matching articles.
     ///
     /// Uses FTS5 for fast search with LIKE fallback for syntax errors or timeout.
+    /// Cross-feed duplicates are collapsed to their preferred copy.
     /// PERF-003: Hard cap at MAX_ARTICLES (2000) to prevent OOM.
     /// SEC-012: FTS5 query wrapped with 5s timeout to prevent CPU-bound DoS.
     pub async fn search_articles(&self, query: &str, scope: SearchScope) -> Result<Vec<Article>> {
+        let (query, tags) = split_tag_filters(query);
+        let (query, time) = split_time_filter(&query);
+        let time_condition = |alias: &str, words_per_minute: usize| {
+            time.map(|t| t.condition_for(alias, words_per_minute))
+                .unwrap_or_default()
+        };
+        let words_per_minute = match time {
+            Some(_) => self.reading_speed().await?,
+            None => 0,
+        };
         // Early return for empty/whitespace-only queries
         let query = query.trim();
         if query.is_empty() {
-            return Ok(Vec::new());
+            if tags.is_empty() && time.is_none() {
+                return Ok(Vec::new());
+            }
+            return self
+                .tag_filtered(&tags, &time_condition("articles", words_per_minute))
+                .await;
         }
 
         // Validate query complexity to prevent DoS via expensive wildcard expansions
         validate_fts_query(query)?;
 
-        tracing::debug!(limit = MAX_ARTICLES, query = %query, scope = ?scope, "search_articles with limit cap");
+        tracing::debug!(limit = MAX_ARTICLES, query = %query, scope = ?scope, ?tags, "search_articles with limit cap");
 
         // Build FTS5 MATCH expression: column filter for TitleAndSummary, bare query for All
         let fts_query = match scope {
@@ -186,39 +288,75 @@ impl Database {
 
         // PERF-002: Try FTS5 MATCH first for fast search
         // SEC-012: Wrap with timeout to prevent CPU-bound queries from blocking
-        let fts_result = tokio::time::timeout(
-            SEARCH_TIMEOUT,
-            sqlx::query_as::<_, ArticleDbRow>(
-                r#"
-                SELECT a.id, a.feed_id, a.guid, a.title, a.url, a.published,
-                       a.summary, a.content, a.read, a.starred, a.fetched_at
-                FROM articles a
-                INNER JOIN articles_fts ON a.id = articles_fts.rowid
-                WHERE articles_fts MATCH ?
-                ORDER BY a.published DESC
-                LIMIT ?
-            "#,
-            )
-            .bind(&fts_query)
-            .bind(MAX_ARTICLES)
-            .fetch_all(&self.pool),
-        )
-        .await;
+        let sql = format!(
+            r#"
+            SELECT a.id, a.feed_id, a.guid, COALESCE(a.title_rewritten, a.title) AS title,
+                   a.url, a.published, COALESCE(a.summary_rewritten, a.summary) AS summary,
+                   a.content, a.read, a.starred, a.archived, a.fetched_at, a.updated_at, a.priority, a.word_count, {}
+            FROM articles a
+            INNER JOIN articles_fts ON a.id = articles_fts.rowid
+            WHERE articles_fts MATCH ? AND a.hidden = 0 AND {}{}{}
+            ORDER BY a.published DESC
+            LIMIT ?
+        "#,
+            article_tags_for("a"),
+            hide_duplicates_for("a"),
+            tag_conditions_for("a", &tags),
+            time_condition("a", words_per_minute)
+        );
+        let mut fts = sqlx::query_as::<_, ArticleDbRow>(&sql).bind(&fts_query);
+        for tag in bound_tags(&tags) {
+            fts = fts.bind(tag);
+        }
+        let fts_result =
+            tokio::time::timeout(SEARCH_TIMEOUT, fts.bind(MAX_ARTICLES).fetch_all(&self.pool))
+                .await;
 
         // Fall back to LIKE for queries that fail FTS5 syntax or time out
         match fts_result {
             Ok(Ok(rows)) => Ok(rows.into_iter().map(ArticleDbRow::into_article).collect()),
             Ok(Err(e)) => {
                 tracing::warn!(error = %e, query = %query, "FTS5 search failed, falling back to LIKE");
-                self.like_fallback(query).await
+                self.like_fallback(query, &tags, &time_condition("articles", words_per_minute))
+                    .await
             }
             Err(_elapsed) => {
                 tracing::warn!(query = %query, "FTS5 search timed out after 5s, falling back to LIKE");
-                self.like_fallback(query).await
+                self.like_fallback(query, &tags, &time_condition("articles", words_per_minute))
+                    .await
             }
         }
     }
 
+    /// Articles carrying `tag` (normalized), newest first, one copy per
+    /// cross-feed duplicate group. Used by the sidebar's tag section.
+    pub async fn get_tagged_articles(&self, tag: &str) -> Result<Vec<Article>> {
+        self.tag_filtered(&[tag.to_string()], "").await
+    }
+
+    /// Articles carrying every tag in `tags` and meeting the extra `condition`
+    /// (SQL on `articles`, without bound parameters), newest first.
+    async fn tag_filtered(&self, tags: &[String], condition: &str) -> Result<Vec<Article>> {
+        let sql = format!(
+            r#"
+            SELECT id, feed_id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                   COALESCE(summary_rewritten, summary) AS summary, NULL as content,
+                   read, starred, archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
+            FROM articles
+            WHERE hidden = 0 AND {HIDE_DUPLICATES}{}{condition}
+            ORDER BY published DESC, fetched_at DESC
+            LIMIT ?
+        "#,
+            tag_conditions_for("articles", tags)
+        );
+        let mut query = sqlx::query_as::<_, ArticleDbRow>(&sql);
+        for tag in bound_tags(tags) {
+            query = query.bind(tag);
+        }
+        let rows = query.bind(MAX_ARTICLES).fetch_all(&self.pool).await?;
+        Ok(rows.into_iter().map(ArticleDbRow::into_article).collect())
+    }
+
     /// Update the FTS5 content column for a specific article.
     ///
     /// Sets `articles.content` which triggers the FTS5 UPDATE trigger to sync
@@ -235,22 +373,33 @@ impl Database {
     }
 
     /// LIKE-based search fallback when FTS5 fails or times out.
-    async fn like_fallback(&self, query: &str) -> Result<Vec<Article>> {
+    async fn like_fallback(
+        &self,
+        query: &str,
+        tags: &[String],
+        condition: &str,
+    ) -> Result<Vec<Article>> {
         let like_pattern = format!("%{}%", query);
-        let rows = sqlx::query_as::<_, ArticleDbRow>(
+        let sql = format!(
             r#"
-            SELECT id, feed_id, guid, title, url, published,
-                   summary, NULL as content, read, starred, fetched_at
+            SELECT id, feed_id, guid, COALESCE(title_rewritten, title) AS title, url, published,
+                   COALESCE(summary_rewritten, summary) AS summary, NULL as content,
+                   read, starred, archived, fetched_at, updated_at, priority, word_count, {ARTICLE_TAGS}
             FROM articles
-            WHERE title LIKE ?1 OR summary LIKE ?1
+            WHERE (COALESCE(title_rewritten, title) LIKE ? OR COALESCE(summary_rewritten, summary) LIKE ?)
+              AND hidden = 0 AND {HIDE_DUPLICATES}{}{condition}
             ORDER BY published DESC
-            LIMIT ?2
+            LIMIT ?
         "#,
-        )
-        .bind(&like_pattern)
-        .bind(MAX_ARTICLES)
-        .fetch_all(&self.pool)
-        .await?;
+            tag_conditions_for("articles", tags)
+        );
+        let mut like = sqlx::query_as::<_, ArticleDbRow>(&sql)
+            .bind(&like_pattern)
+            .bind(&like_pattern);
+        for tag in bound_tags(tags) {
+            like = like.bind(tag);
+        }
+        let rows = like.bind(MAX_ARTICLES).fetch_all(&self.pool).await?;
         Ok(rows.into_iter().map(ArticleDbRow::into_article).collect())
     }
 
@@ -374,7 +523,8 @@ impl Database {
 
 #[cfg(test)]
 mod tests {
-    use crate::storage::{Database, OpmlFeed, ParsedArticle, SearchScope};
+    use super::{split_tag_filters, split_time_filter, ReadingTime};
+    use crate::storage::{Article, Database, OpmlFeed, ParsedArticle, SearchScope};
 
     async fn test_db() -> Database {
         Database::open(":memory:").await.unwrap()
@@ -395,6 +545,7 @@ mod tests {
             url: Some(format!("https://example.com/{}", guid)),
             published: Some(1704067200),
             summary: Some("Test summary".to_string()),
+            author: None,
         }
     }
 
@@ -635,6 +786,57 @@ mod tests {
         assert_eq!(report.fts_count, 2);
     }
 
+    #[test]
+    fn test_split_tag_filters() {
+        assert_eq!(
+            split_tag_filters("rust  \"async io\""),
+            ("rust  \"async io\"".to_string(), vec![])
+        );
+        assert_eq!(
+            split_tag_filters("TAG:Reference rust tag:ref tag:reference tag:"),
+            (
+                "rust tag:".to_string(),
+                vec!["reference".to_string(), "ref".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_split_time_filter() {
+        assert_eq!(
+            split_time_filter("rust  io"),
+            ("rust  io".to_string(), None)
+        );
+        assert_eq!(
+            split_time_filter("time:>3 rust TIME:<5min time:5 time:<x"),
+            (
+                "rust time:5 time:<x".to_string(),
+                Some(ReadingTime::AtMost(5))
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_reading_time() {
+        let db = test_db().await;
+        db.sync_feeds(&[test_feed(1)]).await.unwrap();
+        let feeds = db.get_feeds_with_unread_counts().await.unwrap();
+        let mut long = test_article("2", "Rust Deep Dive");
+        long.summary = Some("word ".repeat(4000));
+        db.upsert_articles(feeds[0].id, &[test_article("1", "Rust Quick Tip"), long])
+            .await
+            .unwrap();
+
+        let titles = |results: Vec<Article>| -> Vec<String> {
+            results.iter().map(|a| a.title.to_string()).collect()
+        };
+        let scope = SearchScope::TitleAndSummary;
+        let results = db.search_articles("rust time:<5", scope).await.unwrap();
+        assert_eq!(titles(results), vec!["Rust Quick Tip"]);
+        let results = db.search_articles("time:>5", scope).await.unwrap();
+        assert_eq!(titles(results), vec!["Rust Deep Dive"]);
+    }
+
     #[test]
     fn test_validate_fts_query_length_limit() {
         // Build a query at MAX_SEARCH_QUERY_LENGTH using short terms to avoid SEC-011 term limit
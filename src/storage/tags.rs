@@ -0,0 +1,387 @@
+use anyhow::Result;
+
+use super::schema::Database;
+use super::types::{TagCount, TaggedArticle};
+
+/// Built-in tag backed by the `starred` flag rather than `article_tags`.
+pub const STARRED_TAG: &str = "starred";
+
+/// Built-in tag backed by the `archived` flag rather than `article_tags`.
+pub const ARCHIVED_TAG: &str = "archived";
+
+/// Whether `tag` is one of the built-in tags, which can't be set with `set_article_tag`.
+fn is_builtin(tag: &str) -> bool {
+    tag == STARRED_TAG || tag == ARCHIVED_TAG
+}
+
+/// Column expression listing an article's tags, space-separated (tags never
+/// contain whitespace, see [`crate::util::normalize_tag`]). Decoded by
+/// `ArticleDbRow::into_article`.
+pub(super) const ARTICLE_TAGS: &str =
+    "(SELECT group_concat(t.tag, ' ') FROM article_tags t WHERE t.article_id = articles.id) AS tags";
+
+/// [`ARTICLE_TAGS`] for a query that aliases `articles` as `alias`.
+pub(super) fn article_tags_for(alias: &str) -> String {
+    ARTICLE_TAGS.replace("articles.id", &format!("{}.id", alias))
+}
+
+/// SQL conditions restricting `alias` to articles carrying every tag in `tags`.
+///
+/// Each tag other than a built-in one adds one `?` placeholder; bind the
+/// tags in order (see [`bound_tags`]) where the fragment is placed.
+pub(super) fn tag_conditions_for(alias: &str, tags: &[String]) -> String {
+    tags.iter()
+        .map(|tag| {
+            if tag == STARRED_TAG {
+                format!(" AND {}.starred = 1", alias)
+            } else if tag == ARCHIVED_TAG {
+                format!(" AND {}.archived = 1", alias)
+            } else {
+                format!(
+                    " AND {}.id IN (SELECT article_id FROM article_tags WHERE tag = ?)",
+                    alias
+                )
+            }
+        })
+        .collect()
+}
+
+/// Tags from [`tag_conditions_for`] that need a bound parameter.
+pub(super) fn bound_tags(tags: &[String]) -> impl Iterator<Item = &String> {
+    tags.iter().filter(|t| !is_builtin(t))
+}
+
+impl Database {
+    // ========================================================================
+    // Tag Operations
+    // ========================================================================
+
+    /// Every tag in use with its article and unread counts, alphabetically.
+    ///
+    /// The built-in [`STARRED_TAG`] comes first (even when nothing is starred)
+    /// so the sidebar and tag picker always offer it, followed by
+    /// [`ARCHIVED_TAG`] once anything is archived.
+    pub async fn get_tag_counts(&self) -> Result<Vec<TagCount>> {
+        let (starred, starred_unread, archived, archived_unread): (
+            Option<i64>,
+            Option<i64>,
+            Option<i64>,
+            Option<i64>,
+        ) = sqlx::query_as(
+            "SELECT SUM(starred = 1), SUM(starred = 1 AND read = 0), \
+                    SUM(archived = 1), SUM(archived = 1 AND read = 0) \
+             FROM articles WHERE hidden = 0",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        let rows: Vec<(String, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT t.tag, COUNT(*), SUM(a.read = 0)
+            FROM article_tags t
+            JOIN articles a ON a.id = t.article_id
+            WHERE a.hidden = 0
+            GROUP BY t.tag
+            ORDER BY t.tag
+        "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut tags = Vec::with_capacity(rows.len() + 2);
+        tags.push(TagCount {
+            tag: STARRED_TAG.to_string(),
+            articles: starred.unwrap_or(0),
+            unread: starred_unread.unwrap_or(0),
+        });
+        if let Some(archived) = archived.filter(|&n| n > 0) {
+            tags.push(TagCount {
+                tag: ARCHIVED_TAG.to_string(),
+                articles: archived,
+                unread: archived_unread.unwrap_or(0),
+            });
+        }
+        tags.extend(rows.into_iter().filter(|(tag, _, _)| !is_builtin(tag)).map(
+            |(tag, articles, unread)| TagCount {
+                tag,
+                articles,
+                unread,
+            },
+        ));
+        Ok(tags)
+    }
+
+    /// Add or remove a tag on an article, returning the article's tags afterwards.
+    ///
+    /// `tag` must already be normalized. Built-in tags are rejected: starring
+    /// and archiving go through `toggle_article_starred` / `toggle_article_archived`.
+    pub async fn set_article_tag(
+        &self,
+        article_id: i64,
+        tag: &str,
+        tagged: bool,
+    ) -> Result<Vec<String>> {
+        if is_builtin(tag) {
+            anyhow::bail!(
+                "'{}' is a built-in tag; star or archive the article instead",
+                tag
+            );
+        }
+        if tagged {
+            sqlx::query("INSERT OR IGNORE INTO article_tags (article_id, tag) VALUES (?, ?)")
+                .bind(article_id)
+                .bind(tag)
+                .execute(&self.pool)
+                .await?;
+        } else {
+            sqlx::query("DELETE FROM article_tags WHERE article_id = ? AND tag = ?")
+                .bind(article_id)
+                .bind(tag)
+                .execute(&self.pool)
+                .await?;
+        }
+        let tags =
+            sqlx::query_scalar("SELECT tag FROM article_tags WHERE article_id = ? ORDER BY tag")
+                .bind(article_id)
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(tags)
+    }
+
+    /// Every article carrying a tag, built-in or not, newest first, for
+    /// `--export-articles`. Hidden articles are left out.
+    pub async fn get_tagged_articles_for_export(&self) -> Result<Vec<TaggedArticle>> {
+        type Row = (
+            String,
+            Option<String>,
+            Option<i64>,
+            bool,
+            bool,
+            bool,
+            String,
+            String,
+            Option<String>,
+        );
+        let rows: Vec<Row> = sqlx::query_as(&format!(
+            r#"
+            SELECT a.title, a.url, a.published, a.read, a.starred, a.archived,
+                   f.title, f.url, {}
+            FROM articles a
+            JOIN feeds f ON f.id = a.feed_id
+            WHERE a.hidden = 0 AND (a.starred = 1 OR a.archived = 1
+                  OR EXISTS (SELECT 1 FROM article_tags t WHERE t.article_id = a.id))
+            ORDER BY a.published DESC, a.id DESC
+        "#,
+            article_tags_for("a")
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(title, url, published, read, starred, archived, feed_title, feed_url, tags)| {
+                    let mut user_tags: Vec<String> = tags
+                        .as_deref()
+                        .unwrap_or_default()
+                        .split_whitespace()
+                        .map(str::to_string)
+                        .collect();
+                    user_tags.sort();
+                    let builtin = [(starred, STARRED_TAG), (archived, ARCHIVED_TAG)];
+                    let tags = builtin
+                        .into_iter()
+                        .filter(|&(set, _)| set)
+                        .map(|(_, tag)| tag.to_string())
+                        .chain(user_tags)
+                        .collect();
+                    TaggedArticle {
+                        title,
+                        url,
+                        published,
+                        read,
+                        feed_title,
+                        feed_url,
+                        tags,
+                    }
+                },
+            )
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ARCHIVED_TAG, STARRED_TAG};
+    use crate::rewrite::RewriteSet;
+    use crate::rules::RuleSet;
+    use crate::storage::{Database, OpmlFeed, ParsedArticle, SearchScope};
+
+    async fn setup() -> (Database, Vec<i64>) {
+        let db = Database::open(":memory:").await.unwrap();
+        db.sync_feeds(&[OpmlFeed {
+            title: "Feed".to_string(),
+            xml_url: "https://example.com/feed.xml".to_string(),
+            html_url: None,
+        }])
+        .await
+        .unwrap();
+        let feed_id = db.get_feeds_with_unread_counts().await.unwrap()[0].id;
+        let articles: Vec<ParsedArticle> = ["Rust release", "Go release", "Cooking"]
+            .iter()
+            .enumerate()
+            .map(|(i, title)| ParsedArticle {
+                guid: i.to_string(),
+                title: title.to_string(),
+                url: Some(format!("https://example.com/{}", i)),
+                published: Some(1704067200 + i as i64),
+                summary: None,
+                author: None,
+            })
+            .collect();
+        db.complete_feed_refresh(
+            feed_id,
+            &articles,
+            &RuleSet::default(),
+            &RewriteSet::default(),
+        )
+        .await
+        .unwrap();
+        let mut ids: Vec<i64> = db
+            .get_articles_for_feed(feed_id, None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|a| a.id)
+            .collect();
+        ids.sort();
+        (db, ids)
+    }
+
+    #[tokio::test]
+    async fn test_set_article_tag_and_counts() {
+        let (db, ids) = setup().await;
+        assert_eq!(
+            db.set_article_tag(ids[0], "reference", true).await.unwrap(),
+            ["reference"]
+        );
+        db.set_article_tag(ids[0], "share", true).await.unwrap();
+        db.set_article_tag(ids[1], "reference", true).await.unwrap();
+        db.mark_article_read(ids[1]).await.unwrap();
+
+        let article = db.get_article_by_id(ids[0]).await.unwrap().unwrap();
+        let tags: Vec<&str> = article.tags.iter().map(|t| &**t).collect();
+        assert_eq!(tags, ["reference", "share"]);
+
+        let counts = db.get_tag_counts().await.unwrap();
+        let summary: Vec<(&str, i64, i64)> = counts
+            .iter()
+            .map(|c| (c.tag.as_str(), c.articles, c.unread))
+            .collect();
+        assert_eq!(
+            summary,
+            [(STARRED_TAG, 0, 0), ("reference", 2, 1), ("share", 1, 1)]
+        );
+
+        // Removing is idempotent
+        assert!(db
+            .set_article_tag(ids[0], "share", false)
+            .await
+            .unwrap()
+            .iter()
+            .all(|t| t != "share"));
+        db.set_article_tag(ids[0], "share", false).await.unwrap();
+        assert!(db.set_article_tag(ids[0], STARRED_TAG, true).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tag_search_filter() {
+        let (db, ids) = setup().await;
+        db.set_article_tag(ids[0], "lang", true).await.unwrap();
+        db.set_article_tag(ids[1], "lang", true).await.unwrap();
+        db.toggle_article_starred(ids[1]).await.unwrap();
+
+        let titles = |articles: Vec<crate::storage::Article>| -> Vec<String> {
+            let mut t: Vec<String> = articles.iter().map(|a| a.title.to_string()).collect();
+            t.sort();
+            t
+        };
+
+        // Tag-only query lists every tagged article
+        let found = db
+            .search_articles("tag:lang", SearchScope::TitleAndSummary)
+            .await
+            .unwrap();
+        assert_eq!(titles(found), ["Go release", "Rust release"]);
+
+        // Combined with text, and with the built-in starred tag
+        let found = db
+            .search_articles("release tag:LANG tag:starred", SearchScope::TitleAndSummary)
+            .await
+            .unwrap();
+        assert_eq!(titles(found), ["Go release"]);
+
+        let found = db.get_tagged_articles("lang").await.unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(db.get_tagged_articles("missing").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tagged_articles_for_export() {
+        let (db, ids) = setup().await;
+        assert!(db
+            .get_tagged_articles_for_export()
+            .await
+            .unwrap()
+            .is_empty());
+
+        db.set_article_tag(ids[0], "share", true).await.unwrap();
+        db.set_article_tag(ids[0], "reference", true).await.unwrap();
+        db.toggle_article_starred(ids[0]).await.unwrap();
+        db.toggle_article_archived(ids[1]).await.unwrap();
+
+        // Untagged articles are left out; newest first
+        let exported = db.get_tagged_articles_for_export().await.unwrap();
+        let summary: Vec<(&str, Vec<&str>)> = exported
+            .iter()
+            .map(|a| {
+                (
+                    a.title.as_str(),
+                    a.tags.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("Go release", vec![ARCHIVED_TAG]),
+                ("Rust release", vec![STARRED_TAG, "reference", "share"]),
+            ]
+        );
+        assert_eq!(exported[1].url.as_deref(), Some("https://example.com/0"));
+        assert_eq!(exported[1].feed_title, "Feed");
+        assert_eq!(exported[1].feed_url, "https://example.com/feed.xml");
+    }
+
+    #[tokio::test]
+    async fn test_archived_builtin_tag() {
+        let (db, ids) = setup().await;
+        let tags = db.get_tag_counts().await.unwrap();
+        assert!(tags.iter().all(|t| t.tag != ARCHIVED_TAG));
+
+        db.toggle_article_archived(ids[2]).await.unwrap();
+        let tags = db.get_tag_counts().await.unwrap();
+        assert_eq!(tags[1].tag, ARCHIVED_TAG);
+        assert_eq!((tags[1].articles, tags[1].unread), (1, 1));
+
+        let found = db
+            .search_articles("tag:archived", SearchScope::TitleAndSummary)
+            .await
+            .unwrap();
+        assert_eq!(&*found[0].title, "Cooking");
+        assert!(found[0].archived);
+        assert!(db
+            .set_article_tag(ids[0], ARCHIVED_TAG, true)
+            .await
+            .is_err());
+    }
+}
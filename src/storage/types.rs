@@ -120,6 +120,8 @@ pub struct ReadingStats {
     pub articles_per_day: f64,
     pub total_minutes: u64,
     pub top_feeds: Vec<(String, u32)>,
+    /// Estimated words read per day (see `Database::get_reading_stats`).
+    pub words_per_day: u64,
 }
 
 /// A single entry from the reading history log.
@@ -137,6 +139,151 @@ pub struct ReadingHistoryEntry {
     pub duration_seconds: Option<i64>,
 }
 
+// ============================================================================
+// Feed Health Types
+// ============================================================================
+
+/// Outcome of a single fetch attempt, stored in `feed_fetch_log.status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchOutcome {
+    /// Feed fetched and parsed successfully
+    Ok,
+    /// Network, HTTP, parse or storage failure
+    Error,
+    /// Server asked us to come back later (Retry-After)
+    Deferred,
+    /// Server answered 410 Gone
+    Gone,
+}
+
+impl FetchOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Error => "error",
+            Self::Deferred => "deferred",
+            Self::Gone => "gone",
+        }
+    }
+}
+
+/// One row of the per-fetch history log.
+#[derive(Debug, Clone)]
+pub struct FetchLogEntry {
+    pub feed_id: i64,
+    pub outcome: FetchOutcome,
+    /// HTTP status of the final response, if one was received
+    pub http_status: Option<u16>,
+    /// Body size in bytes, if the body was read
+    pub bytes: Option<u64>,
+    pub latency_ms: u64,
+    pub new_articles: usize,
+    pub error: Option<String>,
+}
+
+/// Per-feed reliability summary for the feed health view.
+///
+/// Fetch figures cover the rows still retained in `feed_fetch_log`.
+#[derive(Debug, Clone)]
+pub struct FeedHealth {
+    pub feed_id: i64,
+    pub title: String,
+    pub url: String,
+    pub html_url: Option<String>,
+    pub error: Option<String>,
+    pub consecutive_failures: i64,
+    pub gone: bool,
+    pub fetch_attempts: i64,
+    pub fetch_failures: i64,
+    /// Mean latency of successful fetches
+    pub avg_latency_ms: Option<i64>,
+    /// Newest article publish time
+    pub last_published: Option<i64>,
+    /// Articles published per week over the last 90 days
+    pub posts_per_week: f64,
+}
+
+// ============================================================================
+// Revision Types
+// ============================================================================
+
+/// What an article revision captured, stored in `article_revisions.kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevisionKind {
+    /// Feed entry fields (title, URL, publish date, summary)
+    Metadata,
+    /// Full content fetched into `content_cache`
+    Content,
+}
+
+impl RevisionKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Metadata => "metadata",
+            Self::Content => "content",
+        }
+    }
+
+    pub(crate) fn from_db(s: &str) -> Self {
+        if s == "content" {
+            Self::Content
+        } else {
+            Self::Metadata
+        }
+    }
+}
+
+/// A previous version of an article, replaced at `replaced_at` (Unix seconds).
+///
+/// Metadata revisions fill the entry fields; content revisions fill `content`.
+#[derive(Debug, Clone)]
+#[allow(dead_code)] // id/article_id identify the row for library consumers
+pub struct ArticleRevision {
+    pub id: i64,
+    pub article_id: i64,
+    pub kind: RevisionKind,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub published: Option<i64>,
+    pub summary: Option<String>,
+    pub content: Option<String>,
+    pub replaced_at: i64,
+}
+
+// ============================================================================
+// Rule Types
+// ============================================================================
+
+/// A tag with the number of (non-hidden) articles carrying it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagCount {
+    pub tag: String,
+    pub articles: i64,
+    pub unread: i64,
+}
+
+/// An article with its tags, as written by `--export-articles`.
+#[derive(Debug, Clone)]
+pub struct TaggedArticle {
+    pub title: String,
+    pub url: Option<String>,
+    pub published: Option<i64>,
+    pub read: bool,
+    pub feed_title: String,
+    pub feed_url: String,
+    /// Sorted, with the built-in `starred` and `archived` tags first when set.
+    pub tags: Vec<String>,
+}
+
+/// How often an ingest rule has fired, from `rule_hits`.
+#[derive(Debug, Clone)]
+pub struct RuleHits {
+    pub rule: String,
+    pub hits: i64,
+    /// Last time the rule matched an article (Unix seconds)
+    pub last_hit_at: i64,
+}
+
 // ============================================================================
 // Content Cache Types
 // ============================================================================
@@ -150,18 +297,62 @@ pub struct ReadingHistoryEntry {
 pub struct CachedContent {
     pub article_id: i64,
     pub markdown: String,
+    /// `markdown` after `[[rewrites]]`, or `None` when no rewrite applied.
+    pub markdown_rewritten: Option<String>,
     pub fetched_at: String,
     pub expires_at: String,
     pub size_bytes: i64,
 }
 
+/// Original text of one entry, for previewing rewrites.
+pub struct RewritePreview {
+    pub title: String,
+    pub url: Option<String>,
+    pub summary: Option<String>,
+    /// Cached article content, if any
+    pub markdown: Option<String>,
+}
+
 /// Aggregate statistics for the content cache.
-#[allow(dead_code)] // Consumed by TASK-8 (stats panel)
+#[derive(Debug, Default)]
 pub struct CacheStats {
     pub total_entries: i64,
+    /// Uncompressed markdown size.
     pub total_size_bytes: i64,
+    /// Size on disk after compression.
+    pub stored_size_bytes: i64,
+    #[allow(dead_code)] // Reported by cache tests only
     pub oldest_entry: Option<String>,
+    #[allow(dead_code)] // Reported by cache tests only
     pub newest_entry: Option<String>,
+    /// Entries of starred, archived or queued articles, which never expire.
+    pub pinned_entries: i64,
+    pub pinned_size_bytes: i64,
+}
+
+/// One cache entry, for the cache manager's largest-entries list.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CacheEntry {
+    #[allow(dead_code)] // Read by cache tests only
+    pub article_id: i64,
+    pub title: String,
+    pub feed_title: String,
+    pub size_bytes: i64,
+    pub stored_bytes: i64,
+    /// Starred, archived or queued: never expires or gets evicted.
+    pub pinned: bool,
+    pub last_accessed_at: String,
+}
+
+/// Cache usage of one feed, for the cache manager.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct FeedCacheUsage {
+    pub feed_id: i64,
+    pub title: String,
+    pub entries: i64,
+    pub size_bytes: i64,
+    pub stored_bytes: i64,
+    pub pinned_entries: i64,
 }
 
 // ============================================================================
@@ -179,6 +370,12 @@ pub(crate) type FeedRow = (
     i64,
     i64,         // consecutive_failures
     Option<i64>, // category_id
+    bool,        // gone
+    bool,        // url_auto_updated
+    Option<i64>, // next_fetch_at
+    bool,        // prefer_source
+    bool,        // unread_on_update
+    bool,        // auto_prefetch
 );
 
 /// Represents a feed imported from OPML
@@ -197,6 +394,26 @@ pub struct ParsedArticle {
     pub url: Option<String>,
     pub published: Option<i64>,
     pub summary: Option<String>,
+    pub author: Option<String>,
+}
+
+/// Outcome of upserting a batch of feed entries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpsertCounts {
+    /// Entries not seen before
+    pub inserted: usize,
+    /// Existing entries whose metadata changed and was rewritten
+    pub updated: usize,
+    /// Existing entries left untouched
+    pub unchanged: usize,
+}
+
+impl std::ops::AddAssign for UpsertCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.inserted += other.inserted;
+        self.updated += other.updated;
+        self.unchanged += other.unchanged;
+    }
 }
 
 /// Internal row type for Article queries (used by sqlx FromRow)
@@ -213,7 +430,13 @@ pub(crate) struct ArticleDbRow {
     pub content: Option<String>,
     pub read: bool,
     pub starred: bool,
+    pub archived: bool,
     pub fetched_at: i64,
+    pub updated_at: Option<i64>,
+    pub priority: i64,
+    pub word_count: Option<i64>,
+    /// Space-separated tags (see `storage::tags::ARTICLE_TAGS`)
+    pub tags: Option<String>,
 }
 
 impl ArticleDbRow {
@@ -232,11 +455,27 @@ impl ArticleDbRow {
             content: self.content.map(|c| Arc::from(strip_control_chars(&c))),
             read: self.read,
             starred: self.starred,
+            archived: self.archived,
             fetched_at: self.fetched_at,
+            updated_at: self.updated_at,
+            priority: self.priority,
+            word_count: self.word_count.and_then(|w| usize::try_from(w).ok()),
+            tags: split_tags(self.tags.as_deref()),
         }
     }
 }
 
+/// Split the `tags` column of an article query into sorted tags.
+fn split_tags(tags: Option<&str>) -> Vec<Arc<str>> {
+    let mut tags: Vec<Arc<str>> = tags
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|t| Arc::from(strip_control_chars(t)))
+        .collect();
+    tags.sort();
+    tags
+}
+
 /// Article with feed_id for batch queries (used in get_recent_articles_for_feeds)
 #[derive(Debug, sqlx::FromRow)]
 pub(crate) struct ArticleRow {
@@ -250,7 +489,13 @@ pub(crate) struct ArticleRow {
     pub content: Option<String>,
     pub read: bool,
     pub starred: bool,
+    pub archived: bool,
     pub fetched_at: i64,
+    pub updated_at: Option<i64>,
+    pub priority: i64,
+    pub word_count: Option<i64>,
+    /// Space-separated tags (see `storage::tags::ARTICLE_TAGS`)
+    pub tags: Option<String>,
 }
 
 impl ArticleRow {
@@ -269,7 +514,12 @@ impl ArticleRow {
                 content: self.content.map(|c| Arc::from(strip_control_chars(&c))),
                 read: self.read,
                 starred: self.starred,
+                archived: self.archived,
                 fetched_at: self.fetched_at,
+                updated_at: self.updated_at,
+                priority: self.priority,
+                word_count: self.word_count.and_then(|w| usize::try_from(w).ok()),
+                tags: split_tags(self.tags.as_deref()),
             },
         )
     }
@@ -298,6 +548,25 @@ pub struct Feed {
     /// Read by TASK-8 (category sidebar filter) via `filtered_feeds()`.
     #[allow(dead_code)]
     pub category_id: Option<i64>,
+    /// Feed responded 410 Gone; skipped by bulk refresh until a manual retry succeeds.
+    pub gone: bool,
+    /// `url` was rewritten after a permanent redirect (old URL kept as an alias).
+    pub url_auto_updated: bool,
+    /// Adaptive polling: bulk refresh skips the feed until this time (Unix seconds).
+    pub next_fetch_at: Option<i64>,
+    /// Merged views show this feed's copy of stories duplicated across feeds.
+    pub prefer_source: bool,
+    /// Articles the publisher edits are flipped back to unread.
+    pub unread_on_update: bool,
+    /// New unread articles are prefetched after each refresh.
+    pub auto_prefetch: bool,
+}
+
+impl Feed {
+    /// Whether this is the built-in "Saved" pseudo-feed (no URL to fetch).
+    pub fn is_saved(&self) -> bool {
+        self.url == super::SAVED_FEED_URL
+    }
 }
 
 /// Article data from database
@@ -325,7 +594,19 @@ pub struct Article {
     pub content: Option<Arc<str>>,
     pub read: bool,
     pub starred: bool,
+    /// Kept for offline reading: cached content never expires.
+    pub archived: bool,
     pub fetched_at: i64,
+    /// Last time the publisher edited the entry or its content (Unix seconds).
+    pub updated_at: Option<i64>,
+    /// Set by rule actions; higher sorts first (0 by default).
+    pub priority: i64,
+    /// Words in the fetched content, or in the feed's summary until the
+    /// content is fetched (`None` when neither is known).
+    pub word_count: Option<usize>,
+    /// User and rule tags, sorted. Starring and archiving are not tags here
+    /// (see `starred` and `archived`).
+    pub tags: Vec<Arc<str>>,
 }
 
 #[cfg(test)]
@@ -84,11 +84,30 @@ pub struct ColorPalette {
     pub reader_metadata: Style,
     pub reader_code_block: Style,
     pub reader_inline_code: Style,
+    // Code block tokens, patched over `reader_code_block`
+    pub reader_code_keyword: Style,
+    pub reader_code_string: Style,
+    pub reader_code_comment: Style,
+    pub reader_code_number: Style,
+    pub reader_code_type: Style,
     pub reader_emphasis: Style,
     pub reader_strong: Style,
     pub reader_image: Style,
     pub reader_error: Style,
     pub reader_fallback: Style,
+    pub reader_diff_added: Style,
+    pub reader_diff_removed: Style,
+    pub reader_list_marker: Style,
+    pub reader_quote: Style,
+    pub reader_table_border: Style,
+    pub reader_table_header: Style,
+    pub reader_task: Style,
+    pub reader_strikethrough: Style,
+    pub reader_rule: Style,
+    pub reader_footnote: Style,
+    pub reader_link: Style,
+    pub reader_search_match: Style,
+    pub reader_search_current: Style,
 
     // -- Chrome --
     pub status_bar: Style,
@@ -128,11 +147,38 @@ impl ColorPalette {
             reader_metadata: Style::default().fg(Color::DarkGray),
             reader_code_block: Style::default().fg(Color::Yellow).bg(Color::Black),
             reader_inline_code: Style::default().fg(Color::Yellow),
+            reader_code_keyword: Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+            reader_code_string: Style::default().fg(Color::LightGreen),
+            reader_code_comment: Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+            reader_code_number: Style::default().fg(Color::LightCyan),
+            reader_code_type: Style::default().fg(Color::LightBlue),
             reader_emphasis: Style::default().add_modifier(Modifier::ITALIC),
             reader_strong: Style::default().add_modifier(Modifier::BOLD),
             reader_image: Style::default().fg(Color::Blue),
             reader_error: Style::default().fg(Color::Red),
             reader_fallback: Style::default().fg(Color::Yellow),
+            reader_diff_added: Style::default().fg(Color::Green),
+            reader_diff_removed: Style::default().fg(Color::Red),
+            reader_list_marker: Style::default().fg(Color::Cyan),
+            reader_quote: Style::default().fg(Color::Gray),
+            reader_table_border: Style::default().fg(Color::DarkGray),
+            reader_table_header: Style::default().add_modifier(Modifier::BOLD),
+            reader_task: Style::default().fg(Color::Green),
+            reader_strikethrough: Style::default().add_modifier(Modifier::CROSSED_OUT),
+            reader_rule: Style::default().fg(Color::DarkGray),
+            reader_footnote: Style::default().fg(Color::Magenta),
+            reader_link: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::UNDERLINED),
+            reader_search_match: Style::default().fg(Color::Black).bg(Color::Yellow),
+            reader_search_current: Style::default()
+                .fg(Color::Black)
+                .bg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
 
             // Chrome
             status_bar: Style::default().bg(Color::DarkGray).fg(Color::White),
@@ -179,11 +225,40 @@ impl ColorPalette {
             reader_metadata: Style::default().fg(Color::DarkGray),
             reader_code_block: Style::default().fg(Color::DarkGray).bg(Color::White),
             reader_inline_code: Style::default().fg(Color::DarkGray),
+            reader_code_keyword: Style::default()
+                .fg(Color::Magenta)
+                .add_modifier(Modifier::BOLD),
+            reader_code_string: Style::default().fg(Color::Green),
+            reader_code_comment: Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::ITALIC),
+            reader_code_number: Style::default().fg(Color::Red),
+            reader_code_type: Style::default().fg(Color::Blue),
             reader_emphasis: Style::default().add_modifier(Modifier::ITALIC),
             reader_strong: Style::default().add_modifier(Modifier::BOLD),
             reader_image: Style::default().fg(Color::Blue),
             reader_error: Style::default().fg(Color::Red),
             reader_fallback: Style::default().fg(Color::Magenta),
+            reader_diff_added: Style::default().fg(Color::Green),
+            reader_diff_removed: Style::default().fg(Color::Red),
+            reader_list_marker: Style::default().fg(Color::Blue),
+            reader_quote: Style::default().fg(Color::DarkGray),
+            reader_table_border: Style::default().fg(Color::DarkGray),
+            reader_table_header: Style::default()
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+            reader_task: Style::default().fg(Color::Green),
+            reader_strikethrough: Style::default().add_modifier(Modifier::CROSSED_OUT),
+            reader_rule: Style::default().fg(Color::DarkGray),
+            reader_footnote: Style::default().fg(Color::Magenta),
+            reader_link: Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::UNDERLINED),
+            reader_search_match: Style::default().fg(Color::Black).bg(Color::LightYellow),
+            reader_search_current: Style::default()
+                .fg(Color::White)
+                .bg(Color::Red)
+                .add_modifier(Modifier::BOLD),
 
             // Chrome
             status_bar: Style::default().bg(Color::White).fg(Color::Black),
@@ -218,7 +293,7 @@ pub struct StyleMap {
 }
 
 /// All semantic role names, in declaration order.
-const ROLE_NAMES: [&str; 27] = [
+const ROLE_NAMES: [&str; 45] = [
     "feed_normal",
     "feed_selected",
     "feed_unread",
@@ -234,11 +309,29 @@ const ROLE_NAMES: [&str; 27] = [
     "reader_metadata",
     "reader_code_block",
     "reader_inline_code",
+    "reader_code_keyword",
+    "reader_code_string",
+    "reader_code_comment",
+    "reader_code_number",
+    "reader_code_type",
     "reader_emphasis",
     "reader_strong",
     "reader_image",
     "reader_error",
     "reader_fallback",
+    "reader_diff_added",
+    "reader_diff_removed",
+    "reader_list_marker",
+    "reader_quote",
+    "reader_table_border",
+    "reader_table_header",
+    "reader_task",
+    "reader_strikethrough",
+    "reader_rule",
+    "reader_footnote",
+    "reader_link",
+    "reader_search_match",
+    "reader_search_current",
     "status_bar",
     "panel_border",
     "panel_border_focused",
@@ -251,7 +344,7 @@ const ROLE_NAMES: [&str; 27] = [
 impl StyleMap {
     /// Build a `StyleMap` from a `ColorPalette`.
     pub fn from_palette(p: &ColorPalette) -> Self {
-        let styles: [Style; 27] = [
+        let styles: [Style; 45] = [
             p.feed_normal,
             p.feed_selected,
             p.feed_unread,
@@ -267,11 +360,29 @@ impl StyleMap {
             p.reader_metadata,
             p.reader_code_block,
             p.reader_inline_code,
+            p.reader_code_keyword,
+            p.reader_code_string,
+            p.reader_code_comment,
+            p.reader_code_number,
+            p.reader_code_type,
             p.reader_emphasis,
             p.reader_strong,
             p.reader_image,
             p.reader_error,
             p.reader_fallback,
+            p.reader_diff_added,
+            p.reader_diff_removed,
+            p.reader_list_marker,
+            p.reader_quote,
+            p.reader_table_border,
+            p.reader_table_header,
+            p.reader_task,
+            p.reader_strikethrough,
+            p.reader_rule,
+            p.reader_footnote,
+            p.reader_link,
+            p.reader_search_match,
+            p.reader_search_current,
             p.status_bar,
             p.panel_border,
             p.panel_border_focused,
@@ -290,8 +401,8 @@ impl StyleMap {
     }
 
     /// Resolve a role name to its `Style`. Returns `Style::default()` for unknown roles.
-    // PERF-021: HashMap lookup is O(1) amortized for 27 entries (~465ns/frame).
-    // If per-row styling needed, consider enum-indexed [Style; 27] array.
+    // PERF-021: HashMap lookup is O(1) amortized for 45 entries (~465ns/frame).
+    // If per-row styling needed, consider enum-indexed [Style; 45] array.
     pub fn resolve(&self, role: &str) -> Style {
         self.map.get(role).copied().unwrap_or_default()
     }
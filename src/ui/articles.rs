@@ -1,4 +1,5 @@
-use crate::app::{App, Focus};
+use crate::app::{App, Focus, StarredView};
+use crate::ui::Hyperlink;
 use crate::util::{display_width, truncate_to_width};
 use chrono::{DateTime, Utc};
 use ratatui::{
@@ -49,12 +50,14 @@ pub fn format_relative_time_with_now(timestamp: Option<i64>, now: i64) -> String
         .unwrap_or_else(|| "Unknown date".to_string())
 }
 
-/// Render the article list panel
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
+/// Render the article list panel.
+///
+/// Returns hyperlinks for the visible article titles when they're enabled.
+pub fn render(f: &mut Frame, app: &App, area: Rect) -> Vec<Hyperlink> {
     // EDGE-001: Guard against zero-width/height areas
     // Layout may produce zero-sized rects during extreme terminal resizes
     if area.width < 3 || area.height < 3 {
-        return;
+        return Vec::new();
     }
 
     let is_focused = app.focus == Focus::Articles;
@@ -67,6 +70,16 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let style_read = app.style("article_read");
     let style_date = app.style("article_date");
 
+    // Column and width of each article's title, for hyperlinks
+    let mut title_columns = Vec::new();
+    // TL;DR lines shown under the selected article
+    let preview = app
+        .articles
+        .get(app.selected_article)
+        .filter(|_| app.reader_layout.tldr_preview)
+        .and_then(|article| app.tldr_previews.get(&article.id))
+        .map(Vec::as_slice)
+        .unwrap_or_default();
     let items: Vec<ListItem> = if app.articles.is_empty() {
         // EDGE-006: Contextual empty message
         if app.search_mode && !app.search_input.is_empty() {
@@ -81,8 +94,18 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         }
     } else {
         let mut items = Vec::with_capacity(app.articles.len());
+        let continue_reading = app.starred_mode && app.starred_view == StarredView::ContinueReading;
         for (i, article) in app.articles.iter().enumerate() {
-            let time_str = format_relative_time(article.published);
+            // How far it was read instead of its age under "Continue reading"
+            let age = match app.reading_positions.get(&article.id) {
+                Some(position) if continue_reading => format!("{:.0}% read", position * 100.0),
+                _ => format_relative_time(article.published),
+            };
+            let time_str = match app.reading_minutes(article) {
+                Some(minutes) if age.is_empty() => format!("{} min read", minutes),
+                Some(minutes) => format!("{} min read · {}", minutes, age),
+                None => age,
+            };
 
             // PERF-014: In starred mode, use cached feed prefix to avoid per-render allocations
             // The prefix cache is populated when entering starred mode
@@ -92,25 +115,56 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 None
             };
 
-            // Build line with star, cache indicator, feed name (starred mode), title, and time
-            // Pre-allocate spans: at most 5 (star, cache, feed, title, time)
-            let mut spans = Vec::with_capacity(5);
+            // Build line with star, cache indicator, updated, read-later and priority
+            // markers, feed name (starred mode), title, tag chips, and time
+            // Pre-allocate spans: at most 9
+            let mut spans = Vec::with_capacity(9);
 
             // Star indicator
             if article.starred {
                 spans.push(Span::styled("★ ", style_star));
             }
 
-            // Cache indicator
+            // Cache indicator; archived articles (kept offline for good) get a square
             let is_cached = app.cached_article_set.contains(&article.id);
-            let cache_indicator = if is_cached {
-                Span::styled("● ", Style::default().fg(Color::Green))
+            let cache_symbol = match (article.archived, is_cached) {
+                (true, true) => "■ ",
+                (true, false) => "□ ",
+                (false, true) => "● ",
+                (false, false) => "○ ",
+            };
+            let cache_color = if is_cached {
+                Color::Green
             } else {
-                Span::styled("○ ", Style::default().fg(Color::DarkGray))
+                Color::DarkGray
             };
-            spans.push(cache_indicator);
+            spans.push(Span::styled(cache_symbol, Style::default().fg(cache_color)));
             let cache_width: usize = 2;
 
+            // Updated indicator: the publisher edited the article after it was fetched
+            let updated_width = if article.updated_at.is_some() {
+                spans.push(Span::styled("✎ ", style_date));
+                2
+            } else {
+                0
+            };
+
+            // Read-later indicator: the article is in the queue
+            let queued_width = if app.read_later_ids.contains(&article.id) {
+                spans.push(Span::styled("◷ ", style_date));
+                2
+            } else {
+                0
+            };
+
+            // Priority indicator: a rule raised this article's priority
+            let priority_width = if article.priority > 0 {
+                spans.push(Span::styled("! ", style_star));
+                2
+            } else {
+                0
+            };
+
             // Feed name prefix in starred mode
             let feed_prefix_width = if let Some(prefix) = feed_prefix {
                 spans.push(Span::styled(prefix.as_str(), style_feed_prefix));
@@ -136,11 +190,26 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             // Minimum padding between title and time
             let min_padding = 2;
 
-            // Max title width = available - star - cache - feed_prefix - time - padding
+            // Tag chips after the title, capped at a third of the row so the
+            // title stays readable
+            let chips = if article.tags.is_empty() {
+                String::new()
+            } else {
+                let all: String = article.tags.iter().map(|t| format!(" #{}", t)).collect();
+                truncate_to_width(&all, available_width / 3).into_owned()
+            };
+            let chips_width = display_width(&chips);
+
+            // Max title width = available - star - cache - updated - queued - priority
+            //                   - feed_prefix - tags - time - padding
             let max_title_len = available_width
                 .saturating_sub(star_width)
                 .saturating_sub(cache_width)
+                .saturating_sub(updated_width)
+                .saturating_sub(queued_width)
+                .saturating_sub(priority_width)
                 .saturating_sub(feed_prefix_width)
+                .saturating_sub(chips_width)
                 .saturating_sub(time_width)
                 .saturating_sub(min_padding);
 
@@ -148,12 +217,31 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             let title = truncate_to_width(&article.title, max_title_len);
             let title_width = display_width(&title);
 
+            if app.hyperlinks_enabled {
+                let column = star_width
+                    + cache_width
+                    + updated_width
+                    + queued_width
+                    + priority_width
+                    + feed_prefix_width;
+                title_columns.push((column, title_width));
+            }
             spans.push(Span::styled(title, title_style));
+            if !chips.is_empty() {
+                spans.push(Span::styled(chips, style_feed_prefix));
+            }
 
             // Right-align time: calculate padding to push to right edge
             if !time_str.is_empty() {
-                let used_width =
-                    star_width + cache_width + feed_prefix_width + title_width + time_width;
+                let used_width = star_width
+                    + cache_width
+                    + updated_width
+                    + queued_width
+                    + priority_width
+                    + feed_prefix_width
+                    + title_width
+                    + chips_width
+                    + time_width;
                 let padding = available_width.saturating_sub(used_width);
                 spans.push(Span::styled(
                     format!("{:>width$}", time_str, width = padding + time_width),
@@ -161,7 +249,19 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 ));
             }
 
-            items.push(ListItem::new(Line::from(spans)));
+            if i == app.selected_article && !preview.is_empty() {
+                let mut lines = vec![Line::from(spans)];
+                lines.extend(preview.iter().map(|sentence| {
+                    let text = format!("    › {}", sentence);
+                    Line::from(Span::styled(
+                        truncate_to_width(&text, available_width).into_owned(),
+                        style_read,
+                    ))
+                }));
+                items.push(ListItem::new(lines));
+            } else {
+                items.push(ListItem::new(Line::from(spans)));
+            }
         }
         items
     };
@@ -179,12 +279,27 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         };
         format!("{} Search: {}\u{258E}", scope_tag, app.search_input)
     } else if app.starred_mode {
-        "★ Starred Articles".to_owned()
+        match &app.starred_view {
+            StarredView::Starred => "★ Starred Articles".to_owned(),
+            StarredView::Tag(tag) => format!("# {}", tag),
+            StarredView::ReadLater => format!(
+                "Read Later - {} articles, ~{} min",
+                app.articles.len(),
+                app.read_later_minutes
+            ),
+            StarredView::ContinueReading => {
+                format!("Continue Reading - {} articles", app.articles.len())
+            }
+        }
     } else if let Some(feed) = app.selected_feed() {
         format!("Articles - {}", feed.title)
     } else {
         "Articles".to_owned()
     };
+    let title = match &app.reading_time_filter {
+        Some(filter) => format!("{} · under {} min", title, filter.max_minutes),
+        None => title,
+    };
 
     let list = List::new(items)
         .block(
@@ -198,6 +313,30 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     // Use ListState to enable auto-scrolling to keep selection visible
     let mut state = ListState::default().with_selected(Some(app.selected_article));
     f.render_stateful_widget(list, area, &mut state);
+
+    // One row per article from the list's offset, plus the preview's rows
+    // under the selected one
+    let rows = area.height.saturating_sub(2) as usize;
+    let mut row = 0;
+    let mut links = Vec::new();
+    for (i, &(column, width)) in title_columns.iter().enumerate().skip(state.offset()) {
+        if row >= rows {
+            break;
+        }
+        if let Some(url) = app.articles[i].url.as_deref() {
+            links.extend(Hyperlink::new(
+                area.x + 1 + column as u16,
+                area.y + 1 + row as u16,
+                width as u16,
+                url,
+            ));
+        }
+        row += 1;
+        if i == app.selected_article {
+            row += preview.len();
+        }
+    }
+    links
 }
 
 #[cfg(test)]
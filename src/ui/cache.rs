@@ -0,0 +1,222 @@
+//! Offline cache manager rendering.
+
+use crate::app::App;
+use crate::storage::{CacheEntry, FeedCacheUsage};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Human-readable byte count: "512 B", "14.2 KB", "3.1 MB".
+pub(super) fn format_size(bytes: i64) -> String {
+    const KB: f64 = 1024.0;
+    let b = bytes as f64;
+    if b < KB {
+        format!("{} B", bytes)
+    } else if b < KB * KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{:.1} MB", b / (KB * KB))
+    }
+}
+
+/// Feed row: title, entry counts and sizes (uncompressed → on disk).
+fn format_feed_usage(u: &FeedCacheUsage) -> String {
+    let mut line = format!(
+        "{} articles, {} → {}",
+        u.entries,
+        format_size(u.size_bytes),
+        format_size(u.stored_bytes)
+    );
+    if u.pinned_entries > 0 {
+        line.push_str(&format!(" · {} kept", u.pinned_entries));
+    }
+    line
+}
+
+/// Largest-entry row detail: feed, sizes, last read day, pinned marker.
+fn format_entry(e: &CacheEntry) -> String {
+    let pinned = if e.pinned { " · kept" } else { "" };
+    // SQLite datetime text: keep the date
+    let last_read = e.last_accessed_at.get(..10).unwrap_or(&e.last_accessed_at);
+    format!(
+        "{} · {} → {} · read {}{}",
+        e.feed_title,
+        format_size(e.size_bytes),
+        format_size(e.stored_bytes),
+        last_read,
+        pinned
+    )
+}
+
+/// Render the cache manager as a centered overlay.
+pub(super) fn render(f: &mut Frame, app: &App) {
+    let area = f.area();
+
+    // Centered panel: 80% width, 85% height (with reasonable minimums)
+    let width = (area.width * 80 / 100)
+        .max(50)
+        .min(area.width.saturating_sub(4));
+    let height = (area.height * 85 / 100)
+        .max(16)
+        .min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let popup = Rect::new(x, y, width, height);
+
+    if popup.width < 30 || popup.height < 10 {
+        return;
+    }
+
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.style("panel_border_focused"))
+        .title(" Offline Cache ");
+
+    let Some(overview) = &app.cache_overview else {
+        let loading = Paragraph::new("Loading cache usage...")
+            .block(block)
+            .alignment(Alignment::Center)
+            .style(app.style("reader_body"));
+        f.render_widget(loading, popup);
+        return;
+    };
+
+    let inner_area = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let largest_height = overview.largest.len().max(1) as u16 + 1;
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),              // Summary
+            Constraint::Min(3),                 // Per-feed usage
+            Constraint::Length(largest_height), // Largest entries
+            Constraint::Length(1),              // Footer
+        ])
+        .split(inner_area);
+
+    // PERF-021: Hoist style lookups out of per-item loop
+    let style_header = app.style("reader_heading");
+    let style_label = app.style("feed_title");
+    let style_selected = app.style("feed_selected");
+    let style_normal = app.style("feed_normal");
+    let style_detail = app.style("article_date");
+
+    // Summary
+    let stats = &overview.stats;
+    let policy = &app.cache_policy;
+    let budget = policy
+        .max_size_bytes
+        .map_or_else(|| "unlimited".to_string(), format_size);
+    let summary = vec![
+        Line::from(vec![
+            Span::styled("  Cached:  ", style_label),
+            Span::raw(format!(
+                "{} articles, {} ({} on disk)",
+                stats.total_entries,
+                format_size(stats.total_size_bytes),
+                format_size(stats.stored_size_bytes)
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("  Kept:    ", style_label),
+            Span::raw(format!(
+                "{} starred, archived or queued articles ({}) never expire",
+                stats.pinned_entries,
+                format_size(stats.pinned_size_bytes)
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("  Limits:  ", style_label),
+            Span::raw(format!(
+                "{}h TTL ({} feed overrides), budget {}",
+                policy.ttl_hours,
+                policy.feed_ttl_hours.len(),
+                budget
+            )),
+        ]),
+    ];
+    f.render_widget(
+        Paragraph::new(summary).style(app.style("reader_body")),
+        sections[0],
+    );
+
+    // Per-feed usage (selectable)
+    let mut items: Vec<ListItem> = Vec::with_capacity(overview.feeds.len() + 1);
+    items.push(ListItem::new(Line::from(Span::styled(
+        "By feed",
+        style_header,
+    ))));
+    for (i, u) in overview.feeds.iter().enumerate() {
+        let title_style = if i == app.cache_selected {
+            style_selected
+        } else {
+            style_normal
+        };
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("  {}", u.title), title_style),
+            Span::styled(format!("  {}", format_feed_usage(u)), style_detail),
+        ])));
+    }
+    if overview.feeds.is_empty() {
+        items.push(ListItem::new("  Nothing cached"));
+    }
+    let selected_item = (!overview.feeds.is_empty()).then_some(app.cache_selected + 1);
+    let list = List::new(items).highlight_style(Style::default());
+    let mut state = ListState::default().with_selected(selected_item);
+    f.render_stateful_widget(list, sections[1], &mut state);
+
+    // Largest entries
+    let mut lines = vec![Line::from(Span::styled("Largest entries", style_header))];
+    for e in &overview.largest {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}", e.title), style_normal),
+            Span::styled(format!("  {}", format_entry(e)), style_detail),
+        ]));
+    }
+    if overview.largest.is_empty() {
+        lines.push(Line::from("  (none)"));
+    }
+    f.render_widget(Paragraph::new(lines), sections[2]);
+
+    let footer = Paragraph::new(
+        "  [j/k]select [d]purge feed [D]purge all [x]purge expired (kept articles stay) [Esc]close",
+    )
+    .style(app.style("status_bar"));
+    f.render_widget(footer, sections[3]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+    }
+
+    #[test]
+    fn test_format_feed_usage() {
+        let usage = FeedCacheUsage {
+            feed_id: 1,
+            title: "Feed".to_string(),
+            entries: 3,
+            size_bytes: 4096,
+            stored_bytes: 1024,
+            pinned_entries: 1,
+        };
+        assert_eq!(
+            format_feed_usage(&usage),
+            "3 articles, 4.0 KB → 1.0 KB · 1 kept"
+        );
+    }
+}
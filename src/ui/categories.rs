@@ -22,7 +22,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let style_unread = app.style("feed_unread");
     let style_normal = app.style("feed_normal");
 
-    let items: Vec<ListItem> = tree
+    let mut items: Vec<ListItem> = tree
         .iter()
         .enumerate()
         .map(|(i, item)| {
@@ -40,7 +40,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 "  "
             };
 
-            let style = if i == selected_tree_idx {
+            let style = if i == selected_tree_idx && app.selected_tag.is_none() {
                 style_selected
             } else if item.unread_count > 0 {
                 style_unread
@@ -59,6 +59,33 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
+    // Tag section below the tree; the built-in starred tag is always listed
+    let mut selected = selected_tree_idx;
+    if !app.tags.is_empty() {
+        items.push(ListItem::new(Line::from(Span::styled(
+            "Tags",
+            app.style("article_feed_prefix"),
+        ))));
+        let tags_start = items.len();
+        for (i, tag) in app.tags.iter().enumerate() {
+            let style = if app.selected_tag == Some(i) {
+                style_selected
+            } else if tag.unread > 0 {
+                style_unread
+            } else {
+                style_normal
+            };
+            let mut spans = vec![Span::styled(format!("# {}", tag.tag), style)];
+            if tag.unread > 0 {
+                spans.push(Span::styled(format!(" ({})", tag.unread), style));
+            }
+            items.push(ListItem::new(Line::from(spans)));
+        }
+        if let Some(tag_idx) = app.selected_tag {
+            selected = tags_start + tag_idx;
+        }
+    }
+
     let border_style = if is_focused {
         app.style("panel_border_focused")
     } else {
@@ -74,6 +101,6 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         )
         .highlight_style(Style::default());
 
-    let mut state = ListState::default().with_selected(Some(selected_tree_idx));
+    let mut state = ListState::default().with_selected(Some(selected));
     f.render_stateful_widget(list, area, &mut state);
 }
@@ -4,15 +4,19 @@
 //! refresh progress, content loading, and star toggle results.
 
 #[allow(unused_imports)] // SubscribeState used by TASK-7 subscribe dialog event handling
-use crate::app::{App, AppEvent, ContentState, Focus, SubscribeState, View, WhatsNewEntry};
+use crate::app::{
+    is_link_page, App, AppEvent, ContentState, Focus, StarredView, SubscribeState, View,
+    WhatsNewEntry,
+};
+use crate::content::SavedPage;
 use crate::storage::Article;
-use crate::util::strip_control_chars;
+use crate::util::{on_battery, reading_minutes, strip_control_chars, word_count};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 
-use super::reader::render_markdown;
+use super::reader::{render_markdown, render_revision_diff};
 
 /// Maximum number of articles in What's New list to prevent memory exhaustion
 pub(super) const MAX_WHATS_NEW: usize = 100;
@@ -82,7 +86,15 @@ pub(super) async fn handle_app_event(
             app.refresh_progress = Some((done, total));
         }
         AppEvent::RefreshComplete(results) => {
-            handle_refresh_complete(app, results).await;
+            let online = handle_refresh_complete(app, results).await;
+            // Tag rules may have tagged new articles
+            super::helpers::spawn_tag_counts_load(app.db.clone(), event_tx.clone());
+            if app.view == View::Health {
+                super::input::spawn_feed_health_load(app, event_tx);
+            }
+            if online {
+                start_auto_prefetch(app, event_tx);
+            }
             // Reload cached article IDs for cache indicators
             let article_ids: Vec<i64> = app.articles.iter().map(|a| a.id).collect();
             if !article_ids.is_empty() {
@@ -100,6 +112,23 @@ pub(super) async fn handle_app_event(
             cached,
         } => {
             handle_content_loaded(app, article_id, generation, result, cached);
+            // Summarize what is shown, once per article
+            if let ContentState::Loaded {
+                article_id: id,
+                content,
+                ..
+            } = &app.content_state
+            {
+                let summarized = app.reader_tldr.as_ref().is_some_and(|(t, _)| t == id);
+                if *id == article_id && !summarized {
+                    super::helpers::spawn_tldr_load(
+                        article_id,
+                        content.clone(),
+                        app.db.clone(),
+                        event_tx.clone(),
+                    );
+                }
+            }
         }
         AppEvent::FeedRateLimited {
             feed_title,
@@ -115,6 +144,7 @@ pub(super) async fn handle_app_event(
             starred,
         } => {
             handle_star_toggled(app, article_id, starred);
+            super::helpers::spawn_tag_counts_load(app.db.clone(), event_tx.clone());
         }
         AppEvent::StarToggleFailed {
             article_id,
@@ -140,8 +170,13 @@ pub(super) async fn handle_app_event(
         } => {
             handle_search_completed(app, query, generation, results);
         }
-        AppEvent::BulkMarkReadComplete { feed_id, count } => {
+        AppEvent::BulkMarkReadComplete {
+            feed_id,
+            count,
+            copy_feeds,
+        } => {
             handle_bulk_mark_read_complete(app, feed_id, count);
+            app.count_read_in_feeds(&copy_feeds);
         }
         AppEvent::BulkMarkReadFailed { feed_id, error } => {
             handle_bulk_mark_read_failed(app, feed_id, error);
@@ -162,6 +197,9 @@ pub(super) async fn handle_app_event(
             articles_removed,
         } => {
             handle_feed_deleted(app, feed_id, &title, articles_removed);
+            if app.view == View::Health {
+                super::input::spawn_feed_health_load(app, event_tx);
+            }
         }
         AppEvent::FeedDeleteFailed { feed_id, error } => {
             tracing::error!(feed_id, error = %error, "Feed deletion failed");
@@ -221,11 +259,199 @@ pub(super) async fn handle_app_event(
             }
         }
         AppEvent::StatsLoaded(data) => {
+            if let Some(speed) = data.measured_speed {
+                app.words_per_minute = speed;
+            }
             if app.view == View::Stats {
                 app.stats_data = Some(data);
                 app.needs_redraw = true;
             }
         }
+        AppEvent::FeedHealthLoaded(mut rows) => {
+            if app.view == View::Health {
+                let now = chrono::Utc::now().timestamp();
+                super::health::sort_feed_health(&mut rows, now, &app.config.health);
+                app.feed_health_selected =
+                    app.feed_health_selected.min(rows.len().saturating_sub(1));
+                app.feed_health = Some(rows);
+                app.needs_redraw = true;
+            }
+        }
+        AppEvent::CacheOverviewLoaded(overview) => {
+            if app.view == View::Cache {
+                app.cache_selected = app
+                    .cache_selected
+                    .min(overview.feeds.len().saturating_sub(1));
+                app.cache_overview = Some(overview);
+                app.needs_redraw = true;
+            }
+        }
+        AppEvent::CachePurged { result } => {
+            match result {
+                Ok(purged) => app.set_status(format!("Purged {} cached articles", purged)),
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to purge offline cache");
+                    app.set_status(format!("Failed to purge cache: {}", e));
+                }
+            }
+            if app.view == View::Cache {
+                super::input::spawn_cache_overview_load(app, event_tx);
+            }
+            // Refresh offline indicators of the visible list
+            let ids = app.articles.iter().map(|a| a.id).collect();
+            super::helpers::spawn_cached_ids_load(ids, app.db.clone(), event_tx.clone());
+        }
+        AppEvent::FeedRediscovered {
+            feed_id,
+            title,
+            result,
+        } => {
+            match result {
+                Ok(Some(url)) => {
+                    tracing::info!(feed_id, url = %url, "Feed URL rediscovered");
+                    let feeds = Arc::make_mut(&mut app.feeds);
+                    if let Some(feed) = feeds.iter_mut().find(|f| f.id == feed_id) {
+                        feed.url = url.clone();
+                        feed.url_auto_updated = true;
+                    }
+                    app.set_status(format!("{} now uses {}", title, url));
+                }
+                Ok(None) => {
+                    app.set_status(format!("{}: feed URL is already current", title));
+                }
+                Err(e) => {
+                    tracing::warn!(feed_id, error = %e, "Feed rediscovery failed");
+                    app.set_status(format!("Rediscover failed for {}: {}", title, e));
+                }
+            }
+            if app.view == View::Health {
+                super::input::spawn_feed_health_load(app, event_tx);
+            }
+            app.needs_redraw = true;
+        }
+        AppEvent::FeedPreferSourceSet {
+            feed_id,
+            title,
+            result,
+        } => {
+            match result {
+                Ok(prefer) => {
+                    let feeds = Arc::make_mut(&mut app.feeds);
+                    if let Some(feed) = feeds.iter_mut().find(|f| f.id == feed_id) {
+                        feed.prefer_source = prefer;
+                    }
+                    app.sync_feed_cache();
+                    app.set_status(if prefer {
+                        format!("{} is now a preferred source", title)
+                    } else {
+                        format!("{} is no longer a preferred source", title)
+                    });
+                }
+                Err(e) => {
+                    tracing::error!(feed_id, error = %e, "Setting preferred source failed");
+                    app.set_status(format!("Could not update {}: {}", title, e));
+                }
+            }
+            app.needs_redraw = true;
+        }
+        AppEvent::FeedUnreadOnUpdateSet {
+            feed_id,
+            title,
+            result,
+        } => {
+            match result {
+                Ok(enabled) => {
+                    let feeds = Arc::make_mut(&mut app.feeds);
+                    if let Some(feed) = feeds.iter_mut().find(|f| f.id == feed_id) {
+                        feed.unread_on_update = enabled;
+                    }
+                    app.sync_feed_cache();
+                    app.set_status(if enabled {
+                        format!("Edited articles in {} will be marked unread", title)
+                    } else {
+                        format!("Edited articles in {} keep their read state", title)
+                    });
+                }
+                Err(e) => {
+                    tracing::error!(feed_id, error = %e, "Setting unread on update failed");
+                    app.set_status(format!("Could not update {}: {}", title, e));
+                }
+            }
+            app.needs_redraw = true;
+        }
+        AppEvent::FeedAutoPrefetchSet {
+            feed_id,
+            title,
+            result,
+        } => {
+            match result {
+                Ok(enabled) => {
+                    let feeds = Arc::make_mut(&mut app.feeds);
+                    if let Some(feed) = feeds.iter_mut().find(|f| f.id == feed_id) {
+                        feed.auto_prefetch = enabled;
+                    }
+                    app.sync_feed_cache();
+                    app.set_status(if enabled {
+                        format!("New articles in {} will be prefetched after refresh", title)
+                    } else {
+                        format!("{} is no longer prefetched automatically", title)
+                    });
+                }
+                Err(e) => {
+                    tracing::error!(feed_id, error = %e, "Setting auto prefetch failed");
+                    app.set_status(format!("Could not update {}: {}", title, e));
+                }
+            }
+            app.needs_redraw = true;
+        }
+        AppEvent::DuplicateSourcesLoaded {
+            article_id,
+            sources,
+        } => {
+            let still_open = app
+                .reading_session
+                .as_ref()
+                .is_some_and(|s| s.article_id == article_id);
+            if still_open {
+                app.set_status(format!("Also in: {}", sources.join(", ")));
+                app.needs_redraw = true;
+            }
+        }
+        AppEvent::RevisionsLoaded { article_id, result } => {
+            let Some(article) = app.reader_article.as_ref().filter(|a| a.id == article_id) else {
+                return;
+            };
+            if app.reader_saved_content.is_some() {
+                return;
+            }
+            match result {
+                Ok((revisions, _)) if revisions.is_empty() => {
+                    app.set_status("No earlier versions of this article");
+                }
+                Ok((revisions, current)) => {
+                    let rendered_lines =
+                        render_revision_diff(article, &revisions, current.as_deref(), &app.theme);
+                    let diff = ContentState::Loaded {
+                        article_id,
+                        content: String::new(),
+                        rendered_lines,
+                        links: Vec::new(),
+                        link_spans: Vec::new(),
+                        code_lines: Vec::new(),
+                    };
+                    app.reader_saved_content =
+                        Some(std::mem::replace(&mut app.content_state, diff));
+                    app.scroll_offset = 0;
+                    app.reader_cached_line_count = None;
+                    app.refresh_reader_search();
+                }
+                Err(e) => {
+                    tracing::warn!(article_id, error = %e, "Failed to load article revisions");
+                    app.set_status(format!("Could not load revisions: {}", e));
+                }
+            }
+            app.needs_redraw = true;
+        }
         AppEvent::PrefetchProgress { completed, total } => {
             app.prefetch_progress = Some((completed, total));
             app.set_status(format!("Prefetching: {}/{}...", completed + 1, total));
@@ -258,9 +484,222 @@ pub(super) async fn handle_app_event(
             app.cached_article_set = ids;
             app.needs_redraw = true;
         }
+        AppEvent::ArticleTagsChanged {
+            article_id,
+            tag,
+            result,
+        } => {
+            handle_article_tags_changed(app, article_id, &tag, result);
+            super::helpers::spawn_tag_counts_load(app.db.clone(), event_tx.clone());
+        }
+        AppEvent::TagsLoaded(tags) => {
+            app.tags = Arc::new(tags);
+            app.clamp_selections();
+            app.needs_redraw = true;
+        }
+        AppEvent::ReadLaterToggled { article_id, result } => {
+            handle_read_later_toggled(app, article_id, result);
+            super::helpers::spawn_read_later_load(app.db.clone(), event_tx.clone());
+        }
+        AppEvent::ArchiveToggled {
+            article_id,
+            was_archived,
+            result,
+        } => {
+            match result {
+                Ok(archived) => {
+                    super::input::set_article_archived(app, article_id, archived);
+                    app.set_status(if archived {
+                        "Archived \u{2014} kept for offline reading"
+                    } else {
+                        "Removed from archive"
+                    });
+                }
+                Err(e) => {
+                    tracing::error!(article_id, error = %e, "Failed to toggle archive");
+                    super::input::set_article_archived(app, article_id, was_archived);
+                    app.set_status("Failed to save archive status - reverted");
+                }
+            }
+            super::helpers::spawn_tag_counts_load(app.db.clone(), event_tx.clone());
+        }
+        AppEvent::OfflineCopyCached { article_id, result } => match result {
+            Ok(()) => {
+                app.cached_article_set.insert(article_id);
+                app.needs_redraw = true;
+            }
+            Err(e) => {
+                tracing::warn!(article_id, error = %e, "Failed to save offline copy");
+                app.set_status(format!("Not saved for offline reading: {}", e));
+            }
+        },
+        AppEvent::ReadLaterFinished { article_id } => {
+            app.read_later_ids.remove(&article_id);
+            if app.starred_mode && app.starred_view == StarredView::ReadLater {
+                // SAFETY: Arc::make_mut on app.articles only happens on the event loop thread
+                Arc::make_mut(&mut app.articles).retain(|a| a.id != article_id);
+                app.clamp_selections();
+            }
+            app.set_status("Finished \u{2014} removed from read later");
+            super::helpers::spawn_read_later_load(app.db.clone(), event_tx.clone());
+        }
+        AppEvent::ReadLaterMoveFailed { error } => {
+            app.set_status(format!("Could not reorder read later: {}", error));
+            // Put the list back in stored queue order
+            if app.starred_mode && app.starred_view == StarredView::ReadLater {
+                match app.db.get_read_later_articles().await {
+                    Ok(articles) => {
+                        app.reload_articles(articles);
+                        app.clamp_selections();
+                    }
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to reload read-later queue");
+                    }
+                }
+            }
+        }
+        AppEvent::ReadLaterLoaded { ids, words } => {
+            app.read_later_ids = ids.into_iter().collect();
+            app.read_later_minutes = reading_minutes(words, app.words_per_minute);
+            app.needs_redraw = true;
+        }
+        AppEvent::TldrLoaded {
+            article_id,
+            sentences,
+        } => {
+            if !sentences.is_empty() {
+                app.tldr_previews.insert(article_id, sentences.clone());
+            }
+            if app
+                .reader_article
+                .as_ref()
+                .is_some_and(|a| a.id == article_id)
+            {
+                app.reader_tldr = Some((article_id, sentences));
+            }
+            app.needs_redraw = true;
+        }
+        AppEvent::TldrsLoaded(tldrs) => {
+            app.tldr_previews = tldrs;
+            app.needs_redraw = true;
+        }
+        AppEvent::UrlSaved { url, result } => match result {
+            Ok(page) => handle_url_saved(app, page).await,
+            Err(e) => {
+                tracing::warn!(url = %url, error = %e, "Failed to save URL");
+                app.set_status(format!("Could not save {}: {}", url, e));
+            }
+        },
     }
 }
 
+/// Handle a page stored by the "Save URL" dialog.
+///
+/// Reloads feeds (the "Saved" feed may be new) keeping the selected feed, and
+/// the article list when the "Saved" feed is the one shown.
+async fn handle_url_saved(app: &mut App, page: SavedPage) {
+    tracing::info!(article_id = page.article_id, "Saved URL");
+    app.set_status(format!("Saved \"{}\"", page.title));
+    app.cached_article_set.insert(page.article_id);
+
+    let selected_id = app.selected_feed().map(|f| f.id);
+    if let Ok(feeds) = app.db.get_feeds_with_unread_counts().await {
+        app.feeds = Arc::new(feeds);
+        if let Some(i) = selected_id.and_then(|id| app.feeds.iter().position(|f| f.id == id)) {
+            app.selected_feed = i;
+        }
+        app.sync_feed_cache();
+        app.clamp_selections();
+        app.invalidate_category_tree(); // PERF-021: "Saved" feed may be new
+    }
+
+    let showing_saved =
+        !app.starred_mode && !app.search_mode && app.selected_feed().is_some_and(|f| f.is_saved());
+    if showing_saved {
+        let feed_id = app.feeds[app.selected_feed].id;
+        match app.db.get_articles_for_feed(feed_id, None).await {
+            Ok(articles) => {
+                app.reload_articles(articles);
+                app.clamp_selections();
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, feed_id, "Failed to reload saved articles");
+            }
+        }
+    }
+    app.needs_redraw = true;
+}
+
+/// Handle a saved (or failed) read-later toggle.
+///
+/// The queue was updated optimistically; on failure the change is undone.
+fn handle_read_later_toggled(app: &mut App, article_id: i64, result: Result<bool, String>) {
+    match result {
+        Ok(true) => app.set_status("Added to read later"),
+        Ok(false) => app.set_status("Removed from read later"),
+        Err(e) => {
+            tracing::error!(article_id, error = %e, "Failed to update read-later queue");
+            if !app.read_later_ids.remove(&article_id) {
+                app.read_later_ids.insert(article_id);
+            }
+            app.set_status(format!("Could not update read later: {}", e));
+        }
+    }
+    app.needs_redraw = true;
+}
+
+/// Handle a saved (or failed) tag change from the tag picker.
+///
+/// On success the stored tags replace the article's tags everywhere it is
+/// shown; on failure the picker goes back to the article's unchanged tags.
+fn handle_article_tags_changed(
+    app: &mut App,
+    article_id: i64,
+    tag: &str,
+    result: Result<Vec<String>, String>,
+) {
+    let tags = match result {
+        Ok(tags) => tags,
+        Err(e) => {
+            tracing::error!(article_id, tag, error = %e, "Failed to save article tag");
+            app.set_status(format!("Could not update #{}: {}", tag, e));
+            let current = app
+                .reader_article
+                .as_ref()
+                .filter(|a| a.id == article_id)
+                .or_else(|| app.articles.iter().find(|a| a.id == article_id))
+                .map(|a| a.tags.iter().map(|t| t.to_string()).collect());
+            if let (Some(picker), Some(current)) = (app.tag_picker.as_mut(), current) {
+                if picker.article_id == article_id {
+                    picker.tags = current;
+                }
+            }
+            app.needs_redraw = true;
+            return;
+        }
+    };
+
+    let shared: Vec<Arc<str>> = tags.iter().map(|t| Arc::from(t.as_str())).collect();
+    // SAFETY: Arc::make_mut on app.articles only happens on the event loop thread
+    let articles = Arc::make_mut(&mut app.articles);
+    if let Some(article) = articles.iter_mut().find(|a| a.id == article_id) {
+        article.tags = shared.clone();
+    }
+    if let Some(article) = app.reader_article.as_mut().filter(|a| a.id == article_id) {
+        article.tags = shared;
+    }
+    if let Some(picker) = app
+        .tag_picker
+        .as_mut()
+        .filter(|p| p.article_id == article_id)
+    {
+        picker.tags = tags;
+    }
+    // The cached list would come back with the old tags
+    app.cached_articles = None;
+    app.needs_redraw = true;
+}
+
 /// Handle search completed event.
 fn handle_search_completed(
     app: &mut App,
@@ -282,6 +721,7 @@ fn handle_search_completed(
     match results {
         Ok(articles) => {
             let count = articles.len();
+            app.reading_time_filter = None;
             app.articles = Arc::new(articles);
             app.selected_article = 0;
             app.clamp_selections();
@@ -295,7 +735,9 @@ fn handle_search_completed(
 }
 
 /// Handle refresh completion event.
-async fn handle_refresh_complete(app: &mut App, results: Vec<crate::app::FetchResult>) {
+///
+/// Returns `false` when most feeds failed with network errors (offline).
+async fn handle_refresh_complete(app: &mut App, results: Vec<crate::app::FetchResult>) -> bool {
     app.refresh_progress = None;
 
     // BUG-025: Filter out results for feeds deleted during refresh.
@@ -367,7 +809,10 @@ async fn handle_refresh_complete(app: &mut App, results: Vec<crate::app::FetchRe
     // If >80% of feeds fail with network errors, likely offline
     let is_offline = total > 0 && failed > 0 && (network_errors as f64 / total as f64) > 0.8;
 
-    if is_offline {
+    if total == 0 {
+        // Adaptive polling skipped everything (or every feed is circuit-broken)
+        app.set_status("No feeds due for refresh (Ctrl+r refreshes all)");
+    } else if is_offline {
         app.set_status("Offline - Network unavailable. Check your connection.");
     } else if failed > 0 {
         app.set_status(format!(
@@ -390,6 +835,24 @@ async fn handle_refresh_complete(app: &mut App, results: Vec<crate::app::FetchRe
 
         app.invalidate_category_tree(); // PERF-021: Feeds replaced with new unread counts
 
+        // Suggest unsubscribing from feeds that answered 410 Gone in this refresh
+        let gone: Vec<&str> = results
+            .iter()
+            .filter_map(|r| app.feeds.iter().find(|f| f.id == r.feed_id && f.gone))
+            .map(|f| &*f.title)
+            .collect();
+        match gone.as_slice() {
+            [] => {}
+            [title] => app.set_status(format!(
+                "{} is gone (HTTP 410) - press d in the feed list to unsubscribe",
+                title
+            )),
+            many => app.set_status(format!(
+                "{} feeds are gone (HTTP 410), marked ✗ - press d to unsubscribe",
+                many.len()
+            )),
+        }
+
         // DATA-001: Reload current feed's articles to ensure in-memory matches DB
         // This fixes data divergence if user modified articles during refresh
         if let Some(feed) = app.feeds.get(app.selected_feed) {
@@ -398,7 +861,7 @@ async fn handle_refresh_complete(app: &mut App, results: Vec<crate::app::FetchRe
 
             match app.db.get_articles_for_feed(feed_id, None).await {
                 Ok(articles) => {
-                    app.articles = Arc::new(articles);
+                    app.reload_articles(articles);
                     // Preserve selection if still valid
                     app.selected_article = if app.articles.is_empty() {
                         0
@@ -479,6 +942,30 @@ async fn handle_refresh_complete(app: &mut App, results: Vec<crate::app::FetchRe
             }
         }
     }
+
+    !is_offline
+}
+
+/// Start the automatic prefetch after a refresh, unless it is switched off,
+/// nothing opts in, a prefetch is already running, or `[prefetch]` asks to
+/// wait for AC power and the machine is on battery.
+fn start_auto_prefetch(app: &App, event_tx: &mpsc::Sender<AppEvent>) {
+    let settings = &app.config.prefetch;
+    let opted_in = !settings.categories.is_empty() || app.feeds.iter().any(|f| f.auto_prefetch);
+    if settings.max_articles == 0 || !opted_in || app.prefetch_progress.is_some() {
+        return;
+    }
+    if settings.only_on_ac_power && on_battery() {
+        tracing::debug!("On battery power, skipping automatic prefetch");
+        return;
+    }
+    super::helpers::spawn_auto_prefetch(
+        settings.clone(),
+        app.db.clone(),
+        app.http_client.clone(),
+        app.cache_policy.clone(),
+        event_tx.clone(),
+    );
 }
 
 /// Handle content loaded event.
@@ -510,22 +997,42 @@ fn handle_content_loaded(
             let source = if cached { "cached" } else { "fetched" };
             tracing::debug!(article_id, generation, source, "Content loaded");
             app.content_loading_for = None;
+            let link_page = is_link_page(reader_article);
 
             match result {
                 Ok(content) => {
                     // SEC-001: Sanitize content from jina.ai before rendering
                     let content = strip_control_chars(&content).into_owned();
                     // PERF-004: Parse markdown once and cache rendered lines
-                    let rendered_lines = render_markdown(&content, &app.theme);
+                    let width = app.reader_viewport_width;
+                    let rendered =
+                        render_markdown(&content, &app.theme, width, app.reader_layout.justify);
+                    app.reader_wrap_width = width;
                     app.content_state = ContentState::Loaded {
                         article_id,
                         content,
-                        rendered_lines,
+                        rendered_lines: rendered.lines,
+                        links: rendered.links,
+                        link_spans: rendered.link_spans,
+                        code_lines: rendered.code_lines,
+                    };
+                    // A search typed while loading jumps to its first match
+                    app.update_reader_search();
+                    // Linked pages are neither cached nor retried by ID
+                    if !link_page {
+                        // PERF-022: Clear negative cache on success
+                        app.failed_content_cache.remove(&article_id);
+                        // Update cache indicator
+                        app.cached_article_set.insert(article_id);
+                    }
+                    // The header's reading time goes by the full text from now on
+                    let words = match &app.content_state {
+                        ContentState::Loaded { content, .. } => word_count(content),
+                        _ => 0,
                     };
-                    // PERF-022: Clear negative cache on success
-                    app.failed_content_cache.remove(&article_id);
-                    // Update cache indicator
-                    app.cached_article_set.insert(article_id);
+                    if let Some(article) = app.reader_article.as_mut() {
+                        article.word_count = Some(words);
+                    }
                 }
                 Err(e) => {
                     let fallback = reader_article.summary.clone();
@@ -534,9 +1041,11 @@ fn handle_content_loaded(
                         error: e.to_string(),
                         fallback,
                     };
-                    // PERF-022: Insert into negative cache on failure
-                    app.failed_content_cache
-                        .insert(article_id, tokio::time::Instant::now());
+                    if !link_page {
+                        // PERF-022: Insert into negative cache on failure
+                        app.failed_content_cache
+                            .insert(article_id, tokio::time::Instant::now());
+                    }
                 }
             }
             // PERF-020: Invalidate cached line count when content state changes
@@ -10,6 +10,9 @@ use ratatui::{
 
 /// Render the feed list panel
 ///
+/// Markers: `⚠` last refresh failed, `✗` feed is gone (HTTP 410),
+/// `↪` feed URL was updated after a permanent redirect.
+///
 /// # Performance Note (PERF-006)
 ///
 /// This function uses `format!()` for constructing display strings on each render:
@@ -40,6 +43,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let style_unread = app.style("feed_unread");
     let style_normal = app.style("feed_normal");
     let style_error = app.style("feed_error");
+    let style_moved = app.style("article_date");
 
     let items: Vec<ListItem> = if app.feeds.is_empty() {
         vec![ListItem::new("No feeds loaded")]
@@ -57,11 +61,14 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 style_normal
             };
 
-            // Pre-allocate spans: error indicator (optional) + title + count (optional) + time (optional)
-            let mut spans = Vec::with_capacity(4);
+            // Pre-allocate spans: error indicator (optional) + title + moved marker (optional)
+            // + count (optional) + time (optional)
+            let mut spans = Vec::with_capacity(5);
 
-            // Error indicator if present
-            if feed.error.is_some() {
+            // Gone (410) feeds are candidates for unsubscription; other errors get a warning
+            if feed.gone {
+                spans.push(Span::styled("✗ ", style_error));
+            } else if feed.error.is_some() {
                 spans.push(Span::styled("⚠ ", style_error));
             }
 
@@ -69,6 +76,11 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             // PERF-009: Deref Arc<str> to &str
             spans.push(Span::styled(&*feed.title, style));
 
+            // URL was auto-updated after a permanent redirect
+            if feed.url_auto_updated {
+                spans.push(Span::styled(" ↪", style_moved));
+            }
+
             // Unread count span (small allocation only when count > 0)
             if feed.unread_count > 0 {
                 spans.push(Span::styled(format!(" ({})", feed.unread_count), style));
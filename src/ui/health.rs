@@ -0,0 +1,253 @@
+//! Feed health dashboard rendering.
+
+use crate::app::App;
+use crate::config::HealthConfig;
+use crate::storage::FeedHealth;
+use crate::ui::articles::format_relative_time;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Severity bucket for a feed in the health view, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum HealthGroup {
+    Failing,
+    Slow,
+    Stale,
+    Healthy,
+}
+
+impl HealthGroup {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Failing => "Failing",
+            Self::Slow => "Slow",
+            Self::Stale => "Stale",
+            Self::Healthy => "Healthy",
+        }
+    }
+
+    /// Classify a feed by its most severe problem.
+    pub(super) fn classify(h: &FeedHealth, now: i64, cfg: &HealthConfig) -> Self {
+        let stale_cutoff = now - i64::from(cfg.stale_after_months) * 30 * 86_400;
+        if h.gone || h.consecutive_failures > 0 || h.error.is_some() {
+            Self::Failing
+        } else if h
+            .avg_latency_ms
+            .is_some_and(|ms| ms as u64 > cfg.slow_fetch_ms)
+        {
+            Self::Slow
+        } else if match h.last_published {
+            Some(ts) => ts < stale_cutoff,
+            // Fetched before but never carried a dated article
+            None => h.fetch_attempts > 0,
+        } {
+            Self::Stale
+        } else {
+            Self::Healthy
+        }
+    }
+}
+
+/// Sort health rows by severity group, keeping title order within a group.
+pub(super) fn sort_feed_health(rows: &mut [FeedHealth], now: i64, cfg: &HealthConfig) {
+    rows.sort_by_cached_key(|h| HealthGroup::classify(h, now, cfg));
+}
+
+/// One-line metrics summary: failures, latency, last post, posting frequency.
+fn format_health_detail(h: &FeedHealth) -> String {
+    let mut parts = Vec::with_capacity(4);
+    if h.gone {
+        parts.push("gone (HTTP 410)".to_string());
+    } else if let Some(error) = &h.error {
+        parts.push(error.clone());
+    }
+    if h.fetch_attempts > 0 {
+        parts.push(format!("{}/{} failed", h.fetch_failures, h.fetch_attempts));
+    }
+    if let Some(ms) = h.avg_latency_ms {
+        parts.push(format!("{:.1}s avg", ms as f64 / 1000.0));
+    }
+    match h.last_published {
+        Some(_) => parts.push(format!(
+            "last post {}",
+            format_relative_time(h.last_published)
+        )),
+        None => parts.push("no dated posts".to_string()),
+    }
+    parts.push(format!("{:.1}/wk", h.posts_per_week));
+    parts.join(" · ")
+}
+
+/// Render the feed health view as a centered overlay.
+pub(super) fn render(f: &mut Frame, app: &App) {
+    let area = f.area();
+
+    // Centered panel: 80% width, 85% height (with reasonable minimums)
+    let width = (area.width * 80 / 100)
+        .max(50)
+        .min(area.width.saturating_sub(4));
+    let height = (area.height * 85 / 100)
+        .max(12)
+        .min(area.height.saturating_sub(2));
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let popup = Rect::new(x, y, width, height);
+
+    if popup.width < 30 || popup.height < 8 {
+        return;
+    }
+
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.style("panel_border_focused"))
+        .title(" Feed Health ");
+
+    let Some(rows) = &app.feed_health else {
+        let loading = Paragraph::new("Loading feed health...")
+            .block(block)
+            .alignment(Alignment::Center)
+            .style(app.style("reader_body"));
+        f.render_widget(loading, popup);
+        return;
+    };
+
+    let inner_area = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(inner_area);
+
+    // PERF-021: Hoist style lookups out of per-item loop
+    let style_header = app.style("reader_heading");
+    let style_selected = app.style("feed_selected");
+    let style_normal = app.style("feed_normal");
+    let style_detail = app.style("article_date");
+    let style_error = app.style("feed_error");
+
+    let now = chrono::Utc::now().timestamp();
+    let mut items: Vec<ListItem> = Vec::with_capacity(rows.len() + 4);
+    let mut selected_item = None;
+    let mut current_group = None;
+    for (i, h) in rows.iter().enumerate() {
+        let group = HealthGroup::classify(h, now, &app.config.health);
+        if current_group != Some(group) {
+            let count = rows
+                .iter()
+                .filter(|r| HealthGroup::classify(r, now, &app.config.health) == group)
+                .count();
+            items.push(ListItem::new(Line::from(Span::styled(
+                format!("{} ({})", group.label(), count),
+                style_header,
+            ))));
+            current_group = Some(group);
+        }
+
+        let title_style = if i == app.feed_health_selected {
+            selected_item = Some(items.len());
+            style_selected
+        } else {
+            style_normal
+        };
+        let detail_style = if group == HealthGroup::Failing {
+            style_error
+        } else {
+            style_detail
+        };
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("  {}", h.title), title_style),
+            Span::styled(format!("  {}", format_health_detail(h)), detail_style),
+        ])));
+    }
+    if rows.is_empty() {
+        items.push(ListItem::new("No feeds"));
+    }
+
+    let list = List::new(items).highlight_style(Style::default());
+    let mut state = ListState::default().with_selected(selected_item);
+    f.render_stateful_widget(list, sections[0], &mut state);
+
+    let footer =
+        Paragraph::new("  [j/k]select [R]etry [d]unsubscribe [O]pen site [F]rediscover [Esc]close")
+            .style(app.style("status_bar"));
+    f.render_widget(footer, sections[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1705320000;
+
+    fn health(title: &str) -> FeedHealth {
+        FeedHealth {
+            feed_id: 1,
+            title: title.to_string(),
+            url: "https://example.com/rss".to_string(),
+            html_url: None,
+            error: None,
+            consecutive_failures: 0,
+            gone: false,
+            fetch_attempts: 10,
+            fetch_failures: 0,
+            avg_latency_ms: Some(300),
+            last_published: Some(NOW - 86_400),
+            posts_per_week: 2.0,
+        }
+    }
+
+    #[test]
+    fn test_classify_groups() {
+        let cfg = HealthConfig::default();
+        assert_eq!(
+            HealthGroup::classify(&health("ok"), NOW, &cfg),
+            HealthGroup::Healthy
+        );
+
+        let mut gone = health("gone");
+        gone.gone = true;
+        assert_eq!(
+            HealthGroup::classify(&gone, NOW, &cfg),
+            HealthGroup::Failing
+        );
+
+        let mut slow = health("slow");
+        slow.avg_latency_ms = Some(9000);
+        assert_eq!(HealthGroup::classify(&slow, NOW, &cfg), HealthGroup::Slow);
+
+        let mut stale = health("stale");
+        stale.last_published = Some(NOW - 365 * 86_400);
+        assert_eq!(HealthGroup::classify(&stale, NOW, &cfg), HealthGroup::Stale);
+    }
+
+    #[test]
+    fn test_sort_by_severity_keeps_title_order() {
+        let cfg = HealthConfig::default();
+        let mut failing = health("b-failing");
+        failing.consecutive_failures = 2;
+        let mut rows = vec![health("a-ok"), failing, health("c-ok")];
+        sort_feed_health(&mut rows, NOW, &cfg);
+        let titles: Vec<&str> = rows.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, vec!["b-failing", "a-ok", "c-ok"]);
+    }
+
+    #[test]
+    fn test_format_health_detail() {
+        let mut h = health("x");
+        h.fetch_failures = 3;
+        h.gone = true;
+        let detail = format_health_detail(&h);
+        assert!(detail.starts_with("gone (HTTP 410)"));
+        assert!(detail.contains("3/10 failed"));
+        assert!(detail.contains("0.3s avg"));
+        assert!(detail.ends_with("2.0/wk"));
+    }
+}
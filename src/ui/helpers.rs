@@ -3,13 +3,17 @@
 //! This module contains utility functions shared across the UI layer,
 //! including mode transitions, content loading, and URL validation.
 
-use crate::app::{App, AppEvent, ContentState};
+use crate::app::{App, AppEvent, ContentState, StarredView};
+use crate::config::PrefetchConfig;
 use crate::content::fetch_content;
-use crate::storage::{Article, Database};
+use crate::rewrite::RewriteSet;
+use crate::storage::{Article, CachePolicy, Database};
 use anyhow::Result;
+use futures::stream::{self, StreamExt};
 use futures::FutureExt;
 use std::collections::HashSet;
 use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
@@ -82,6 +86,8 @@ pub(super) async fn exit_starred_mode(app: &mut App, log_context: &str) -> Resul
     }
 
     app.starred_mode = false;
+    app.starred_view = StarredView::Starred;
+    app.reading_time_filter = None;
     // PERF-014: Clear prefix cache when exiting starred mode
     app.feed_prefix_cache.clear();
     tracing::info!("Exiting starred mode via {}", log_context);
@@ -167,6 +173,7 @@ pub(super) async fn restore_articles_from_search(
     app: &mut App,
     use_search_feed_id: bool,
 ) -> Result<()> {
+    app.reading_time_filter = None;
     let current_feed_id = app.selected_feed().map(|f| f.id);
 
     if let Some(cached) = app.cached_articles.take() {
@@ -331,6 +338,8 @@ pub(super) fn try_spawn_content_load(
         url,
         app.http_client.clone(),
         app.db.clone(),
+        app.rewrites.clone(),
+        app.cache_policy.clone(),
         event_tx.clone(),
     ));
 
@@ -341,6 +350,7 @@ pub(super) fn try_spawn_content_load(
 ///
 /// Checks content_cache table first (TTL-aware), then articles.content column,
 /// then fetches from jina.ai on miss. Caches fetched content and indexes for FTS5.
+/// Content rewrites are applied to whichever copy is shown (see `Database::rewrite_content`).
 /// Sends `AppEvent::ContentLoaded` on completion (success or failure).
 ///
 /// # Arguments
@@ -350,6 +360,8 @@ pub(super) fn try_spawn_content_load(
 /// * `url` - The article URL to fetch
 /// * `client` - HTTP client for fetching
 /// * `db` - Database for caching
+/// * `rewrites` - Compiled `[[rewrites]]` applied before display
+/// * `cache_policy` - TTL and size budget for newly cached content
 /// * `tx` - Channel to send completion event
 ///
 /// # Returns
@@ -358,25 +370,44 @@ pub(super) fn try_spawn_content_load(
 /// the task if a new content load is started or the reader view is exited.
 ///
 /// PERF-010: Takes Arc<str> for cheap reference counting instead of String clone.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn spawn_content_load(
     article_id: i64,
     generation: u64,
     url: Arc<str>,
     client: reqwest::Client,
     db: Database,
+    rewrites: Arc<RewriteSet>,
+    cache_policy: Arc<CachePolicy>,
     tx: mpsc::Sender<AppEvent>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         let tx_panic = tx.clone();
         match catch_task_panic(async {
+            // Original markdown stays cached; the rewritten copy is what gets shown
+            let rewrite = |markdown: String| {
+                let db = db.clone();
+                let rewrites = rewrites.clone();
+                async move {
+                    match db.rewrite_content(article_id, &rewrites, &markdown).await {
+                        Ok(shown) => shown,
+                        Err(e) => {
+                            tracing::warn!(article_id, error = %e, "Failed to apply content rewrites");
+                            markdown
+                        }
+                    }
+                }
+            };
+
             // 1. Check content_cache table (TTL-aware)
             if let Ok(Some(cached)) = db.get_cached_content(article_id).await {
                 tracing::debug!(article_id, generation, "content_cache hit");
+                let markdown = rewrite(cached.markdown).await;
                 if let Err(e) = tx
                     .send(AppEvent::ContentLoaded {
                         article_id,
                         generation,
-                        result: Ok(cached.markdown),
+                        result: Ok(markdown),
                         cached: true,
                     })
                     .await
@@ -390,9 +421,10 @@ pub(super) fn spawn_content_load(
             if let Ok(Some(content)) = db.get_article_content(article_id).await {
                 tracing::debug!(article_id, generation, "articles.content hit, migrating to cache");
                 // Migrate legacy content into content_cache for future TTL-aware lookups
-                if let Err(e) = db.cache_content(article_id, &content, None).await {
+                if let Err(e) = db.cache_content(article_id, &content, &cache_policy).await {
                     tracing::warn!(article_id, error = %e, "Failed to migrate content to cache");
                 }
+                let content = rewrite(content).await;
                 if let Err(e) = tx
                     .send(AppEvent::ContentLoaded {
                         article_id,
@@ -412,7 +444,7 @@ pub(super) fn spawn_content_load(
 
             // On success: cache content, update articles.content, index for FTS5
             if let Ok(ref markdown) = result {
-                if let Err(e) = db.cache_content(article_id, markdown, None).await {
+                if let Err(e) = db.cache_content(article_id, markdown, &cache_policy).await {
                     tracing::warn!(article_id, error = %e, "Failed to cache content");
                     let _ = tx
                         .send(AppEvent::ContentCacheFailed {
@@ -427,6 +459,10 @@ pub(super) fn spawn_content_load(
                     tracing::warn!(article_id, error = %e, "Failed to index content for FTS5");
                 }
             }
+            let result = match result {
+                Ok(markdown) => Ok(rewrite(markdown).await),
+                Err(e) => Err(e),
+            };
 
             if let Err(e) = tx
                 .send(AppEvent::ContentLoaded {
@@ -456,6 +492,51 @@ pub(super) fn spawn_content_load(
     })
 }
 
+/// Load a page reached through a link into the reader.
+///
+/// Fetched through jina.ai like article content, but the page is not stored,
+/// so nothing is cached, indexed or rewritten. Uses the same generation
+/// counter and handle as article loads; sends `AppEvent::ContentLoaded`.
+pub(super) fn spawn_link_load(app: &mut App, article: &Article, event_tx: &mpsc::Sender<AppEvent>) {
+    let Some(url) = article.url.clone() else {
+        return;
+    };
+    if let Some(handle) = app.content_load_handle.take() {
+        handle.abort();
+    }
+    let article_id = article.id;
+    app.content_loading_for = Some(article_id);
+    app.content_load_generation += 1;
+    let generation = app.content_load_generation;
+    let client = app.http_client.clone();
+    let tx = event_tx.clone();
+
+    app.content_load_handle = Some(tokio::spawn(async move {
+        let tx_panic = tx.clone();
+        if let Err(panic_msg) = catch_task_panic(async {
+            let result = fetch_content(&client, &url, None).await;
+            let _ = tx
+                .send(AppEvent::ContentLoaded {
+                    article_id,
+                    generation,
+                    result,
+                    cached: false,
+                })
+                .await;
+        })
+        .await
+        {
+            tracing::error!(task = "link_load", error = %panic_msg, "Background task panicked");
+            let _ = tx_panic
+                .send(AppEvent::TaskPanicked {
+                    task: "link_load",
+                    error: panic_msg,
+                })
+                .await;
+        }
+    }));
+}
+
 /// Spawn a background task to load cached article IDs for the current article list.
 ///
 /// Sends `AppEvent::CachedIdsLoaded` with the set of article IDs that have valid
@@ -468,6 +549,15 @@ pub(super) fn spawn_cached_ids_load(
     tokio::spawn(async move {
         match db.cached_article_ids(&article_ids).await {
             Ok(ids) => {
+                // TL;DRs already stored for them, for the list preview
+                match db.cached_tldrs(&ids).await {
+                    Ok(tldrs) => {
+                        let _ = tx.send(AppEvent::TldrsLoaded(tldrs)).await;
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Failed to load stored TL;DRs");
+                    }
+                }
                 let set: HashSet<i64> = ids.into_iter().collect();
                 let _ = tx.send(AppEvent::CachedIdsLoaded(set)).await;
             }
@@ -478,6 +568,99 @@ pub(super) fn spawn_cached_ids_load(
     });
 }
 
+/// Spawn a background task to summarize the content shown in the reader.
+///
+/// Sends `AppEvent::TldrLoaded`; the summary is computed once and then read
+/// back from the content cache (see `Database::article_tldr`).
+pub(super) fn spawn_tldr_load(
+    article_id: i64,
+    markdown: String,
+    db: Database,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    tokio::spawn(async move {
+        match db.article_tldr(article_id, &markdown).await {
+            Ok(sentences) => {
+                let _ = tx
+                    .send(AppEvent::TldrLoaded {
+                        article_id,
+                        sentences,
+                    })
+                    .await;
+            }
+            Err(e) => {
+                tracing::warn!(article_id, error = %e, "Failed to summarize article");
+            }
+        }
+    });
+}
+
+/// Spawn a background task to reload tag counts for the sidebar and tag picker.
+///
+/// Sends `AppEvent::TagsLoaded`. Called after anything that changes which
+/// articles carry a tag (tagging, starring, refreshes running tag rules).
+pub(super) fn spawn_tag_counts_load(db: Database, tx: mpsc::Sender<AppEvent>) {
+    tokio::spawn(async move {
+        match db.get_tag_counts().await {
+            Ok(tags) => {
+                let _ = tx.send(AppEvent::TagsLoaded(tags)).await;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load tag counts");
+            }
+        }
+    });
+}
+
+/// Spawn a background task to reload the read-later queue IDs and its length.
+///
+/// Sends `AppEvent::ReadLaterLoaded`, which keeps list indicators and the
+/// queue's reading-time estimate current after the queue changes.
+pub(super) fn spawn_read_later_load(db: Database, tx: mpsc::Sender<AppEvent>) {
+    tokio::spawn(async move {
+        match tokio::try_join!(db.read_later_ids(), db.read_later_words()) {
+            Ok((ids, words)) => {
+                let _ = tx.send(AppEvent::ReadLaterLoaded { ids, words }).await;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load read-later queue");
+            }
+        }
+    });
+}
+
+/// Fetch and cache an article's content unless a copy is already cached.
+///
+/// Returns `None` when nothing needed saving. Used for articles whose cached
+/// content never expires (starred, archived and queued ones).
+pub(super) async fn save_offline_copy(
+    db: &Database,
+    client: &reqwest::Client,
+    cache_policy: &CachePolicy,
+    article_id: i64,
+    url: Option<&str>,
+) -> Option<Result<(), String>> {
+    // cached_article_ids, unlike get_cached_content, doesn't count as a read
+    if matches!(db.cached_article_ids(&[article_id]).await, Ok(ids) if !ids.is_empty()) {
+        return None;
+    }
+    let Some(url) = url else {
+        return Some(Err(ERR_ARTICLE_NO_URL.to_string()));
+    };
+    Some(match fetch_content(client, url, None).await {
+        Ok(markdown) => {
+            // index_content writes articles.content AND triggers FTS5 update
+            if let Err(e) = db.index_content(article_id, &markdown).await {
+                tracing::warn!(article_id, error = %e, "Failed to index content for FTS5");
+            }
+            db.cache_content(article_id, &markdown, cache_policy)
+                .await
+                .map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    })
+}
+
 /// Spawn a background prefetch task for a feed's unread articles.
 ///
 /// Fetches up to `limit` unread articles without cache entries, caching each one.
@@ -486,6 +669,7 @@ pub(super) fn spawn_prefetch(
     feed_id: i64,
     db: Database,
     client: reqwest::Client,
+    cache_policy: Arc<CachePolicy>,
     tx: mpsc::Sender<AppEvent>,
 ) {
     const PREFETCH_LIMIT: i64 = 50;
@@ -552,7 +736,9 @@ pub(super) fn spawn_prefetch(
                 match fetch_content(&client, url, None).await {
                     Ok(markdown) => {
                         // Cache content (non-fatal on error)
-                        let _ = db.cache_content(*article_id, &markdown, None).await;
+                        let _ = db
+                            .cache_content(*article_id, &markdown, &cache_policy)
+                            .await;
                         // index_content writes articles.content AND triggers FTS5 update
                         let _ = db.index_content(*article_id, &markdown).await;
                         succeeded += 1;
@@ -583,3 +769,108 @@ pub(super) fn spawn_prefetch(
         }
     });
 }
+
+/// Spawn the automatic prefetch that follows a refresh.
+///
+/// Fetches new unread articles of auto-prefetch feeds and categories within
+/// the `[prefetch]` limits, `concurrency` at a time. Failures are recorded so
+/// later runs retry them with backoff. Sends `PrefetchProgress` and
+/// `PrefetchComplete` only when there is something to fetch.
+pub(super) fn spawn_auto_prefetch(
+    settings: PrefetchConfig,
+    db: Database,
+    client: reqwest::Client,
+    cache_policy: Arc<CachePolicy>,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    tokio::spawn(async move {
+        let tx_panic = tx.clone();
+        match catch_task_panic(async {
+            let limit = i64::try_from(settings.max_articles).unwrap_or(i64::MAX);
+            let candidates = match db
+                .auto_prefetch_candidates(&settings.categories, limit)
+                .await
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to get automatic prefetch candidates");
+                    return;
+                }
+            };
+            let total = candidates.len();
+            if total == 0 {
+                return;
+            }
+            let _ = tx
+                .send(AppEvent::PrefetchProgress {
+                    completed: 0,
+                    total,
+                })
+                .await;
+
+            // Checked before each fetch starts, so a run may overshoot by
+            // the articles already in flight
+            let max_bytes = usize::try_from(settings.max_mb.saturating_mul(1024 * 1024))
+                .unwrap_or(usize::MAX);
+            let fetched_bytes = AtomicUsize::new(0);
+            let (db, client, cache_policy, fetched_bytes) =
+                (&db, &client, &cache_policy, &fetched_bytes);
+            let mut outcomes = stream::iter(candidates)
+                .map(|(article_id, url)| async move {
+                    if fetched_bytes.load(Ordering::Relaxed) >= max_bytes {
+                        return None;
+                    }
+                    Some(match fetch_content(client, &url, None).await {
+                        Ok(markdown) => {
+                            fetched_bytes.fetch_add(markdown.len(), Ordering::Relaxed);
+                            // index_content writes articles.content AND triggers FTS5 update
+                            let _ = db.index_content(article_id, &markdown).await;
+                            let _ = db.cache_content(article_id, &markdown, cache_policy).await;
+                            let _ = db.clear_prefetch_failure(article_id).await;
+                            true
+                        }
+                        Err(e) => {
+                            tracing::warn!(article_id, error = %e, "Automatic prefetch failed for article");
+                            if let Err(e) = db.record_prefetch_failure(article_id, &e.to_string()).await {
+                                tracing::warn!(article_id, error = %e, "Failed to record prefetch failure");
+                            }
+                            false
+                        }
+                    })
+                })
+                .buffer_unordered(settings.concurrency.max(1));
+
+            let (mut completed, mut succeeded, mut failed) = (0, 0, 0);
+            while let Some(outcome) = outcomes.next().await {
+                completed += 1;
+                match outcome {
+                    Some(true) => succeeded += 1,
+                    Some(false) => failed += 1,
+                    None => {} // Over the byte budget; left for the next run
+                }
+                if completed < total {
+                    let _ = tx
+                        .send(AppEvent::PrefetchProgress { completed, total })
+                        .await;
+                }
+            }
+
+            let _ = tx
+                .send(AppEvent::PrefetchComplete { succeeded, failed })
+                .await;
+        })
+        .await
+        {
+            Ok(()) => {}
+            Err(panic_msg) => {
+                tracing::error!(task = "auto_prefetch", error = %panic_msg, "Automatic prefetch task panicked");
+                let _ = tx_panic
+                    .send(AppEvent::TaskPanicked {
+                        task: "auto_prefetch",
+                        error: panic_msg,
+                    })
+                    .await;
+            }
+        }
+    });
+}
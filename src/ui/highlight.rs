@@ -0,0 +1,689 @@
+//! Syntax highlighting for fenced code blocks in the reader.
+//!
+//! A small line-by-line tokenizer rather than a full grammar: keywords,
+//! strings, comments, numbers and type-like names are enough to make code
+//! in blog posts readable. Block comments and multi-line strings carry over
+//! to the next line. Token colors are theme roles patched over
+//! `reader_code_block`, so they follow the active palette.
+
+use crate::theme::StyleMap;
+use ratatui::{style::Style, text::Span};
+
+/// How a language's lines are tokenized beyond the common rules.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Code,
+    /// `#` starts a comment only after whitespace; `$NAME` is a variable.
+    Shell,
+    /// Strings followed by `:` are keys.
+    Json,
+    /// `key:` at the start of a line is a key; `#` as in shell.
+    Yaml,
+    /// Whole lines styled by their `+`/`-`/`@@` prefix.
+    Diff,
+}
+
+struct Language {
+    /// Fence info strings naming the language.
+    names: &'static [&'static str],
+    kind: Kind,
+    keywords: &'static [&'static str],
+    /// Literals and built-in types, styled as types.
+    constants: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    /// String delimiters, longest first; each closes itself.
+    strings: &'static [&'static str],
+    /// Delimiters of strings that may run over several lines.
+    multiline_strings: &'static [&'static str],
+    /// Keywords match in any case (SQL).
+    case_insensitive: bool,
+    /// Capitalized names are types (Rust, Python, TypeScript).
+    capitalized_types: bool,
+}
+
+const CODE: Language = Language {
+    names: &[],
+    kind: Kind::Code,
+    keywords: &[],
+    constants: &[],
+    line_comments: &[],
+    block_comment: None,
+    strings: &[],
+    multiline_strings: &[],
+    case_insensitive: false,
+    capitalized_types: false,
+};
+
+const LANGUAGES: &[Language] = &[
+    Language {
+        names: &["rust", "rs"],
+        keywords: &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+            "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+            "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "type",
+            "unsafe", "use", "where", "while",
+        ],
+        constants: &["true", "false"],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        strings: &["\""],
+        multiline_strings: &["\""],
+        capitalized_types: true,
+        ..CODE
+    },
+    Language {
+        names: &["python", "py", "python3"],
+        keywords: &[
+            "and", "as", "assert", "async", "await", "break", "case", "class", "continue", "def",
+            "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+            "in", "is", "lambda", "match", "nonlocal", "not", "or", "pass", "raise", "return",
+            "try", "while", "with", "yield",
+        ],
+        constants: &["True", "False", "None", "self"],
+        line_comments: &["#"],
+        strings: &["\"\"\"", "'''", "\"", "'"],
+        multiline_strings: &["\"\"\"", "'''"],
+        capitalized_types: true,
+        ..CODE
+    },
+    Language {
+        names: &["go", "golang"],
+        keywords: &[
+            "break",
+            "case",
+            "chan",
+            "const",
+            "continue",
+            "default",
+            "defer",
+            "else",
+            "fallthrough",
+            "for",
+            "func",
+            "go",
+            "goto",
+            "if",
+            "import",
+            "interface",
+            "map",
+            "package",
+            "range",
+            "return",
+            "select",
+            "struct",
+            "switch",
+            "type",
+            "var",
+        ],
+        constants: &[
+            "true", "false", "nil", "iota", "any", "bool", "byte", "error", "float32", "float64",
+            "int", "int8", "int16", "int32", "int64", "rune", "string", "uint", "uint8", "uint16",
+            "uint32", "uint64",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        strings: &["\"", "`", "'"],
+        multiline_strings: &["`"],
+        ..CODE
+    },
+    Language {
+        names: &[
+            "javascript",
+            "js",
+            "jsx",
+            "mjs",
+            "cjs",
+            "typescript",
+            "ts",
+            "tsx",
+        ],
+        keywords: &[
+            "as",
+            "async",
+            "await",
+            "break",
+            "case",
+            "catch",
+            "class",
+            "const",
+            "continue",
+            "debugger",
+            "declare",
+            "default",
+            "delete",
+            "do",
+            "else",
+            "enum",
+            "export",
+            "extends",
+            "finally",
+            "for",
+            "from",
+            "function",
+            "if",
+            "implements",
+            "import",
+            "in",
+            "instanceof",
+            "interface",
+            "let",
+            "new",
+            "of",
+            "private",
+            "protected",
+            "public",
+            "readonly",
+            "return",
+            "static",
+            "super",
+            "switch",
+            "this",
+            "throw",
+            "try",
+            "type",
+            "typeof",
+            "var",
+            "void",
+            "while",
+            "yield",
+        ],
+        constants: &[
+            "true",
+            "false",
+            "null",
+            "undefined",
+            "NaN",
+            "Infinity",
+            "string",
+            "number",
+            "boolean",
+            "any",
+            "unknown",
+            "never",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        strings: &["`", "\"", "'"],
+        multiline_strings: &["`"],
+        capitalized_types: true,
+        ..CODE
+    },
+    Language {
+        names: &["sh", "bash", "shell", "zsh", "console"],
+        kind: Kind::Shell,
+        keywords: &[
+            "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case",
+            "esac", "in", "function", "return", "export", "local", "readonly", "unset", "source",
+        ],
+        constants: &["true", "false"],
+        line_comments: &["#"],
+        strings: &["\"", "'"],
+        multiline_strings: &["\"", "'"],
+        ..CODE
+    },
+    Language {
+        names: &["sql", "postgres", "postgresql", "mysql", "sqlite"],
+        keywords: &[
+            "add",
+            "all",
+            "alter",
+            "and",
+            "as",
+            "asc",
+            "begin",
+            "between",
+            "by",
+            "case",
+            "check",
+            "column",
+            "commit",
+            "constraint",
+            "create",
+            "default",
+            "delete",
+            "desc",
+            "distinct",
+            "drop",
+            "else",
+            "end",
+            "exists",
+            "foreign",
+            "from",
+            "full",
+            "group",
+            "having",
+            "if",
+            "in",
+            "index",
+            "inner",
+            "insert",
+            "into",
+            "is",
+            "join",
+            "key",
+            "left",
+            "like",
+            "limit",
+            "not",
+            "offset",
+            "on",
+            "or",
+            "order",
+            "outer",
+            "primary",
+            "references",
+            "replace",
+            "returning",
+            "right",
+            "rollback",
+            "select",
+            "set",
+            "table",
+            "then",
+            "transaction",
+            "union",
+            "unique",
+            "update",
+            "values",
+            "view",
+            "when",
+            "where",
+            "with",
+        ],
+        constants: &[
+            "null",
+            "true",
+            "false",
+            "int",
+            "integer",
+            "bigint",
+            "smallint",
+            "text",
+            "varchar",
+            "char",
+            "boolean",
+            "bool",
+            "date",
+            "timestamp",
+            "timestamptz",
+            "real",
+            "float",
+            "double",
+            "numeric",
+            "decimal",
+            "serial",
+            "blob",
+            "json",
+            "jsonb",
+            "uuid",
+        ],
+        line_comments: &["--"],
+        block_comment: Some(("/*", "*/")),
+        strings: &["'", "\""],
+        case_insensitive: true,
+        ..CODE
+    },
+    Language {
+        names: &["json", "jsonc", "json5"],
+        kind: Kind::Json,
+        constants: &["true", "false", "null"],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        strings: &["\""],
+        ..CODE
+    },
+    Language {
+        names: &["yaml", "yml"],
+        kind: Kind::Yaml,
+        constants: &["true", "false", "null", "yes", "no"],
+        line_comments: &["#"],
+        strings: &["\"", "'"],
+        ..CODE
+    },
+    Language {
+        names: &["diff", "patch"],
+        kind: Kind::Diff,
+        ..CODE
+    },
+];
+
+/// A comment or string still open at the end of the previous line.
+struct Carry {
+    close: &'static str,
+    comment: bool,
+}
+
+/// Highlights the lines of one code block in order.
+pub(super) struct Highlighter {
+    language: Option<&'static Language>,
+    carry: Option<Carry>,
+}
+
+impl Highlighter {
+    /// Highlighter for a fence info string such as `rust`, `py title="x"`
+    /// or `language-ts`. Unknown languages get the plain code block style.
+    pub(super) fn new(info: &str) -> Self {
+        let name = info
+            .split(|c: char| c == ',' || c == '{' || c.is_whitespace())
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let name = name.strip_prefix("language-").unwrap_or(&name);
+        Self {
+            language: LANGUAGES.iter().find(|l| l.names.contains(&name)),
+            carry: None,
+        }
+    }
+
+    /// Styled spans for the next line of the block.
+    pub(super) fn line(&mut self, text: &str, styles: &StyleMap) -> Vec<Span<'static>> {
+        let base = styles.resolve("reader_code_block");
+        let Some(language) = self.language else {
+            return vec![Span::styled(text.to_string(), base)];
+        };
+        let role = |name: &str| base.patch(styles.resolve(name));
+        let mut out = Tokens::default();
+
+        if language.kind == Kind::Diff {
+            let style = if text.starts_with("+++") || text.starts_with("---") {
+                role("reader_code_keyword")
+            } else if text.starts_with('+') {
+                role("reader_diff_added")
+            } else if text.starts_with('-') {
+                role("reader_diff_removed")
+            } else if text.starts_with("@@") {
+                role("reader_code_type")
+            } else {
+                base
+            };
+            out.push(text, style);
+            return out.spans;
+        }
+
+        let mut i = 0;
+        if let Some(carry) = self.carry.take() {
+            let (end, closed) = find_close(text, 0, carry.close, !carry.comment);
+            let style = if carry.comment {
+                role("reader_code_comment")
+            } else {
+                role("reader_code_string")
+            };
+            out.push(&text[..end], style);
+            if !closed {
+                self.carry = Some(carry);
+                return out.spans;
+            }
+            i = end;
+        }
+
+        if language.kind == Kind::Yaml && i == 0 {
+            if let Some((start, end)) = yaml_key(text) {
+                out.push(&text[..start], base);
+                out.push(&text[start..end], role("reader_code_keyword"));
+                i = end;
+            }
+        }
+
+        while i < text.len() {
+            let rest = &text[i..];
+            let prev = text[..i].chars().next_back();
+            let hash_needs_space = matches!(language.kind, Kind::Shell | Kind::Yaml);
+
+            let line_comment = language.line_comments.iter().any(|c| rest.starts_with(c))
+                && !(hash_needs_space && prev.is_some_and(|p| !p.is_whitespace()));
+            if line_comment {
+                out.push(rest, role("reader_code_comment"));
+                break;
+            }
+            if let Some((open, close)) = language.block_comment {
+                if rest.starts_with(open) {
+                    let (end, closed) = find_close(text, i + open.len(), close, false);
+                    out.push(&text[i..end], role("reader_code_comment"));
+                    if !closed {
+                        self.carry = Some(Carry {
+                            close,
+                            comment: true,
+                        });
+                    }
+                    i = end;
+                    continue;
+                }
+            }
+            if let Some(&delim) = language.strings.iter().find(|d| rest.starts_with(*d)) {
+                let (end, closed) = find_close(text, i + delim.len(), delim, true);
+                let key = language.kind == Kind::Json && text[end..].trim_start().starts_with(':');
+                let style = if key {
+                    role("reader_code_type")
+                } else {
+                    role("reader_code_string")
+                };
+                out.push(&text[i..end], style);
+                if !closed && language.multiline_strings.contains(&delim) {
+                    self.carry = Some(Carry {
+                        close: delim,
+                        comment: false,
+                    });
+                }
+                i = end;
+                continue;
+            }
+
+            let c = rest.chars().next().unwrap_or_default();
+            let after_word = prev.is_some_and(is_word_char);
+            if c.is_ascii_digit() && !after_word {
+                let end = word_end(text, i, |c| is_word_char(c) || c == '.');
+                out.push(&text[i..end], role("reader_code_number"));
+                i = end;
+            } else if (c.is_alphabetic() || c == '_') && !after_word {
+                let end = word_end(text, i, is_word_char);
+                let word = &text[i..end];
+                out.push(word, word_style(language, word, base, &role));
+                i = end;
+            } else if c == '$' && language.kind == Kind::Shell {
+                let end = if rest.starts_with("${") {
+                    rest.find('}').map_or(text.len(), |close| i + close + 1)
+                } else {
+                    word_end(text, i + 1, is_word_char)
+                };
+                out.push(&text[i..end], role("reader_code_type"));
+                i = end;
+            } else {
+                out.push(&rest[..c.len_utf8()], base);
+                i += c.len_utf8();
+            }
+        }
+        out.spans
+    }
+}
+
+/// Spans built token by token, merging neighbours of the same style.
+#[derive(Default)]
+struct Tokens {
+    spans: Vec<Span<'static>>,
+}
+
+impl Tokens {
+    fn push(&mut self, text: &str, style: Style) {
+        if text.is_empty() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(text),
+            _ => self.spans.push(Span::styled(text.to_string(), style)),
+        }
+    }
+}
+
+fn word_style(
+    language: &Language,
+    word: &str,
+    base: Style,
+    role: &impl Fn(&str) -> Style,
+) -> Style {
+    let listed = |list: &[&str]| {
+        if language.case_insensitive {
+            list.iter().any(|w| w.eq_ignore_ascii_case(word))
+        } else {
+            list.contains(&word)
+        }
+    };
+    if listed(language.keywords) {
+        role("reader_code_keyword")
+    } else if listed(language.constants)
+        || (language.capitalized_types && word.starts_with(|c: char| c.is_uppercase()))
+    {
+        role("reader_code_type")
+    } else {
+        base
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Byte index after the run of `accept` chars starting at `from`.
+fn word_end(text: &str, from: usize, accept: impl Fn(char) -> bool) -> usize {
+    text[from..]
+        .char_indices()
+        .find(|&(_, c)| !accept(c))
+        .map_or(text.len(), |(at, _)| from + at)
+}
+
+/// Byte index after `close` searching from `from`, and whether it was
+/// found; with `escapes`, a backslash skips the next char.
+fn find_close(text: &str, from: usize, close: &str, escapes: bool) -> (usize, bool) {
+    let mut chars = text[from..].char_indices();
+    while let Some((at, c)) = chars.next() {
+        if escapes && c == '\\' {
+            chars.next();
+        } else if text[from + at..].starts_with(close) {
+            return (from + at + close.len(), true);
+        }
+    }
+    (text.len(), false)
+}
+
+/// Byte range of a YAML mapping key (`key:`), after indentation and list dashes.
+fn yaml_key(text: &str) -> Option<(usize, usize)> {
+    let body = text.trim_start_matches([' ', '-']);
+    let start = text.len() - body.len();
+    let colon = body.find(':')?;
+    let key = &body[..colon];
+    let ends_key = body[colon + 1..]
+        .chars()
+        .next()
+        .is_none_or(char::is_whitespace);
+    let plain =
+        !key.is_empty() && !key.starts_with(['#', '"', '\'', '{', '[']) && !key.contains(" #");
+    (ends_key && plain).then_some((start, start + colon))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::ThemeVariant;
+
+    fn styles() -> StyleMap {
+        StyleMap::from_palette(&ThemeVariant::Dark.palette())
+    }
+
+    /// Text of the spans styled with `role` on the given lines.
+    fn tokens(lang: &str, lines: &[&str], role: &str) -> Vec<String> {
+        let styles = styles();
+        let style = styles
+            .resolve("reader_code_block")
+            .patch(styles.resolve(role));
+        let mut highlighter = Highlighter::new(lang);
+        lines
+            .iter()
+            .flat_map(|line| highlighter.line(line, &styles))
+            .filter(|span| span.style == style)
+            .map(|span| span.content.into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_rust_tokens() {
+        let line = ["pub fn add(x: u32) -> Option<u32> { Some(x + 1) } // \"not a string\""];
+        assert_eq!(tokens("rust", &line, "reader_code_keyword"), ["pub", "fn"]);
+        assert_eq!(
+            tokens("rust", &line, "reader_code_type"),
+            ["Option", "Some"]
+        );
+        assert_eq!(tokens("rust", &line, "reader_code_number"), ["1"]);
+        assert_eq!(
+            tokens("rust", &line, "reader_code_comment"),
+            ["// \"not a string\""]
+        );
+        // Digits inside names aren't numbers
+        assert!(tokens("rs", &["let u32x2 = 0;"], "reader_code_number") == ["0"]);
+    }
+
+    #[test]
+    fn test_multiline_strings_and_comments_carry() {
+        let lines = ["x = '''doc", "still doc", "''' if y else 2"];
+        assert_eq!(
+            tokens("python", &lines, "reader_code_string"),
+            ["'''doc", "still doc", "'''"]
+        );
+        assert_eq!(
+            tokens("python", &lines, "reader_code_keyword"),
+            ["if", "else"]
+        );
+        let lines = ["a /* one", "two */ b"];
+        assert_eq!(
+            tokens("go", &lines, "reader_code_comment"),
+            ["/* one", "two */"]
+        );
+    }
+
+    #[test]
+    fn test_data_and_shell_languages() {
+        let line = [r#"{"name": "skim", "stars": 42, "ok": true}"#];
+        assert_eq!(
+            tokens("json", &line, "reader_code_type"),
+            ["\"name\"", "\"stars\"", "\"ok\"", "true"]
+        );
+        assert_eq!(tokens("json", &line, "reader_code_string"), ["\"skim\""]);
+
+        let lines = ["- name: build # the job", "  url: http://x.example/#top"];
+        assert_eq!(
+            tokens("yaml", &lines, "reader_code_keyword"),
+            ["name", "url"]
+        );
+        assert_eq!(tokens("yaml", &lines, "reader_code_comment"), ["# the job"]);
+
+        let line = ["if [ -n \"$HOME\" ]; then echo a#b $USER; fi # done"];
+        assert_eq!(
+            tokens("bash", &line, "reader_code_keyword"),
+            ["if", "then", "fi"]
+        );
+        assert_eq!(tokens("sh", &line, "reader_code_type"), ["$USER"]);
+        assert_eq!(tokens("sh", &line, "reader_code_comment"), ["# done"]);
+
+        let line = ["Select * FROM t WHERE id = 'x' -- note"];
+        assert_eq!(
+            tokens("sql", &line, "reader_code_keyword"),
+            ["Select", "FROM", "WHERE"]
+        );
+    }
+
+    #[test]
+    fn test_diff_and_unknown_languages() {
+        let lines = ["@@ -1 +1 @@", "-old", "+new", " same"];
+        assert_eq!(tokens("diff", &lines, "reader_diff_removed"), ["-old"]);
+        assert_eq!(tokens("diff", &lines, "reader_diff_added"), ["+new"]);
+
+        let styles = styles();
+        let spans = Highlighter::new("brainfuck").line("fn if 1", &styles);
+        assert_eq!(
+            spans,
+            [Span::styled("fn if 1", styles.resolve("reader_code_block"))]
+        );
+        assert!(Highlighter::new("language-TS {.numberLines}")
+            .language
+            .is_some());
+    }
+}
@@ -0,0 +1,142 @@
+//! OSC 8 hyperlinks on top of ratatui's cell buffer.
+//!
+//! Cells can't carry hyperlinks, so after each draw the loop runner re-prints
+//! the cells of every link wrapped in `ESC ] 8 ; ; URL ESC \` ... `ESC ] 8 ; ; ESC \`.
+//! The terminal keeps the link on those cells until they are printed again,
+//! which ratatui only does when their content changes, so links of the
+//! previous frame that are gone are re-printed plain.
+
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Color as CColor, Colors, Print, ResetColor, SetAttribute, SetColors},
+};
+use ratatui::{buffer::Buffer, style::Modifier};
+use std::io::{self, Write};
+use unicode_width::UnicodeWidthStr;
+
+use crate::util::hyperlink_target;
+
+/// A run of screen cells on one row that links to `url`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hyperlink {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    url: String,
+}
+
+impl Hyperlink {
+    /// Link `width` cells at (`x`, `y`) to `url`, or `None` if the URL isn't
+    /// safe to emit (see [`hyperlink_target`]).
+    pub fn new(x: u16, y: u16, width: u16, url: &str) -> Option<Self> {
+        if width == 0 {
+            return None;
+        }
+        Some(Self {
+            x,
+            y,
+            width,
+            url: hyperlink_target(url)?,
+        })
+    }
+}
+
+/// Escape sequences that make `links` clickable in the drawn `buffer`, after
+/// re-printing the `stale` links of the previous frame that aren't links now.
+pub fn overlay(buffer: &Buffer, links: &[Hyperlink], stale: &[Hyperlink]) -> io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for link in stale.iter().filter(|l| !links.contains(l)) {
+        print_cells(&mut out, buffer, link)?;
+    }
+    for link in links {
+        write!(out, "\x1b]8;;{}\x1b\\", link.url)?;
+        print_cells(&mut out, buffer, link)?;
+        write!(out, "\x1b]8;;\x1b\\")?;
+    }
+    if !out.is_empty() {
+        queue!(out, SetAttribute(Attribute::Reset), ResetColor)?;
+    }
+    Ok(out)
+}
+
+/// Print the cells under `link` again with their styles.
+fn print_cells(out: &mut Vec<u8>, buffer: &Buffer, link: &Hyperlink) -> io::Result<()> {
+    let area = buffer.area;
+    if link.y < area.top() || link.y >= area.bottom() {
+        return Ok(());
+    }
+    let mut x = link.x.max(area.left());
+    // Don't start on the right half of a wide character
+    if x > area.left() && buffer[(x - 1, link.y)].symbol().width() > 1 {
+        x += 1;
+    }
+    let end = link.x.saturating_add(link.width).min(area.right());
+    queue!(out, MoveTo(x, link.y))?;
+    while x < end {
+        let cell = &buffer[(x, link.y)];
+        let width = cell.symbol().width().max(1) as u16;
+        if cell.skip || x + width > end {
+            break;
+        }
+        queue!(out, SetAttribute(Attribute::Reset))?;
+        for (modifier, attribute) in ATTRIBUTES {
+            if cell.modifier.contains(modifier) {
+                queue!(out, SetAttribute(attribute))?;
+            }
+        }
+        queue!(
+            out,
+            SetColors(Colors::new(CColor::from(cell.fg), CColor::from(cell.bg))),
+            Print(cell.symbol())
+        )?;
+        x += width;
+    }
+    Ok(())
+}
+
+const ATTRIBUTES: [(Modifier, Attribute); 9] = [
+    (Modifier::BOLD, Attribute::Bold),
+    (Modifier::DIM, Attribute::Dim),
+    (Modifier::ITALIC, Attribute::Italic),
+    (Modifier::UNDERLINED, Attribute::Underlined),
+    (Modifier::SLOW_BLINK, Attribute::SlowBlink),
+    (Modifier::RAPID_BLINK, Attribute::RapidBlink),
+    (Modifier::REVERSED, Attribute::Reverse),
+    (Modifier::HIDDEN, Attribute::Hidden),
+    (Modifier::CROSSED_OUT, Attribute::CrossedOut),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_overlay_wraps_cells() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        buffer.set_string(0, 1, "see 世界!", ratatui::style::Style::default());
+        let link = Hyperlink::new(4, 1, 4, "https://example.com/a b").unwrap();
+
+        let out =
+            String::from_utf8(overlay(&buffer, std::slice::from_ref(&link), &[]).unwrap()).unwrap();
+        let start = out.find("\x1b]8;;https://example.com/a%20b\x1b\\").unwrap();
+        let end = out.rfind("\x1b]8;;\x1b\\").unwrap();
+        let linked = &out[start..end];
+        assert!(linked.contains('世') && linked.contains('界'));
+        assert!(!linked.contains('!'));
+
+        // Unchanged links are printed again, gone ones plain
+        let out = String::from_utf8(overlay(&buffer, &[], &[link]).unwrap()).unwrap();
+        assert!(out.contains('世'));
+        assert!(!out.contains("\x1b]8"));
+        assert!(overlay(&buffer, &[], &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unsafe_targets_rejected() {
+        assert!(Hyperlink::new(0, 0, 3, "https://example.com/\x1b]8;;x").is_none());
+        assert!(Hyperlink::new(0, 0, 3, "file:///etc/passwd").is_none());
+        assert!(Hyperlink::new(0, 0, 0, "https://example.com/").is_none());
+    }
+}
@@ -4,25 +4,32 @@
 //! handler based on current view and mode.
 
 use crate::app::{
-    App, AppEvent, CachedArticleState, ConfirmAction, ContentState, ContextMenuState,
-    ContextMenuSubState, FetchResult, Focus, ReadingSession, StatsData, SubscribeState, View,
-    CONTEXT_MENU_ITEMS,
+    is_link_page, App, AppEvent, CacheOverview, CachedArticleState, ConfirmAction, ContentState,
+    ContextMenuState, ContextMenuSubState, FetchResult, Focus, LinkPickerState, ReadingSession,
+    StarredView, StatsData, SubscribeState, TagPickerState, View, CONTEXT_MENU_ITEMS,
 };
+use crate::config::{ReaderConfig, MIN_READER_WIDTH};
+use crate::content::save_url;
 use crate::feed::{discover_feed, refresh_all, refresh_one};
 use crate::keybindings::{Action as KbAction, Context as KbContext};
-use crate::util::validate_url;
+use crate::storage::{Feed, STARRED_TAG};
+use crate::util::{on_battery, validate_url};
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 use super::helpers::{
     catch_task_panic, exit_search_mode, exit_starred_mode, restore_articles_from_search,
-    spawn_cached_ids_load, spawn_prefetch, try_spawn_content_load, ERR_ARTICLE_NO_URL,
+    save_offline_copy, spawn_cached_ids_load, spawn_link_load, spawn_prefetch,
+    spawn_tag_counts_load, try_spawn_content_load, ERR_ARTICLE_NO_URL,
 };
 use super::Action;
-use crate::util::{validate_url_for_open, MAX_SEARCH_QUERY_LENGTH};
+use crate::util::{
+    reading_minutes, resolve_link, validate_url_for_open, MAX_SEARCH_QUERY_LENGTH, MAX_TAG_LENGTH,
+};
 
 /// Map the current focus panel to a keybinding context for context-specific lookups.
 fn focus_to_context(focus: Focus) -> KbContext {
@@ -58,11 +65,31 @@ pub(super) async fn handle_input(
         return handle_subscribe_input(app, code, event_tx).await;
     }
 
+    // Handle save URL dialog input (captures all keys when visible)
+    if app.save_url_input.is_some() {
+        return Ok(handle_save_url_input(app, code, event_tx));
+    }
+
     // Handle context menu input (captures all keys when visible)
     if app.context_menu.is_some() {
         return handle_context_menu_input(app, code, event_tx).await;
     }
 
+    // Handle tag picker input (captures all keys when visible)
+    if app.tag_picker.is_some() {
+        return handle_tag_picker_input(app, code, event_tx).await;
+    }
+
+    // Handle link picker input (captures all keys when visible)
+    if app.link_picker.is_some() {
+        return Ok(handle_link_picker_input(app, code, event_tx));
+    }
+
+    // Handle the reader's search prompt (captures all keys while typing)
+    if app.view == View::Reader && app.reader_search.as_ref().is_some_and(|s| s.editing) {
+        return Ok(handle_reader_search_input(app, code, modifiers));
+    }
+
     // Handle search mode input separately
     if app.search_mode {
         return handle_search_input(app, code, modifiers).await;
@@ -70,8 +97,10 @@ pub(super) async fn handle_input(
 
     match app.view {
         View::Browse => handle_browse_input(app, code, modifiers, event_tx).await,
-        View::Reader => handle_reader_input(app, code, modifiers, event_tx),
-        View::Stats => Ok(handle_stats_input(app, code, modifiers)),
+        View::Reader => handle_reader_input(app, code, modifiers, event_tx).await,
+        View::Stats => Ok(handle_stats_input(app, code, modifiers, event_tx)),
+        View::Health => Ok(handle_health_input(app, code, modifiers, event_tx)),
+        View::Cache => Ok(handle_cache_input(app, code, modifiers, event_tx)),
     }
 }
 
@@ -107,7 +136,7 @@ async fn handle_browse_input(
 
     match action {
         Some(KbAction::Quit) => {
-            close_reading_session(app);
+            close_reading_session(app, event_tx);
             return Ok(Action::Quit);
         }
         Some(KbAction::Back) => {
@@ -147,28 +176,31 @@ async fn handle_browse_input(
         }
         Some(KbAction::ToggleCategories) => {
             app.show_categories = !app.show_categories;
-            if !app.show_categories && app.focus == Focus::Categories {
+            if app.show_categories {
+                // Unread counts in the tag section drift as articles are read
+                spawn_tag_counts_load(app.db.clone(), event_tx.clone());
+            } else if app.focus == Focus::Categories {
                 app.focus = Focus::Feeds;
             }
             app.needs_redraw = true;
         }
-        Some(KbAction::CollapseCategory) => {
-            if app.focus == Focus::Categories {
-                if let Some(cat_id) = app.selected_category_id() {
-                    if !app.collapsed_categories.contains(&cat_id) {
-                        app.toggle_category_collapse(cat_id);
-                        app.needs_redraw = true;
-                    }
+        Some(KbAction::CollapseCategory)
+            if app.focus == Focus::Categories && app.selected_tag.is_none() =>
+        {
+            if let Some(cat_id) = app.selected_category_id() {
+                if !app.collapsed_categories.contains(&cat_id) {
+                    app.toggle_category_collapse(cat_id);
+                    app.needs_redraw = true;
                 }
             }
         }
-        Some(KbAction::ExpandCategory) => {
-            if app.focus == Focus::Categories {
-                if let Some(cat_id) = app.selected_category_id() {
-                    if app.collapsed_categories.contains(&cat_id) {
-                        app.toggle_category_collapse(cat_id);
-                        app.needs_redraw = true;
-                    }
+        Some(KbAction::ExpandCategory)
+            if app.focus == Focus::Categories && app.selected_tag.is_none() =>
+        {
+            if let Some(cat_id) = app.selected_category_id() {
+                if app.collapsed_categories.contains(&cat_id) {
+                    app.toggle_category_collapse(cat_id);
+                    app.needs_redraw = true;
                 }
             }
         }
@@ -176,7 +208,10 @@ async fn handle_browse_input(
             handle_enter_key(app, event_tx).await?;
         }
         Some(KbAction::RefreshAll) => {
-            handle_refresh_all(app, event_tx).await;
+            handle_refresh_all(app, event_tx, false).await;
+        }
+        Some(KbAction::ForceRefreshAll) => {
+            handle_refresh_all(app, event_tx, true).await;
         }
         Some(KbAction::RefreshOne) => {
             handle_refresh_one(app, event_tx).await;
@@ -220,19 +255,77 @@ async fn handle_browse_input(
             // Arc::clone is O(1) - just increments reference count
             app.cached_articles = Some(CachedArticleState {
                 feed_id: app.selected_feed().map(|f| f.id),
-                articles: Arc::clone(&app.articles),
+                articles: app.unfiltered_articles(),
                 selected: app.selected_article,
             });
+            app.reading_time_filter = None;
             app.search_mode = true;
             app.search_input.clear();
             app.search_feed_id = app.selected_feed().map(|f| f.id);
         }
         Some(KbAction::ToggleStarredMode) => {
-            handle_starred_mode_toggle(app).await?;
+            handle_starred_mode_toggle(app, StarredView::Starred).await?;
+        }
+        Some(KbAction::ToggleReadLaterMode) => {
+            handle_starred_mode_toggle(app, StarredView::ReadLater).await?;
+        }
+        Some(KbAction::ToggleContinueReading) => {
+            handle_starred_mode_toggle(app, StarredView::ContinueReading).await?;
+        }
+        Some(KbAction::ToggleReadLater) => {
+            if let Some(article) = app.selected_article() {
+                let (article_id, url) = (article.id, article.url.clone());
+                toggle_read_later(app, article_id, url, event_tx);
+            }
+        }
+        Some(KbAction::ToggleArchive) => {
+            if let Some(article_id) = app.selected_article().map(|a| a.id) {
+                toggle_archive(app, article_id, event_tx);
+            }
+        }
+        Some(KbAction::MoveItemUp) => move_in_read_later(app, true, event_tx),
+        Some(KbAction::MoveItemDown) => move_in_read_later(app, false, event_tx),
+        Some(KbAction::CycleReadingTimeFilter) => match app.cycle_reading_time_filter() {
+            Some(minutes) => app.set_status(format!(
+                "Showing articles under {} min ({} listed)",
+                minutes,
+                app.articles.len()
+            )),
+            None => app.set_status("Showing all articles"),
+        },
+        Some(KbAction::ToggleTldrPreview) => {
+            let layout = ReaderConfig {
+                tldr_preview: !app.reader_layout.tldr_preview,
+                ..app.reader_layout
+            };
+            set_reader_layout(app, layout).await;
+            app.set_status(if layout.tldr_preview {
+                "TL;DR preview on (articles read or saved offline)"
+            } else {
+                "TL;DR preview off"
+            });
+        }
+        Some(KbAction::EditTags) => {
+            if let Some(article) = app.selected_article() {
+                app.tag_picker = Some(TagPickerState::new(article));
+            }
         }
         Some(KbAction::ViewStats) => {
             enter_stats_view(app, event_tx);
         }
+        Some(KbAction::ViewFeedHealth) => {
+            enter_feed_health_view(app, event_tx);
+        }
+        Some(KbAction::ViewCache) => {
+            enter_cache_view(app, event_tx);
+        }
+        Some(KbAction::RediscoverFeed) => {
+            if let Some(feed) = app.selected_feed().cloned() {
+                spawn_rediscover_feed(app, &feed, event_tx);
+            } else {
+                app.set_status("No feed selected");
+            }
+        }
         Some(KbAction::MarkFeedRead) => {
             handle_mark_feed_read(app, event_tx).await;
         }
@@ -262,6 +355,9 @@ async fn handle_browse_input(
                 input: String::new(),
             });
         }
+        Some(KbAction::SaveUrl) => {
+            app.save_url_input = Some(String::new());
+        }
         Some(KbAction::ContextMenu) => {
             if let Some(feed) = app.selected_feed() {
                 app.context_menu = Some(ContextMenuState {
@@ -285,6 +381,7 @@ async fn handle_browse_input(
                     feed_id,
                     app.db.clone(),
                     app.http_client.clone(),
+                    app.cache_policy.clone(),
                     event_tx.clone(),
                 );
             } else {
@@ -358,6 +455,84 @@ async fn handle_context_menu_input(
                             app.set_status(format!("Opening {}...", menu.feed_title));
                         }
                     }
+                    5 => {
+                        // Toggle Preferred Source: shown instead of other copies of duplicates
+                        let prefer = !app
+                            .feeds
+                            .iter()
+                            .find(|f| f.id == menu.feed_id)
+                            .is_some_and(|f| f.prefer_source);
+                        let db = app.db.clone();
+                        let tx = event_tx.clone();
+                        let (feed_id, title) = (menu.feed_id, menu.feed_title);
+                        tokio::spawn(catch_task_panic(async move {
+                            let result = db
+                                .set_feed_prefer_source(feed_id, prefer)
+                                .await
+                                .map(|()| prefer)
+                                .map_err(|e| e.to_string());
+                            let _ = tx
+                                .send(AppEvent::FeedPreferSourceSet {
+                                    feed_id,
+                                    title,
+                                    result,
+                                })
+                                .await;
+                            Ok::<(), String>(())
+                        }));
+                    }
+                    6 => {
+                        // Toggle Unread on Update: edited articles become unread again
+                        let enabled = !app
+                            .feeds
+                            .iter()
+                            .find(|f| f.id == menu.feed_id)
+                            .is_some_and(|f| f.unread_on_update);
+                        let db = app.db.clone();
+                        let tx = event_tx.clone();
+                        let (feed_id, title) = (menu.feed_id, menu.feed_title);
+                        tokio::spawn(catch_task_panic(async move {
+                            let result = db
+                                .set_feed_unread_on_update(feed_id, enabled)
+                                .await
+                                .map(|()| enabled)
+                                .map_err(|e| e.to_string());
+                            let _ = tx
+                                .send(AppEvent::FeedUnreadOnUpdateSet {
+                                    feed_id,
+                                    title,
+                                    result,
+                                })
+                                .await;
+                            Ok::<(), String>(())
+                        }));
+                    }
+                    7 => {
+                        // Toggle Auto Prefetch: fetch new articles after each refresh
+                        let enabled = !app
+                            .feeds
+                            .iter()
+                            .find(|f| f.id == menu.feed_id)
+                            .is_some_and(|f| f.auto_prefetch);
+                        let db = app.db.clone();
+                        let tx = event_tx.clone();
+                        let (feed_id, title) = (menu.feed_id, menu.feed_title);
+                        tokio::spawn(catch_task_panic(async move {
+                            let result = db
+                                .set_feed_auto_prefetch(feed_id, enabled)
+                                .await
+                                .map(|()| enabled)
+                                .map_err(|e| e.to_string());
+                            let _ = tx
+                                .send(AppEvent::FeedAutoPrefetchSet {
+                                    feed_id,
+                                    title,
+                                    result,
+                                })
+                                .await;
+                            Ok::<(), String>(())
+                        }));
+                    }
                     _ => {
                         app.context_menu = Some(menu);
                     }
@@ -487,6 +662,107 @@ async fn handle_context_menu_input(
     Ok(Action::Continue)
 }
 
+/// Handle input while the tag picker is visible.
+///
+/// Typing filters the tags (and names a new one), Up/Down move, Enter
+/// toggles the highlighted tag on the article and Esc closes the picker.
+/// The built-in starred tag goes through the regular star toggle.
+async fn handle_tag_picker_input(
+    app: &mut App,
+    code: KeyCode,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Result<Action> {
+    let Some(mut picker) = app.tag_picker.take() else {
+        return Ok(Action::Continue);
+    };
+    app.needs_redraw = true;
+
+    match code {
+        KeyCode::Esc => {
+            // Close — tag_picker is already None from take()
+            return Ok(Action::Continue);
+        }
+        KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+        KeyCode::Down => {
+            let count = picker.options(&app.tags).len();
+            picker.selected = (picker.selected + 1).min(count.saturating_sub(1));
+        }
+        KeyCode::Backspace => {
+            picker.input.pop();
+            picker.selected = 0;
+        }
+        KeyCode::Char(c) => {
+            // SEC-017: Cap input length; longer names are truncated anyway
+            if picker.input.chars().count() < MAX_TAG_LENGTH {
+                picker.input.push(c);
+            }
+            picker.selected = 0;
+        }
+        KeyCode::Enter => {
+            let options = picker.options(&app.tags);
+            if let Some(option) = options.get(picker.selected) {
+                if option.tag == STARRED_TAG {
+                    picker.starred = !picker.starred;
+                    if app.view == View::Reader {
+                        handle_star_toggle_reader(app, event_tx);
+                    } else {
+                        handle_star_toggle_browse(app, event_tx).await;
+                    }
+                } else {
+                    // Optimistic in the picker only; the article list follows
+                    // ArticleTagsChanged with the stored tags
+                    let tagged = !option.checked;
+                    if tagged {
+                        picker.tags.push(option.tag.clone());
+                        picker.tags.sort();
+                    } else {
+                        picker.tags.retain(|t| *t != option.tag);
+                    }
+                    spawn_set_article_tag(app, picker.article_id, &option.tag, tagged, event_tx);
+                }
+                if option.new {
+                    picker.input.clear();
+                    picker.selected = picker
+                        .options(&app.tags)
+                        .iter()
+                        .position(|o| o.tag == option.tag)
+                        .unwrap_or(0);
+                }
+            }
+        }
+        _ => {}
+    }
+    app.tag_picker = Some(picker);
+    Ok(Action::Continue)
+}
+
+/// Add or remove a tag in the background; reports `AppEvent::ArticleTagsChanged`.
+fn spawn_set_article_tag(
+    app: &App,
+    article_id: i64,
+    tag: &str,
+    tagged: bool,
+    event_tx: &mpsc::Sender<AppEvent>,
+) {
+    let db = app.db.clone();
+    let tx = event_tx.clone();
+    let tag = tag.to_string();
+    tokio::spawn(catch_task_panic(async move {
+        let result = db
+            .set_article_tag(article_id, &tag, tagged)
+            .await
+            .map_err(|e| e.to_string());
+        let _ = tx
+            .send(AppEvent::ArticleTagsChanged {
+                article_id,
+                tag,
+                result,
+            })
+            .await;
+        Ok::<(), String>(())
+    }));
+}
+
 /// Handle input while the subscribe dialog is visible.
 async fn handle_subscribe_input(
     app: &mut App,
@@ -589,6 +865,56 @@ async fn handle_subscribe_input(
     Ok(Action::Continue)
 }
 
+/// Handle input while the save URL dialog is visible.
+///
+/// Enter validates the URL, closes the dialog and saves the page in the
+/// background (`AppEvent::UrlSaved`); Esc cancels.
+fn handle_save_url_input(
+    app: &mut App,
+    code: KeyCode,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Action {
+    let Some(input) = app.save_url_input.as_mut() else {
+        return Action::Continue;
+    };
+    match code {
+        // SEC-017: Cap URL input length to prevent memory abuse from held keys
+        KeyCode::Char(c) if input.len() < 2048 => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Enter => {
+            let url = input.trim().to_owned();
+            if url.is_empty() {
+                return Action::Continue;
+            }
+            if let Err(e) = validate_url(&url) {
+                app.set_status(format!("Invalid URL: {}", e));
+                return Action::Continue;
+            }
+            app.save_url_input = None;
+            app.set_status(format!("Saving {}...", url));
+            let db = app.db.clone();
+            let client = app.http_client.clone();
+            let cache_policy = app.cache_policy.clone();
+            let tx = event_tx.clone();
+            tokio::spawn(catch_task_panic(async move {
+                let result = save_url(&db, &client, &cache_policy, &url)
+                    .await
+                    .map_err(|e| format!("{:#}", e));
+                let _ = tx.send(AppEvent::UrlSaved { url, result }).await;
+                Ok::<(), String>(())
+            }));
+        }
+        KeyCode::Esc => {
+            app.save_url_input = None;
+        }
+        _ => {}
+    }
+    app.needs_redraw = true;
+    Action::Continue
+}
+
 /// Handle input while the confirmation dialog is visible.
 ///
 /// y/Y confirms the action, n/N/Esc cancels.
@@ -669,27 +995,17 @@ async fn handle_enter_key(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) -> R
         });
 
         if let Some(entry) = entry {
-            let article_id = entry.article_id;
-            match app.db.get_article_by_id(article_id).await? {
-                Some(article) => {
-                    app.view = View::Reader;
-                    app.scroll_offset = 0;
-                    app.content_state = ContentState::Loading { article_id };
-                    app.reader_article = Some(article.clone());
-                    app.db.mark_article_read(article_id).await?;
-
-                    // Start reading session tracking
-                    start_reading_session(app, article.id, article.feed_id, event_tx);
-
-                    try_spawn_content_load(app, &article, event_tx);
-                }
-                None => {
-                    app.set_status("Article no longer exists");
-                }
+            if !open_article(app, entry.article_id, event_tx).await? {
+                app.set_status("Article no longer exists");
             }
         } else {
             app.set_status("No new articles");
         }
+    } else if app.focus == Focus::Categories && app.selected_tag.is_some() {
+        // Tag selected in the categories sidebar → show its articles
+        if let Some(tag) = app.selected_tag().map(|t| t.tag.clone()) {
+            enter_starred_mode(app, StarredView::Tag(tag)).await;
+        }
     } else if app.focus == Focus::Categories {
         // Category selected → filter feeds, move focus to Feeds panel
         app.focus = Focus::Feeds;
@@ -706,6 +1022,7 @@ async fn handle_enter_key(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) -> R
             if app.search_mode {
                 app.search_feed_id = Some(feed_id);
             }
+            app.reading_time_filter = None;
             app.articles = Arc::new(app.db.get_articles_for_feed(feed_id, None).await?);
             app.selected_article = 0;
             app.clamp_selections();
@@ -717,36 +1034,195 @@ async fn handle_enter_key(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) -> R
             app.focus = Focus::Articles;
         }
     } else if app.focus == Focus::Articles {
-        // Enter reader view and spawn content loading task
-        if let Some(article) = app.enter_reader() {
-            app.db.mark_article_read(article.id).await?;
+        open_selected_article(app, event_tx).await?;
+    }
+    Ok(())
+}
+
+/// Open the selected article of the article list in the reader.
+async fn open_selected_article(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) -> Result<()> {
+    // Enter reader view and spawn content loading task
+    let Some(article) = app.enter_reader() else {
+        return Ok(());
+    };
+    let read_in = app.db.mark_article_read(article.id).await?;
+    app.count_read_in_feeds(&read_in);
+    if let Some(listed) = Arc::make_mut(&mut app.articles).get_mut(app.selected_article) {
+        // Kept in step for next-unread in the reader
+        listed.read = true;
+        app.cached_articles = None;
+    }
+
+    // Start reading session tracking
+    start_reading_session(app, article.id, article.feed_id, event_tx);
+    load_reading_position(app, article.id).await;
+
+    // Spawn content loading task
+    try_spawn_content_load(app, &article, event_tx);
+    prefetch_next_article(app, event_tx);
+    Ok(())
+}
+
+/// Open a stored article in the reader by ID, as from the What's New panel
+/// or when the session is restored. Returns `false` when it no longer exists.
+pub async fn open_article(
+    app: &mut App,
+    article_id: i64,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Result<bool> {
+    // PERF-023: Fetch full Article from DB for reader entry
+    let Some(article) = app.db.get_article_by_id(article_id).await? else {
+        return Ok(false);
+    };
+    app.view = View::Reader;
+    app.scroll_offset = 0;
+    app.content_state = ContentState::Loading { article_id };
+    app.reader_article = Some(article.clone());
+    let read_in = app.db.mark_article_read(article_id).await?;
+    app.count_read_in_feeds(&read_in);
+
+    // Start reading session tracking
+    start_reading_session(app, article.id, article.feed_id, event_tx);
+    load_reading_position(app, article.id).await;
+
+    try_spawn_content_load(app, &article, event_tx);
+    prefetch_next_article(app, event_tx);
+    Ok(true)
+}
 
-            // Start reading session tracking
-            start_reading_session(app, article.id, article.feed_id, event_tx);
+/// Open the next or previous article of the list the reader was opened
+/// from without going back to it, or with `unread_only` the next unread one.
+///
+/// The current reading session is closed and a new one started, and the
+/// list selection follows so leaving the reader lands on the new article.
+async fn step_reader_article(
+    app: &mut App,
+    forward: bool,
+    unread_only: bool,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Result<()> {
+    let (ids, current) = app.reader_list();
+    // What's New entries don't carry the read flag; find the next unread one
+    let mut unread_ids = HashSet::new();
+    if unread_only && app.focus == Focus::WhatsNew {
+        for &id in ids.iter().skip(current + 1) {
+            if app.db.get_article_by_id(id).await?.is_some_and(|a| !a.read) {
+                unread_ids.insert(id);
+                break;
+            }
+        }
+    }
+    let target = app.reader_step_target(forward, unread_only, &unread_ids);
+    let Some(index) = target else {
+        app.set_status(match (forward, unread_only) {
+            (_, true) => "No unread articles after this one",
+            (true, false) => "No next article",
+            (false, false) => "No previous article",
+        });
+        return Ok(());
+    };
 
-            // Spawn content loading task
-            try_spawn_content_load(app, &article, event_tx);
+    close_reading_session(app, event_tx);
+    app.exit_reader();
+    if app.focus == Focus::WhatsNew {
+        app.whats_new_selected = index;
+        if !open_article(app, ids[index], event_tx).await? {
+            app.set_status("Article no longer exists");
         }
+    } else {
+        app.selected_article = index;
+        open_selected_article(app, event_tx).await?;
     }
     Ok(())
 }
 
-/// Handle refresh all feeds (r key).
-async fn handle_refresh_all(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
+/// Fetch the content of the article after the open one into the cache, so
+/// stepping to it with `>` shows it without waiting.
+///
+/// Follows the `[prefetch]` settings like the automatic prefetch: off when
+/// `max_articles` is 0 or on battery with `only_on_ac_power`, and failures
+/// share its backoff.
+fn prefetch_next_article(app: &App, event_tx: &mpsc::Sender<AppEvent>) {
+    let settings = &app.config.prefetch;
+    if settings.max_articles == 0 || (settings.only_on_ac_power && on_battery()) {
+        return;
+    }
+    let (ids, current) = app.reader_list();
+    let Some(&article_id) = ids.get(current + 1) else {
+        return;
+    };
+    if app.cached_article_set.contains(&article_id) {
+        return;
+    }
+    let db = app.db.clone();
+    let client = app.http_client.clone();
+    let cache_policy = app.cache_policy.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        // Shares the backoff of the automatic prefetch
+        if !db.prefetch_allowed(article_id).await.unwrap_or(false) {
+            return Ok(());
+        }
+        let url = match db.get_article_by_id(article_id).await {
+            Ok(Some(article)) => article.url,
+            _ => return Ok(()),
+        };
+        match save_offline_copy(&db, &client, &cache_policy, article_id, url.as_deref()).await {
+            Some(Ok(())) => {
+                let _ = db.clear_prefetch_failure(article_id).await;
+                let _ = tx
+                    .send(AppEvent::OfflineCopyCached {
+                        article_id,
+                        result: Ok(()),
+                    })
+                    .await;
+            }
+            // Fetched again when opened; no need to interrupt reading
+            Some(Err(e)) => {
+                tracing::warn!(article_id, error = %e, "Prefetching next article failed");
+                if let Err(e) = db.record_prefetch_failure(article_id, &e).await {
+                    tracing::warn!(article_id, error = %e, "Failed to record prefetch failure");
+                }
+            }
+            None => {}
+        }
+        Ok::<(), String>(())
+    }));
+}
+
+/// Look up where an article was left, for the reader to scroll there once
+/// its content is shown.
+async fn load_reading_position(app: &mut App, article_id: i64) {
+    app.reader_resume = match app.db.get_reading_position(article_id).await {
+        Ok(position) => position.map(|position| (article_id, position)),
+        Err(e) => {
+            tracing::warn!(error = %e, article_id, "Failed to load reading position");
+            None
+        }
+    };
+}
+
+/// Handle refresh all feeds (r key, or Ctrl+r with `force_all` to ignore the polling schedule).
+async fn handle_refresh_all(app: &mut App, event_tx: &mpsc::Sender<AppEvent>, force_all: bool) {
     // Prevent multiple concurrent refreshes
     if app.refresh_progress.is_some() {
         app.set_status("Refresh already in progress");
     } else if app.feeds.is_empty() {
         app.set_status("No feeds to refresh");
     } else {
-        app.set_status("Refreshing feeds...");
+        app.set_status(if force_all {
+            "Refreshing all feeds..."
+        } else {
+            "Refreshing feeds..."
+        });
         app.refresh_progress = Some((0, app.feeds.len()));
 
         // Clone what we need for the background task
         // PERF-011: Arc::clone is O(1) - just increments reference count
         let db = app.db.clone();
-        let client = app.http_client.clone();
+        let client = app.feed_client.clone();
         let feeds = Arc::clone(&app.feeds);
+        let policy = app.config.fetch_policy();
         let tx = event_tx.clone();
 
         // Create progress channel with proper lifecycle management
@@ -765,8 +1241,16 @@ async fn handle_refresh_all(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
 
                 // Spawn the refresh in a separate task
                 let mut refresh_handle = tokio::spawn(async move {
-                    refresh_all(db, client, feeds, progress_tx_for_task, Some(rate_limit_tx))
-                        .await
+                    refresh_all(
+                        db,
+                        client,
+                        feeds,
+                        progress_tx_for_task,
+                        Some(rate_limit_tx),
+                        policy,
+                        force_all,
+                    )
+                    .await
                 });
 
                 // Forward progress updates with timeout safety net
@@ -841,20 +1325,31 @@ async fn handle_refresh_all(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
 
 /// Handle refresh single feed (Shift+R).
 async fn handle_refresh_one(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
-    if app.refresh_progress.is_some() {
+    match app.selected_feed().cloned() {
+        Some(feed) => spawn_refresh_one(app, feed, event_tx),
+        None => app.set_status("No feed selected"),
+    }
+}
+
+/// Refresh a single feed in the background, reporting via `RefreshComplete`.
+fn spawn_refresh_one(app: &mut App, feed: Feed, event_tx: &mpsc::Sender<AppEvent>) {
+    if feed.is_saved() {
+        app.set_status("Saved pages have no feed to refresh");
+    } else if app.refresh_progress.is_some() {
         app.set_status("Refresh already in progress");
-    } else if let Some(feed) = app.selected_feed().cloned() {
+    } else {
         app.set_status(format!("Refreshing {}...", feed.title));
         app.refresh_progress = Some((0, 1));
 
         let db = app.db.clone();
-        let client = app.http_client.clone();
+        let client = app.feed_client.clone();
+        let policy = app.config.fetch_policy();
         let tx = event_tx.clone();
 
         tokio::spawn(async move {
             let tx_panic = tx.clone();
             match catch_task_panic(async {
-                let result = refresh_one(&db, &client, &feed, Some(&tx)).await;
+                let result = refresh_one(&db, &client, &feed, Some(&tx), policy).await;
 
                 // Send progress complete
                 if let Err(e) = tx.send(AppEvent::RefreshProgress(1, 1)).await {
@@ -889,8 +1384,6 @@ async fn handle_refresh_one(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
                 }
             }
         });
-    } else {
-        app.set_status("No feed selected");
     }
 }
 
@@ -899,6 +1392,7 @@ async fn handle_star_toggle_browse(app: &mut App, event_tx: &mpsc::Sender<AppEve
     if let Some(article) = app.selected_article() {
         let article_id = article.id;
         let current_starred = article.starred;
+        let url = article.url.clone();
 
         // SAFETY: All Arc::make_mut calls on app.articles must happen on the event loop
         // thread (never in spawned tasks). Background tasks may hold Arc clones for reading
@@ -920,6 +1414,8 @@ async fn handle_star_toggle_browse(app: &mut App, event_tx: &mpsc::Sender<AppEve
 
         // Spawn background task to persist change
         let db = app.db.clone();
+        let client = app.http_client.clone();
+        let cache_policy = app.cache_policy.clone();
         let tx = event_tx.clone();
         tokio::spawn(async move {
             let tx_panic = tx.clone();
@@ -935,6 +1431,14 @@ async fn handle_star_toggle_browse(app: &mut App, event_tx: &mpsc::Sender<AppEve
                         {
                             tracing::warn!(error = %e, event = "StarToggled", "Channel send failed (receiver dropped)");
                         }
+                        // Starred content never expires; make sure there is a copy to keep
+                        if new_status {
+                            if let Some(result) =
+                                save_offline_copy(&db, &client, &cache_policy, article_id, url.as_deref()).await
+                            {
+                                let _ = tx.send(AppEvent::OfflineCopyCached { article_id, result }).await;
+                            }
+                        }
                     }
                     Err(e) => {
                         tracing::error!(error = %e, article_id, "Failed to toggle star");
@@ -989,11 +1493,12 @@ async fn handle_mark_feed_read(app: &mut App, event_tx: &mpsc::Sender<AppEvent>)
         let tx_panic = tx.clone();
         match catch_task_panic(async {
             match db.mark_all_read_for_feed(feed_id).await {
-                Ok(count) => {
+                Ok((count, copy_feeds)) => {
                     if let Err(e) = tx
                         .send(AppEvent::BulkMarkReadComplete {
                             feed_id: Some(feed_id),
                             count,
+                            copy_feeds,
                         })
                         .await
                     {
@@ -1053,6 +1558,7 @@ async fn handle_mark_all_read(app: &mut App, event_tx: &mpsc::Sender<AppEvent>)
                         .send(AppEvent::BulkMarkReadComplete {
                             feed_id: None,
                             count,
+                            copy_feeds: Vec::new(),
                         })
                         .await
                     {
@@ -1166,57 +1672,114 @@ fn handle_export_opml(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
     });
 }
 
-/// Handle starred mode toggle (S key).
-async fn handle_starred_mode_toggle(app: &mut App) -> Result<()> {
-    if app.starred_mode {
-        exit_starred_mode(app, "S toggle").await?;
+/// Handle starred mode toggle (S key), or with `view` another starred-mode
+/// list (L key for the read-later queue).
+///
+/// From a different starred-mode view, switches to `view` instead of leaving.
+async fn handle_starred_mode_toggle(app: &mut App, view: StarredView) -> Result<()> {
+    if app.starred_mode && app.starred_view == view {
+        exit_starred_mode(app, "toggle").await?;
     } else {
-        // Enter starred mode - cache current state first
+        enter_starred_mode(app, view).await;
+    }
+    Ok(())
+}
+
+/// Show a cross-feed list in starred mode: the starred articles, a tag's
+/// articles, the read-later queue or the partially read articles.
+///
+/// All of them get feed prefixes and restore the feed's list on exit.
+/// Switching between them keeps the list cached on first entry.
+async fn enter_starred_mode(app: &mut App, view: StarredView) {
+    let view = match view {
+        StarredView::Tag(tag) if tag == STARRED_TAG => StarredView::Starred,
+        view => view,
+    };
+    let was_starred_mode = app.starred_mode;
+    if !was_starred_mode {
         // PERF-008: Cache current state before entering starred mode
         // Arc::clone is O(1) - just increments reference count
         app.cached_articles = Some(CachedArticleState {
             feed_id: app.selected_feed().map(|f| f.id),
-            articles: Arc::clone(&app.articles),
+            articles: app.unfiltered_articles(),
             selected: app.selected_article,
         });
+    }
+    app.reading_time_filter = None;
 
-        match app.db.get_starred_articles().await {
-            Ok(starred) => {
-                tracing::info!(starred_count = starred.len(), "Entering starred mode");
-                app.starred_mode = true;
-
-                // PERF-014: Build prefix cache for starred mode display
-                // Pre-compute formatted feed prefixes to avoid N allocations per render frame
-                app.feed_prefix_cache.clear();
-                for article in &starred {
-                    if !app.feed_prefix_cache.contains_key(&article.feed_id) {
-                        if let Some(feed_title) = app.feed_title_cache.get(&article.feed_id) {
-                            let prefix =
-                                format!("[{}] ", crate::util::truncate_to_width(feed_title, 15));
-                            app.feed_prefix_cache.insert(article.feed_id, prefix);
-                        }
+    let result = match &view {
+        StarredView::Starred => app.db.get_starred_articles().await,
+        StarredView::Tag(tag) => app.db.get_tagged_articles(tag).await,
+        StarredView::ReadLater => app.db.get_read_later_articles().await,
+        StarredView::ContinueReading => app.db.get_continue_reading_articles().await,
+    };
+    match result {
+        Ok(starred) => {
+            tracing::info!(starred_count = starred.len(), view = ?view, "Entering starred mode");
+            if view == StarredView::ReadLater {
+                match app.db.read_later_words().await {
+                    Ok(words) => {
+                        app.read_later_minutes = reading_minutes(words, app.words_per_minute)
                     }
+                    Err(e) => tracing::warn!(error = %e, "Failed to estimate read-later time"),
                 }
-
-                app.articles = Arc::new(starred);
-                app.selected_article = 0;
-                app.clamp_selections();
-                app.focus = Focus::Articles;
             }
-            Err(e) => {
-                // Failed to enter starred mode, clear cache
+            if view == StarredView::ContinueReading {
+                match app.db.get_reading_positions().await {
+                    Ok(positions) => app.reading_positions = positions,
+                    Err(e) => tracing::warn!(error = %e, "Failed to load reading positions"),
+                }
+            }
+            app.starred_mode = true;
+            app.starred_view = view;
+
+            // PERF-014: Build prefix cache for starred mode display
+            // Pre-compute formatted feed prefixes to avoid N allocations per render frame
+            app.feed_prefix_cache.clear();
+            for article in &starred {
+                if !app.feed_prefix_cache.contains_key(&article.feed_id) {
+                    if let Some(feed_title) = app.feed_title_cache.get(&article.feed_id) {
+                        let prefix =
+                            format!("[{}] ", crate::util::truncate_to_width(feed_title, 15));
+                        app.feed_prefix_cache.insert(article.feed_id, prefix);
+                    }
+                }
+            }
+
+            app.articles = Arc::new(starred);
+            app.selected_article = 0;
+            app.clamp_selections();
+            app.focus = Focus::Articles;
+        }
+        Err(e) => {
+            // Failed to enter starred mode, clear cache
+            if !was_starred_mode {
                 app.cached_articles = None;
-                app.set_status(format!("Failed to load starred: {}", e));
+            }
+            match view {
+                StarredView::Starred => app.set_status(format!("Failed to load starred: {}", e)),
+                StarredView::Tag(tag) => app.set_status(format!("Failed to load #{}: {}", tag, e)),
+                StarredView::ReadLater => {
+                    app.set_status(format!("Failed to load read later: {}", e))
+                }
+                StarredView::ContinueReading => {
+                    app.set_status(format!("Failed to load partially read articles: {}", e))
+                }
             }
         }
     }
-    Ok(())
 }
 
+/// Columns code blocks move per Left/Right press in the reader.
+const CODE_SCROLL_STEP: isize = 8;
+
+/// Columns the reader's text column narrows or widens per key press.
+const TEXT_WIDTH_STEP: usize = 10;
+
 /// Handle input in reader view.
 ///
 /// Uses keybinding registry for action dispatch with Reader context.
-pub(super) fn handle_reader_input(
+pub(super) async fn handle_reader_input(
     app: &mut App,
     code: KeyCode,
     modifiers: KeyModifiers,
@@ -1226,15 +1789,42 @@ pub(super) fn handle_reader_input(
         .keybindings
         .action_for_key(code, modifiers, KbContext::Reader);
 
+    // Linked pages aren't stored, so article actions don't apply to them
+    let article_action = matches!(
+        action,
+        Some(
+            KbAction::ToggleStar
+                | KbAction::ToggleRevisions
+                | KbAction::EditTags
+                | KbAction::ToggleReadLater
+                | KbAction::ToggleArchive
+        )
+    );
+    if article_action && app.reader_article.as_ref().is_some_and(is_link_page) {
+        app.set_status("Not available for linked pages");
+        return Ok(Action::Continue);
+    }
+
     match action {
         Some(KbAction::Quit) => {
-            close_reading_session(app);
+            close_reading_session(app, event_tx);
             return Ok(Action::Quit);
         }
+        // Esc clears the search highlights before leaving the reader
+        Some(KbAction::ExitReader) if code == KeyCode::Esc && app.reader_search.is_some() => {
+            app.reader_search = None;
+        }
         Some(KbAction::ExitReader) => {
-            close_reading_session(app);
+            close_reading_session(app, event_tx);
             app.exit_reader();
         }
+        // Pages turn as a whole in paginated mode
+        Some(KbAction::ScrollDown | KbAction::PageDown) if app.reader_layout.paginate => {
+            app.turn_reader_page(true);
+        }
+        Some(KbAction::ScrollUp | KbAction::PageUp) if app.reader_layout.paginate => {
+            app.turn_reader_page(false);
+        }
         Some(KbAction::ScrollDown) => {
             app.scroll_down(1);
             app.clamp_reader_scroll();
@@ -1245,6 +1835,85 @@ pub(super) fn handle_reader_input(
             app.clamp_reader_scroll();
         }
         Some(KbAction::PageUp) => app.scroll_up(20),
+        Some(KbAction::NextPage) => {
+            let offset = app.scroll_offset;
+            app.turn_reader_page(true);
+            if app.scroll_offset == offset {
+                app.set_status("End of article");
+            }
+        }
+        Some(KbAction::TogglePaginate) => {
+            let layout = ReaderConfig {
+                paginate: !app.reader_layout.paginate,
+                ..app.reader_layout
+            };
+            set_reader_layout(app, layout).await;
+            app.set_status(if layout.paginate {
+                "Paginated reading on"
+            } else {
+                "Paginated reading off"
+            });
+        }
+        Some(KbAction::ToggleJustify) => {
+            let layout = ReaderConfig {
+                justify: !app.reader_layout.justify,
+                ..app.reader_layout
+            };
+            set_reader_layout(app, layout).await;
+            app.set_status(if layout.justify {
+                "Justified text on"
+            } else {
+                "Justified text off"
+            });
+        }
+        Some(KbAction::ToggleTldr) => {
+            let layout = ReaderConfig {
+                collapse_tldr: !app.reader_layout.collapse_tldr,
+                ..app.reader_layout
+            };
+            set_reader_layout(app, layout).await;
+            if app.reader_tldr.as_ref().is_some_and(|(_, s)| s.is_empty()) {
+                app.set_status("Article too short for a TL;DR");
+            }
+        }
+        Some(KbAction::NarrowText) => {
+            let width = app
+                .reader_viewport_width
+                .saturating_sub(TEXT_WIDTH_STEP)
+                .max(MIN_READER_WIDTH);
+            if width == app.reader_viewport_width {
+                app.set_status("Text column is at its narrowest");
+            } else {
+                let layout = ReaderConfig {
+                    max_width: width,
+                    ..app.reader_layout
+                };
+                set_reader_layout(app, layout).await;
+                app.set_status(format!("Text width: {} columns", width));
+            }
+        }
+        Some(KbAction::WidenText) => {
+            let max_width = app.reader_layout.max_width;
+            if max_width == 0 {
+                app.set_status("Text already uses the full width");
+            } else {
+                // Once the window is the limit, the setting comes off
+                let width = if max_width > app.reader_viewport_width {
+                    0
+                } else {
+                    max_width + TEXT_WIDTH_STEP
+                };
+                let layout = ReaderConfig {
+                    max_width: width,
+                    ..app.reader_layout
+                };
+                set_reader_layout(app, layout).await;
+                app.set_status(match width {
+                    0 => "Text width: full window".to_string(),
+                    _ => format!("Text width: {} columns", width),
+                });
+            }
+        }
         Some(KbAction::ToggleStar) => {
             handle_star_toggle_reader(app, event_tx);
         }
@@ -1260,22 +1929,274 @@ pub(super) fn handle_reader_input(
                 }
             }
         }
-        _ => {}
-    }
-    Ok(Action::Continue)
-}
-
-/// Handle star toggle in reader view.
+        Some(KbAction::ToggleRevisions) => toggle_revision_diff(app, event_tx),
+        Some(KbAction::EditTags) => {
+            if let Some(article) = app.reader_article.as_ref() {
+                app.tag_picker = Some(TagPickerState::new(article));
+            }
+        }
+        Some(KbAction::ToggleReadLater) => {
+            if let Some(article) = app.reader_article.as_ref() {
+                let (article_id, url) = (article.id, article.url.clone());
+                toggle_read_later(app, article_id, url, event_tx);
+            }
+        }
+        Some(KbAction::ToggleArchive) => {
+            if let Some(article_id) = app.reader_article.as_ref().map(|a| a.id) {
+                toggle_archive(app, article_id, event_tx);
+            }
+        }
+        Some(KbAction::FollowLink) => open_link_picker(app, None),
+        Some(KbAction::EnterSearch) => app.start_reader_search(),
+        Some(KbAction::SearchNext | KbAction::SearchPrev) => {
+            let forward = action == Some(KbAction::SearchNext);
+            if app.reader_search.is_none() {
+                app.set_status("Press / to search the article");
+            } else if !app.step_reader_search(forward) {
+                app.set_status("No matches");
+            }
+        }
+        Some(KbAction::ScrollLeft) => {
+            app.scroll_reader_code(-CODE_SCROLL_STEP);
+        }
+        Some(KbAction::ScrollRight) => {
+            app.scroll_reader_code(CODE_SCROLL_STEP);
+        }
+        Some(KbAction::NextArticle) => step_reader_article(app, true, false, event_tx).await?,
+        Some(KbAction::PrevArticle) => step_reader_article(app, false, false, event_tx).await?,
+        Some(KbAction::NextUnreadArticle) => step_reader_article(app, true, true, event_tx).await?,
+        Some(KbAction::HistoryBack) => {
+            if app.reader_history_back() {
+                resume_reader_page(app, event_tx);
+            } else {
+                app.set_status("No previous page");
+            }
+        }
+        Some(KbAction::HistoryForward) => {
+            if app.reader_history_forward() {
+                resume_reader_page(app, event_tx);
+            } else {
+                app.set_status("No next page");
+            }
+        }
+        // Typing a link number opens the picker at that link
+        None => {
+            if let KeyCode::Char(c @ '1'..='9') = code {
+                open_link_picker(app, Some(c));
+            }
+        }
+        _ => {}
+    }
+    Ok(Action::Continue)
+}
+
+/// Apply a new reader layout and save it in the preferences.
+async fn set_reader_layout(app: &mut App, layout: ReaderConfig) {
+    if layout.justify != app.reader_layout.justify {
+        // Wrap the content again on the next render
+        app.reader_wrap_width = 0;
+    }
+    app.reader_layout = layout;
+    app.reader_cached_line_count = None;
+    app.clamp_reader_scroll();
+    app.needs_redraw = true;
+    if let Err(e) = app.prefs.set_reader_layout(&app.db, layout).await {
+        tracing::warn!(error = %e, "Failed to save reader layout");
+    }
+}
+
+/// Handle input while typing an in-article search.
+///
+/// Matches update as the query changes. Enter keeps them highlighted for
+/// n/N; Esc drops the search and returns to where it started.
+fn handle_reader_search_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> Action {
+    let Some(search) = app.reader_search.as_mut() else {
+        return Action::Continue;
+    };
+    match code {
+        KeyCode::Esc => {
+            app.scroll_offset = search.origin;
+            app.reader_search = None;
+        }
+        KeyCode::Enter => {
+            if search.query.is_empty() {
+                app.reader_search = None;
+            } else {
+                search.editing = false;
+            }
+        }
+        KeyCode::Backspace => {
+            search.query.pop();
+            app.update_reader_search();
+        }
+        KeyCode::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => {
+            if search.query.len() >= MAX_SEARCH_QUERY_LENGTH {
+                app.set_status(format!(
+                    "Search query at max length ({} chars)",
+                    MAX_SEARCH_QUERY_LENGTH
+                ));
+            } else {
+                search.query.push(c);
+                app.update_reader_search();
+            }
+        }
+        _ => {}
+    }
+    app.needs_redraw = true;
+    Action::Continue
+}
+
+/// Open the link picker over the reader, optionally at a typed link number.
+fn open_link_picker(app: &mut App, digit: Option<char>) {
+    let count = app.reader_links().len();
+    if count == 0 {
+        app.set_status("No links in this article");
+        return;
+    }
+    let mut picker = LinkPickerState::default();
+    if let Some(digit) = digit {
+        picker.push_digit(digit, count);
+    }
+    app.link_picker = Some(picker);
+    app.needs_redraw = true;
+}
+
+/// Handle input while the link picker is visible.
+///
+/// j/k or Up/Down move, digits jump to a link number, Enter reads the link
+/// in the reader, `o` opens it in the browser and Esc closes the picker.
+fn handle_link_picker_input(
+    app: &mut App,
+    code: KeyCode,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Action {
+    let Some(mut picker) = app.link_picker.take() else {
+        return Action::Continue;
+    };
+    app.needs_redraw = true;
+    let count = app.reader_links().len();
+
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('f') => {
+            // Close — link_picker is already None from take()
+            return Action::Continue;
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            picker.selected = picker.selected.saturating_sub(1);
+            picker.input.clear();
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            picker.selected = (picker.selected + 1).min(count.saturating_sub(1));
+            picker.input.clear();
+        }
+        KeyCode::Char(c) if c.is_ascii_digit() => picker.push_digit(c, count),
+        KeyCode::Backspace => {
+            picker.input.pop();
+        }
+        KeyCode::Enter | KeyCode::Char('o') => {
+            let Some(link) = app.reader_links().get(picker.selected).cloned() else {
+                return Action::Continue;
+            };
+            let base = app.reader_article.as_ref().and_then(|a| a.url.clone());
+            let Some(url) = resolve_link(base.as_deref(), &link.url) else {
+                app.set_status(format!("Cannot follow link: {}", link.url));
+                return Action::Continue;
+            };
+            if code == KeyCode::Enter {
+                // SEC: Same SSRF checks as feed and article URLs
+                if let Err(e) = validate_url(&url) {
+                    app.set_status(format!("Cannot read link: {}", e));
+                    return Action::Continue;
+                }
+                let article = app.open_link_page(&url, &link.text);
+                spawn_link_load(app, &article, event_tx);
+            } else if let Err(e) = validate_url_for_open(&url) {
+                // SEC: Validate URL before open::that() to prevent command injection
+                app.set_status(e);
+            } else if let Err(e) = open::that(&url) {
+                app.set_status(format!("Failed to open browser: {}", e));
+            }
+            return Action::Continue;
+        }
+        _ => {}
+    }
+    app.link_picker = Some(picker);
+    Action::Continue
+}
+
+/// Restart the content load of a page restored from the reader history
+/// if it was still loading when the reader moved on.
+fn resume_reader_page(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
+    if !matches!(app.content_state, ContentState::Loading { .. }) {
+        return;
+    }
+    let Some(article) = app.reader_article.clone() else {
+        return;
+    };
+    if is_link_page(&article) {
+        spawn_link_load(app, &article, event_tx);
+    } else {
+        try_spawn_content_load(app, &article, event_tx);
+    }
+}
+
+/// Toggle the reader between the article and its revision diff.
+///
+/// Turning the diff on loads the article's revisions in the background;
+/// `AppEvent::RevisionsLoaded` swaps the diff in once it arrives.
+fn toggle_revision_diff(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
+    if let Some(saved) = app.reader_saved_content.take() {
+        app.content_state = saved;
+        app.scroll_offset = 0;
+        app.reader_cached_line_count = None;
+        app.refresh_reader_search();
+        app.needs_redraw = true;
+        return;
+    }
+    let Some(article) = app.reader_article.as_ref() else {
+        return;
+    };
+    if article.updated_at.is_none() {
+        app.set_status("No earlier versions of this article");
+        return;
+    }
+    if matches!(app.content_state, ContentState::Loading { .. }) {
+        app.set_status("Wait for the article to load");
+        return;
+    }
+
+    let article_id = article.id;
+    let db = app.db.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        let result = async {
+            let revisions = db.get_article_revisions(article_id).await?;
+            let current = db.get_cached_content(article_id).await?;
+            anyhow::Ok((revisions, current.map(|c| c.markdown)))
+        }
+        .await
+        .map_err(|e| e.to_string());
+        let _ = tx
+            .send(AppEvent::RevisionsLoaded { article_id, result })
+            .await;
+        Ok::<(), String>(())
+    }));
+}
+
+/// Handle star toggle in reader view.
 fn handle_star_toggle_reader(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
     if let Some(article) = app.reader_article.as_mut() {
         let article_id = article.id;
         let original_starred = article.starred;
+        let url = article.url.clone();
 
         // Optimistic UI update in reader
         article.starred = !original_starred;
         app.needs_redraw = true;
 
         let db = app.db.clone();
+        let client = app.http_client.clone();
+        let cache_policy = app.cache_policy.clone();
         let tx = event_tx.clone();
 
         // Invalidate cache if it exists - prevents stale data on search/starred mode exit
@@ -1302,6 +2223,13 @@ fn handle_star_toggle_reader(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
                         {
                             tracing::warn!(error = %e, event = "StarToggled", "Channel send failed (receiver dropped)");
                         }
+                        if new_status {
+                            if let Some(result) =
+                                save_offline_copy(&db, &client, &cache_policy, article_id, url.as_deref()).await
+                            {
+                                let _ = tx.send(AppEvent::OfflineCopyCached { article_id, result }).await;
+                            }
+                        }
                     }
                     Err(e) => {
                         tracing::error!(error = %e, article_id, "Failed to toggle star");
@@ -1331,6 +2259,158 @@ fn handle_star_toggle_reader(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
     }
 }
 
+/// Add an article to the read-later queue or take it out (optimistic).
+///
+/// Adding also prefetches the article's content, which stays cached while
+/// the article is queued, so the queue can be read offline;
+/// `AppEvent::OfflineCopyCached` reports the prefetch.
+fn toggle_read_later(
+    app: &mut App,
+    article_id: i64,
+    url: Option<Arc<str>>,
+    event_tx: &mpsc::Sender<AppEvent>,
+) {
+    let queue = !app.read_later_ids.contains(&article_id);
+    if queue {
+        app.read_later_ids.insert(article_id);
+    } else {
+        app.read_later_ids.remove(&article_id);
+        // Taking an article out of the queue view drops its row
+        if app.starred_mode && app.starred_view == StarredView::ReadLater {
+            Arc::make_mut(&mut app.articles).retain(|a| a.id != article_id);
+            app.clamp_selections();
+        }
+    }
+    app.needs_redraw = true;
+
+    let db = app.db.clone();
+    let client = app.http_client.clone();
+    let cache_policy = app.cache_policy.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        let result = if queue {
+            db.add_to_read_later(article_id).await.map(|_| true)
+        } else {
+            db.remove_from_read_later(article_id).await.map(|_| false)
+        }
+        .map_err(|e| e.to_string());
+        let added = result == Ok(true);
+        let _ = tx
+            .send(AppEvent::ReadLaterToggled { article_id, result })
+            .await;
+        if added {
+            if let Some(result) =
+                save_offline_copy(&db, &client, &cache_policy, article_id, url.as_deref()).await
+            {
+                let _ = tx
+                    .send(AppEvent::OfflineCopyCached { article_id, result })
+                    .await;
+            }
+        }
+        Ok::<(), String>(())
+    }));
+}
+
+/// Archive an article or take it out of the archive (optimistic).
+///
+/// Archived articles keep their cached content forever, so archiving also
+/// saves an offline copy if none is cached yet.
+fn toggle_archive(app: &mut App, article_id: i64, event_tx: &mpsc::Sender<AppEvent>) {
+    let Some(article) = app
+        .reader_article
+        .as_ref()
+        .filter(|a| a.id == article_id)
+        .or_else(|| app.articles.iter().find(|a| a.id == article_id))
+    else {
+        return;
+    };
+    let was_archived = article.archived;
+    let url = article.url.clone();
+    set_article_archived(app, article_id, !was_archived);
+
+    let db = app.db.clone();
+    let client = app.http_client.clone();
+    let cache_policy = app.cache_policy.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        let result = db
+            .toggle_article_archived(article_id)
+            .await
+            .map_err(|e| e.to_string());
+        let archived = result == Ok(true);
+        let _ = tx
+            .send(AppEvent::ArchiveToggled {
+                article_id,
+                was_archived,
+                result,
+            })
+            .await;
+        if archived {
+            if let Some(result) =
+                save_offline_copy(&db, &client, &cache_policy, article_id, url.as_deref()).await
+            {
+                let _ = tx
+                    .send(AppEvent::OfflineCopyCached { article_id, result })
+                    .await;
+            }
+        }
+        Ok::<(), String>(())
+    }));
+}
+
+/// Set the archived flag on the article in the list and the reader.
+pub(super) fn set_article_archived(app: &mut App, article_id: i64, archived: bool) {
+    // SAFETY: Arc::make_mut on app.articles only happens on the event loop thread
+    let articles = Arc::make_mut(&mut app.articles);
+    if let Some(article) = articles.iter_mut().find(|a| a.id == article_id) {
+        article.archived = archived;
+    }
+    if let Some(article) = app.reader_article.as_mut().filter(|a| a.id == article_id) {
+        article.archived = archived;
+    }
+    // Cached list state would bring back the old flag on search/starred mode exit
+    app.cached_articles = None;
+    app.needs_redraw = true;
+}
+
+/// Move the selected article up or down the read-later queue.
+///
+/// Only applies in the read-later view, where the list is the queue.
+fn move_in_read_later(app: &mut App, up: bool, event_tx: &mpsc::Sender<AppEvent>) {
+    if !(app.starred_mode && app.starred_view == StarredView::ReadLater) {
+        app.set_status("Open the read-later queue (L) to reorder it");
+        return;
+    }
+    let from = app.selected_article;
+    let to = if up {
+        from.checked_sub(1)
+    } else {
+        Some(from + 1).filter(|&i| i < app.articles.len())
+    };
+    let (Some(to), Some(article)) = (to, app.articles.get(from)) else {
+        return;
+    };
+    let article_id = article.id;
+    // SAFETY: Arc::make_mut on app.articles only happens on the event loop thread
+    Arc::make_mut(&mut app.articles).swap(from, to);
+    app.selected_article = to;
+    app.needs_redraw = true;
+
+    let db = app.db.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        if let Err(e) = db.move_in_read_later(article_id, up).await {
+            tracing::error!(article_id, error = %e, "Failed to reorder read-later queue");
+            let _ = tx
+                .send(AppEvent::ReadLaterMoveFailed {
+                    error: e.to_string(),
+                })
+                .await;
+        }
+        Ok::<(), String>(())
+    }));
+}
+
 /// Enter the reading stats view and spawn async data loading.
 fn enter_stats_view(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
     app.view = View::Stats;
@@ -1342,8 +2422,16 @@ fn enter_stats_view(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
         let today = db.get_reading_stats(1).await.unwrap_or_default();
         let week = db.get_reading_stats(7).await.unwrap_or_default();
         let month = db.get_reading_stats(30).await.unwrap_or_default();
+        let cache = db.cache_stats().await.unwrap_or_default();
+        let measured_speed = db.measured_reading_speed().await.unwrap_or(None);
         let _ = tx
-            .send(AppEvent::StatsLoaded(StatsData { today, week, month }))
+            .send(AppEvent::StatsLoaded(StatsData {
+                today,
+                week,
+                month,
+                cache,
+                measured_speed,
+            }))
             .await;
         Ok::<(), String>(())
     }));
@@ -1352,14 +2440,19 @@ fn enter_stats_view(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
 /// Handle input in the stats view.
 ///
 /// Esc, q, or I dismisses the view and returns to Browse.
-fn handle_stats_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> Action {
+fn handle_stats_input(
+    app: &mut App,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Action {
     let action = app
         .keybindings
         .action_for_key(code, modifiers, KbContext::Global);
 
     match action {
         Some(KbAction::Quit) => {
-            close_reading_session(app);
+            close_reading_session(app, event_tx);
             return Action::Quit;
         }
         Some(KbAction::Back) | Some(KbAction::ViewStats) => {
@@ -1372,6 +2465,267 @@ fn handle_stats_input(app: &mut App, code: KeyCode, modifiers: KeyModifiers) ->
     Action::Continue
 }
 
+/// Enter the feed health view and spawn async data loading.
+fn enter_feed_health_view(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
+    app.view = View::Health;
+    app.feed_health = None;
+    app.feed_health_selected = 0;
+    spawn_feed_health_load(app, event_tx);
+}
+
+/// Load feed health rows in the background, reporting via `FeedHealthLoaded`.
+pub(super) fn spawn_feed_health_load(app: &App, event_tx: &mpsc::Sender<AppEvent>) {
+    let db = app.db.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        match db.get_feed_health().await {
+            Ok(rows) => {
+                let _ = tx.send(AppEvent::FeedHealthLoaded(rows)).await;
+            }
+            Err(e) => tracing::warn!(error = %e, "Failed to load feed health"),
+        }
+        Ok::<(), String>(())
+    }));
+}
+
+/// Handle input in the feed health view.
+///
+/// Actions apply to the selected feed: retry (refresh), unsubscribe,
+/// open its website, or rediscover its feed URL from the website.
+fn handle_health_input(
+    app: &mut App,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Action {
+    let action = app
+        .keybindings
+        .action_for_key(code, modifiers, KbContext::FeedList);
+
+    let selected = app
+        .feed_health
+        .as_ref()
+        .and_then(|rows| rows.get(app.feed_health_selected))
+        .cloned();
+
+    match action {
+        Some(KbAction::Quit) => {
+            close_reading_session(app, event_tx);
+            return Action::Quit;
+        }
+        Some(KbAction::Back) | Some(KbAction::ViewFeedHealth) => {
+            app.view = View::Browse;
+            app.feed_health = None;
+        }
+        Some(KbAction::NavDown) => {
+            let len = app.feed_health.as_ref().map_or(0, Vec::len);
+            if app.feed_health_selected + 1 < len {
+                app.feed_health_selected += 1;
+            }
+        }
+        Some(KbAction::NavUp) => {
+            app.feed_health_selected = app.feed_health_selected.saturating_sub(1);
+        }
+        Some(KbAction::RefreshOne) | Some(KbAction::RefreshAll) => {
+            let feed = selected.and_then(|h| app.feeds.iter().find(|f| f.id == h.feed_id).cloned());
+            if let Some(feed) = feed {
+                spawn_refresh_one(app, feed, event_tx);
+            }
+        }
+        Some(KbAction::DeleteFeed) => {
+            if let Some(h) = selected {
+                app.pending_confirm = Some(ConfirmAction::DeleteFeed {
+                    feed_id: h.feed_id,
+                    title: h.title,
+                });
+            }
+        }
+        Some(KbAction::OpenFeedSite) | Some(KbAction::OpenInBrowser) => {
+            if let Some(h) = selected {
+                let url = h.html_url.as_deref().unwrap_or(&h.url);
+                // SEC: Validate URL before open::that() to prevent command injection
+                if let Err(e) = validate_url_for_open(url) {
+                    app.set_status(e);
+                } else if let Err(e) = open::that(url) {
+                    app.set_status(format!("Failed to open browser: {}", e));
+                } else {
+                    app.set_status(format!("Opening {} website...", h.title));
+                }
+            }
+        }
+        Some(KbAction::RediscoverFeed) => {
+            let feed = selected.and_then(|h| app.feeds.iter().find(|f| f.id == h.feed_id).cloned());
+            if let Some(feed) = feed {
+                spawn_rediscover_feed(app, &feed, event_tx);
+            }
+        }
+        _ => {}
+    }
+
+    Action::Continue
+}
+
+/// Number of entries listed under "Largest entries" in the cache manager.
+const CACHE_LARGEST_ENTRIES: i64 = 10;
+
+/// Enter the cache manager and spawn async data loading.
+fn enter_cache_view(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
+    app.view = View::Cache;
+    app.cache_overview = None;
+    app.cache_selected = 0;
+    spawn_cache_overview_load(app, event_tx);
+}
+
+/// Load cache stats, the largest entries and per-feed usage in the background.
+///
+/// Sends `AppEvent::CacheOverviewLoaded`.
+pub(super) fn spawn_cache_overview_load(app: &App, event_tx: &mpsc::Sender<AppEvent>) {
+    let db = app.db.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        match tokio::try_join!(
+            db.cache_stats(),
+            db.largest_cache_entries(CACHE_LARGEST_ENTRIES),
+            db.cache_usage_by_feed()
+        ) {
+            Ok((stats, largest, feeds)) => {
+                let _ = tx
+                    .send(AppEvent::CacheOverviewLoaded(CacheOverview {
+                        stats,
+                        largest,
+                        feeds,
+                    }))
+                    .await;
+            }
+            Err(e) => tracing::warn!(error = %e, "Failed to load cache overview"),
+        }
+        Ok::<(), String>(())
+    }));
+}
+
+/// What a purge in the cache manager removes.
+enum CachePurge {
+    /// Expired entries (`Database::evict_expired`).
+    Expired,
+    /// Unpinned entries of one feed.
+    Feed(i64),
+    /// Every unpinned entry.
+    All,
+}
+
+/// Run a purge in the background; `AppEvent::CachePurged` reports it.
+fn spawn_cache_purge(app: &mut App, purge: CachePurge, event_tx: &mpsc::Sender<AppEvent>) {
+    app.set_status("Purging offline cache...");
+    let db = app.db.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        let result = match purge {
+            CachePurge::Expired => db.evict_expired().await,
+            CachePurge::Feed(feed_id) => db.purge_cache(Some(feed_id)).await,
+            CachePurge::All => db.purge_cache(None).await,
+        }
+        .map_err(|e| e.to_string());
+        let _ = tx.send(AppEvent::CachePurged { result }).await;
+        Ok::<(), String>(())
+    }));
+}
+
+/// Handle input in the cache manager.
+///
+/// `d` purges the selected feed's cache, `D` the whole cache and `x` the
+/// expired entries. Starred, archived and queued articles are always kept.
+fn handle_cache_input(
+    app: &mut App,
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    event_tx: &mpsc::Sender<AppEvent>,
+) -> Action {
+    match code {
+        KeyCode::Char('d') => {
+            let feed_id = app
+                .cache_overview
+                .as_ref()
+                .and_then(|o| o.feeds.get(app.cache_selected))
+                .map(|f| f.feed_id);
+            if let Some(feed_id) = feed_id {
+                spawn_cache_purge(app, CachePurge::Feed(feed_id), event_tx);
+            }
+            return Action::Continue;
+        }
+        KeyCode::Char('D') => {
+            spawn_cache_purge(app, CachePurge::All, event_tx);
+            return Action::Continue;
+        }
+        KeyCode::Char('x') => {
+            spawn_cache_purge(app, CachePurge::Expired, event_tx);
+            return Action::Continue;
+        }
+        _ => {}
+    }
+
+    let action = app
+        .keybindings
+        .action_for_key(code, modifiers, KbContext::FeedList);
+    match action {
+        Some(KbAction::Quit) => {
+            close_reading_session(app, event_tx);
+            return Action::Quit;
+        }
+        Some(KbAction::Back) | Some(KbAction::ViewCache) => {
+            app.view = View::Browse;
+            app.cache_overview = None;
+        }
+        Some(KbAction::NavDown) => {
+            let len = app.cache_overview.as_ref().map_or(0, |o| o.feeds.len());
+            if app.cache_selected + 1 < len {
+                app.cache_selected += 1;
+            }
+        }
+        Some(KbAction::NavUp) => {
+            app.cache_selected = app.cache_selected.saturating_sub(1);
+        }
+        _ => {}
+    }
+
+    Action::Continue
+}
+
+/// Re-run feed discovery against a feed's website and adopt the URL it finds.
+///
+/// Falls back to the feed URL itself when the feed has no website. If the
+/// discovered URL differs, the feed is repointed and the old URL kept as an
+/// alias so OPML re-imports still match it.
+fn spawn_rediscover_feed(app: &mut App, feed: &Feed, event_tx: &mpsc::Sender<AppEvent>) {
+    let feed_id = feed.id;
+    let title = feed.title.to_string();
+    let current_url = feed.url.clone();
+    let site_url = feed.html_url.clone().unwrap_or_else(|| feed.url.clone());
+    app.set_status(format!("Rediscovering feed URL for {}...", title));
+
+    let db = app.db.clone();
+    let client = app.http_client.clone();
+    let tx = event_tx.clone();
+    tokio::spawn(catch_task_panic(async move {
+        let result = match discover_feed(&client, &site_url).await {
+            Ok(found) if found.feed_url == current_url => Ok(None),
+            Ok(found) => db
+                .update_feed_url(feed_id, &found.feed_url)
+                .await
+                .map(|()| Some(found.feed_url))
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let _ = tx
+            .send(AppEvent::FeedRediscovered {
+                feed_id,
+                title,
+                result,
+            })
+            .await;
+        Ok::<(), String>(())
+    }));
+}
+
 /// Minimum reading session duration (seconds) to record.
 /// Sessions shorter than this are considered accidental and discarded.
 const MIN_SESSION_DURATION_SECS: u64 = 2;
@@ -1407,6 +2761,20 @@ fn start_reading_session(
                 tracing::warn!(error = %e, "Failed to record reading session open");
             }
         }
+        match db.get_duplicate_sources(article_id).await {
+            Ok(sources) if !sources.is_empty() => {
+                let _ = tx
+                    .send(AppEvent::DuplicateSourcesLoaded {
+                        article_id,
+                        sources,
+                    })
+                    .await;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load duplicate sources");
+            }
+        }
         Ok::<(), String>(())
     }));
 }
@@ -1414,21 +2782,62 @@ fn start_reading_session(
 /// Close the active reading session, if any.
 ///
 /// Computes duration from `started_at.elapsed()`. Sessions shorter than
-/// `MIN_SESSION_DURATION_SECS` or without a confirmed `history_id` are discarded.
-/// The DB close is fire-and-forget (spawned, non-blocking).
-fn close_reading_session(app: &mut App) {
+/// `MIN_SESSION_DURATION_SECS` are discarded, and so is the history row of one
+/// without a confirmed `history_id`. The DB close is fire-and-forget (spawned,
+/// non-blocking). A queued article then leaves the read-later queue if it was
+/// scrolled to the end or has been read long enough at the current reading
+/// speed (`finish_read_later`).
+fn close_reading_session(app: &mut App, event_tx: &mpsc::Sender<AppEvent>) {
+    // Remember where the article was left, however short the session
+    if let Some((article_id, position)) = app.reader_position() {
+        let db = app.db.clone();
+        tokio::spawn(catch_task_panic(async move {
+            db.save_reading_position(article_id, position)
+                .await
+                .map_err(|e| e.to_string())
+        }));
+    }
+
+    // The last line of the article (not the revision diff) was on screen
+    let reached_end = matches!(app.content_state, ContentState::Loaded { .. })
+        && app.reader_saved_content.is_none()
+        && app.reader_article.as_ref().map(|a| a.id)
+            == app.reading_session.as_ref().map(|s| s.article_id)
+        && app.scroll_offset + app.reader_visible_lines >= app.reader_content_lines();
+
     if let Some(session) = app.reading_session.take() {
         let duration = session.started_at.elapsed().as_secs() as i64;
-        if duration >= MIN_SESSION_DURATION_SECS as i64 && session.history_id > 0 {
-            let db = app.db.clone();
-            let history_id = session.history_id;
-            tokio::spawn(catch_task_panic(async move {
+        if duration < MIN_SESSION_DURATION_SECS as i64 {
+            return;
+        }
+        let db = app.db.clone();
+        let tx = event_tx.clone();
+        let history_id = session.history_id;
+        let article_id = session.article_id;
+        let queued = app.read_later_ids.contains(&article_id);
+        let words_per_minute = app.words_per_minute;
+        tokio::spawn(catch_task_panic(async move {
+            if history_id > 0 {
                 if let Err(e) = db.record_close(history_id, duration).await {
                     tracing::warn!(error = %e, history_id, "Failed to close reading session");
                 }
-                Ok::<(), String>(())
-            }));
-        }
+            }
+            if queued {
+                match db
+                    .finish_read_later(article_id, reached_end, words_per_minute)
+                    .await
+                {
+                    Ok(true) => {
+                        let _ = tx.send(AppEvent::ReadLaterFinished { article_id }).await;
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        tracing::warn!(error = %e, article_id, "Failed to check read-later progress");
+                    }
+                }
+            }
+            Ok::<(), String>(())
+        }));
     }
 }
 
@@ -1474,6 +2883,7 @@ async fn handle_search_input(
             if !query.is_empty() {
                 // Committing to search results - clear cache
                 app.cached_articles = None;
+                app.reading_time_filter = None;
                 app.articles = Arc::new(app.db.search_articles(&query, app.search_scope).await?);
                 app.selected_article = 0;
                 app.clamp_selections();
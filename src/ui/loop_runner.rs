@@ -12,7 +12,7 @@ use crossterm::{
 };
 use futures::StreamExt;
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io::{self, Stdout};
+use std::io::{self, Stdout, Write};
 use std::time::Duration;
 use tokio::sync::mpsc;
 
@@ -20,6 +20,7 @@ use tokio::sync::mpsc;
 use tokio::signal::unix::{signal, SignalKind};
 
 use super::events::handle_app_event;
+use super::hyperlink::{self, Hyperlink};
 use super::input::handle_input;
 use super::render::render;
 use crate::util::MAX_SEARCH_QUERY_LENGTH;
@@ -86,10 +87,22 @@ pub async fn run(
     #[cfg(unix)]
     let mut sigint = signal(SignalKind::interrupt())?;
 
+    // Hyperlinks on screen, re-printed plain once they're gone
+    let mut drawn_links: Vec<Hyperlink> = Vec::new();
+
     loop {
         // PERF-010: Only render when state has changed
         if app.needs_redraw {
-            terminal.draw(|f| render(f, app))?;
+            let frame = terminal.draw(|f| render(f, app))?;
+            if app.hyperlinks_enabled {
+                let bytes = hyperlink::overlay(frame.buffer, &app.hyperlinks, &drawn_links)?;
+                if !bytes.is_empty() {
+                    let backend = terminal.backend_mut();
+                    backend.write_all(&bytes)?;
+                    backend.flush()?;
+                }
+                drawn_links = std::mem::take(&mut app.hyperlinks);
+            }
             app.needs_redraw = false;
         }
 
@@ -159,6 +172,13 @@ pub async fn run(
         }
     }
 
+    // Remember where the open article was left before the process exits
+    if let Some((article_id, position)) = app.reader_position() {
+        if let Err(e) = app.db.save_reading_position(article_id, position).await {
+            tracing::warn!(error = %e, "Failed to save reading position");
+        }
+    }
+
     // Save session snapshot on exit
     let snapshot = app.snapshot();
     match serde_json::to_string(&snapshot) {
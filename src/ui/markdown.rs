@@ -0,0 +1,1290 @@
+//! Markdown to styled terminal lines for the reader.
+//!
+//! Covers CommonMark plus the GFM extensions common in jina output: tables,
+//! task lists, strikethrough and footnotes. Text is wrapped here rather than
+//! by the `Paragraph` widget so list items and quotes keep hanging indents.
+//!
+//! Links are numbered inline (`text[3]`) and listed again under "Links" at
+//! the end, so the reader's link picker can refer to them by number. Where
+//! each link landed after wrapping is reported too (`LinkSpan`), so the
+//! reader can make it clickable; for that every rendered line is exactly one
+//! screen row. Code block lines are the exception: they are highlighted
+//! (see `highlight`) and kept whole, and reported as `CodeLine`s so the
+//! reader can scroll them sideways.
+
+use super::highlight::Highlighter;
+use crate::theme::StyleMap;
+use crate::util::display_width;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use std::collections::HashMap;
+use unicode_width::UnicodeWidthChar;
+
+/// Width of rules and tables when the caller leaves wrapping to the widget.
+const UNWRAPPED_WIDTH: usize = 80;
+
+/// Bullets for unordered lists, by nesting depth.
+const BULLETS: [&str; 3] = ["• ", "◦ ", "▪ "];
+
+/// Quote bar drawn in front of every line of a blockquote.
+const QUOTE_BAR: &str = "│ ";
+
+/// A numbered link in rendered markdown. Link `[n]` is `links[n - 1]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownLink {
+    /// Destination as written in the article; may be relative.
+    pub url: String,
+    /// Plain text of the first link to this destination.
+    pub text: String,
+}
+
+/// Cells of a rendered line that show (part of) a link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkSpan {
+    /// Index into the rendered lines.
+    pub line: usize,
+    /// Display column where the link text starts.
+    pub column: usize,
+    /// Display width of the link text on this line.
+    pub width: usize,
+    /// Index into the links.
+    pub link: usize,
+}
+
+/// A rendered line of a code block, which may be wider than the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeLine {
+    /// Index into the rendered lines.
+    pub line: usize,
+    /// Display width of the quote bars and list indentation before the code.
+    pub indent: usize,
+}
+
+/// Rendered markdown: owned Lines for caching (PERF-004), the links they
+/// refer to by number, where those links appear and which lines are code.
+#[derive(Debug, Clone, Default)]
+pub struct RenderedMarkdown {
+    pub lines: Vec<Line<'static>>,
+    pub links: Vec<MarkdownLink>,
+    pub link_spans: Vec<LinkSpan>,
+    pub code_lines: Vec<CodeLine>,
+}
+
+/// Inline text and the link (index into the links) it belongs to.
+pub(super) type Inline = (Span<'static>, Option<usize>);
+
+/// Link being rendered.
+struct OpenLink {
+    index: usize,
+    /// First link to its destination, so its text names the link.
+    new: bool,
+    text: String,
+}
+
+/// Convert markdown to styled ratatui Lines.
+///
+/// Lines are wrapped to `width` columns, continuing list items, quotes and
+/// footnotes under their first line. A `width` of 0 leaves wrapping to the
+/// caller (hanging indents are lost). With `justify`, wrapped paragraph
+/// lines are stretched to the full width. Accepts a `StyleMap` to resolve
+/// semantic roles for markdown elements.
+pub fn render_markdown(
+    md: &str,
+    styles: &StyleMap,
+    width: usize,
+    justify: bool,
+) -> RenderedMarkdown {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    // Estimate: markdown lines roughly map to output lines
+    let mut renderer = Renderer::new(styles, width, md.lines().count());
+    renderer.justify = justify;
+    for event in Parser::new_ext(md, options) {
+        renderer.event(event);
+    }
+    renderer.finish()
+}
+
+/// Block element enclosing the text being rendered.
+enum Container {
+    Quote,
+    /// `next` is the number of the next ordered item (`None` for bullets).
+    List {
+        next: Option<u64>,
+    },
+    /// `started` once the marker has been drawn; later lines get blank indent.
+    Item {
+        marker: String,
+        role: &'static str,
+        started: bool,
+    },
+}
+
+/// Table being collected; rendered once all cells are known.
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<Vec<Inline>>>,
+    header_rows: usize,
+    in_head: bool,
+}
+
+struct Renderer<'s> {
+    styles: &'s StyleMap,
+    width: usize,
+    justify: bool,
+    lines: Vec<Line<'static>>,
+    link_spans: Vec<LinkSpan>,
+    // Most lines have few styled spans (text, emphasis, code, etc.)
+    spans: Vec<Inline>,
+    containers: Vec<Container>,
+    /// A blank line goes before the next block (dropped at the end).
+    pending_blank: bool,
+    /// Set inside a code block.
+    code_block: Option<Highlighter>,
+    code_lines: Vec<CodeLine>,
+    in_heading: bool,
+    in_emphasis: bool,
+    in_strong: bool,
+    in_strikethrough: bool,
+    table: Option<Table>,
+    /// Footnote definitions, rendered after the article. Swapped with
+    /// `lines` (and their link spans) while a definition is being rendered.
+    footnotes: Vec<Line<'static>>,
+    footnote_link_spans: Vec<LinkSpan>,
+    footnote_code_lines: Vec<CodeLine>,
+    footnote_numbers: HashMap<String, usize>,
+    main_pending_blank: bool,
+    links: Vec<MarkdownLink>,
+    /// `None` outside links and inside same-page (`#fragment`) links, which
+    /// aren't numbered.
+    link: Option<OpenLink>,
+}
+
+impl<'s> Renderer<'s> {
+    fn new(styles: &'s StyleMap, width: usize, capacity: usize) -> Self {
+        Self {
+            styles,
+            width,
+            justify: false,
+            lines: Vec::with_capacity(capacity),
+            link_spans: Vec::new(),
+            spans: Vec::with_capacity(4),
+            containers: Vec::new(),
+            pending_blank: false,
+            code_block: None,
+            code_lines: Vec::new(),
+            in_heading: false,
+            in_emphasis: false,
+            in_strong: false,
+            in_strikethrough: false,
+            table: None,
+            footnotes: Vec::new(),
+            footnote_link_spans: Vec::new(),
+            footnote_code_lines: Vec::new(),
+            footnote_numbers: HashMap::new(),
+            main_pending_blank: false,
+            links: Vec::new(),
+            link: None,
+        }
+    }
+
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(Tag::Paragraph) => {}
+            Event::End(TagEnd::Paragraph) => self.end_block(),
+            Event::Start(Tag::Heading { .. }) => {
+                self.flush();
+                self.in_heading = true;
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                self.end_block();
+                self.in_heading = false;
+            }
+            Event::Start(Tag::BlockQuote(_)) => {
+                self.flush();
+                self.containers.push(Container::Quote);
+            }
+            Event::End(TagEnd::BlockQuote(_)) => {
+                self.flush();
+                self.containers.pop();
+                self.pending_blank = true;
+            }
+            Event::Start(Tag::CodeBlock(kind)) => {
+                self.flush();
+                let info = match &kind {
+                    CodeBlockKind::Fenced(info) => info.as_ref(),
+                    CodeBlockKind::Indented => "",
+                };
+                self.code_block = Some(Highlighter::new(info));
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                self.code_block = None;
+                self.pending_blank = true;
+            }
+            Event::Start(Tag::List(start)) => {
+                // An item's own text comes before its nested list
+                self.flush();
+                self.containers.push(Container::List { next: start });
+            }
+            Event::End(TagEnd::List(_)) => {
+                self.flush();
+                self.containers.pop();
+                if !self.in_item() {
+                    self.pending_blank = true;
+                }
+            }
+            Event::Start(Tag::Item) => {
+                self.flush();
+                let depth = self
+                    .containers
+                    .iter()
+                    .filter(|c| matches!(c, Container::List { .. }))
+                    .count();
+                let marker = match self.containers.last_mut() {
+                    Some(Container::List { next: Some(n) }) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    }
+                    _ => BULLETS[depth.saturating_sub(1) % BULLETS.len()].to_string(),
+                };
+                self.containers.push(Container::Item {
+                    marker,
+                    role: "reader_list_marker",
+                    started: false,
+                });
+            }
+            Event::End(TagEnd::Item) => {
+                self.flush();
+                self.containers.pop();
+            }
+            Event::TaskListMarker(checked) => {
+                if let Some(Container::Item { marker, role, .. }) = self.containers.last_mut() {
+                    *marker = if checked { "[x] " } else { "[ ] " }.to_string();
+                    *role = "reader_task";
+                }
+            }
+            Event::Start(Tag::Emphasis) => self.in_emphasis = true,
+            Event::End(TagEnd::Emphasis) => self.in_emphasis = false,
+            Event::Start(Tag::Strong) => self.in_strong = true,
+            Event::End(TagEnd::Strong) => self.in_strong = false,
+            Event::Start(Tag::Strikethrough) => self.in_strikethrough = true,
+            Event::End(TagEnd::Strikethrough) => self.in_strikethrough = false,
+            // Same-page (`#fragment`) links fall through unnumbered
+            Event::Start(Tag::Link { dest_url, .. })
+                if !dest_url.is_empty() && !dest_url.starts_with('#') =>
+            {
+                let url = dest_url.into_string();
+                let (index, new) = match self.links.iter().position(|l| l.url == url) {
+                    Some(i) => (i, false),
+                    None => {
+                        self.links.push(MarkdownLink {
+                            url,
+                            text: String::new(),
+                        });
+                        (self.links.len() - 1, true)
+                    }
+                };
+                self.link = Some(OpenLink {
+                    index,
+                    new,
+                    text: String::new(),
+                });
+            }
+            Event::End(TagEnd::Link) => {
+                if let Some(link) = &self.link {
+                    let style = self.styles.resolve("reader_link");
+                    let number = format!("[{}]", link.index + 1);
+                    self.push_inline(Span::styled(number, style));
+                }
+                if let Some(link) = self.link.take().filter(|l| l.new) {
+                    self.links[link.index].text = link.text.trim().to_string();
+                }
+            }
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                let style = self.styles.resolve("reader_image");
+                self.push_inline(Span::styled(format!("[Image: {}]", dest_url), style));
+            }
+            Event::Start(Tag::Table(alignments)) => {
+                self.flush();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    header_rows: 0,
+                    in_head: false,
+                });
+            }
+            Event::Start(Tag::TableHead) => {
+                if let Some(table) = &mut self.table {
+                    table.in_head = true;
+                    table.rows.push(Vec::new());
+                }
+            }
+            Event::End(TagEnd::TableHead) => {
+                if let Some(table) = &mut self.table {
+                    table.in_head = false;
+                    table.header_rows = table.rows.len();
+                }
+            }
+            Event::Start(Tag::TableRow) => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Event::Start(Tag::TableCell) => {
+                if let Some(row) = self.table.as_mut().and_then(|t| t.rows.last_mut()) {
+                    row.push(Vec::new());
+                }
+            }
+            Event::End(TagEnd::Table) => {
+                if let Some(table) = self.table.take() {
+                    self.render_table(table);
+                }
+                self.pending_blank = true;
+            }
+            Event::FootnoteReference(label) => {
+                let number = self.footnote_number(&label);
+                let style = self.styles.resolve("reader_footnote");
+                self.push_inline(Span::styled(format!("[^{}]", number), style));
+            }
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                self.flush();
+                let number = self.footnote_number(&label);
+                std::mem::swap(&mut self.lines, &mut self.footnotes);
+                std::mem::swap(&mut self.link_spans, &mut self.footnote_link_spans);
+                std::mem::swap(&mut self.code_lines, &mut self.footnote_code_lines);
+                self.main_pending_blank = self.pending_blank;
+                // Separates this footnote from the previous one, if any
+                self.pending_blank = true;
+                self.containers.push(Container::Item {
+                    marker: format!("[^{}] ", number),
+                    role: "reader_footnote",
+                    started: false,
+                });
+            }
+            Event::End(TagEnd::FootnoteDefinition) => {
+                self.flush();
+                self.containers.pop();
+                std::mem::swap(&mut self.lines, &mut self.footnotes);
+                std::mem::swap(&mut self.link_spans, &mut self.footnote_link_spans);
+                std::mem::swap(&mut self.code_lines, &mut self.footnote_code_lines);
+                self.pending_blank = self.main_pending_blank;
+            }
+            Event::Rule => {
+                self.flush();
+                let width = self.content_width();
+                let style = self.styles.resolve("reader_rule");
+                self.emit(vec![Span::styled("─".repeat(width), style)], &[]);
+                self.pending_blank = true;
+            }
+            Event::Text(text) if self.code_block.is_some() => {
+                for line in text.lines() {
+                    let spans = match &mut self.code_block {
+                        Some(highlighter) => highlighter.line(line, self.styles),
+                        None => Vec::new(),
+                    };
+                    let indent = self.emit(spans, &[]);
+                    self.code_lines.push(CodeLine {
+                        line: self.lines.len() - 1,
+                        indent,
+                    });
+                }
+            }
+            Event::Text(text) => {
+                let style = self.text_style();
+                if let Some(link) = &mut self.link {
+                    link.text.push_str(&text);
+                }
+                // PERF-011: CowStr::into_string() is O(1) for Boxed variant (no allocation),
+                // vs .to_string() which always allocates
+                self.push_inline(Span::styled(text.into_string(), style));
+            }
+            Event::Code(code) => {
+                let style = self.styles.resolve("reader_inline_code");
+                if let Some(link) = &mut self.link {
+                    link.text.push_str(&code);
+                }
+                self.push_inline(Span::styled(format!("`{}`", code), style));
+            }
+            Event::SoftBreak => {
+                if let Some(link) = &mut self.link {
+                    link.text.push(' ');
+                }
+                self.push_inline(Span::raw(" "));
+            }
+            Event::HardBreak if self.table.is_some() => self.push_inline(Span::raw(" ")),
+            Event::HardBreak => self.flush(),
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> RenderedMarkdown {
+        self.flush();
+        if !self.footnotes.is_empty() {
+            if !self.lines.is_empty() {
+                self.lines.push(Line::from(""));
+            }
+            let width = self.content_width();
+            self.lines.push(Line::from(Span::styled(
+                "─".repeat(width.min(20)),
+                self.styles.resolve("reader_rule"),
+            )));
+            let offset = self.lines.len();
+            self.link_spans
+                .extend(self.footnote_link_spans.iter().map(|s| LinkSpan {
+                    line: s.line + offset,
+                    ..*s
+                }));
+            self.code_lines
+                .extend(self.footnote_code_lines.iter().map(|c| CodeLine {
+                    line: c.line + offset,
+                    ..*c
+                }));
+            self.lines.append(&mut self.footnotes);
+        }
+        self.push_references();
+        RenderedMarkdown {
+            lines: self.lines,
+            links: self.links,
+            link_spans: self.link_spans,
+            code_lines: self.code_lines,
+        }
+    }
+
+    /// List the numbered links under a "Links" heading, URLs hanging
+    /// under their numbers.
+    fn push_references(&mut self) {
+        if self.links.is_empty() {
+            return;
+        }
+        if !self.lines.is_empty() {
+            self.lines.push(Line::from(""));
+        }
+        self.lines.push(Line::from(Span::styled(
+            "Links",
+            self.styles.resolve("reader_heading"),
+        )));
+        let number_style = self.styles.resolve("reader_link");
+        let url_style = self.styles.resolve("reader_metadata");
+        let digits = self.links.len().to_string().len();
+        for (i, link) in self.links.iter().enumerate() {
+            // Pad numbers so the URLs line up: "[ 9] " above "[10] "
+            let marker = format!("[{:>digits$}] ", i + 1);
+            let indent = " ".repeat(marker.len());
+            let spans = vec![(Span::styled(link.url.clone(), url_style), Some(i))];
+            let (lines, spans) = wrap_spans(
+                spans,
+                &[Span::styled(marker, number_style)],
+                &[Span::raw(indent)],
+                self.width,
+            );
+            let base = self.lines.len();
+            self.link_spans.extend(spans.into_iter().map(|s| LinkSpan {
+                line: s.line + base,
+                ..s
+            }));
+            self.lines.extend(lines);
+        }
+    }
+
+    /// Style for body text in the current inline context.
+    fn text_style(&self) -> Style {
+        let mut style = if self.in_heading {
+            self.styles.resolve("reader_heading")
+        } else if self
+            .containers
+            .iter()
+            .any(|c| matches!(c, Container::Quote))
+        {
+            self.styles.resolve("reader_quote")
+        } else {
+            self.styles.resolve("reader_body")
+        };
+        if self.table.as_ref().is_some_and(|t| t.in_head) {
+            style = style.patch(self.styles.resolve("reader_table_header"));
+        }
+        if self.in_strong {
+            style = style.patch(self.styles.resolve("reader_strong"));
+        }
+        if self.in_emphasis {
+            style = style.patch(self.styles.resolve("reader_emphasis"));
+        }
+        if self.in_strikethrough {
+            style = style.patch(self.styles.resolve("reader_strikethrough"));
+        }
+        if self.link.is_some() {
+            style = style.patch(self.styles.resolve("reader_link"));
+        }
+        style
+    }
+
+    fn in_item(&self) -> bool {
+        self.containers
+            .iter()
+            .any(|c| matches!(c, Container::Item { .. }))
+    }
+
+    fn footnote_number(&mut self, label: &str) -> usize {
+        let next = self.footnote_numbers.len() + 1;
+        *self
+            .footnote_numbers
+            .entry(label.to_string())
+            .or_insert(next)
+    }
+
+    /// Add inline content to the current table cell or text block.
+    fn push_inline(&mut self, span: Span<'static>) {
+        let span = (span, self.link.as_ref().map(|l| l.index));
+        match self
+            .table
+            .as_mut()
+            .and_then(|t| t.rows.last_mut())
+            .and_then(|row| row.last_mut())
+        {
+            Some(cell) => cell.push(span),
+            None => self.spans.push(span),
+        }
+    }
+
+    /// End a paragraph-like block: wrap its text and separate it from the next.
+    fn end_block(&mut self) {
+        self.flush();
+        self.pending_blank = true;
+    }
+
+    /// Wrap the collected inline text into lines under the current prefixes.
+    fn flush(&mut self) {
+        if self.spans.iter().all(|(s, _)| s.content.trim().is_empty()) {
+            self.spans.clear();
+            return;
+        }
+        let spans = std::mem::take(&mut self.spans);
+        self.push_pending_blank();
+        let (first, rest) = self.prefixes();
+        let (mut wrapped, mut link_spans) = wrap_spans(spans, &first, &rest, self.width);
+        if self.justify && self.width > 0 && !self.in_heading {
+            // Every line but the last fills the width
+            let indent = |p: &[Span<'_>]| p.iter().map(|s| display_width(&s.content)).sum();
+            let (first_indent, rest_indent) = (indent(&first), indent(&rest));
+            let last = wrapped.len().saturating_sub(1);
+            for (i, line) in wrapped[..last].iter_mut().enumerate() {
+                let indent = if i == 0 { first_indent } else { rest_indent };
+                justify_line(line, i, indent, self.width, &mut link_spans);
+            }
+        }
+        let base = self.lines.len();
+        self.link_spans
+            .extend(link_spans.into_iter().map(|s| LinkSpan {
+                line: s.line + base,
+                ..s
+            }));
+        self.lines.extend(wrapped);
+    }
+
+    /// Add one pre-laid-out line (code, rule, table row) under the current
+    /// prefixes. `links` columns count from the start of `content`.
+    /// Returns the width of the prefixes.
+    fn emit(&mut self, content: Vec<Span<'static>>, links: &[LinkSpan]) -> usize {
+        self.push_pending_blank();
+        let (mut line, _) = self.prefixes();
+        let indent: usize = line.iter().map(|s| display_width(&s.content)).sum();
+        let base = self.lines.len();
+        self.link_spans.extend(links.iter().map(|s| LinkSpan {
+            line: base,
+            column: s.column + indent,
+            ..*s
+        }));
+        line.extend(content);
+        self.lines.push(Line::from(line));
+        indent
+    }
+
+    fn push_pending_blank(&mut self) {
+        if std::mem::take(&mut self.pending_blank) && !self.lines.is_empty() {
+            // Keep quote bars running through blank lines inside a quote
+            let style = self.styles.resolve("reader_quote");
+            let bars = self
+                .containers
+                .iter()
+                .filter(|c| matches!(c, Container::Quote))
+                .count();
+            let line = QUOTE_BAR.repeat(bars);
+            self.lines
+                .push(Line::from(Span::styled(line.trim_end().to_string(), style)));
+        }
+    }
+
+    /// Prefixes for the first and the following lines of a block. Drawing
+    /// the first one uses up any pending list markers.
+    fn prefixes(&mut self) -> (Vec<Span<'static>>, Vec<Span<'static>>) {
+        let mut first = Vec::new();
+        let mut rest = Vec::new();
+        for container in &mut self.containers {
+            match container {
+                Container::Quote => {
+                    let bar = Span::styled(QUOTE_BAR, self.styles.resolve("reader_quote"));
+                    first.push(bar.clone());
+                    rest.push(bar);
+                }
+                Container::List { .. } => {}
+                Container::Item {
+                    marker,
+                    role,
+                    started,
+                } => {
+                    let indent = " ".repeat(display_width(marker));
+                    if *started {
+                        first.push(Span::raw(indent.clone()));
+                    } else {
+                        first.push(Span::styled(marker.clone(), self.styles.resolve(role)));
+                        *started = true;
+                    }
+                    rest.push(Span::raw(indent));
+                }
+            }
+        }
+        (first, rest)
+    }
+
+    /// Columns left for content after quote bars and list indents.
+    fn content_width(&self) -> usize {
+        let indent: usize = self
+            .containers
+            .iter()
+            .map(|c| match c {
+                Container::Quote => display_width(QUOTE_BAR),
+                Container::List { .. } => 0,
+                Container::Item { marker, .. } => display_width(marker),
+            })
+            .sum();
+        let width = if self.width == 0 {
+            UNWRAPPED_WIDTH
+        } else {
+            self.width
+        };
+        width.saturating_sub(indent).max(1)
+    }
+
+    /// Lay out a table with box-drawing borders, wrapping cells that don't
+    /// fit the available width.
+    fn render_table(&mut self, table: Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+        let mut widths = vec![1usize; columns];
+        for row in &table.rows {
+            for (i, cell) in row.iter().enumerate() {
+                let width = cell.iter().map(|(s, _)| display_width(&s.content)).sum();
+                widths[i] = widths[i].max(width);
+            }
+        }
+        // "│ " before each column, " │" after the last
+        let budget = self
+            .content_width()
+            .saturating_sub(3 * columns + 1)
+            .max(columns);
+        while widths.iter().sum::<usize>() > budget {
+            let widest = (0..columns).max_by_key(|&i| widths[i]).unwrap_or(0);
+            if widths[widest] <= 1 {
+                break;
+            }
+            widths[widest] -= 1;
+        }
+
+        let border = self.styles.resolve("reader_table_border");
+        let rule = |left: &str, mid: &str, right: &str| -> Vec<Span<'static>> {
+            let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+            vec![Span::styled(
+                format!("{}{}{}", left, segments.join(mid), right),
+                border,
+            )]
+        };
+
+        self.emit(rule("┌", "┬", "┐"), &[]);
+        for (r, row) in table.rows.iter().enumerate() {
+            if r > 0 && r == table.header_rows {
+                self.emit(rule("├", "┼", "┤"), &[]);
+            }
+            let cells: Vec<(Vec<Line<'static>>, Vec<LinkSpan>)> = (0..columns)
+                .map(|i| {
+                    let spans = row.get(i).cloned().unwrap_or_default();
+                    wrap_spans(spans, &[], &[], widths[i])
+                })
+                .collect();
+            let height = cells.iter().map(|(l, _)| l.len()).max().unwrap_or(0).max(1);
+            for k in 0..height {
+                let mut line = Vec::with_capacity(columns * 3 + 1);
+                let mut links = Vec::new();
+                let mut column = 0;
+                for (i, (cell, cell_links)) in cells.iter().enumerate() {
+                    line.push(Span::styled("│ ", border));
+                    let content = cell.get(k).map(|l| l.spans.clone()).unwrap_or_default();
+                    let used: usize = content.iter().map(|s| display_width(&s.content)).sum();
+                    let pad = widths[i].saturating_sub(used);
+                    let (before, after) = match table.alignments.get(i) {
+                        Some(Alignment::Right) => (pad, 0),
+                        Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+                        _ => (0, pad),
+                    };
+                    if before > 0 {
+                        line.push(Span::raw(" ".repeat(before)));
+                    }
+                    let start = column + 2 + before;
+                    links.extend(cell_links.iter().filter(|s| s.line == k).map(|s| LinkSpan {
+                        column: s.column + start,
+                        ..*s
+                    }));
+                    line.extend(content);
+                    line.push(Span::raw(" ".repeat(after + 1)));
+                    column += widths[i] + 3;
+                }
+                line.push(Span::styled("│", border));
+                self.emit(line, &links);
+            }
+        }
+        self.emit(rule("└", "┴", "┘"), &[]);
+    }
+}
+
+/// Word-wrap styled spans to `width` columns. The first line starts with
+/// `first`, later lines with `rest`. Words longer than a line are split.
+/// A `width` of 0 produces a single unwrapped line.
+///
+/// Also returns where linked text ended up, by output line and column.
+pub(super) fn wrap_spans(
+    spans: Vec<Inline>,
+    first: &[Span<'static>],
+    rest: &[Span<'static>],
+    width: usize,
+) -> (Vec<Line<'static>>, Vec<LinkSpan>) {
+    let prefix_width = |p: &[Span<'_>]| p.iter().map(|s| display_width(&s.content)).sum::<usize>();
+    let mut links = Vec::new();
+
+    if width == 0 {
+        let mut line = first.to_vec();
+        let mut column = prefix_width(first);
+        for (span, link) in spans {
+            let span_width = display_width(&span.content);
+            if let Some(link) = link {
+                add_link_span(&mut links, 0, column, span_width, link);
+            }
+            column += span_width;
+            line.push(span);
+        }
+        return (vec![Line::from(line)], links);
+    }
+
+    // Words may span several styles ("more" + footnote "[2]"), so group the
+    // non-space runs of consecutive spans before fitting them on lines
+    // Each part is some text, its style and its link
+    type Part<'a> = (&'a str, Style, Option<usize>);
+    let mut words: Vec<(bool, Vec<Part>)> = Vec::new();
+    for (span, link) in &spans {
+        for (token, is_space) in tokens(&span.content) {
+            let part = (token, span.style, *link);
+            match words.last_mut() {
+                Some((space, parts)) if *space == is_space => parts.push(part),
+                _ => words.push((is_space, vec![part])),
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut line: Vec<Span<'static>> = first.to_vec();
+    let mut indent = prefix_width(first);
+    let mut available = width.saturating_sub(indent).max(1);
+    let mut used = 0;
+
+    for (is_space, parts) in words {
+        let word_width: usize = parts.iter().map(|(t, _, _)| display_width(t)).sum();
+        let fits = used + word_width <= available;
+        if is_space && !(used > 0 && fits) {
+            // Spaces never start a line or push a word to the next one
+            continue;
+        }
+        if used > 0 && !fits {
+            let full = std::mem::replace(&mut line, rest.to_vec());
+            end_line(&mut lines, full, &mut links);
+            indent = prefix_width(rest);
+            available = width.saturating_sub(indent).max(1);
+            used = 0;
+        }
+        if word_width <= available - used {
+            for (text, style, link) in parts {
+                push_text(&mut line, text, style);
+                let text_width = display_width(text);
+                if let Some(link) = link {
+                    add_link_span(&mut links, lines.len(), indent + used, text_width, link);
+                }
+                used += text_width;
+            }
+            continue;
+        }
+        // Longer than a whole line (URLs): split at the line edge
+        for (text, style, link) in parts {
+            for ch in text.chars() {
+                let ch_width = ch.width().unwrap_or(0);
+                if used + ch_width > available && used > 0 {
+                    let full = std::mem::replace(&mut line, rest.to_vec());
+                    end_line(&mut lines, full, &mut links);
+                    indent = prefix_width(rest);
+                    available = width.saturating_sub(indent).max(1);
+                    used = 0;
+                }
+                let mut buf = [0u8; 4];
+                push_text(&mut line, ch.encode_utf8(&mut buf), style);
+                if let Some(link) = link {
+                    add_link_span(&mut links, lines.len(), indent + used, ch_width, link);
+                }
+                used += ch_width;
+            }
+        }
+    }
+    end_line(&mut lines, line, &mut links);
+    (lines, links)
+}
+
+/// Finish a wrapped line: drop trailing spaces, also from the links on it.
+fn end_line(
+    lines: &mut Vec<Line<'static>>,
+    mut line: Vec<Span<'static>>,
+    links: &mut Vec<LinkSpan>,
+) {
+    trim_trailing_spaces(&mut line);
+    let line_width: usize = line.iter().map(|s| display_width(&s.content)).sum();
+    let index = lines.len();
+    links.retain_mut(|s| {
+        if s.line == index {
+            s.width = s.width.min(line_width.saturating_sub(s.column));
+        }
+        s.width > 0
+    });
+    lines.push(Line::from(line));
+}
+
+/// Widen the gaps between words after the first `indent` columns so line
+/// `index` fills `width` columns, moving the link spans on it along.
+fn justify_line(
+    line: &mut Line<'static>,
+    index: usize,
+    indent: usize,
+    width: usize,
+    links: &mut [LinkSpan],
+) {
+    let line_width: usize = line.spans.iter().map(|s| display_width(&s.content)).sum();
+    // Column of each gap between words
+    let mut gaps = Vec::new();
+    let mut column = 0;
+    let mut after_space = true;
+    for span in &line.spans {
+        for c in span.content.chars() {
+            let is_space = c == ' ';
+            if is_space && !after_space && column >= indent {
+                gaps.push(column);
+            }
+            after_space = is_space;
+            column += c.width().unwrap_or(0);
+        }
+    }
+    let extra = width.saturating_sub(line_width);
+    if gaps.is_empty() || extra == 0 {
+        return;
+    }
+    let widen = |gap: usize| extra / gaps.len() + usize::from(gap < extra % gaps.len());
+    // Added columns before `column`
+    let shift = |column: usize| -> usize {
+        let before = gaps.partition_point(|&g| g < column);
+        (0..before).map(widen).sum()
+    };
+
+    let mut gap = 0;
+    let mut column = 0;
+    for span in &mut line.spans {
+        let mut text = String::with_capacity(span.content.len() + extra);
+        for c in span.content.chars() {
+            if gaps.get(gap) == Some(&column) {
+                text.extend(std::iter::repeat_n(' ', widen(gap)));
+                gap += 1;
+            }
+            text.push(c);
+            column += c.width().unwrap_or(0);
+        }
+        span.content = text.into();
+    }
+    for link in links.iter_mut().filter(|l| l.line == index) {
+        let end = link.column + link.width;
+        let column = link.column + shift(link.column);
+        link.width = end + shift(end) - column;
+        link.column = column;
+    }
+}
+
+/// Record linked text, extending the previous span when it continues it.
+fn add_link_span(links: &mut Vec<LinkSpan>, line: usize, column: usize, width: usize, link: usize) {
+    match links.last_mut() {
+        Some(last)
+            if last.line == line && last.link == link && last.column + last.width == column =>
+        {
+            last.width += width;
+        }
+        _ => links.push(LinkSpan {
+            line,
+            column,
+            width,
+            link,
+        }),
+    }
+}
+
+/// Split text into alternating runs of whitespace and non-whitespace,
+/// flagging the whitespace runs.
+fn tokens(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let is_space = first.is_whitespace();
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| c.is_whitespace() != is_space)
+            .map_or(rest.len(), |(i, _)| i);
+        let (token, tail) = rest.split_at(end);
+        rest = tail;
+        Some((token, is_space))
+    })
+}
+
+/// Append text, extending the last span when the style matches.
+fn push_text(line: &mut Vec<Span<'static>>, text: &str, style: Style) {
+    match line.last_mut() {
+        Some(last) if last.style == style => last.content.to_mut().push_str(text),
+        _ => line.push(Span::styled(text.to_string(), style)),
+    }
+}
+
+fn trim_trailing_spaces(line: &mut Vec<Span<'static>>) {
+    while let Some(last) = line.last_mut() {
+        let trimmed = last.content.trim_end().len();
+        if trimmed > 0 {
+            last.content.to_mut().truncate(trimmed);
+            return;
+        }
+        line.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::ThemeVariant;
+
+    fn test_styles() -> StyleMap {
+        StyleMap::from_palette(&ThemeVariant::Dark.palette())
+    }
+
+    /// Rendered lines as plain text.
+    fn text(md: &str, width: usize) -> Vec<String> {
+        render_markdown(md, &test_styles(), width, false)
+            .lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_plain_text() {
+        let lines = render_markdown("Hello world", &test_styles(), 80, false).lines;
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn test_render_heading() {
+        let lines = render_markdown("# Heading 1\n\n## Heading 2", &test_styles(), 80, false).lines;
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn test_render_bold() {
+        let lines = render_markdown("This is **bold** text", &test_styles(), 80, false).lines;
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn test_render_italic() {
+        let lines = render_markdown("This is *italic* text", &test_styles(), 80, false).lines;
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn test_render_code_block() {
+        assert_eq!(text("```\nfirst\nsecond\n```", 80), ["first", "second"]);
+    }
+
+    #[test]
+    fn test_render_link() {
+        let lines = render_markdown(
+            "[link text](https://example.com)",
+            &test_styles(),
+            80,
+            false,
+        )
+        .lines;
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn test_render_empty() {
+        let lines = render_markdown("", &test_styles(), 80, false).lines;
+        // Should not panic - empty input is valid
+        assert!(lines.is_empty());
+    }
+
+    #[test]
+    fn test_render_unicode() {
+        let lines = render_markdown("Hello 世界 🌍", &test_styles(), 80, false).lines;
+        assert!(!lines.is_empty());
+    }
+
+    #[test]
+    fn test_paragraphs_wrap_and_separate() {
+        assert_eq!(
+            text("one two three four\n\nnext", 9),
+            ["one two", "three", "four", "", "next"]
+        );
+        // Width 0 leaves wrapping to the widget
+        assert_eq!(text("one two three four", 0), ["one two three four"]);
+        // Words longer than the line are split
+        assert_eq!(text("abcdefghij", 4), ["abcd", "efgh", "ij"]);
+    }
+
+    #[test]
+    fn test_nested_lists_hang_indent() {
+        let md = "- first item wraps here\n  - nested\n- second\n\n1. one\n2. two";
+        assert_eq!(
+            text(md, 14),
+            [
+                "• first item",
+                "  wraps here",
+                "  ◦ nested",
+                "• second",
+                "",
+                "1. one",
+                "2. two",
+            ]
+        );
+        assert_eq!(text("3. three\n4. four", 80), ["3. three", "4. four"]);
+    }
+
+    #[test]
+    fn test_task_list_and_strikethrough() {
+        let lines = render_markdown("- [x] done\n- [ ] ~~todo~~", &test_styles(), 80, false).lines;
+        let styles = test_styles();
+        assert_eq!(lines[0].spans[0].content, "[x] ");
+        assert_eq!(lines[0].spans[0].style, styles.resolve("reader_task"));
+        assert_eq!(lines[1].spans[0].content, "[ ] ");
+        assert_eq!(lines[1].spans[1].content, "todo");
+        assert_eq!(
+            lines[1].spans[1].style,
+            styles
+                .resolve("reader_body")
+                .patch(styles.resolve("reader_strikethrough"))
+        );
+    }
+
+    #[test]
+    fn test_blockquote_bars() {
+        assert_eq!(
+            text("> quoted text here\n>\n> more\n\nafter", 12),
+            ["│ quoted", "│ text here", "│", "│ more", "", "after"]
+        );
+        assert_eq!(
+            text("> outer\n> > inner", 80),
+            ["│ outer", "│ │", "│ │ inner"]
+        );
+    }
+
+    #[test]
+    fn test_table_box_drawn_and_aligned() {
+        let md = "| Name | Qty |\n|:-----|----:|\n| apple | 3 |\n| kiwi | 12 |";
+        assert_eq!(
+            text(md, 80),
+            [
+                "┌───────┬─────┐",
+                "│ Name  │ Qty │",
+                "├───────┼─────┤",
+                "│ apple │   3 │",
+                "│ kiwi  │  12 │",
+                "└───────┴─────┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_cells_wrap_to_fit() {
+        let md = "| a | b |\n|---|---|\n| one two three | x |";
+        let lines = text(md, 16);
+        assert!(lines.iter().all(|l| display_width(l) <= 16), "{:?}", lines);
+        assert_eq!(lines[3], "│ one two  │ x │");
+        assert_eq!(lines[4], "│ three    │   │");
+    }
+
+    #[test]
+    fn test_rule_and_footnotes() {
+        let md = "Claim[^src] and more[^n].\n\n---\n\n[^n]: Second note.\n[^src]: First note.";
+        assert_eq!(
+            text(md, 12),
+            [
+                "Claim[^1]",
+                "and",
+                "more[^2].",
+                "",
+                "────────────",
+                "",
+                "────────────",
+                "[^2] Second",
+                "     note.",
+                "",
+                "[^1] First",
+                "     note.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_links_numbered_and_listed() {
+        let md = "See [the docs](https://example.com/docs), [again](https://example.com/docs) \
+                  and [`api`](/api).\n\n[Top](#top)";
+        let rendered = render_markdown(md, &test_styles(), 30, false);
+        assert_eq!(
+            rendered.links,
+            [
+                MarkdownLink {
+                    url: "https://example.com/docs".to_string(),
+                    text: "the docs".to_string(),
+                },
+                MarkdownLink {
+                    url: "/api".to_string(),
+                    text: "api".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            text(md, 30),
+            [
+                "See the docs[1], again[1] and",
+                "`api`[2].",
+                "",
+                "Top",
+                "",
+                "Links",
+                "[1] https://example.com/docs",
+                "[2] /api",
+            ]
+        );
+
+        // Link text and numbers use the link role; long URLs hang under their number
+        let styles = test_styles();
+        let link = rendered.lines[0]
+            .spans
+            .iter()
+            .find(|s| s.content.ends_with("[1]"))
+            .unwrap();
+        assert_eq!(link.style, styles.resolve("reader_link"));
+        assert_eq!(
+            text("[x](https://example.com/a/long/path)", 20)[3..],
+            ["[1] https://example.", "    com/a/long/path"]
+        );
+    }
+
+    #[test]
+    fn test_link_spans_follow_wrapping() {
+        // Text under a link span, for lines of width-1 characters
+        let under = |rendered: &RenderedMarkdown, span: &LinkSpan| -> String {
+            let line: String = rendered.lines[span.line]
+                .spans
+                .iter()
+                .map(|s| s.content.as_ref())
+                .collect();
+            line.chars().skip(span.column).take(span.width).collect()
+        };
+
+        let md = "See [the docs](https://example.com/docs), [again](https://example.com/docs) \
+                  and [`api`](/api).";
+        let rendered = render_markdown(md, &test_styles(), 30, false);
+        let spans: Vec<(String, usize)> = rendered
+            .link_spans
+            .iter()
+            .map(|s| (under(&rendered, s), s.link))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("the docs[1]".to_string(), 0),
+                ("again[1]".to_string(), 0),
+                ("`api`[2]".to_string(), 1),
+                ("https://example.com/docs".to_string(), 0),
+                ("/api".to_string(), 1),
+            ]
+        );
+
+        // A link broken across lines gets a span on each
+        let rendered = render_markdown(
+            "> [a long link](https://x.example/)",
+            &test_styles(),
+            10,
+            false,
+        );
+        let spans: Vec<String> = rendered
+            .link_spans
+            .iter()
+            .take(2)
+            .map(|s| under(&rendered, s))
+            .collect();
+        assert_eq!(spans, ["a long", "link[1]"]);
+
+        // Table cells are offset by their position in the row
+        let md = "| a | [b](https://b.example/) |\n|---|---|\n| 1 | 2 |";
+        let rendered = render_markdown(md, &test_styles(), 30, false);
+        assert_eq!(under(&rendered, &rendered.link_spans[0]), "b[1]");
+    }
+
+    #[test]
+    fn test_justified_lines_fill_width() {
+        let md = "> one two [three](https://x.example/) four five six";
+        let rendered = render_markdown(md, &test_styles(), 20, true);
+        let lines: Vec<String> = rendered
+            .lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        // The quote bar isn't stretched, nor is the last line
+        assert_eq!(lines[..2], ["│ one  two  three[1]", "│ four five six"]);
+        assert_eq!(
+            rendered.link_spans[0],
+            LinkSpan {
+                line: 0,
+                column: 12,
+                width: 8,
+                link: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_code_lines_kept_whole_and_highlighted() {
+        assert_eq!(text("```\nabcdefghij\n```", 4), ["abcdefghij"]);
+        let rendered = render_markdown("> ```rust\n> let x = 1;\n> ```", &test_styles(), 4, false);
+        assert_eq!(rendered.code_lines, [CodeLine { line: 0, indent: 2 }]);
+
+        let styles = test_styles();
+        let keyword = styles
+            .resolve("reader_code_block")
+            .patch(styles.resolve("reader_code_keyword"));
+        let let_span = rendered.lines[0].spans.iter().find(|s| s.content == "let");
+        assert_eq!(let_span.map(|s| s.style), Some(keyword));
+    }
+}
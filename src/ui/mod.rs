@@ -16,18 +16,27 @@
 //! - `articles` - Article list widget
 //! - `feeds` - Feed list widget
 //! - `reader` - Article reader widget
+//! - `markdown` - Markdown rendering for the reader
+//! - `highlight` - Syntax highlighting for code blocks in the reader
+//! - `hyperlink` - OSC 8 hyperlinks over the drawn frame
 //! - `status` - Status bar widget
+//! - `cache` - Offline cache manager
 //! - `whatsnew` - What's New panel widget
 
 // Submodules for UI components
 mod articles;
+mod cache;
 mod categories;
 mod events;
 mod feeds;
+mod health;
 mod help;
 mod helpers;
+mod highlight;
+mod hyperlink;
 mod input;
 mod loop_runner;
+mod markdown;
 pub mod reader;
 mod render;
 mod stats;
@@ -35,4 +44,6 @@ mod status;
 mod whatsnew;
 
 // Re-export the public API
+pub use hyperlink::Hyperlink;
+pub use input::open_article;
 pub use loop_runner::{run, Action};
@@ -1,37 +1,64 @@
-use crate::app::{App, ContentState};
+use crate::app::{is_link_page, App, ContentState};
+use crate::storage::{Article, ArticleRevision, RevisionKind};
 use crate::theme::StyleMap;
 use crate::ui::articles::format_relative_time;
-use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use crate::ui::Hyperlink;
+use crate::util::{line_diff, resolve_link, DiffLine};
+use chrono::DateTime;
 use ratatui::{
     layout::Rect,
+    style::Style,
     text::{Line, Span, Text},
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-/// Render the article reader view
-pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
+use super::markdown::wrap_spans;
+pub use super::markdown::{render_markdown, CodeLine, LinkSpan, MarkdownLink};
+
+/// Render the article reader view.
+///
+/// Returns hyperlinks for the visible links of the article and the feed's
+/// site in the header when they're enabled.
+pub fn render(f: &mut Frame, app: &mut App, area: Rect) -> Vec<Hyperlink> {
     // EDGE-001: Guard against zero-width/height areas
     // Layout may produce zero-sized rects during extreme terminal resizes
     if area.width < 3 || area.height < 3 {
-        return;
+        return Vec::new();
     }
 
-    // Update viewport dimensions for scroll clamping (minus 2 for borders)
-    app.reader_visible_lines = area.height.saturating_sub(2) as usize;
-    app.reader_viewport_width = area.width.saturating_sub(2) as usize;
+    // Text goes in a column inside the borders, centered when narrower
+    let (column_offset, column_width) = app
+        .reader_layout
+        .column(area.width.saturating_sub(2) as usize);
+    let text_area = Rect {
+        x: area.x + 1 + column_offset as u16,
+        y: area.y + 1,
+        width: column_width as u16,
+        height: area.height.saturating_sub(2),
+    };
+
+    // Update viewport dimensions for scroll clamping
+    app.reader_visible_lines = text_area.height as usize;
+    app.reader_viewport_width = column_width;
+    rewrap_content(app);
 
     // BUG-012: Clamp scroll BEFORE rendering to prevent visual glitches on resize.
     // Previously, clamping happened after render in render.rs, which could cause
     // one frame to render with invalid scroll offset during terminal resize.
     app.clamp_reader_scroll();
+    // Laid out now, so a saved reading position can be found again
+    app.apply_reader_resume();
 
     let Some(article) = app.reader_article.as_ref() else {
         let paragraph = Paragraph::new("No article selected")
             .block(Block::default().borders(Borders::ALL).title("Reader"));
         f.render_widget(paragraph, area);
-        return;
+        return Vec::new();
     };
 
     // Build header - O(1) lookup via feed title cache (PERF-005)
@@ -42,15 +69,94 @@ pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
         .map(|s| &**s)
         .unwrap_or("Unknown Feed");
     let time_str = format_relative_time(article.published);
+    let metadata_style = app.style("reader_metadata");
+    // The feed name links to the feed's site, a linked page's URL to itself
+    let (mut metadata, site) = if is_link_page(article) {
+        // Linked pages have no feed or date; show where they came from
+        let url = article.url.as_deref().unwrap_or_default();
+        (
+            vec![
+                (Span::styled("Linked page • ", metadata_style), None),
+                (Span::styled(url.to_string(), metadata_style), Some(0)),
+            ],
+            Some(url),
+        )
+    } else {
+        let site = app
+            .feeds
+            .iter()
+            .find(|feed| feed.id == article.feed_id)
+            .and_then(|feed| feed.html_url.as_deref());
+        (
+            vec![
+                (Span::styled(feed_name.to_string(), metadata_style), Some(0)),
+                (
+                    Span::styled(format!(" • {}", time_str), metadata_style),
+                    None,
+                ),
+            ],
+            site,
+        )
+    };
+    let mut extra = String::new();
+    if let Some(minutes) = app.reading_minutes(article) {
+        extra.push_str(&format!(" • {} min read", minutes));
+    }
+    if article.archived {
+        extra.push_str(" • archived");
+    }
+    for tag in &article.tags {
+        extra.push_str(" #");
+        extra.push_str(tag);
+    }
+    metadata.push((Span::styled(extra, metadata_style), None));
 
-    let header = vec![
-        Line::from(Span::styled(&*article.title, app.style("reader_heading"))),
-        Line::from(Span::styled(
-            format!("{} • {}", feed_name, time_str),
-            app.style("reader_metadata"),
-        )),
-        Line::from(""), // Blank line
-    ];
+    // Wrapped here rather than by the widget so the header's rows are known
+    let width = app.reader_viewport_width;
+    let title = vec![(
+        Span::styled(article.title.to_string(), app.style("reader_heading")),
+        None,
+    )];
+    let (mut header, _) = wrap_spans(title, &[], &[], width);
+    let (metadata_lines, site_spans) = wrap_spans(metadata, &[], &[], width);
+    let metadata_row = header.len();
+    header.extend(metadata_lines);
+    if let Some((id, sentences)) = &app.reader_tldr {
+        if *id == article.id && !sentences.is_empty() {
+            header.push(Line::from(""));
+            header.extend(tldr_lines(
+                sentences,
+                app.reader_layout.collapse_tldr,
+                width,
+                &app.theme,
+            ));
+        }
+    }
+    header.push(Line::from("")); // Blank line
+
+    // Link positions as (text line, column, width, url)
+    let mut link_positions: Vec<(usize, usize, usize, &str)> = Vec::new();
+    if app.hyperlinks_enabled {
+        if let Some(site) = site {
+            link_positions.extend(
+                site_spans
+                    .iter()
+                    .map(|s| (metadata_row + s.line, s.column, s.width, site)),
+            );
+        }
+        if let ContentState::Loaded {
+            links, link_spans, ..
+        } = &app.content_state
+        {
+            // Spans only line up with screen rows when wrapped at this width
+            if app.reader_wrap_width == width {
+                link_positions.extend(link_spans.iter().map(|s| {
+                    let url = links[s.link].url.as_str();
+                    (header.len() + s.line, s.column, s.width, url)
+                }));
+            }
+        }
+    }
 
     // Build content based on state (PERF-003)
     // Note: ratatui's Text requires ownership of lines. We use Cow to reference cached
@@ -90,177 +196,601 @@ pub fn render(f: &mut Frame, app: &mut App, area: Rect) {
         }
     };
 
+    // Highlight search matches, the current one in its own style
+    let mut highlights: HashMap<usize, Vec<(Range<usize>, Style)>> = HashMap::new();
+    if let (Some(search), ContentState::Loaded { .. }) = (&app.reader_search, &app.content_state) {
+        let match_style = app.style("reader_search_match");
+        let current_style = app.style("reader_search_current");
+        for (i, m) in search.matches.iter().enumerate() {
+            let style = if i == search.current {
+                current_style
+            } else {
+                match_style
+            };
+            for (line, range) in &m.parts {
+                highlights
+                    .entry(*line)
+                    .or_default()
+                    .push((range.clone(), style));
+            }
+        }
+    }
+
+    // Code lines aren't wrapped but cut to the width at the sideways scroll
+    let code_lines: HashMap<usize, usize> = match &app.content_state {
+        ContentState::Loaded { code_lines, .. } => {
+            code_lines.iter().map(|c| (c.line, c.indent)).collect()
+        }
+        _ => HashMap::new(),
+    };
+    let marker_style = app.style("reader_metadata");
+
     // Build text by chaining iterators - avoids intermediate Vec allocation
-    let text = Text::from_iter(header.into_iter().chain(content_lines.iter().cloned()));
+    app.reader_header_lines = header.len();
+    let text = Text::from_iter(
+        header
+            .into_iter()
+            .chain(content_lines.iter().enumerate().map(|(i, line)| {
+                let line = match highlights.get(&i) {
+                    Some(ranges) => Cow::Owned(highlight_line(line, ranges)),
+                    None => Cow::Borrowed(line),
+                };
+                match code_lines.get(&i) {
+                    Some(&indent) => {
+                        clip_code_line(&line, indent, app.reader_code_scroll, width, marker_style)
+                    }
+                    None => line.into_owned(),
+                }
+            })),
+    );
 
     // scroll_offset is guaranteed <= u16::MAX by clamp_scroll() (see app::MAX_SCROLL)
     let paragraph = Paragraph::new(text)
-        .block(Block::default().borders(Borders::ALL).title("Article"))
         .wrap(Wrap { trim: false })
         .scroll((app.scroll_offset as u16, 0));
 
-    f.render_widget(paragraph, area);
+    let mut block = Block::default().borders(Borders::ALL).title("Article");
+    if let Some(progress) = app.reader_progress() {
+        block = block.title_top(Line::from(format!(" {}% ", progress)).right_aligned());
+    }
+    f.render_widget(block, area);
+    f.render_widget(paragraph, text_area);
+
+    // Relative links resolve against the article, as in the link picker
+    let base = article.url.as_deref();
+    let scroll = app.scroll_offset;
+    link_positions
+        .into_iter()
+        .filter(|&(line, ..)| line >= scroll && line - scroll < app.reader_visible_lines)
+        .filter_map(|(line, column, link_width, url)| {
+            let url = resolve_link(base, url)?;
+            Hyperlink::new(
+                text_area.x + column as u16,
+                text_area.y + (line - scroll) as u16,
+                link_width.min(width.saturating_sub(column)) as u16,
+                &url,
+            )
+        })
+        .collect()
+}
+
+/// The TL;DR box under the article's header: a bulleted list of its key
+/// sentences, or a single line saying how many there are when collapsed.
+fn tldr_lines(
+    sentences: &[String],
+    collapsed: bool,
+    width: usize,
+    styles: &StyleMap,
+) -> Vec<Line<'static>> {
+    if collapsed {
+        return vec![Line::from(Span::styled(
+            format!("▸ TL;DR · {} key sentences (z to expand)", sentences.len()),
+            styles.resolve("reader_metadata"),
+        ))];
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        "▾ TL;DR",
+        styles.resolve("reader_strong"),
+    ))];
+    let bar = Span::styled("│ ", styles.resolve("reader_quote"));
+    let first = [
+        bar.clone(),
+        Span::styled("• ", styles.resolve("reader_list_marker")),
+    ];
+    let rest = [bar, Span::raw("  ")];
+    for sentence in sentences {
+        let text = vec![(
+            Span::styled(sentence.clone(), styles.resolve("reader_body")),
+            None,
+        )];
+        lines.extend(wrap_spans(text, &first, &rest, width).0);
+    }
+    lines
+}
+
+/// A match of the in-article search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Rendered line and byte range in that line's text, for each line the
+    /// match covers (more than one when it runs across a wrap).
+    pub parts: Vec<(usize, Range<usize>)>,
 }
 
-/// Convert markdown to styled ratatui Lines.
-/// Returns owned Lines for caching (PERF-004).
+impl SearchMatch {
+    /// First rendered line of the match.
+    pub fn line(&self) -> usize {
+        self.parts.first().map_or(0, |(line, _)| *line)
+    }
+}
+
+/// Find `query` in rendered lines, in reading order.
 ///
-/// Accepts a `StyleMap` to resolve semantic roles for markdown elements.
-pub fn render_markdown(md: &str, styles: &StyleMap) -> Vec<Line<'static>> {
-    let parser = Parser::new(md);
-    // Estimate: markdown lines roughly map to output lines
-    let mut lines: Vec<Line<'static>> = Vec::with_capacity(md.lines().count());
-    // Most lines have few styled spans (text, emphasis, code, etc.)
-    let mut current_spans: Vec<Span<'static>> = Vec::with_capacity(4);
-    let mut in_code_block = false;
-    let mut in_heading = false;
-    let mut in_emphasis = false;
-    let mut in_strong = false;
-
-    for event in parser {
-        match event {
-            Event::Start(Tag::Heading { .. }) => {
-                in_heading = true;
+/// Case-insensitive (with Unicode case folding) unless the query has an
+/// uppercase letter. Lines are searched as if joined by spaces, without their
+/// indentation and quote bars, and any run of whitespace matches any other,
+/// so phrases are found across wrapped lines.
+pub fn find_matches(lines: &[Line<'_>], query: &str) -> Vec<SearchMatch> {
+    if query.trim().is_empty() {
+        return Vec::new();
+    }
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    // Folded chars with where each came from: a line and byte range, or
+    // `None` for the spaces between lines
+    type Source = Option<(usize, usize, usize)>;
+    let push = |c: char, source: Source, text: &mut Vec<char>, origin: &mut Vec<Source>| {
+        if c.is_whitespace() {
+            if text.last() != Some(&' ') {
+                text.push(' ');
+                origin.push(source);
             }
-            Event::End(TagEnd::Heading(_)) => {
-                if !current_spans.is_empty() {
-                    lines.push(Line::from(std::mem::take(&mut current_spans)));
-                }
-                in_heading = false;
+        } else if case_sensitive {
+            text.push(c);
+            origin.push(source);
+        } else {
+            for lower in c.to_lowercase() {
+                text.push(lower);
+                origin.push(source);
             }
-            Event::Start(Tag::Paragraph) => {}
-            Event::End(TagEnd::Paragraph) => {
-                if !current_spans.is_empty() {
-                    lines.push(Line::from(std::mem::take(&mut current_spans)));
+        }
+    };
+
+    let (mut needle, mut needle_origin) = (Vec::new(), Vec::new());
+    for c in query.chars() {
+        push(c, None, &mut needle, &mut needle_origin);
+    }
+
+    let mut text: Vec<char> = Vec::new();
+    let mut origin: Vec<Source> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            push(' ', None, &mut text, &mut origin);
+        }
+        let mut offset = 0;
+        let mut indent = true;
+        for span in &line.spans {
+            for (at, c) in span.content.char_indices() {
+                indent = indent && (c.is_whitespace() || c == '│');
+                if !indent {
+                    let start = offset + at;
+                    push(
+                        c,
+                        Some((i, start, start + c.len_utf8())),
+                        &mut text,
+                        &mut origin,
+                    );
                 }
-                lines.push(Line::from("")); // Blank line after paragraph
-            }
-            Event::Start(Tag::CodeBlock(_)) => {
-                in_code_block = true;
-            }
-            Event::End(TagEnd::CodeBlock) => {
-                in_code_block = false;
-                lines.push(Line::from(""));
-            }
-            Event::Start(Tag::Emphasis) => {
-                in_emphasis = true;
-            }
-            Event::End(TagEnd::Emphasis) => {
-                in_emphasis = false;
-            }
-            Event::Start(Tag::Strong) => {
-                in_strong = true;
             }
-            Event::End(TagEnd::Strong) => {
-                in_strong = false;
+            offset += span.content.len();
+        }
+    }
+
+    let mut matches = Vec::new();
+    let mut at = 0;
+    while at + needle.len() <= text.len() {
+        if text[at..at + needle.len()] != needle[..] {
+            at += 1;
+            continue;
+        }
+        let mut parts: Vec<(usize, Range<usize>)> = Vec::new();
+        for &(line, start, end) in origin[at..at + needle.len()].iter().flatten() {
+            match parts.last_mut() {
+                Some((last, range)) if *last == line => range.end = range.end.max(end),
+                _ => parts.push((line, start..end)),
             }
-            Event::Start(Tag::Link { .. }) => {
-                // Links will show URL after text
+        }
+        if !parts.is_empty() {
+            matches.push(SearchMatch { parts });
+        }
+        at += needle.len();
+    }
+    matches
+}
+
+/// Restyle the byte `ranges` of a line, each with its style patched on top.
+pub fn highlight_line(line: &Line<'static>, ranges: &[(Range<usize>, Style)]) -> Line<'static> {
+    let mut spans = Vec::with_capacity(line.spans.len() + ranges.len() * 2);
+    let mut offset = 0;
+    for span in &line.spans {
+        let end = offset + span.content.len();
+        let mut at = offset;
+        for (range, style) in ranges {
+            let (start, stop) = (range.start.max(at), range.end.min(end));
+            if start >= stop {
+                continue;
             }
-            Event::End(TagEnd::Link) => {}
-            Event::Start(Tag::Image { dest_url, .. }) => {
-                current_spans.push(Span::styled(
-                    format!("[Image: {}]", dest_url),
-                    styles.resolve("reader_image"),
-                ));
+            if start > at {
+                let text = &span.content[at - offset..start - offset];
+                spans.push(Span::styled(text.to_string(), span.style));
             }
-            Event::Text(text) => {
-                let style = if in_code_block {
-                    styles.resolve("reader_code_block")
-                } else if in_heading {
-                    styles.resolve("reader_heading")
-                } else if in_strong {
-                    styles.resolve("reader_strong")
-                } else if in_emphasis {
-                    styles.resolve("reader_emphasis")
-                } else {
-                    styles.resolve("reader_body")
-                };
-                // PERF-011: CowStr::into_string() is O(1) for Boxed variant (no allocation),
-                // vs .to_string() which always allocates
-                current_spans.push(Span::styled(text.into_string(), style));
+            let text = &span.content[start - offset..stop - offset];
+            spans.push(Span::styled(text.to_string(), span.style.patch(*style)));
+            at = stop;
+        }
+        if at == offset {
+            spans.push(span.clone());
+        } else if at < end {
+            spans.push(Span::styled(
+                span.content[at - offset..].to_string(),
+                span.style,
+            ));
+        }
+        offset = end;
+    }
+    Line::from(spans).style(line.style)
+}
+
+/// Cut a code line to `width` columns, scrolled `offset` columns past its
+/// `indent` (quote bars, list indentation), which stays in place.
+///
+/// `‹` and `›` take the place of the first and last column when code is
+/// hidden on that side.
+pub fn clip_code_line(
+    line: &Line<'static>,
+    indent: usize,
+    offset: usize,
+    width: usize,
+    marker_style: Style,
+) -> Line<'static> {
+    let line_width: usize = line.spans.iter().map(|s| s.content.width()).sum();
+    if offset == 0 && line_width <= width {
+        return line.clone();
+    }
+    let start = indent + offset;
+    let end = start + width.saturating_sub(indent);
+    let left_marker = offset > 0;
+    let right_marker = end < line_width;
+
+    let mut spans: Vec<Span<'static>> = Vec::with_capacity(line.spans.len() + 2);
+    let mut column = 0;
+    for span in &line.spans {
+        let mut text = String::new();
+        for c in span.content.chars() {
+            let w = c.width().unwrap_or(0);
+            let at = column;
+            column += w;
+            if at >= indent && (column <= start || at >= end) {
+                continue;
             }
-            Event::Code(code) => {
-                current_spans.push(Span::styled(
-                    format!("`{}`", code),
-                    styles.resolve("reader_inline_code"),
+            if at >= indent && left_marker && at <= start {
+                // The marker takes the first column, blanks the rest of a
+                // wide char cut by the edge
+                spans.push(Span::styled(std::mem::take(&mut text), span.style));
+                spans.push(Span::styled("‹", marker_style));
+                text.extend(std::iter::repeat_n(
+                    ' ',
+                    (column.min(end) - start).saturating_sub(1),
                 ));
+                continue;
             }
-            Event::SoftBreak => {
-                current_spans.push(Span::raw(" "));
-            }
-            Event::HardBreak => {
-                if !current_spans.is_empty() {
-                    lines.push(Line::from(std::mem::take(&mut current_spans)));
-                }
+            if at >= indent && right_marker && column >= end {
+                text.extend(std::iter::repeat_n(' ', end - 1 - at));
+                spans.push(Span::styled(std::mem::take(&mut text), span.style));
+                spans.push(Span::styled("›", marker_style));
+                continue;
             }
-            _ => {}
+            text.push(c);
+        }
+        if !text.is_empty() {
+            spans.push(Span::styled(text, span.style));
         }
     }
+    spans.retain(|s| !s.content.is_empty());
+    Line::from(spans).style(line.style)
+}
 
-    // Flush remaining spans
-    if !current_spans.is_empty() {
-        lines.push(Line::from(current_spans));
+/// Re-render loaded article content when the viewport width has changed
+/// since it was wrapped, so hanging indents and tables fit again.
+///
+/// The revision diff (empty `content`) is left to the widget's wrapping.
+fn rewrap_content(app: &mut App) {
+    let width = app.reader_viewport_width;
+    if width == app.reader_wrap_width {
+        return;
+    }
+    if let ContentState::Loaded {
+        content,
+        rendered_lines,
+        links,
+        link_spans,
+        code_lines,
+        ..
+    } = &mut app.content_state
+    {
+        if content.is_empty() {
+            return;
+        }
+        let rendered = render_markdown(content, &app.theme, width, app.reader_layout.justify);
+        *rendered_lines = rendered.lines;
+        *links = rendered.links;
+        *link_spans = rendered.link_spans;
+        *code_lines = rendered.code_lines;
+        app.reader_wrap_width = width;
+        app.reader_cached_line_count = None;
+        // Matches point into the old lines
+        app.refresh_reader_search();
+    }
+}
+
+/// Unchanged lines shown around each change in the revision diff.
+const DIFF_CONTEXT_LINES: usize = 2;
+
+/// Render the changes between each revision of an article and the version that replaced it.
+///
+/// `revisions` are newest first. Each one is compared with the next newer
+/// revision of the same kind, or with the current article (`current_content`
+/// for content revisions). Unchanged stretches are collapsed to a few lines
+/// of context.
+pub fn render_revision_diff(
+    article: &Article,
+    revisions: &[ArticleRevision],
+    current_content: Option<&str>,
+    styles: &StyleMap,
+) -> Vec<Line<'static>> {
+    let current_metadata = metadata_text(
+        &article.title,
+        article.url.as_deref(),
+        article.published,
+        article.summary.as_deref(),
+    );
+    let mut lines = Vec::new();
+
+    for (i, revision) in revisions.iter().enumerate() {
+        let newer = revisions[..i]
+            .iter()
+            .rev()
+            .find(|r| r.kind == revision.kind);
+        let (old, new) = match revision.kind {
+            RevisionKind::Metadata => (
+                revision_metadata_text(revision),
+                newer.map_or_else(|| current_metadata.clone(), revision_metadata_text),
+            ),
+            RevisionKind::Content => (
+                revision.content.clone().unwrap_or_default(),
+                newer
+                    .and_then(|r| r.content.clone())
+                    .or_else(|| current_content.map(str::to_owned))
+                    .unwrap_or_default(),
+            ),
+        };
+
+        let what = match revision.kind {
+            RevisionKind::Metadata => "feed entry",
+            RevisionKind::Content => "content",
+        };
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Changed {} • {}",
+                what,
+                format_relative_time(Some(revision.replaced_at))
+            ),
+            styles.resolve("reader_heading"),
+        )));
+        lines.push(Line::from(""));
+        push_diff_hunks(&mut lines, &line_diff(&old, &new), styles);
+        lines.push(Line::from(""));
     }
 
     lines
 }
 
+/// Append `diff` as prefixed lines, collapsing unchanged runs beyond the context.
+fn push_diff_hunks(lines: &mut Vec<Line<'static>>, diff: &[DiffLine<'_>], styles: &StyleMap) {
+    let changed: Vec<usize> = diff
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| !matches!(d, DiffLine::Same(_)))
+        .map(|(i, _)| i)
+        .collect();
+    if changed.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No visible changes",
+            styles.resolve("reader_metadata"),
+        )));
+        return;
+    }
+
+    let near_change = |i: usize| {
+        let pos = changed.partition_point(|&c| c + DIFF_CONTEXT_LINES < i);
+        changed
+            .get(pos)
+            .is_some_and(|&c| c <= i + DIFF_CONTEXT_LINES)
+    };
+    let mut skipped = false;
+    for (i, line) in diff.iter().enumerate() {
+        let (prefix, text, role) = match line {
+            DiffLine::Same(t) => ("  ", t, "reader_body"),
+            DiffLine::Removed(t) => ("- ", t, "reader_diff_removed"),
+            DiffLine::Added(t) => ("+ ", t, "reader_diff_added"),
+        };
+        if matches!(line, DiffLine::Same(_)) && !near_change(i) {
+            skipped = true;
+            continue;
+        }
+        if skipped {
+            lines.push(Line::from(Span::styled(
+                "  ⋯",
+                styles.resolve("reader_metadata"),
+            )));
+            skipped = false;
+        }
+        lines.push(Line::from(Span::styled(
+            format!("{}{}", prefix, text),
+            styles.resolve(role),
+        )));
+    }
+    if skipped {
+        lines.push(Line::from(Span::styled(
+            "  ⋯",
+            styles.resolve("reader_metadata"),
+        )));
+    }
+}
+
+fn revision_metadata_text(revision: &ArticleRevision) -> String {
+    metadata_text(
+        revision.title.as_deref().unwrap_or(""),
+        revision.url.as_deref(),
+        revision.published,
+        revision.summary.as_deref(),
+    )
+}
+
+/// Feed entry fields laid out one per line so a line diff shows which one changed.
+fn metadata_text(
+    title: &str,
+    url: Option<&str>,
+    published: Option<i64>,
+    summary: Option<&str>,
+) -> String {
+    let published = published
+        .and_then(|ts| DateTime::from_timestamp(ts, 0))
+        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_default();
+    format!(
+        "Title: {}\nURL: {}\nPublished: {}\n\n{}",
+        title,
+        url.unwrap_or(""),
+        published,
+        summary.unwrap_or("")
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::theme::ThemeVariant;
+    use ratatui::style::Color;
 
-    fn test_styles() -> StyleMap {
-        StyleMap::from_palette(&ThemeVariant::Dark.palette())
+    fn lines(text: &[&str]) -> Vec<Line<'static>> {
+        text.iter().map(|t| Line::from(t.to_string())).collect()
     }
 
     #[test]
-    fn test_render_plain_text() {
-        let lines = render_markdown("Hello world", &test_styles());
-        assert!(!lines.is_empty());
-    }
+    fn test_tldr_lines() {
+        let styles = StyleMap::from_palette(&crate::theme::ThemeVariant::Dark.palette());
+        let sentences = vec![
+            "The first key sentence is long enough to wrap.".to_string(),
+            "Short one.".to_string(),
+        ];
+        let text =
+            |lines: &[Line<'_>]| -> Vec<String> { lines.iter().map(|l| l.to_string()).collect() };
 
-    #[test]
-    fn test_render_heading() {
-        let lines = render_markdown("# Heading 1\n\n## Heading 2", &test_styles());
-        assert!(!lines.is_empty());
+        assert_eq!(
+            text(&tldr_lines(&sentences, false, 30, &styles)),
+            vec![
+                "▾ TL;DR",
+                "│ • The first key sentence is",
+                "│   long enough to wrap.",
+                "│ • Short one.",
+            ]
+        );
+        assert_eq!(
+            text(&tldr_lines(&sentences, true, 30, &styles)),
+            vec!["▸ TL;DR · 2 key sentences (z to expand)"]
+        );
     }
 
     #[test]
-    fn test_render_bold() {
-        let lines = render_markdown("This is **bold** text", &test_styles());
-        assert!(!lines.is_empty());
+    fn test_find_matches_unicode_case() {
+        let text = lines(&["Une ÉCOLE, une école", "Straße"]);
+        let found = find_matches(&text, "école");
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].parts, [(0, 4..10)]);
+        assert_eq!(found[1].parts, [(0, 16..22)]);
+        assert_eq!(find_matches(&text, "STRASSE"), []);
+        assert_eq!(find_matches(&text, "straße").len(), 1);
+        // An uppercase letter makes the search case-sensitive
+        assert_eq!(find_matches(&text, "École"), []);
+        assert_eq!(find_matches(&text, "  "), []);
     }
 
     #[test]
-    fn test_render_italic() {
-        let lines = render_markdown("This is *italic* text", &test_styles());
-        assert!(!lines.is_empty());
+    fn test_find_matches_across_wrap() {
+        let text = lines(&["the quick", "brown fox", "quick"]);
+        let found = find_matches(&text, "quick brown");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].parts, [(0, 4..9), (1, 0..5)]);
+        assert_eq!(found[0].line(), 0);
+        assert_eq!(find_matches(&text, "QUICK").len(), 0);
+        assert_eq!(find_matches(&text, "quick").len(), 2);
     }
 
     #[test]
-    fn test_render_code_block() {
-        let lines = render_markdown("```\ncode block\n```", &test_styles());
-        assert!(!lines.is_empty());
+    fn test_find_matches_after_rewrap() {
+        let styles = StyleMap::from_palette(&crate::theme::ThemeVariant::Dark.palette());
+        let md = "> Reading the *long article* again, the long article \
+                  reads like a long article.";
+        for width in [12, 20, 80] {
+            let rendered = render_markdown(md, &styles, width, false);
+            let found = find_matches(&rendered.lines, "long article");
+            assert_eq!(found.len(), 3, "width {}", width);
+        }
     }
 
     #[test]
-    fn test_render_link() {
-        let lines = render_markdown("[link text](https://example.com)", &test_styles());
-        assert!(!lines.is_empty());
+    fn test_highlight_line_splits_spans() {
+        let bold = Style::default().fg(Color::Red);
+        let mark = Style::default().bg(Color::Yellow);
+        let line = Line::from(vec![Span::raw("ab"), Span::styled("cdef", bold)]);
+        let highlighted = highlight_line(&line, &[(1..3, mark), (5..6, mark)]);
+        let parts: Vec<(&str, Style)> = highlighted
+            .spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style))
+            .collect();
+        assert_eq!(
+            parts,
+            [
+                ("a", Style::default()),
+                ("b", mark),
+                ("c", bold.patch(mark)),
+                ("de", bold),
+                ("f", bold.patch(mark)),
+            ]
+        );
     }
 
     #[test]
-    fn test_render_empty() {
-        let lines = render_markdown("", &test_styles());
-        // Should not panic - empty input is valid
-        assert!(lines.is_empty());
-    }
+    fn test_clip_code_line_scrolls_past_indent() {
+        let marker = Style::default().fg(Color::Red);
+        let text = |line: Line<'static>| -> String {
+            line.spans.iter().map(|s| s.content.as_ref()).collect()
+        };
+        let line = Line::from(vec![Span::raw("│ "), Span::raw("0123456789")]);
+        // Fits: unchanged
+        assert_eq!(
+            text(clip_code_line(&line, 2, 0, 12, marker)),
+            "│ 0123456789"
+        );
+        assert_eq!(text(clip_code_line(&line, 2, 0, 8, marker)), "│ 01234›");
+        assert_eq!(text(clip_code_line(&line, 2, 3, 8, marker)), "│ ‹4567›");
+        assert_eq!(text(clip_code_line(&line, 2, 4, 8, marker)), "│ ‹56789");
+        let clipped = clip_code_line(&line, 2, 4, 8, marker);
+        assert_eq!(clipped.spans[1].style, marker);
 
-    #[test]
-    fn test_render_unicode() {
-        let lines = render_markdown("Hello 世界 🌍", &test_styles());
-        assert!(!lines.is_empty());
+        // A wide char cut by the edge leaves a blank
+        let wide = Line::from("ab世界cd");
+        assert_eq!(text(clip_code_line(&wide, 0, 1, 4, marker)), "‹世›");
+        assert_eq!(text(clip_code_line(&wide, 0, 2, 4, marker)), "‹  ›");
+        assert_eq!(text(clip_code_line(&wide, 0, 4, 4, marker)), "‹ cd");
     }
 }
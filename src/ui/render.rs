@@ -4,7 +4,8 @@
 //! view based on application state.
 
 use crate::app::{
-    App, ConfirmAction, ContextMenuSubState, SubscribeState, View, CONTEXT_MENU_ITEMS,
+    App, ConfirmAction, ContextMenuSubState, LinkPickerState, SubscribeState, TagPickerState, View,
+    CONTEXT_MENU_ITEMS,
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -12,7 +13,9 @@ use ratatui::{
     Frame,
 };
 
-use super::{articles, categories, feeds, help, reader, stats, status, whatsnew};
+use super::{
+    articles, cache, categories, feeds, health, help, reader, stats, status, whatsnew, Hyperlink,
+};
 
 /// Minimum terminal dimensions required for normal operation.
 pub(super) const MIN_WIDTH: u16 = 60;
@@ -24,6 +27,7 @@ pub(super) const MIN_HEIGHT: u16 = 10;
 /// Handles terminal size validation before rendering.
 pub(super) fn render(f: &mut Frame, app: &mut App) {
     let area = f.area();
+    app.hyperlinks.clear();
 
     // EDGE-001: Guard against zero-width/height to prevent panics
     // At truly minimal dimensions, we can't render anything meaningful
@@ -47,10 +51,33 @@ pub(super) fn render(f: &mut Frame, app: &mut App) {
         return;
     }
 
-    match app.view {
+    let hyperlinks = match app.view {
         View::Browse => render_browse(f, app),
         View::Reader => render_reader(f, app),
-        View::Stats => stats::render(f, app),
+        View::Stats => {
+            stats::render(f, app);
+            Vec::new()
+        }
+        View::Health => {
+            health::render(f, app);
+            Vec::new()
+        }
+        View::Cache => {
+            cache::render(f, app);
+            Vec::new()
+        }
+    };
+
+    // Overlays may cover any of the links, so only keep them without one
+    let overlay = app.show_help
+        || app.pending_confirm.is_some()
+        || app.subscribe_state.is_some()
+        || app.save_url_input.is_some()
+        || app.context_menu.is_some()
+        || app.tag_picker.is_some()
+        || app.link_picker.is_some();
+    if !overlay {
+        app.hyperlinks = hyperlinks;
     }
 
     // Render help overlay on top of any view when active
@@ -68,14 +95,29 @@ pub(super) fn render(f: &mut Frame, app: &mut App) {
         render_subscribe_overlay(f, app, state);
     }
 
+    // Render save URL dialog on top of any view when active
+    if let Some(ref input) = app.save_url_input {
+        render_save_url_overlay(f, app, input);
+    }
+
     // Render context menu on top of any view when active
     if app.context_menu.is_some() {
         render_context_menu_overlay(f, app);
     }
+
+    // Render tag picker on top of the article list or reader when active
+    if let Some(ref picker) = app.tag_picker {
+        render_tag_picker_overlay(f, app, picker);
+    }
+
+    // Render link picker on top of the reader when active
+    if let Some(ref picker) = app.link_picker {
+        render_link_picker_overlay(f, app, picker);
+    }
 }
 
-/// Render the browse view (feeds + articles panels).
-fn render_browse(f: &mut Frame, app: &App) {
+/// Render the browse view (feeds + articles panels), returning its hyperlinks.
+fn render_browse(f: &mut Frame, app: &App) -> Vec<Hyperlink> {
     // Layout depends on whether What's New panel is visible
     if app.show_whats_new && !app.whats_new.is_empty() {
         // Three rows: What's New (dynamic height), main panels, status bar
@@ -92,8 +134,8 @@ fn render_browse(f: &mut Frame, app: &App) {
             .split(f.area());
 
         whatsnew::render(f, app, chunks[0]);
-        render_main_panels(f, app, chunks[1]);
         status::render(f, app, chunks[2]);
+        render_main_panels(f, app, chunks[1])
     } else {
         // Normal two-row layout
         let chunks = Layout::default()
@@ -101,8 +143,8 @@ fn render_browse(f: &mut Frame, app: &App) {
             .constraints([Constraint::Min(0), Constraint::Length(1)])
             .split(f.area());
 
-        render_main_panels(f, app, chunks[0]);
         status::render(f, app, chunks[1]);
+        render_main_panels(f, app, chunks[0])
     }
 }
 
@@ -110,7 +152,7 @@ fn render_browse(f: &mut Frame, app: &App) {
 ///
 /// When `show_categories` is true, the left side splits into categories (20%) and feeds (80%).
 /// Otherwise, feeds take the full left panel width.
-fn render_main_panels(f: &mut Frame, app: &App, area: Rect) {
+fn render_main_panels(f: &mut Frame, app: &App, area: Rect) -> Vec<Hyperlink> {
     if app.show_categories {
         // Three-column layout: categories | feeds | articles
         let main_chunks = Layout::default()
@@ -124,7 +166,7 @@ fn render_main_panels(f: &mut Frame, app: &App, area: Rect) {
 
         categories::render(f, app, main_chunks[0]);
         feeds::render(f, app, main_chunks[1]);
-        articles::render(f, app, main_chunks[2]);
+        articles::render(f, app, main_chunks[2])
     } else {
         // Two-column layout: feeds | articles
         let main_chunks = Layout::default()
@@ -133,7 +175,7 @@ fn render_main_panels(f: &mut Frame, app: &App, area: Rect) {
             .split(area);
 
         feeds::render(f, app, main_chunks[0]);
-        articles::render(f, app, main_chunks[1]);
+        articles::render(f, app, main_chunks[1])
     }
 }
 
@@ -228,6 +270,38 @@ fn render_subscribe_overlay(f: &mut Frame, app: &App, state: &SubscribeState) {
     f.render_widget(paragraph, overlay);
 }
 
+/// Render the save URL dialog overlay centered on screen.
+fn render_save_url_overlay(f: &mut Frame, app: &App, input: &str) {
+    let area = f.area();
+    let text = format!(
+        "Save a web page to the Saved feed:\n\n> {}_\n\n(Enter) Save  (Esc) Cancel",
+        input
+    );
+
+    let width = 60u16.min(area.width.saturating_sub(4));
+    let height = 8u16.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let overlay = Rect::new(x, y, width, height);
+
+    if overlay.width < 20 || overlay.height < 6 {
+        return;
+    }
+
+    f.render_widget(Clear, overlay);
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.style("panel_border_focused"))
+                .title(" Save URL "),
+        )
+        .style(app.style("reader_body"));
+
+    f.render_widget(paragraph, overlay);
+}
+
 /// Render the context menu overlay centered on screen.
 ///
 /// Shows the main menu items, rename input, or category picker depending
@@ -309,13 +383,152 @@ fn render_context_menu_overlay(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, overlay);
 }
 
+/// Render the tag picker overlay centered on screen.
+///
+/// Lists the tags matching the input with a check mark on the article's own,
+/// followed by the typed name when it would create a new tag.
+fn render_tag_picker_overlay(f: &mut Frame, app: &App, picker: &TagPickerState) {
+    let area = f.area();
+
+    let options = picker.options(&app.tags);
+    let mut items: Vec<String> = options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            let cursor = if i == picker.selected { ">" } else { " " };
+            if option.new {
+                format!("{} + #{} (new)", cursor, option.tag)
+            } else {
+                let check = if option.checked { "x" } else { " " };
+                format!("{} [{}] #{}", cursor, check, option.tag)
+            }
+        })
+        .collect();
+    if items.is_empty() {
+        items.push("  No matching tags".to_string());
+    }
+
+    // Keep the highlighted row visible in long tag lists
+    let max_rows = (area.height.saturating_sub(12) as usize).max(1);
+    let skip = picker.selected.saturating_sub(max_rows - 1);
+    let text = format!(
+        "Filter: {}_\n\n{}\n\n(Enter) Toggle  (Esc) Close",
+        picker.input,
+        items
+            .iter()
+            .skip(skip)
+            .take(max_rows)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+    let title = format!(
+        " Tags: {} ",
+        crate::util::truncate_to_width(&picker.article_title, 30)
+    );
+
+    let content_lines = text.lines().count() as u16 + 2; // +2 for borders
+    let width = 45u16.min(area.width.saturating_sub(4));
+    let height = content_lines.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let overlay = Rect::new(x, y, width, height);
+
+    if overlay.width < 20 || overlay.height < 5 {
+        return;
+    }
+
+    f.render_widget(Clear, overlay);
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.style("panel_border_focused"))
+                .title(title),
+        )
+        .style(app.style("reader_body"));
+
+    f.render_widget(paragraph, overlay);
+}
+
+/// Render the link picker overlay centered on screen.
+///
+/// Lists the article's numbered links with their text and destination.
+fn render_link_picker_overlay(f: &mut Frame, app: &App, picker: &LinkPickerState) {
+    let area = f.area();
+    let links = app.reader_links();
+
+    let width = 70u16.min(area.width.saturating_sub(4));
+    let digits = links.len().to_string().len();
+    // "> [n] " before each row, 2 for borders
+    let text_width = (width as usize).saturating_sub(digits + 7);
+    let items: Vec<String> = links
+        .iter()
+        .enumerate()
+        .map(|(i, link)| {
+            let cursor = if i == picker.selected { ">" } else { " " };
+            let label = if link.text.is_empty() || link.text == link.url {
+                link.url.clone()
+            } else {
+                format!("{} — {}", link.text, link.url)
+            };
+            format!(
+                "{} [{:>digits$}] {}",
+                cursor,
+                i + 1,
+                crate::util::truncate_to_width(&label, text_width)
+            )
+        })
+        .collect();
+
+    // Keep the highlighted row visible in long link lists
+    let max_rows = (area.height.saturating_sub(12) as usize).max(1);
+    let skip = picker.selected.saturating_sub(max_rows - 1);
+    let text = format!(
+        "Link: {}_\n\n{}\n\n(Enter) Read  (o) Browser  (Esc) Close",
+        picker.input,
+        items
+            .iter()
+            .skip(skip)
+            .take(max_rows)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+
+    let content_lines = text.lines().count() as u16 + 2; // +2 for borders
+    let height = content_lines.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+    let overlay = Rect::new(x, y, width, height);
+
+    if overlay.width < 20 || overlay.height < 5 {
+        return;
+    }
+
+    f.render_widget(Clear, overlay);
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.style("panel_border_focused"))
+                .title(format!(" Links ({}) ", links.len())),
+        )
+        .style(app.style("reader_body"));
+
+    f.render_widget(paragraph, overlay);
+}
+
 /// Render the reader view (article content + status bar).
-fn render_reader(f: &mut Frame, app: &mut App) {
+fn render_reader(f: &mut Frame, app: &mut App) -> Vec<Hyperlink> {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(0), Constraint::Length(1)])
         .split(f.area());
 
-    reader::render(f, app, chunks[0]);
+    let hyperlinks = reader::render(f, app, chunks[0]);
     status::render(f, app, chunks[1]);
+    hyperlinks
 }
@@ -99,6 +99,14 @@ pub(super) fn render(f: &mut Frame, app: &App) {
                     Span::styled("  This month: ", app.style("feed_title")),
                     Span::raw(format_stats_line(&data.month)),
                 ]),
+                Line::from(vec![
+                    Span::styled("  Offline:    ", app.style("feed_title")),
+                    Span::raw(format_cache_line(&data.cache)),
+                ]),
+                Line::from(vec![
+                    Span::styled("  Speed:      ", app.style("feed_title")),
+                    Span::raw(format_speed_line(data.measured_speed)),
+                ]),
             ];
             let summary = Paragraph::new(summary_lines).style(app.style("reader_body"));
             f.render_widget(summary, sections[0]);
@@ -149,5 +157,35 @@ fn format_stats_line(stats: &crate::storage::ReadingStats) -> String {
         format!("{}m", stats.total_minutes)
     };
 
-    format!("{} articles, {} reading time", articles, time_str)
+    if stats.words_per_day == 0 {
+        return format!("{} articles, {} reading time", articles, time_str);
+    }
+    format!(
+        "{} articles, {} reading time, {} words/day",
+        articles, time_str, stats.words_per_day
+    )
+}
+
+/// Format the reading speed line, measured or the default estimate.
+fn format_speed_line(measured: Option<usize>) -> String {
+    match measured {
+        Some(wpm) => format!("{} words/min (measured)", wpm),
+        None => format!(
+            "{} words/min (default, not enough history yet)",
+            crate::util::WORDS_PER_MINUTE
+        ),
+    }
+}
+
+/// Format the offline storage line: "X articles, Y on disk (Z kept permanently)"
+fn format_cache_line(cache: &crate::storage::CacheStats) -> String {
+    if cache.total_entries == 0 {
+        return "nothing cached".to_string();
+    }
+    format!(
+        "{} articles, {} on disk ({} kept permanently)",
+        cache.total_entries,
+        super::cache::format_size(cache.stored_size_bytes),
+        cache.pinned_entries
+    )
 }
@@ -12,7 +12,15 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     }
 
     // Use Cow to avoid allocations for static strings and borrowed status messages
-    let text: Cow<'_, str> = if let Some((done, total)) = app.refresh_progress {
+    let reader_search = app
+        .reader_search
+        .as_ref()
+        .filter(|_| app.view == View::Reader);
+    let text: Cow<'_, str> = if let Some(search) = reader_search.filter(|s| s.editing) {
+        // The prompt stays visible over progress and status messages while typing
+        let count = app.reader_search_status().unwrap_or_default();
+        Cow::Owned(format!("/{}\u{258E}  {}", search.query, count))
+    } else if let Some((done, total)) = app.refresh_progress {
         // Dynamic content requires allocation
         Cow::Owned(format!("Refreshing... {}/{} feeds", done, total))
     } else if let Some((completed, total)) = app.prefetch_progress {
@@ -46,8 +54,26 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                     )
                 }
             }
-            View::Reader => Cow::Borrowed("[b]ack [j/k]scroll [Ctrl+d/u]page [s]tar [o]pen [q]uit"),
+            View::Reader => match app.reader_search_status() {
+                Some(count) => Cow::Owned(format!(
+                    "{}  [n/N]next/prev [/]new search [Esc]clear",
+                    count
+                )),
+                None => match app.reader_page() {
+                    Some((page, pages)) => Cow::Owned(format!(
+                        "page {}/{}  [Space/j]next [k]previous [p]scroll mode [b]ack [q]uit",
+                        page, pages
+                    )),
+                    None => Cow::Borrowed(
+                        "[b]ack [j/k]scroll [Ctrl+d/u]page [</>/.]article [s]tar [o]pen [u]pdates [f]links [/]find [[/]]history [q]uit",
+                    ),
+                },
+            },
             View::Stats => Cow::Borrowed("[Esc]close [q]uit"),
+            View::Health => {
+                Cow::Borrowed("[j/k]select [R]etry [d]elete [O]pen site [F]rediscover [Esc]close")
+            }
+            View::Cache => Cow::Borrowed("[j/k]select [d/D/x]purge [Esc]close"),
         }
     };
 
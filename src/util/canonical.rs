@@ -0,0 +1,213 @@
+//! Article URL canonicalization and duplicate-detection keys.
+//!
+//! The same story often reaches several feeds (the original blog, an
+//! aggregator, a "planet" feed) with different tracking parameters or
+//! scheme/host spellings. These helpers reduce such variants to one form.
+
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+use url::Url;
+
+/// Query parameters that only track the click, never select content.
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid",
+    "gclid",
+    "dclid",
+    "msclkid",
+    "yclid",
+    "igshid",
+    "mc_cid",
+    "mc_eid",
+    "_hsenc",
+    "_hsmi",
+    "mkt_tok",
+    "ref_src",
+    "ref_url",
+    "cmpid",
+    "s_cid",
+    "guccounter",
+];
+
+/// Query parameter prefixes used by campaign trackers (`utm_source`, `pk_campaign`, ...).
+const TRACKING_PREFIXES: &[&str] = &["utm_", "pk_", "mtm_", "hmb_", "oly_"];
+
+/// Normalized titles shorter than this are too generic ("Weekly links") to match on.
+const MIN_TITLE_KEY_LEN: usize = 20;
+
+fn is_tracking_param(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    TRACKING_PARAMS.contains(&name.as_str())
+        || TRACKING_PREFIXES.iter().any(|p| name.starts_with(p))
+}
+
+/// Canonicalize an article URL for storage.
+///
+/// Drops the fragment and tracking query parameters. Scheme and host are
+/// already lowercased (and default ports removed) by [`Url`] parsing. The
+/// remaining query keeps its order, since some sites are order-sensitive.
+pub fn canonicalize_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    url.set_fragment(None);
+
+    // Only rewrite the query when something is dropped, to keep its encoding intact
+    if url.query_pairs().any(|(name, _)| is_tracking_param(&name)) {
+        let kept: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(name, _)| !is_tracking_param(name))
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        if kept.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(kept);
+        }
+    }
+    url
+}
+
+/// Duplicate-detection key for an article URL.
+///
+/// Stricter than [`canonicalize_url`]: ignores the scheme, a leading `www.`,
+/// a trailing slash and query parameter order, so `http://www.example.com/a/`
+/// and `https://example.com/a` share a key. Returns `None` for unparseable URLs.
+pub fn url_dedup_key(url: &str) -> Option<String> {
+    let url = canonicalize_url(&Url::parse(url).ok()?);
+    let host = url.host_str()?;
+    let host = host.strip_prefix("www.").unwrap_or(host);
+
+    let mut key = String::with_capacity(url.as_str().len());
+    key.push_str(host);
+    if let Some(port) = url.port() {
+        let _ = write!(key, ":{}", port);
+    }
+    key.push_str(url.path().trim_end_matches('/'));
+
+    let mut params: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+    if !params.is_empty() {
+        params.sort();
+        key.push('?');
+        for (i, (name, value)) in params.iter().enumerate() {
+            if i > 0 {
+                key.push('&');
+            }
+            let _ = write!(key, "{}={}", name, value);
+        }
+    }
+    Some(key)
+}
+
+/// Hash of a normalized article title, for matching reposts with different URLs.
+///
+/// Case, punctuation and whitespace are ignored. Returns `None` for titles too
+/// short to be distinctive.
+pub fn title_hash(title: &str) -> Option<String> {
+    let normalized = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ");
+    if normalized.chars().count() < MIN_TITLE_KEY_LEN {
+        return None;
+    }
+
+    let hash = Sha256::digest(normalized.as_bytes());
+    // 64 bits is plenty to tell titles apart within one library
+    let mut hex = String::with_capacity(16);
+    for byte in &hash[..8] {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    Some(hex)
+}
+
+/// Absolute form of a link found in an article, resolving relative links
+/// (`/about`, `../post`) against the page they appear on.
+///
+/// Returns `None` when the link can't be parsed, or is relative and there
+/// is no usable base.
+pub fn resolve_link(base: Option<&str>, href: &str) -> Option<String> {
+    match Url::parse(href) {
+        Ok(url) => Some(url.into()),
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            let base = Url::parse(base?).ok()?;
+            base.join(href).ok().map(String::from)
+        }
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canon(s: &str) -> String {
+        canonicalize_url(&Url::parse(s).unwrap()).to_string()
+    }
+
+    #[test]
+    fn test_canonicalize_strips_tracking_and_fragment() {
+        assert_eq!(
+            canon("HTTPS://Example.COM:443/post?utm_source=rss&id=7&fbclid=x#comments"),
+            "https://example.com/post?id=7"
+        );
+        assert_eq!(
+            canon("https://example.com/post?utm_medium=feed"),
+            "https://example.com/post"
+        );
+        // Non-tracking parameters keep their order
+        assert_eq!(
+            canon("https://example.com/?b=2&a=1"),
+            "https://example.com/?b=2&a=1"
+        );
+        // `ref` often selects content (a git branch, an API version)
+        assert_eq!(
+            canon("https://gitlab.com/group/repo/-/blob/file.rs?ref=main&ref_src=twsrc"),
+            "https://gitlab.com/group/repo/-/blob/file.rs?ref=main"
+        );
+    }
+
+    #[test]
+    fn test_dedup_key_ignores_scheme_www_and_slash() {
+        let a = url_dedup_key("http://www.example.com/a/?y=2&x=1&utm_campaign=z").unwrap();
+        let b = url_dedup_key("https://example.com/a?x=1&y=2").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(b, "example.com/a?x=1&y=2");
+
+        assert_ne!(
+            url_dedup_key("https://example.com/a").unwrap(),
+            url_dedup_key("https://example.com/b").unwrap()
+        );
+        assert_eq!(url_dedup_key("not a url"), None);
+    }
+
+    #[test]
+    fn test_title_hash_normalizes() {
+        let a = title_hash("Rust 2024: What's New in the Edition?").unwrap();
+        let b = title_hash("rust 2024 -- what s new in the edition").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 16);
+        assert_ne!(Some(a), title_hash("Rust 2021: What's New in the Edition?"));
+
+        // Too short to be distinctive
+        assert_eq!(title_hash("Weekly links"), None);
+    }
+
+    #[test]
+    fn test_resolve_link() {
+        let base = Some("https://example.com/blog/post.html");
+        assert_eq!(
+            resolve_link(base, "https://other.org/x").as_deref(),
+            Some("https://other.org/x")
+        );
+        assert_eq!(
+            resolve_link(base, "/about").as_deref(),
+            Some("https://example.com/about")
+        );
+        assert_eq!(
+            resolve_link(base, "next.html#top").as_deref(),
+            Some("https://example.com/blog/next.html#top")
+        );
+        assert_eq!(resolve_link(None, "/about"), None);
+        assert_eq!(resolve_link(Some("not a url"), "/about"), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! Small LZ77 codec for cached article bodies.
+//!
+//! Markdown is highly repetitive (link syntax, markup, boilerplate), so a
+//! greedy byte-level LZ77 pass typically halves it without pulling in a
+//! compression dependency.
+//!
+//! Format: a version byte, the decompressed length as a LEB128 varint, then
+//! sequences of `literal length, literals, match offset, match length - 4`
+//! (all lengths and offsets varints). The final sequence has literals only.
+
+/// Format version byte at the start of every compressed buffer.
+const VERSION: u8 = 1;
+
+/// Shortest back-reference worth encoding.
+const MIN_MATCH: usize = 4;
+
+/// Furthest back a match may point.
+const MAX_OFFSET: usize = 65_535;
+
+/// Bits of the match-finder hash table (16K entries).
+const HASH_BITS: u32 = 14;
+
+/// Largest decompressed size accepted, guarding against corrupt length headers.
+const MAX_DECOMPRESSED: usize = 64 * 1024 * 1024;
+
+/// Compress `input`. Never fails; incompressible input grows by a few bytes.
+///
+/// # Examples
+///
+/// ```
+/// use skim::util::{compress, decompress};
+///
+/// let text = "# Title\n\n".repeat(100);
+/// let packed = compress(text.as_bytes());
+/// assert!(packed.len() < text.len() / 4);
+/// assert_eq!(decompress(&packed).unwrap(), text.as_bytes());
+/// ```
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len() / 2 + 16);
+    out.push(VERSION);
+    write_varint(&mut out, input.len());
+
+    let mut table = vec![0usize; 1 << HASH_BITS]; // position + 1; 0 = empty
+    let mut anchor = 0;
+    let mut i = 0;
+    while i + MIN_MATCH <= input.len() {
+        let h = hash(&input[i..]);
+        let candidate = table[h].checked_sub(1);
+        table[h] = i + 1;
+
+        let Some(start) = candidate
+            .filter(|&c| i - c <= MAX_OFFSET && input[c..c + MIN_MATCH] == input[i..i + MIN_MATCH])
+        else {
+            i += 1;
+            continue;
+        };
+
+        let mut len = MIN_MATCH;
+        while i + len < input.len() && input[start + len] == input[i + len] {
+            len += 1;
+        }
+        write_varint(&mut out, i - anchor);
+        out.extend_from_slice(&input[anchor..i]);
+        write_varint(&mut out, i - start);
+        write_varint(&mut out, len - MIN_MATCH);
+
+        // Index positions inside the match so later text can refer to them
+        let end = i + len;
+        for p in (i + 1)..end.min(input.len().saturating_sub(MIN_MATCH - 1)) {
+            table[hash(&input[p..])] = p + 1;
+        }
+        i = end;
+        anchor = end;
+    }
+    write_varint(&mut out, input.len() - anchor);
+    out.extend_from_slice(&input[anchor..]);
+    out
+}
+
+/// Decompress a buffer produced by [`compress`].
+///
+/// Returns `None` for truncated or corrupt input.
+pub fn decompress(input: &[u8]) -> Option<Vec<u8>> {
+    let (&version, mut rest) = input.split_first()?;
+    if version != VERSION {
+        return None;
+    }
+    let len = read_varint(&mut rest)?;
+    if len > MAX_DECOMPRESSED {
+        return None;
+    }
+    let mut out = Vec::with_capacity(len.min(input.len().saturating_mul(16)));
+    loop {
+        let literals = read_varint(&mut rest)?;
+        if literals > rest.len() || out.len() + literals > len {
+            return None;
+        }
+        let (bytes, tail) = rest.split_at(literals);
+        out.extend_from_slice(bytes);
+        rest = tail;
+        if out.len() == len {
+            return rest.is_empty().then_some(out);
+        }
+
+        let offset = read_varint(&mut rest)?;
+        let match_len = read_varint(&mut rest)?.checked_add(MIN_MATCH)?;
+        if offset == 0 || offset > out.len() || out.len() + match_len > len {
+            return None;
+        }
+        // Byte by byte: a match may overlap the bytes it produces
+        let start = out.len() - offset;
+        for k in 0..match_len {
+            out.push(out[start + k]);
+        }
+    }
+}
+
+fn hash(bytes: &[u8]) -> usize {
+    let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    (word.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(input: &mut &[u8]) -> Option<usize> {
+    let mut value = 0usize;
+    for shift in (0..usize::BITS).step_by(7) {
+        let (&byte, rest) = input.split_first()?;
+        *input = rest;
+        value |= usize::from(byte & 0x7f).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_roundtrip_and_ratio() {
+        let markdown =
+            "## Section\n\nSee [the docs](https://example.com/docs) for details.\n\n".repeat(200);
+        let packed = compress(markdown.as_bytes());
+        assert!(packed.len() * 10 < markdown.len(), "{} bytes", packed.len());
+        assert_eq!(decompress(&packed).unwrap(), markdown.as_bytes());
+
+        for input in [
+            &b""[..],
+            b"abc",
+            b"aaaaaaaaaaaaaaaaaaaaaaaaa",
+            "héllo wörld".as_bytes(),
+        ] {
+            assert_eq!(decompress(&compress(input)).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_corrupt_input_rejected() {
+        let packed = compress(b"hello hello hello hello");
+        assert_eq!(decompress(&[]), None);
+        assert_eq!(decompress(&packed[..packed.len() - 1]), None);
+        let mut wrong_version = packed.clone();
+        wrong_version[0] = 9;
+        assert_eq!(decompress(&wrong_version), None);
+        // Match pointing before the start of the output
+        assert_eq!(decompress(&[VERSION, 8, 0, 5, 4]), None);
+        // Length header beyond the cap
+        assert_eq!(decompress(&[VERSION, 0xff, 0xff, 0xff, 0xff, 0x7f]), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip(input in proptest::collection::vec(0u8..4, 0..2000)) {
+            prop_assert_eq!(decompress(&compress(&input)).unwrap(), input);
+        }
+
+        #[test]
+        fn prop_decompress_never_panics(input in proptest::collection::vec(any::<u8>(), 0..200)) {
+            let _ = decompress(&input);
+        }
+    }
+}
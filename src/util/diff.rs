@@ -0,0 +1,152 @@
+//! Line-based diff between two versions of a text.
+//!
+//! Used by the reader to show what a publisher changed when an article is
+//! edited after it was first fetched.
+
+/// One line of a diff, borrowing from the compared texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    /// Present in both versions
+    Same(&'a str),
+    /// Only in the old version
+    Removed(&'a str),
+    /// Only in the new version
+    Added(&'a str),
+}
+
+/// Beyond this many cells the LCS table is skipped and the changed middle is
+/// shown as a plain remove-then-add block.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// Diff `old` against `new` line by line.
+///
+/// Common leading and trailing lines are matched first; the changed middle is
+/// aligned by longest common subsequence.
+///
+/// # Examples
+///
+/// ```
+/// use skim::util::{line_diff, DiffLine};
+///
+/// let diff = line_diff("a\nb\nc", "a\nx\nc");
+/// assert_eq!(
+///     diff,
+///     vec![
+///         DiffLine::Same("a"),
+///         DiffLine::Removed("b"),
+///         DiffLine::Added("x"),
+///         DiffLine::Same("c"),
+///     ]
+/// );
+/// ```
+pub fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    diff.extend(old[..prefix].iter().map(|l| DiffLine::Same(l)));
+
+    if old_mid.len().saturating_mul(new_mid.len()) > MAX_LCS_CELLS {
+        diff.extend(old_mid.iter().map(|l| DiffLine::Removed(l)));
+        diff.extend(new_mid.iter().map(|l| DiffLine::Added(l)));
+    } else {
+        lcs_diff(old_mid, new_mid, &mut diff);
+    }
+
+    diff.extend(old[old.len() - suffix..].iter().map(|l| DiffLine::Same(l)));
+    diff
+}
+
+/// Append the LCS alignment of `old` and `new` to `out`.
+fn lcs_diff<'a>(old: &[&'a str], new: &[&'a str], out: &mut Vec<DiffLine<'a>>) {
+    let (n, m) = (old.len(), new.len());
+    // lengths[i * (m + 1) + j] = LCS length of old[i..] and new[j..]
+    let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i * (m + 1) + j] = if old[i] == new[j] {
+                lengths[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lengths[(i + 1) * (m + 1) + j].max(lengths[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            out.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * (m + 1) + j] >= lengths[i * (m + 1) + j + 1] {
+            out.push(DiffLine::Removed(old[i]));
+            i += 1;
+        } else {
+            out.push(DiffLine::Added(new[j]));
+            j += 1;
+        }
+    }
+    out.extend(old[i..].iter().map(|l| DiffLine::Removed(l)));
+    out.extend(new[j..].iter().map(|l| DiffLine::Added(l)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DiffLine::{Added, Removed, Same};
+
+    #[test]
+    fn test_identical_texts() {
+        assert_eq!(line_diff("a\nb", "a\nb"), vec![Same("a"), Same("b")]);
+    }
+
+    #[test]
+    fn test_empty_texts() {
+        assert!(line_diff("", "").is_empty());
+        assert_eq!(line_diff("", "a"), vec![Added("a")]);
+        assert_eq!(line_diff("a", ""), vec![Removed("a")]);
+    }
+
+    #[test]
+    fn test_inserted_and_removed_lines() {
+        assert_eq!(
+            line_diff("a\nb\nc\nd", "a\nc\nd\ne"),
+            vec![Same("a"), Removed("b"), Same("c"), Same("d"), Added("e")]
+        );
+    }
+
+    #[test]
+    fn test_changed_middle_aligned() {
+        assert_eq!(
+            line_diff("head\nx\nkeep\ny\ntail", "head\nkeep\nz\ntail"),
+            vec![
+                Same("head"),
+                Removed("x"),
+                Same("keep"),
+                Removed("y"),
+                Added("z"),
+                Same("tail"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_large_change_falls_back_to_block() {
+        let old: String = (0..2100).map(|i| format!("old {i}\n")).collect();
+        let new: String = (0..2100).map(|i| format!("new {i}\n")).collect();
+        let diff = line_diff(&old, &new);
+        assert_eq!(diff.len(), 4200);
+        assert_eq!(diff[0], Removed("old 0"));
+        assert_eq!(diff[2100], Added("new 0"));
+    }
+}
@@ -3,6 +3,13 @@
 //! This module provides reusable utilities for:
 //!
 //! - **URL validation**: Security-focused validation to prevent SSRF attacks
+//! - **URL canonicalization**: Tracking-parameter stripping, duplicate-detection keys
+//!   and resolving relative links
+//! - **Line diffs**: Comparing revisions of an edited article
+//! - **Compression**: Small LZ77 codec for cached article bodies
+//! - **Power**: Battery detection for deferring background downloads
+//! - **Summaries**: Extractive TL;DR of article markdown
+//! - **Terminal**: Hyperlink support detection and safe OSC 8 link targets
 //! - **Text processing**: Unicode-aware string width calculation and truncation
 //!
 //! # Examples
@@ -20,10 +27,25 @@
 //! let truncated = truncate_to_width("Long article title", 15);
 //! ```
 
+mod canonical;
+mod compress;
+mod diff;
+mod power;
+mod summary;
+mod terminal;
 mod text;
 mod url_validator;
 
-pub use text::{display_width, strip_control_chars, truncate_to_width};
+pub use canonical::{canonicalize_url, resolve_link, title_hash, url_dedup_key};
+pub use compress::{compress, decompress};
+pub use diff::{line_diff, DiffLine};
+pub use power::on_battery;
+pub use summary::summarize;
+pub use terminal::{hyperlink_target, supports_hyperlinks};
+pub use text::{
+    display_width, normalize_tag, reading_minutes, strip_control_chars, truncate_to_width,
+    word_count, MAX_TAG_LENGTH, WORDS_PER_MINUTE,
+};
 pub use url_validator::{validate_url, validate_url_for_open};
 
 /// Maximum allowed search query length — shared across UI validation and FTS5 validation layers
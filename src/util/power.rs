@@ -0,0 +1,73 @@
+//! Power source detection for background work that should wait for AC power.
+
+use std::path::Path;
+
+/// Whether the machine is running on battery.
+///
+/// Reads `/sys/class/power_supply` on Linux. Elsewhere, or when nothing can
+/// be read, this returns `false` so callers never stall on unknown hardware.
+pub fn on_battery() -> bool {
+    cfg!(target_os = "linux") && on_battery_at(Path::new("/sys/class/power_supply"))
+}
+
+/// Battery check against a `power_supply` class directory.
+///
+/// A connected mains adapter wins; otherwise any discharging battery counts.
+fn on_battery_at(root: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return false;
+    };
+    let read = |dir: &Path, name: &str| {
+        std::fs::read_to_string(dir.join(name))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut discharging = false;
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        match read(&dir, "type").as_str() {
+            "Mains" if read(&dir, "online") == "1" => return false,
+            "Battery" if read(&dir, "status") == "Discharging" => discharging = true,
+            _ => {}
+        }
+    }
+    discharging
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supply(root: &Path, name: &str, files: &[(&str, &str)]) {
+        let dir = root.join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (file, value) in files {
+            std::fs::write(dir.join(file), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_on_battery_at() {
+        let root = std::env::temp_dir().join("skim_power_test");
+        std::fs::remove_dir_all(&root).ok();
+
+        // No power_supply directory (desktop without sysfs entries, other OS)
+        assert!(!on_battery_at(&root));
+
+        supply(
+            &root,
+            "BAT0",
+            &[("type", "Battery"), ("status", "Discharging")],
+        );
+        assert!(on_battery_at(&root));
+
+        supply(&root, "AC", &[("type", "Mains"), ("online", "0")]);
+        assert!(on_battery_at(&root));
+
+        supply(&root, "AC", &[("type", "Mains"), ("online", "1")]);
+        assert!(!on_battery_at(&root));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
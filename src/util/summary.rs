@@ -0,0 +1,316 @@
+//! Extractive summaries ("TL;DR") of article markdown.
+//!
+//! Sentences are ranked with TextRank: each one is a TF-IDF vector, sentences
+//! are linked by cosine similarity, and PageRank over that graph favors the
+//! sentences that share the most vocabulary with the rest of the article.
+//! The best few are returned in reading order. Everything runs locally.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::collections::HashMap;
+
+use super::strip_control_chars;
+
+/// Sentences an article needs before it gets a summary.
+const MIN_SENTENCES: usize = 6;
+
+/// Sentences ranked at most; later ones are ignored (ranking is quadratic).
+const MAX_SENTENCES: usize = 400;
+
+/// Words a sentence needs to be picked, and the most it may have.
+const MIN_SENTENCE_WORDS: usize = 6;
+const MAX_SENTENCE_WORDS: usize = 60;
+
+/// PageRank damping factor, iteration cap and convergence threshold.
+const DAMPING: f64 = 0.85;
+const MAX_ITERATIONS: usize = 50;
+const TOLERANCE: f64 = 1e-6;
+
+/// Words too common to say anything about a sentence.
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at", "be",
+    "because", "been", "before", "being", "but", "by", "can", "could", "did", "do", "does", "for",
+    "from", "had", "has", "have", "he", "her", "here", "him", "his", "how", "i", "if", "in",
+    "into", "is", "it", "its", "just", "me", "more", "most", "my", "no", "not", "now", "of", "on",
+    "one", "only", "or", "other", "our", "out", "over", "said", "she", "so", "some", "than",
+    "that", "the", "their", "them", "then", "there", "these", "they", "this", "those", "to", "too",
+    "up", "us", "very", "was", "we", "were", "what", "when", "where", "which", "while", "who",
+    "why", "will", "with", "would", "you", "your",
+];
+
+/// Abbreviations whose period doesn't end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "st", "vs", "etc", "e.g", "i.e", "inc", "ltd", "jr", "sr",
+    "no", "fig", "approx", "u.s",
+];
+
+/// Key sentences of `markdown`, in the order they appear.
+///
+/// Three for most articles, up to five for long ones, and none for articles
+/// too short to need a summary. Only paragraph and list text counts:
+/// headings, code blocks, tables and HTML are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use skim::util::summarize;
+///
+/// assert!(summarize("Too short to summarize.").is_empty());
+/// ```
+pub fn summarize(markdown: &str) -> Vec<String> {
+    let sentences: Vec<String> = prose_blocks(markdown)
+        .iter()
+        .flat_map(|block| split_sentences(block))
+        .take(MAX_SENTENCES)
+        .collect();
+    if sentences.len() < MIN_SENTENCES {
+        return Vec::new();
+    }
+    let count = (sentences.len() / 10).clamp(3, 5);
+
+    let terms: Vec<Vec<String>> = sentences.iter().map(|s| terms(s)).collect();
+    let ranks = text_rank(&tf_idf(&terms));
+
+    let mut picked: Vec<usize> = (0..sentences.len())
+        .filter(|&i| {
+            let words = sentences[i].split_whitespace().count();
+            (MIN_SENTENCE_WORDS..=MAX_SENTENCE_WORDS).contains(&words)
+        })
+        .collect();
+    picked.sort_by(|&a, &b| ranks[b].total_cmp(&ranks[a]).then(a.cmp(&b)));
+    picked.truncate(count);
+    picked.sort_unstable();
+    picked.into_iter().map(|i| sentences[i].clone()).collect()
+}
+
+/// Text of each paragraph and list item, with inline markup dropped.
+fn prose_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current = String::new();
+    // Inside something whose text isn't prose (heading, code, table, image, note)
+    let mut skipped = 0usize;
+    let mut flush = |current: &mut String| {
+        let text = current.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !text.is_empty() {
+            blocks.push(strip_control_chars(&text).into_owned());
+        }
+        current.clear();
+    };
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES | Options::ENABLE_STRIKETHROUGH;
+    for event in Parser::new_ext(markdown, options) {
+        match event {
+            Event::Start(
+                Tag::Heading { .. }
+                | Tag::CodeBlock(_)
+                | Tag::Table(_)
+                | Tag::Image { .. }
+                | Tag::FootnoteDefinition(_),
+            ) => skipped += 1,
+            Event::End(
+                TagEnd::Heading(_)
+                | TagEnd::CodeBlock
+                | TagEnd::Table
+                | TagEnd::Image
+                | TagEnd::FootnoteDefinition,
+            ) => skipped = skipped.saturating_sub(1),
+            Event::Start(Tag::Paragraph | Tag::Item)
+            | Event::End(TagEnd::Paragraph | TagEnd::Item) => flush(&mut current),
+            Event::Text(text) | Event::Code(text) if skipped == 0 => current.push_str(&text),
+            Event::SoftBreak | Event::HardBreak if skipped == 0 => current.push(' '),
+            _ => {}
+        }
+    }
+    flush(&mut current);
+    blocks
+}
+
+/// Split a paragraph into sentences at `.`, `!` or `?` followed by a space,
+/// unless the next word is lowercase or the period ends an abbreviation or
+/// an initial.
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if !matches!(c, '.' | '!' | '?') {
+            continue;
+        }
+        // Closing quotes and brackets belong to the sentence
+        let mut end = i + c.len_utf8();
+        while let Some(&(j, next)) = chars.peek() {
+            if !matches!(next, '"' | '\'' | ')' | '”' | '’' | '.' | '!' | '?') {
+                break;
+            }
+            end = j + next.len_utf8();
+            chars.next();
+        }
+        if chars.peek().is_none_or(|&(_, next)| next != ' ') {
+            continue;
+        }
+        if text[end..].trim_start().starts_with(char::is_lowercase) {
+            continue;
+        }
+        if c == '.' {
+            let word = text[start..i].rsplit(' ').next().unwrap_or_default();
+            let word = word.trim_start_matches(|c: char| !c.is_alphanumeric());
+            let initial = word.chars().count() == 1 && word.chars().all(char::is_uppercase);
+            if initial || ABBREVIATIONS.contains(&word.to_lowercase().as_str()) {
+                continue;
+            }
+        }
+        let sentence = text[start..end].trim();
+        if !sentence.is_empty() {
+            sentences.push(sentence.to_string());
+        }
+        start = end;
+    }
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest.to_string());
+    }
+    sentences
+}
+
+/// Lowercased words of a sentence that aren't stop words or numbers.
+fn terms(sentence: &str) -> Vec<String> {
+    sentence
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() > 1 && !w.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_lowercase)
+        .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Unit-length TF-IDF vector of each sentence, sentences being the documents.
+fn tf_idf(sentences: &[Vec<String>]) -> Vec<HashMap<&str, f64>> {
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for terms in sentences {
+        let mut seen: Vec<&str> = terms.iter().map(String::as_str).collect();
+        seen.sort_unstable();
+        seen.dedup();
+        for term in seen {
+            *document_frequency.entry(term).or_default() += 1;
+        }
+    }
+    let n = sentences.len() as f64;
+    sentences
+        .iter()
+        .map(|terms| {
+            let mut vector: HashMap<&str, f64> = HashMap::new();
+            for term in terms {
+                *vector.entry(term.as_str()).or_default() += 1.0;
+            }
+            for (term, weight) in vector.iter_mut() {
+                let df = document_frequency[term] as f64;
+                *weight *= ((1.0 + n) / (1.0 + df)).ln() + 1.0;
+            }
+            let norm = vector.values().map(|w| w * w).sum::<f64>().sqrt();
+            if norm > 0.0 {
+                vector.values_mut().for_each(|w| *w /= norm);
+            }
+            vector
+        })
+        .collect()
+}
+
+/// PageRank of each sentence over the graph of cosine similarities.
+fn text_rank(vectors: &[HashMap<&str, f64>]) -> Vec<f64> {
+    let n = vectors.len();
+    let mut similarity = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let (small, large) = if vectors[i].len() <= vectors[j].len() {
+                (&vectors[i], &vectors[j])
+            } else {
+                (&vectors[j], &vectors[i])
+            };
+            let cosine: f64 = small
+                .iter()
+                .filter_map(|(term, w)| large.get(term).map(|v| w * v))
+                .sum();
+            similarity[i][j] = cosine;
+            similarity[j][i] = cosine;
+        }
+    }
+    let out_weight: Vec<f64> = similarity.iter().map(|row| row.iter().sum()).collect();
+
+    let mut ranks = vec![1.0 / n as f64; n];
+    for _ in 0..MAX_ITERATIONS {
+        let next: Vec<f64> = (0..n)
+            .map(|i| {
+                let incoming: f64 = (0..n)
+                    .filter(|&j| out_weight[j] > 0.0)
+                    .map(|j| similarity[j][i] / out_weight[j] * ranks[j])
+                    .sum();
+                (1.0 - DAMPING) / n as f64 + DAMPING * incoming
+            })
+            .collect();
+        let change: f64 = next.iter().zip(&ranks).map(|(a, b)| (a - b).abs()).sum();
+        ranks = next;
+        if change < TOLERANCE {
+            break;
+        }
+    }
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("Dr. Smith met J. Doe at 3.5 km. \"Really?\" he asked! (Yes.) End"),
+            vec![
+                "Dr. Smith met J. Doe at 3.5 km.",
+                "\"Really?\" he asked!",
+                "(Yes.)",
+                "End"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prose_blocks_skip_code_and_headings() {
+        let markdown = "# Title\n\nSome *styled* text\nwrapped.\n\n```rust\nlet x = 1;\n```\n\n- An `item`\n\n| a | b |\n|---|---|\n| c | d |";
+        assert_eq!(
+            prose_blocks(markdown),
+            vec!["Some styled text wrapped.", "An item"]
+        );
+    }
+
+    #[test]
+    fn test_summarize_picks_central_sentences() {
+        let markdown = "\
+The city council approved the new budget for public transport on Monday evening.
+
+The budget adds twelve new bus routes and extends the tram network to the airport.
+
+Several residents attended the meeting, which ran late into the night.
+
+Transport officials said the new bus routes and the tram extension will open next year.
+
+The weather was mild and many people walked home afterwards.
+
+Critics argue the public transport budget leaves cycling infrastructure underfunded.
+
+A local bakery handed out free pastries to everyone in the queue outside.
+
+The council will review the transport budget again after the first year of service.";
+        let summary = summarize(markdown);
+        assert_eq!(summary.len(), 3);
+        assert!(summary
+            .iter()
+            .all(|s| !s.contains("weather") && !s.contains("bakery")));
+        // In reading order
+        let positions: Vec<usize> = summary.iter().map(|s| markdown.find(s).unwrap()).collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_summarize_short_article() {
+        assert!(summarize("One sentence here. And another one.").is_empty());
+        assert!(summarize("").is_empty());
+    }
+}
@@ -0,0 +1,112 @@
+//! Terminal capability detection and OSC 8 hyperlink targets.
+
+use super::validate_url_for_open;
+
+/// Whether the terminal likely renders OSC 8 hyperlinks.
+///
+/// There is no reliable query for this, so it goes by the environment the
+/// terminal sets. Multiplexers are treated as unsupported since they may
+/// drop or mangle the escape sequences.
+pub fn supports_hyperlinks() -> bool {
+    supports(|name| std::env::var(name).ok())
+}
+
+/// Hyperlink support check against an environment lookup.
+fn supports(env: impl Fn(&str) -> Option<String>) -> bool {
+    let set = |name: &str| env(name).is_some_and(|v| !v.is_empty());
+    let term = env("TERM").unwrap_or_default();
+
+    if set("TMUX") || set("STY") || term == "dumb" || term == "linux" {
+        return false;
+    }
+    if let Some(program) = env("TERM_PROGRAM") {
+        if matches!(
+            program.as_str(),
+            "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper" | "rio"
+        ) {
+            return true;
+        }
+    }
+    const MARKERS: &[&str] = &[
+        "KITTY_WINDOW_ID",
+        "WEZTERM_EXECUTABLE",
+        "WT_SESSION",
+        "KONSOLE_VERSION",
+        "ALACRITTY_WINDOW_ID",
+    ];
+    if MARKERS.iter().any(|name| set(name)) {
+        return true;
+    }
+    if ["kitty", "foot", "alacritty", "ghostty"]
+        .iter()
+        .any(|name| term.contains(name))
+    {
+        return true;
+    }
+    // GNOME Terminal and other VTE terminals since 0.50
+    env("VTE_VERSION")
+        .and_then(|v| v.parse::<u32>().ok())
+        .is_some_and(|v| v >= 5000)
+}
+
+/// URL to put in an OSC 8 hyperlink, or `None` if it isn't safe to emit.
+///
+/// The target must pass [`validate_url_for_open`], since clicking opens it
+/// just like the `o` key. It is re-serialized so the result is plain
+/// printable ASCII: nothing in it can end the escape sequence early.
+pub fn hyperlink_target(url: &str) -> Option<String> {
+    if url.chars().any(char::is_control) {
+        return None;
+    }
+    validate_url_for_open(url).ok()?;
+    let target = url::Url::parse(url).ok()?.to_string();
+    target
+        .bytes()
+        .all(|b| (0x21..=0x7e).contains(&b))
+        .then_some(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn test_supports() {
+        assert!(!supports(env(&[])));
+        assert!(!supports(env(&[("TERM", "xterm-256color")])));
+        assert!(supports(env(&[("TERM", "xterm-kitty")])));
+        assert!(supports(env(&[("TERM_PROGRAM", "WezTerm")])));
+        assert!(supports(env(&[("WT_SESSION", "abc")])));
+        assert!(supports(env(&[("VTE_VERSION", "6800")])));
+        assert!(!supports(env(&[("VTE_VERSION", "4200")])));
+        // Multiplexers win over the outer terminal
+        assert!(!supports(env(&[
+            ("TERM", "xterm-kitty"),
+            ("TMUX", "/tmp/t,1,0")
+        ])));
+    }
+
+    #[test]
+    fn test_hyperlink_target() {
+        assert_eq!(
+            hyperlink_target("https://example.com/a b").as_deref(),
+            Some("https://example.com/a%20b")
+        );
+        assert_eq!(
+            hyperlink_target("https://例え.jp/パス").as_deref(),
+            Some("https://xn--r8jz45g.jp/%E3%83%91%E3%82%B9")
+        );
+        assert!(hyperlink_target("https://example.com/\x1b\\").is_none());
+        assert!(hyperlink_target("https://example.com/\u{9c}").is_none());
+        assert!(hyperlink_target("javascript:alert(1)").is_none());
+        assert!(hyperlink_target("http://127.0.0.1/").is_none());
+    }
+}
@@ -239,6 +239,67 @@ pub fn strip_control_chars(s: &str) -> Cow<'_, str> {
     Cow::Owned(out)
 }
 
+/// Longest tag kept by [`normalize_tag`], in characters.
+pub const MAX_TAG_LENGTH: usize = 32;
+
+/// Normalize a user- or rule-supplied article tag.
+///
+/// Tags are lowercased, a leading `#` is dropped, whitespace runs become `-`
+/// (so a tag is one word in `tag:` search filters) and control characters
+/// are removed. Returns `None` when nothing is left.
+///
+/// # Examples
+///
+/// ```
+/// use skim::util::normalize_tag;
+///
+/// assert_eq!(normalize_tag(" #Read Later ").as_deref(), Some("read-later"));
+/// assert_eq!(normalize_tag("  "), None);
+/// ```
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = strip_control_chars(tag);
+    let tag = tag.trim().trim_start_matches('#');
+    let normalized: String = tag
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+        .chars()
+        .take(MAX_TAG_LENGTH)
+        .collect();
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+/// Reading speed assumed for time estimates, in words per minute.
+pub const WORDS_PER_MINUTE: usize = 230;
+
+/// Count the words in article text (markdown or plain).
+///
+/// A word is a whitespace-separated run containing at least one alphanumeric
+/// character, so markdown markers like `#`, `-` and `>` are not counted.
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Estimated minutes to read `words` words at `words_per_minute`, rounded
+/// up (0 only for no words).
+///
+/// # Examples
+///
+/// ```
+/// use skim::util::{reading_minutes, WORDS_PER_MINUTE};
+///
+/// assert_eq!(reading_minutes(0, WORDS_PER_MINUTE), 0);
+/// assert_eq!(reading_minutes(10, WORDS_PER_MINUTE), 1);
+/// assert_eq!(reading_minutes(1150, WORDS_PER_MINUTE), 5);
+/// assert_eq!(reading_minutes(1150, 400), 3);
+/// ```
+pub fn reading_minutes(words: usize, words_per_minute: usize) -> usize {
+    words.div_ceil(words_per_minute.max(1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -362,6 +423,21 @@ mod tests {
         assert_eq!(result, "deleteme");
     }
 
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag("Security").as_deref(), Some("security"));
+        assert_eq!(
+            normalize_tag("share  with\tteam").as_deref(),
+            Some("share-with-team")
+        );
+        assert_eq!(normalize_tag("#ref\x1b[31m").as_deref(), Some("ref"));
+        assert_eq!(normalize_tag("#"), None);
+        assert_eq!(
+            normalize_tag(&"x".repeat(40)).map(|t| t.len()),
+            Some(MAX_TAG_LENGTH)
+        );
+    }
+
     #[test]
     fn test_strip_ansi_color_codes() {
         // CSI SGR: \x1b[31m (red) and \x1b[0m (reset)
@@ -422,4 +498,10 @@ mod tests {
         let result = strip_control_chars(input);
         assert_eq!(result, "日本語 赤い テキスト");
     }
+
+    #[test]
+    fn test_word_count_skips_markup() {
+        assert_eq!(word_count(""), 0);
+        assert_eq!(word_count("# Title\n\n- one *two*\n> three --- 4"), 5);
+    }
 }
@@ -5,7 +5,7 @@
 //! content caching, retrieval, eviction, FTS5 indexing, and batch queries
 //! compose correctly.
 
-use skim::storage::{Database, OpmlFeed, ParsedArticle, SearchScope};
+use skim::storage::{CachePolicy, Database, OpmlFeed, ParsedArticle, SearchScope};
 
 async fn test_db() -> Database {
     Database::open(":memory:").await.unwrap()
@@ -26,6 +26,7 @@ fn test_article(guid: &str, title: &str) -> ParsedArticle {
         url: Some(format!("https://example.com/{guid}")),
         published: Some(1704067200),
         summary: Some(format!("Summary for {title}")),
+        author: None,
     }
 }
 
@@ -54,7 +55,7 @@ async fn test_cache_content_and_get() {
     let db = test_db().await;
     let (_feed_id, ids) = seed_articles(&db, 1).await;
 
-    db.cache_content(ids[0], "# Hello World", None)
+    db.cache_content(ids[0], "# Hello World", &CachePolicy::default())
         .await
         .unwrap();
 
@@ -70,8 +71,12 @@ async fn test_cache_replace_updates_content() {
     let db = test_db().await;
     let (_feed_id, ids) = seed_articles(&db, 1).await;
 
-    db.cache_content(ids[0], "original", None).await.unwrap();
-    db.cache_content(ids[0], "updated", None).await.unwrap();
+    db.cache_content(ids[0], "original", &CachePolicy::default())
+        .await
+        .unwrap();
+    db.cache_content(ids[0], "updated", &CachePolicy::default())
+        .await
+        .unwrap();
 
     let cached = db.get_cached_content(ids[0]).await.unwrap().unwrap();
     assert_eq!(cached.markdown, "updated");
@@ -108,9 +113,15 @@ async fn test_cache_stats_accuracy() {
     let db = test_db().await;
     let (_feed_id, ids) = seed_articles(&db, 3).await;
 
-    db.cache_content(ids[0], "aaa", None).await.unwrap();
-    db.cache_content(ids[1], "bbbbb", None).await.unwrap();
-    db.cache_content(ids[2], "c", None).await.unwrap();
+    db.cache_content(ids[0], "aaa", &CachePolicy::default())
+        .await
+        .unwrap();
+    db.cache_content(ids[1], "bbbbb", &CachePolicy::default())
+        .await
+        .unwrap();
+    db.cache_content(ids[2], "c", &CachePolicy::default())
+        .await
+        .unwrap();
 
     let stats = db.cache_stats().await.unwrap();
     assert_eq!(stats.total_entries, 3);
@@ -133,7 +144,9 @@ async fn test_prefetch_candidates_excludes_cached() {
     assert_eq!(candidates.len(), 3);
 
     // Cache one
-    db.cache_content(ids[0], "cached", None).await.unwrap();
+    db.cache_content(ids[0], "cached", &CachePolicy::default())
+        .await
+        .unwrap();
     let candidates = db.prefetch_candidates(10).await.unwrap();
     assert_eq!(candidates.len(), 2);
     assert!(!candidates.contains(&ids[0]));
@@ -215,8 +228,12 @@ async fn test_cached_article_ids_returns_subset() {
     let db = test_db().await;
     let (_feed_id, ids) = seed_articles(&db, 4).await;
 
-    db.cache_content(ids[0], "content0", None).await.unwrap();
-    db.cache_content(ids[2], "content2", None).await.unwrap();
+    db.cache_content(ids[0], "content0", &CachePolicy::default())
+        .await
+        .unwrap();
+    db.cache_content(ids[2], "content2", &CachePolicy::default())
+        .await
+        .unwrap();
 
     let cached = db.cached_article_ids(&ids).await.unwrap();
     assert_eq!(cached.len(), 2);
@@ -244,10 +261,12 @@ async fn test_cascade_delete_removes_cache() {
     let db = test_db().await;
     let (_feed_id, ids) = seed_articles(&db, 2).await;
 
-    db.cache_content(ids[0], "cached content", None)
+    db.cache_content(ids[0], "cached content", &CachePolicy::default())
+        .await
+        .unwrap();
+    db.cache_content(ids[1], "more cached", &CachePolicy::default())
         .await
         .unwrap();
-    db.cache_content(ids[1], "more cached", None).await.unwrap();
 
     let stats = db.cache_stats().await.unwrap();
     assert_eq!(stats.total_entries, 2);
@@ -314,7 +333,9 @@ async fn test_cache_and_index_workflow() {
     let markdown = "# Deep Learning Guide\n\nNeural networks explained step by step.";
 
     // Simulate TASK-4 workflow: cache + set_article_content + index
-    db.cache_content(ids[0], markdown, None).await.unwrap();
+    db.cache_content(ids[0], markdown, &CachePolicy::default())
+        .await
+        .unwrap();
     db.set_article_content(ids[0], markdown).await.unwrap();
     db.index_content(ids[0], markdown).await.unwrap();
 
@@ -341,8 +362,12 @@ async fn test_evict_expired_no_valid_entries_affected() {
     let (_feed_id, ids) = seed_articles(&db, 2).await;
 
     // Cache with default TTL (72h — valid)
-    db.cache_content(ids[0], "content0", None).await.unwrap();
-    db.cache_content(ids[1], "content1", None).await.unwrap();
+    db.cache_content(ids[0], "content0", &CachePolicy::default())
+        .await
+        .unwrap();
+    db.cache_content(ids[1], "content1", &CachePolicy::default())
+        .await
+        .unwrap();
 
     let evicted = db.evict_expired().await.unwrap();
     assert_eq!(evicted, 0, "No entries should be evicted with fresh cache");
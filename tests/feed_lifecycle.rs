@@ -17,6 +17,7 @@ fn test_parsed_article(guid: &str, title: &str) -> ParsedArticle {
         url: Some(format!("https://example.com/{}", guid)),
         published: Some(1700000000),
         summary: Some("Test summary".to_string()),
+        author: None,
     }
 }
 
@@ -165,8 +166,8 @@ async fn test_delete_feed_removes_articles() {
         test_parsed_article("guid2", "Article 2"),
         test_parsed_article("guid3", "Article 3"),
     ];
-    let inserted = db.upsert_articles(feed_id, &articles).await.unwrap();
-    assert_eq!(inserted, 3);
+    let counts = db.upsert_articles(feed_id, &articles).await.unwrap();
+    assert_eq!(counts.inserted, 3);
 
     // Verify articles exist
     let feed_articles = db.get_articles_for_feed(feed_id, None).await.unwrap();